@@ -0,0 +1,166 @@
+use axum::{
+    extract::{MatchedPath, Request, State},
+    middleware::Next,
+    response::Response,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Per-route access log sampling (see `REFLECT_LOG_SAMPLING`).
+///
+/// Parsed from `route=N` pairs separated by commas, e.g.
+/// `/health=100,/stablecoins/stablecoin/:index/apy=10`: one in every `N`
+/// successful requests to that route is logged. Routes are matched against
+/// the route pattern, falling back to the literal path. Unlisted routes are
+/// always logged.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogSampling(pub Vec<(String, u64)>);
+
+impl FromStr for LogSampling {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (route, every) = entry
+                    .rsplit_once('=')
+                    .ok_or_else(|| format!("Expected route=N, got '{}'", entry))?;
+                let every = every
+                    .trim()
+                    .parse::<u64>()
+                    .ok()
+                    .filter(|every| *every > 0)
+                    .ok_or_else(|| format!("Sample rate for '{}' must be a positive integer", route))?;
+                Ok((route.trim().to_string(), every))
+            })
+            .collect::<Result<_, _>>()
+            .map(LogSampling)
+    }
+}
+
+/// Sampling state shared by every request: one counter per sampled route.
+#[derive(Debug, Default)]
+pub struct AccessLog {
+    routes: HashMap<String, (u64, AtomicU64)>,
+}
+
+impl AccessLog {
+    pub fn new(sampling: &LogSampling) -> Self {
+        let routes = sampling
+            .0
+            .iter()
+            .map(|(route, every)| (route.clone(), (*every, AtomicU64::new(0))))
+            .collect();
+        Self { routes }
+    }
+
+    /// Whether a request to `route` that finished with `status` is logged.
+    ///
+    /// Errors (4xx and 5xx) are always logged; successes on a sampled route
+    /// are logged for the first request and every `N`th one after it.
+    pub fn should_log(&self, route: &str, status: u16) -> bool {
+        if status >= 400 {
+            return true;
+        }
+        match self.routes.get(route) {
+            Some((every, seen)) => seen.fetch_add(1, Ordering::Relaxed) % every == 0,
+            None => true,
+        }
+    }
+}
+
+/// Middleware writing one `reflect_api::access` line per request, down-sampled
+/// per route by [`AccessLog`].
+pub async fn log_requests(State(log): State<Arc<AccessLog>>, request: Request, next: Next) -> Response {
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or_else(|| path.clone(), |matched| matched.as_str().to_string());
+    let started = Instant::now();
+
+    let response = next.run(request).await;
+
+    let status = response.status().as_u16();
+    if log.should_log(&route, status) {
+        let latency_ms = started.elapsed().as_millis() as u64;
+        if response.status().is_server_error() {
+            tracing::error!(target: "reflect_api::access", %method, %path, status, latency_ms, "Request failed");
+        } else if response.status().is_client_error() {
+            tracing::warn!(target: "reflect_api::access", %method, %path, status, latency_ms, "Request rejected");
+        } else {
+            tracing::info!(target: "reflect_api::access", %method, %path, status, latency_ms, "Request completed");
+        }
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_route_rates() {
+        assert_eq!(
+            " /health=100 , /stats/stream=10 ".parse::<LogSampling>().unwrap(),
+            LogSampling(vec![("/health".to_string(), 100), ("/stats/stream".to_string(), 10)])
+        );
+        assert_eq!("".parse::<LogSampling>().unwrap(), LogSampling::default());
+        assert!("/health".parse::<LogSampling>().is_err());
+        assert!("/health=0".parse::<LogSampling>().is_err());
+    }
+
+    #[test]
+    fn errors_bypass_sampling() {
+        let log = AccessLog::new(&LogSampling(vec![("/health".to_string(), 10)]));
+
+        let logged = (0..30).filter(|_| log.should_log("/health", 200)).count();
+        assert_eq!(logged, 3);
+        assert!((0..30).all(|_| log.should_log("/health", 503)));
+        assert!((0..5).all(|_| log.should_log("/health", 404)));
+        assert!((0..5).all(|_| log.should_log("/stablecoins", 200)));
+    }
+
+    #[tokio::test]
+    async fn middleware_samples_matched_route() {
+        use axum::body::Body;
+        use axum::http::StatusCode;
+        use axum::routing::get;
+        use axum::{middleware, Router};
+        use tower::ServiceExt;
+
+        use crate::capture::Capture;
+
+        let (capture, _guard) = Capture::install();
+
+        let log = Arc::new(AccessLog::new(&LogSampling(vec![("/items/:id".to_string(), 5)])));
+        let app = Router::new()
+            .route(
+                "/items/:id",
+                get(|axum::extract::Path(id): axum::extract::Path<u32>| async move {
+                    if id == 0 { StatusCode::INTERNAL_SERVER_ERROR } else { StatusCode::OK }
+                }),
+            )
+            .layer(middleware::from_fn_with_state(log, log_requests));
+
+        for id in [1, 2, 3, 4, 5, 0, 6, 7, 8, 9, 10, 0] {
+            let request = Request::get(format!("/items/{}", id)).body(Body::empty()).unwrap();
+            app.clone().oneshot(request).await.unwrap();
+        }
+
+        // Successes 1 and 6 of 10 sampled; both failures logged
+        let statuses: Vec<String> = capture
+            .events()
+            .iter()
+            .filter(|event| event.target == "reflect_api::access")
+            .filter_map(|event| event.field("status").map(String::from))
+            .collect();
+        assert_eq!(statuses, ["200", "500", "200", "500"]);
+    }
+}
@@ -0,0 +1,287 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::BTreeMap;
+
+use crate::auth::AdminKey;
+use crate::error::ApiError;
+use crate::integration::store::IntegrationRecord;
+use crate::json_body::JsonBody;
+use crate::timestamp::Timestamp;
+use crate::validation::{clamp_param, sanitize_name, ClampMode};
+use crate::AppState;
+
+/// Version of the backup document written by [`export_integrations`].
+pub const BACKUP_VERSION: u32 = 1;
+
+/// Backup document for integration state.
+///
+/// Holds every integration (archived ones included) with its config and
+/// lifecycle timestamps, and the whitelisted users of each integration by id.
+/// `whitelists` may be omitted, meaning none. Uploaded metadata is not kept
+/// server-side, and API keys are never part of a backup, so a document
+/// contains no secrets.
+///
+/// ### Example
+/// ```json
+/// {
+///   "version": 1,
+///   "exported_at": "2026-01-01T00:00:00.000Z",
+///   "integrations": [
+///     {
+///       "id": "int_1",
+///       "name": "My Integration",
+///       "authority": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+///       "config": { "fee_percent": 0.5 },
+///       "created_at": "2025-12-18T17:46:10.274Z",
+///       "updated_at": "2025-12-18T17:46:10.274Z",
+///       "deleted_at": null
+///     }
+///   ],
+///   "whitelists": {
+///     "int_1": ["9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"]
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntegrationBackup {
+    pub version: u32,
+    pub exported_at: Timestamp,
+    pub integrations: Vec<IntegrationRecord>,
+    #[serde(default)]
+    pub whitelists: BTreeMap<String, Vec<String>>,
+}
+
+/// Handler for `GET /admin/export`.
+///
+/// Admin-only. Returns an [`IntegrationBackup`] of the current state, ready
+/// to be sent back to `POST /admin/import`.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request GET \
+///   --url http://localhost:3000/admin/export \
+///   --header 'x-api-key: <admin key>' > backup.json
+/// ```
+pub async fn export_integrations(_admin: AdminKey, State(state): State<AppState>) -> impl IntoResponse {
+    let backup = IntegrationBackup {
+        version: BACKUP_VERSION,
+        exported_at: Timestamp::now(),
+        integrations: state.integrations.export(),
+        whitelists: state.whitelists.export(),
+    };
+    (StatusCode::OK, Json(json!({ "success": true, "data": backup })))
+}
+
+/// Handler for `POST /admin/import`.
+///
+/// Admin-only. Replaces every integration and whitelist with the contents of
+/// an [`IntegrationBackup`]. The document is checked in full before anything
+/// is replaced: an unknown `version`, malformed or duplicate ids, blank
+/// names, a `fee_percent` outside 0..=100 (as for `/integrations/config/update`)
+/// or a whitelist for an integration not in the document are rejected with
+/// 422. Names are stored trimmed, as on creation.
+///
+/// Every issued API key is revoked: backups hold none, and a key left in
+/// place would act on whichever imported integration now has its id.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request POST \
+///   --url http://localhost:3000/admin/import \
+///   --header 'x-api-key: <admin key>' \
+///   --header 'Content-Type: application/json' \
+///   --data @backup.json
+/// ```
+///
+/// Expected output:
+/// ```json
+/// {
+///   "success": true,
+///   "data": { "imported": 1 }
+/// }
+/// ```
+pub async fn import_integrations(
+    _admin: AdminKey,
+    State(state): State<AppState>,
+    JsonBody(mut backup): JsonBody<IntegrationBackup>,
+) -> Result<impl IntoResponse, ApiError> {
+    if backup.version != BACKUP_VERSION {
+        return Err(ApiError::Unprocessable(format!(
+            "Unsupported backup version {}; expected {}",
+            backup.version, BACKUP_VERSION
+        )));
+    }
+    for record in &mut backup.integrations {
+        record.name = sanitize_name(&record.name)?;
+        clamp_param("fee_percent", record.config.fee_percent, 0.0, 100.0, ClampMode::Reject)?;
+    }
+
+    if let Some(id) = backup
+        .whitelists
+        .keys()
+        .find(|id| !backup.integrations.iter().any(|record| &record.id == *id))
+    {
+        return Err(ApiError::Unprocessable(format!("whitelist for unknown integration '{}'", id)));
+    }
+
+    let imported = state.integrations.import(backup.integrations).map_err(ApiError::Unprocessable)?;
+    state.api_keys.clear();
+    state.whitelists.replace(backup.whitelists);
+    tracing::info!(imported, "Imported integration backup");
+    Ok((StatusCode::OK, Json(json!({ "success": true, "data": { "imported": imported } }))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::Scope;
+    use crate::config::Config;
+    use crate::integration::api_keys::KeyOverlap;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    fn state() -> AppState {
+        AppState::new(Config {
+            admin_api_keys: vec!["admin-key".to_string()],
+            ..Config::default()
+        })
+    }
+
+    async fn send(state: &AppState, request: axum::http::request::Builder, body: Body) -> (StatusCode, Value) {
+        let app = super::super::router().with_state(state.clone());
+        let request = request
+            .header("x-api-key", "admin-key")
+            .header("content-type", "application/json")
+            .body(body)
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 1 << 20).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_export_import_round_trip() {
+        let source = state();
+        source.integrations.create("First".to_string(), "authority_a".to_string());
+        let archived = source.integrations.create("Second".to_string(), "authority_b".to_string());
+        source.integrations.archive(&archived.id);
+        source.whitelists.add("int_1", ["user_a".to_string()]);
+
+        let (status, exported) = send(&source, Request::get("/export"), Body::empty()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(exported["data"]["version"], 1);
+
+        let target = state();
+        let (status, json) = send(&target, Request::post("/import"), Body::from(exported["data"].to_string())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["imported"], 2);
+
+        // Compared as JSON: timestamps are kept to the millisecond they serialize with
+        assert_eq!(json!(target.integrations.export()), json!(source.integrations.export()));
+        assert_eq!(target.whitelists.get("int_1"), vec!["user_a"]);
+        // New integrations continue the imported numbering
+        assert_eq!(target.integrations.create("Third".to_string(), "c".to_string()).id, "int_3");
+    }
+
+    #[tokio::test]
+    async fn test_import_resets_keys_and_never_reuses_ids() {
+        let target = state();
+        for name in ["A", "B", "C"] {
+            target.integrations.create(name.to_string(), "authority".to_string());
+        }
+        target.api_keys.rotate("int_3", "rflk_", None, &Scope::ALL, KeyOverlap::NONE);
+        target.whitelists.add("int_3", ["user_a".to_string()]);
+
+        let backup = json!({
+            "version": 1,
+            "exported_at": "2026-01-01T00:00:00Z",
+            "integrations": [{
+                "id": "int_1", "name": "A", "authority": "x", "config": { "fee_percent": 0.5 },
+                "created_at": "2026-01-01T00:00:00Z", "updated_at": "2026-01-01T00:00:00Z", "deleted_at": null
+            }]
+        });
+        let (status, _) = send(&target, Request::post("/import"), Body::from(backup.to_string())).await;
+        assert_eq!(status, StatusCode::OK);
+
+        // The next integration does not take over int_3's keys or whitelist
+        let created = target.integrations.create("D".to_string(), "authority".to_string());
+        assert_eq!(created.id, "int_4");
+        assert!(target.api_keys.keys("int_3").is_empty());
+        assert!(target.whitelists.get("int_3").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_import_validates_shape() {
+        let target = state();
+        target.integrations.create("Keep me".to_string(), "authority".to_string());
+
+        let (status, json) = send(&target, Request::post("/import"), Body::from(r#"{"version": 1}"#)).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "field `exported_at` is required");
+
+        let record = json!({
+            "id": "int_1", "name": "A", "authority": "x", "config": { "fee_percent": 0.5 },
+            "created_at": "2026-01-01T00:00:00Z", "updated_at": "2026-01-01T00:00:00Z", "deleted_at": null
+        });
+        let duplicated = json!({ "version": 1, "exported_at": "2026-01-01T00:00:00Z", "integrations": [record, record] });
+        let (status, json) = send(&target, Request::post("/import"), Body::from(duplicated.to_string())).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "duplicate integration id 'int_1'");
+
+        let orphan = json!({ "version": 1, "exported_at": "2026-01-01T00:00:00Z", "integrations": [], "whitelists": { "int_9": ["a"] } });
+        let (status, json) = send(&target, Request::post("/import"), Body::from(orphan.to_string())).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "whitelist for unknown integration 'int_9'");
+
+        let mut overcharging = record.clone();
+        overcharging["config"]["fee_percent"] = json!(500.0);
+        let backup = json!({ "version": 1, "exported_at": "2026-01-01T00:00:00Z", "integrations": [overcharging] });
+        let (status, json) = send(&target, Request::post("/import"), Body::from(backup.to_string())).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid request data: fee_percent must be between 0 and 100");
+
+        let future = json!({ "version": 2, "exported_at": "2026-01-01T00:00:00Z", "integrations": [] });
+        let (status, _) = send(&target, Request::post("/import"), Body::from(future.to_string())).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        // Rejected imports leave the existing state alone
+        assert_eq!(target.integrations.export()[0].name, "Keep me");
+    }
+
+    #[tokio::test]
+    async fn test_import_stores_trimmed_names() {
+        let target = state();
+        let backup = json!({
+            "version": 1,
+            "exported_at": "2026-01-01T00:00:00Z",
+            "integrations": [{
+                "id": "int_1", "name": "  Acme  ", "authority": "x", "config": { "fee_percent": 0.5 },
+                "created_at": "2026-01-01T00:00:00Z", "updated_at": "2026-01-01T00:00:00Z", "deleted_at": null
+            }]
+        });
+        let (status, _) = send(&target, Request::post("/import"), Body::from(backup.to_string())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(target.integrations.export()[0].name, "Acme");
+    }
+
+    #[tokio::test]
+    async fn test_requires_admin_key() {
+        let app = super::super::router().with_state(AppState::default());
+        let response = app
+            .oneshot(Request::get("/export").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
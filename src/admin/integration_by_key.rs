@@ -0,0 +1,145 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::auth::AdminKey;
+use crate::error::ApiError;
+use crate::validation::malformed_field;
+use crate::AppState;
+
+/// Query parameters for `GET /admin/integrations/by-key`.
+///
+/// ### Fields
+/// - `fingerprint`: Hex SHA-256 of the API key (64 characters, any case).
+#[derive(Debug, Deserialize)]
+pub struct ByKeyQuery {
+    pub fingerprint: String,
+}
+
+/// Handler for `GET /admin/integrations/by-key?fingerprint=<sha256>`.
+///
+/// Admin-only. Finds the integration whose current API key has the given
+/// fingerprint, so support can identify an integration without ever
+/// handling the key itself. The response carries the integration and the
+/// key's metadata; the key is not stored and cannot be returned. Malformed
+/// fingerprints are `400`, unmatched ones `404`.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request GET \
+///   --url "http://localhost:3000/admin/integrations/by-key?fingerprint=9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08" \
+///   --header 'x-api-key: <admin key>'
+/// ```
+///
+/// Expected output:
+/// ```json
+/// {
+///   "success": true,
+///   "data": {
+///     "integration": { "id": "int_1", "name": "Acme", "authority": "auth_1", ... },
+///     "api_key": {
+///       "fingerprint": "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08",
+///       "created_at": "2025-12-18T17:46:10.274Z"
+///     }
+///   }
+/// }
+/// ```
+pub async fn get_integration_by_key(
+    _admin: AdminKey,
+    State(state): State<AppState>,
+    Query(query): Query<ByKeyQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let fingerprint = query.fingerprint.trim().to_ascii_lowercase();
+    if fingerprint.len() != 64 || !fingerprint.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return Err(malformed_field("fingerprint", "fingerprint must be a 64-character hex SHA-256 digest"));
+    }
+
+    let (integration, key) = state
+        .api_keys
+        .find_by_fingerprint(&fingerprint)
+        .and_then(|(id, key)| Some((state.integrations.get(&id)?, key)))
+        .ok_or_else(|| ApiError::NotFound("No integration holds a key with this fingerprint".to_string()))?;
+
+    Ok((
+        StatusCode::OK,
+        Json(json!({ "success": true, "data": { "integration": state.cased(integration), "api_key": state.cased(key) } })),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::auth::Scope;
+    use crate::integration::api_keys::{fingerprint, KeyOverlap, DEFAULT_API_KEY_PREFIX};
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::get;
+    use axum::Router;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    fn admin_state() -> AppState {
+        AppState::new(Config {
+            admin_api_keys: vec!["admin-key".to_string()],
+            ..Config::default()
+        })
+    }
+
+    async fn send(state: &AppState, key: Option<&str>, fingerprint: &str) -> (StatusCode, Value) {
+        let app = Router::new()
+            .route("/admin/integrations/by-key", get(get_integration_by_key))
+            .with_state(state.clone());
+
+        let mut request = Request::get(format!("/admin/integrations/by-key?fingerprint={}", fingerprint));
+        if let Some(key) = key {
+            request = request.header("x-api-key", key);
+        }
+        let response = app.oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_matching_fingerprint_finds_integration() {
+        let state = admin_state();
+        state.integrations.create("Other".into(), "auth_2".into());
+        let integration = state.integrations.create("Acme".into(), "auth_1".into());
+        state.api_keys.rotate("int_1", DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, KeyOverlap::NONE);
+        let (api_key, _) = state.api_keys.rotate(&integration.id, DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, KeyOverlap::NONE);
+
+        let (status, json) = send(&state, Some("admin-key"), &fingerprint(&api_key).to_uppercase()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["integration"]["id"], integration.id);
+        assert_eq!(json["data"]["integration"]["name"], "Acme");
+        assert_eq!(json["data"]["api_key"]["fingerprint"], fingerprint(&api_key));
+        assert!(!json.to_string().contains(&api_key));
+    }
+
+    #[tokio::test]
+    async fn test_unmatched_or_malformed_fingerprint() {
+        let state = admin_state();
+        let integration = state.integrations.create("Acme".into(), "auth_1".into());
+        let (old_key, _) = state.api_keys.rotate(&integration.id, DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, KeyOverlap::NONE);
+        state.api_keys.rotate(&integration.id, DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, KeyOverlap::NONE);
+
+        // A rotated-out key no longer matches
+        let (status, json) = send(&state, Some("admin-key"), &fingerprint(&old_key)).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(json["message"], "No integration holds a key with this fingerprint");
+
+        // The raw key is not accepted in place of its fingerprint
+        let (status, _) = send(&state, Some("admin-key"), &old_key).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = send(&state, None, &fingerprint(&old_key)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+}
@@ -0,0 +1,16 @@
+use axum::Router;
+use crate::AppState;
+
+pub mod backup;
+pub mod integration_by_key;
+pub mod recompute_stats;
+pub mod supply_caps;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/stats/recompute", axum::routing::post(recompute_stats::recompute_stats))
+        .route("/export", axum::routing::get(backup::export_integrations))
+        .route("/import", axum::routing::post(backup::import_integrations))
+        .route("/supply-caps", axum::routing::post(supply_caps::set_supply_cap))
+        .route("/integrations/by-key", axum::routing::get(integration_by_key::get_integration_by_key))
+}
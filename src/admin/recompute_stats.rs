@@ -0,0 +1,179 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde_json::json;
+
+use crate::auth::AdminKey;
+use crate::AppState;
+
+/// Handler for `POST /admin/stats/recompute`.
+///
+/// Admin-only. Rebuilds the stats from the event store, discarding whatever
+/// the running figures had drifted to: the volume ledger behind
+/// `GET /stats/protocol` and the mint / redeem counters behind
+/// `GET /integrations/stats`. Cached stats are dropped as well. Returns the
+/// recomputed counters. Every event recorded since startup is counted,
+/// including those already evicted from the recent-events buffer.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request POST \
+///   --url http://localhost:3000/admin/stats/recompute \
+///   --header 'x-api-key: <admin key>'
+/// ```
+///
+/// Expected output:
+/// ```json
+/// {
+///   "success": true,
+///   "data": {
+///     "protocol": { "mints": 3, "redeems": 1 },
+///     "integrations": { "int_1": { "mints": 2, "redeems": 1 } }
+///   }
+/// }
+/// ```
+pub async fn recompute_stats(_admin: AdminKey, State(state): State<AppState>) -> impl IntoResponse {
+    let before = (state.aggregates.snapshot(), state.ledger.totals());
+    // Both are rebuilt while no event is recorded, so they stay in step
+    let totals = state.aggregates.rebuild(|| {
+        state.ledger.rebuild(state.events.volumes());
+        state.events.totals()
+    });
+    state.stats_cache.clear();
+
+    let after = (totals.clone(), state.ledger.totals());
+    if after != before {
+        tracing::warn!(?before, ?after, "Recomputed stats had drifted");
+    }
+
+    (StatusCode::OK, Json(json!({ "success": true, "data": state.cased(totals) })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::events::store::{EventRecord, EVENT_BUFFER_CAPACITY};
+    use crate::timestamp::Timestamp;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::post;
+    use axum::Router;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    async fn send(state: &AppState, key: Option<&str>) -> (StatusCode, Value) {
+        let app = Router::new()
+            .route("/admin/stats/recompute", post(recompute_stats))
+            .with_state(state.clone());
+
+        let mut request = Request::post("/admin/stats/recompute");
+        if let Some(key) = key {
+            request = request.header("x-api-key", key);
+        }
+        let response = app.oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_recompute_corrects_drift() {
+        let state = AppState::new(Config {
+            admin_api_keys: vec!["admin-key".to_string()],
+            ..Config::default()
+        });
+        state.record_event("mint", "signer_a", Some("int_1"), None, Timestamp::now());
+        state.record_event("mint", "signer_b", None, None, Timestamp::now());
+        state.record_event("burn", "signer_a", Some("int_1"), None, Timestamp::now());
+
+        // Corrupt the counters with a mint that never reached the event store
+        state.aggregates.apply(&EventRecord {
+            id: "evt_ghost".to_string(),
+            event_type: "mint".to_string(),
+            timestamp: Timestamp::now(),
+            signer: "signer_a".to_string(),
+            integration_id: Some("int_1".to_string()),
+            volume: None,
+        });
+        assert_eq!(state.aggregates.snapshot().protocol.mints, 3);
+
+        let (status, json) = send(&state, Some("admin-key")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            json["data"],
+            json!({
+                "protocol": { "mints": 2, "redeems": 1 },
+                "integrations": { "int_1": { "mints": 1, "redeems": 1 } }
+            })
+        );
+        assert_eq!(state.aggregates.snapshot().protocol.mints, 2);
+    }
+
+    #[tokio::test]
+    async fn test_recompute_corrects_protocol_stats() {
+        use crate::build_app;
+        use crate::events::store::EventVolume;
+
+        let state = AppState::new(Config {
+            admin_api_keys: vec!["admin-key".to_string()],
+            ..Config::default()
+        });
+        let volume = |amount| Some(EventVolume { index: 0, amount });
+        state.record_event("mint", "signer_a", None, volume(1_000), Timestamp::now());
+        state.record_event("burn", "signer_a", None, volume(400), Timestamp::now());
+
+        // Corrupt the ledger with a mint that never reached the event store
+        state.ledger.apply(&EventRecord {
+            id: "evt_ghost".to_string(),
+            event_type: "mint".to_string(),
+            timestamp: Timestamp::now(),
+            signer: "signer_a".to_string(),
+            integration_id: None,
+            volume: volume(5_000),
+        });
+
+        let protocol_stats = || async {
+            let response = build_app(state.clone())
+                .oneshot(Request::get("/stats/protocol").body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+            serde_json::from_slice::<Value>(&bytes).unwrap()
+        };
+        assert_eq!(protocol_stats().await["total_minted"], 6_000);
+
+        let (status, _) = send(&state, Some("admin-key")).await;
+        assert_eq!(status, StatusCode::OK);
+        let json = protocol_stats().await;
+        assert_eq!(json["total_minted"], 1_000);
+        assert_eq!(json["total_redeemed"], 400);
+        assert_eq!(json["net_supply"], 600);
+    }
+
+    #[tokio::test]
+    async fn test_recompute_counts_evicted_events() {
+        let state = AppState::new(Config {
+            admin_api_keys: vec!["admin-key".to_string()],
+            ..Config::default()
+        });
+        for _ in 0..=EVENT_BUFFER_CAPACITY {
+            state.record_event("mint", "signer_a", None, None, Timestamp::now());
+        }
+
+        let (status, json) = send(&state, Some("admin-key")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["protocol"]["mints"], EVENT_BUFFER_CAPACITY as u64 + 1);
+    }
+
+    #[tokio::test]
+    async fn test_requires_admin_key() {
+        let (status, _) = send(&AppState::default(), None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+}
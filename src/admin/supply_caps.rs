@@ -0,0 +1,160 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::auth::AdminKey;
+use crate::error::ApiError;
+use crate::json_body::JsonBody;
+use crate::stablecoin::get_supply_caps::SupplyCap;
+use crate::stablecoin::supply::SetCapError;
+use crate::AppState;
+
+/// Request structure for `POST /admin/supply-caps`.
+///
+/// ### Example Request
+/// ```json
+/// { "index": 0, "supplyCap": 2000000000 }
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetSupplyCapRequest {
+    pub index: u32,
+    pub supply_cap: u64,
+}
+
+/// Handler for `POST /admin/supply-caps`.
+///
+/// Admin-only. Raises or lowers a stablecoin's supply cap without a redeploy;
+/// the new cap, remaining capacity and utilization show up in
+/// `GET /stablecoins/supply-caps` straight away. A cap below the current
+/// supply is rejected with `400`, an index without supply figures with `404`.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request POST \
+///   --url http://localhost:3000/admin/supply-caps \
+///   --header 'x-api-key: <admin key>' \
+///   --header 'Content-Type: application/json' \
+///   --data '{"index": 0, "supplyCap": 2000000000}'
+/// ```
+///
+/// Expected output:
+/// ```json
+/// {
+///   "success": true,
+///   "data": {
+///     "index": 0,
+///     "supplyCap": 2000000000,
+///     "currentSupply": 500000000,
+///     "remainingCapacity": 1500000000,
+///     "utilizationPercentage": 25
+///   }
+/// }
+/// ```
+pub async fn set_supply_cap(
+    _admin: AdminKey,
+    State(state): State<AppState>,
+    JsonBody(req): JsonBody<SetSupplyCapRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let supply = state.supplies.set_cap(req.index, req.supply_cap).map_err(|error| match error {
+        SetCapError::Unknown => ApiError::NotFound(format!("No supply figures for stablecoin {}", req.index)),
+        SetCapError::BelowSupply(current_supply) => ApiError::BadRequest(format!(
+            "supplyCap {} is below the current supply of {}",
+            req.supply_cap, current_supply
+        )),
+    })?;
+    tracing::info!(index = req.index, supply_cap = req.supply_cap, "Supply cap changed");
+    Ok((StatusCode::OK, Json(json!({ "success": true, "data": state.cased(SupplyCap::from(supply)) }))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use axum::body::{to_bytes, Body};
+    use axum::extract::Query;
+    use axum::http::Request;
+    use axum::routing::post;
+    use axum::Router;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use crate::pagination::PageParams;
+    use crate::stablecoin::get_supply_caps::get_supply_caps;
+
+    fn admin_state() -> AppState {
+        AppState::new(Config {
+            admin_api_keys: vec!["admin-key".to_string()],
+            ..Config::default()
+        })
+    }
+
+    async fn send(state: &AppState, key: Option<&str>, body: Value) -> (StatusCode, Value) {
+        let app = Router::new()
+            .route("/admin/supply-caps", post(set_supply_cap))
+            .with_state(state.clone());
+
+        let mut request = Request::post("/admin/supply-caps").header("content-type", "application/json");
+        if let Some(key) = key {
+            request = request.header("x-api-key", key);
+        }
+        let response = app
+            .oneshot(request.body(Body::from(body.to_string())).unwrap())
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_raise_cap_is_reflected_in_supply_caps() {
+        let state = admin_state();
+
+        let (status, json) = send(&state, Some("admin-key"), json!({ "index": 0, "supplyCap": 2_000_000_000u64 })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["supplyCap"], 2_000_000_000u64);
+        assert_eq!(json["data"]["remainingCapacity"], 1_500_000_000u64);
+        assert_eq!(json["data"]["utilizationPercentage"], 25);
+
+        let response = get_supply_caps(State(state), Query(PageParams::default())).await.into_response();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["data"][0]["supplyCap"], 2_000_000_000u64);
+        assert_eq!(json["data"][0]["utilizationPercentage"], 25);
+    }
+
+    #[tokio::test]
+    async fn test_cap_below_supply_rejected() {
+        let state = admin_state();
+
+        let (status, json) = send(&state, Some("admin-key"), json!({ "index": 0, "supplyCap": 499_999_999 })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["message"], "supplyCap 499999999 is below the current supply of 500000000");
+        assert_eq!(state.supplies.get(0).unwrap().supply_cap, 1_000_000_000);
+
+        // Exactly the current supply is allowed
+        let (status, json) = send(&state, Some("admin-key"), json!({ "index": 0, "supplyCap": 500_000_000 })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["remainingCapacity"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_index_and_auth() {
+        let state = admin_state();
+
+        let (status, _) = send(&state, Some("admin-key"), json!({ "index": 9, "supplyCap": 1 })).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _) = send(&state, None, json!({ "index": 0, "supplyCap": 2_000_000_000u64 })).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(state.supplies.get(0).unwrap().supply_cap, 1_000_000_000);
+    }
+}
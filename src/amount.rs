@@ -0,0 +1,182 @@
+use rust_decimal::Decimal;
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+/// Largest integer an `f64` represents exactly (`2^53`); float amounts above it
+/// cannot be trusted to be whole.
+const MAX_EXACT_F64: f64 = 9_007_199_254_740_992.0;
+
+/// Basis points in 100%.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// A positive token amount in the token's smallest unit.
+///
+/// Request bodies carry amounts as this type, so a zero, negative,
+/// fractional or oversized value is rejected while the body is deserialized
+/// and handlers never see one. Whole floats (`1000.0`) are accepted up to
+/// `2^53`. Arithmetic on amounts goes through the checked methods here, which
+/// return `None` instead of overflowing; their results are plain `u64`s
+/// since fees, totals and remainders may be zero. On the wire it is a plain
+/// JSON number.
+///
+/// ### Example Rejections
+/// - `0`, `-5`: `"must be positive"`
+/// - `1000000.5`: `"must be a whole number"`
+/// - `1e20`: `"out of range"`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(transparent)]
+pub struct Amount(u64);
+
+impl Amount {
+    /// `units` as an amount, or `None` when it is zero.
+    pub const fn new(units: u64) -> Option<Self> {
+        if units == 0 {
+            None
+        } else {
+            Some(Amount(units))
+        }
+    }
+
+    /// The amount in smallest units.
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// `units` added to the amount, or `None` on overflow.
+    pub fn checked_add(self, units: u64) -> Option<u64> {
+        self.0.checked_add(units)
+    }
+
+    /// What is left of the amount after taking `units`, or `None` when
+    /// `units` is more than the amount.
+    pub fn checked_sub(self, units: u64) -> Option<u64> {
+        self.0.checked_sub(units)
+    }
+
+    /// `bps` basis points of the amount, rounded down, or `None` above 100%.
+    /// Computed in `u128`, so no amount overflows.
+    pub fn share(self, bps: u32) -> Option<u64> {
+        if bps > BPS_DENOMINATOR {
+            return None;
+        }
+        // At most the amount itself, so it fits back into a u64
+        Some((u128::from(self.0) * u128::from(bps) / u128::from(BPS_DENOMINATOR)) as u64)
+    }
+
+    /// Like [`Amount::share`], but rounded up.
+    pub fn share_rounded_up(self, bps: u32) -> Option<u64> {
+        if bps > BPS_DENOMINATOR {
+            return None;
+        }
+        Some((u128::from(self.0) * u128::from(bps)).div_ceil(u128::from(BPS_DENOMINATOR)) as u64)
+    }
+
+    /// The amount in whole tokens for a mint with `decimals` decimals, e.g.
+    /// `1500000` with 6 decimals is `1.500000`. `None` when `decimals`
+    /// exceeds 28, the largest scale a [`Decimal`] supports.
+    pub fn to_ui(self, decimals: u8) -> Option<Decimal> {
+        Decimal::try_from_i128_with_scale(i128::from(self.0), u32::from(decimals)).ok()
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl de::Visitor<'_> for Visitor {
+            type Value = Amount;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a positive whole number of smallest units")
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Amount::new(v).ok_or_else(|| E::custom("must be positive"))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                u64::try_from(v)
+                    .ok()
+                    .and_then(Amount::new)
+                    .ok_or_else(|| E::custom("must be positive"))
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+                if v <= 0.0 {
+                    Err(E::custom("must be positive"))
+                } else if v.fract() != 0.0 && v < MAX_EXACT_F64 {
+                    Err(E::custom("must be a whole number"))
+                } else if v <= MAX_EXACT_F64 {
+                    Ok(Amount(v as u64))
+                } else {
+                    Err(E::custom("out of range"))
+                }
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn parse(value: serde_json::Value) -> Result<Amount, String> {
+        serde_json::from_value(value).map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn accepts_positive_whole_amounts() {
+        assert_eq!(parse(json!(1_000_000)), Ok(Amount(1_000_000)));
+        assert_eq!(parse(json!(1000.0)), Ok(Amount(1000)));
+        assert_eq!(parse(json!(u64::MAX)), Ok(Amount(u64::MAX)));
+        assert_eq!(serde_json::to_value(Amount(42)).unwrap(), json!(42));
+    }
+
+    #[test]
+    fn rejects_zero_negative_fractional_and_oversized_amounts() {
+        for value in [json!(0), json!(-5), json!(-0.5), json!(i64::MIN)] {
+            assert_eq!(parse(value), Err("must be positive".to_string()));
+        }
+        assert_eq!(parse(json!(1000000.5)), Err("must be a whole number".to_string()));
+        assert_eq!(parse(json!(1e20)), Err("out of range".to_string()));
+        assert!(parse(json!("1000")).is_err());
+    }
+
+    #[test]
+    fn arithmetic_is_checked() {
+        assert_eq!(Amount(1).checked_add(2), Some(3));
+        assert_eq!(Amount(u64::MAX).checked_add(1), None);
+        assert_eq!(Amount(2).checked_sub(1), Some(1));
+        assert_eq!(Amount(1).checked_sub(1), Some(0));
+        assert_eq!(Amount(1).checked_sub(2), None);
+        assert_eq!(Amount::new(0), None);
+        assert_eq!(Amount::new(7).map(Amount::get), Some(7));
+    }
+
+    #[test]
+    fn shares_round_down_without_overflow() {
+        assert_eq!(Amount(1_000_000).share(10), Some(1_000));
+        assert_eq!(Amount(999).share(10), Some(0));
+        assert_eq!(Amount(u64::MAX).share(BPS_DENOMINATOR), Some(u64::MAX));
+        assert_eq!(Amount(u64::MAX).share(9_999), Some(u64::MAX - u64::MAX / 10_000 - 1));
+        assert_eq!(Amount(1).share(BPS_DENOMINATOR + 1), None);
+    }
+
+    #[test]
+    fn converts_to_ui_amounts() {
+        assert_eq!(Amount(1_500_000).to_ui(6).unwrap().to_string(), "1.500000");
+        assert_eq!(Amount(1).to_ui(9).unwrap().to_string(), "0.000000001");
+        assert_eq!(Amount(42).to_ui(0), Some(Decimal::from(42)));
+        assert_eq!(Amount(42).to_ui(28).map(|ui| ui.scale()), Some(28));
+        assert_eq!(Amount(42).to_ui(29), None);
+    }
+}
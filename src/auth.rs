@@ -0,0 +1,169 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{request::Parts, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+use crate::AppState;
+
+/// Header carrying the caller's API key.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Extractor guarding admin-only handlers.
+///
+/// Succeeds when the `x-api-key` header matches one of the keys configured in
+/// `REFLECT_ADMIN_API_KEYS`. When no admin keys are configured every admin
+/// request is rejected.
+///
+/// ### Error Response (HTTP 401)
+/// ```json
+/// {
+///   "success": false,
+///   "message": "Missing or invalid API key"
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AdminKey;
+
+#[async_trait]
+impl FromRequestParts<AppState> for AdminKey {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        match provided_key(&parts.headers) {
+            Some(key) if is_configured_key(&state.config.admin_api_keys, key) => Ok(AdminKey),
+            _ => Err(unauthorized()),
+        }
+    }
+}
+
+/// A permission attached to an issued integration API key.
+///
+/// Each mutating integration route requires one scope: `read` for POST
+/// lookups, `mint` for mint / redeem transactions and `admin` for setup and
+/// configuration changes. `admin` also grants the other two. Routes whose
+/// payload names no integration take operator keys only, whatever the scope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Scope {
+    Read,
+    Mint,
+    Admin,
+}
+
+impl Scope {
+    /// Every scope; what keys get when none are requested.
+    pub const ALL: [Scope; 3] = [Scope::Read, Scope::Mint, Scope::Admin];
+}
+
+/// The scopes of the key that authenticated a request, stored in the request
+/// extensions by the integration API key middleware.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrantedScopes(pub Vec<Scope>);
+
+impl GrantedScopes {
+    pub fn allows(&self, required: Scope) -> bool {
+        self.0.iter().any(|&scope| scope == required || scope == Scope::Admin)
+    }
+}
+
+/// The integration an issued API key belongs to, stored in the request
+/// extensions by the integration API key middleware. Keys from
+/// `REFLECT_INTEGRATION_API_KEYS` belong to the operator, not to one
+/// integration, and carry no owner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyOwner(pub String);
+
+/// Whether `key` is one of the configured `keys`.
+///
+/// Keys are compared as SHA-256 digests, byte by byte and against every
+/// configured key, like issued keys in `ApiKeyRecord::verify`, so the time
+/// taken reveals neither how much of a key matched nor its length.
+pub fn is_configured_key(keys: &[String], key: &str) -> bool {
+    let hash = Sha256::digest(key.as_bytes());
+    keys.iter().fold(false, |found, configured| {
+        let configured = Sha256::digest(configured.as_bytes());
+        found | (hash.iter().zip(configured).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0)
+    })
+}
+
+/// The `x-api-key` header of a request, if present and valid UTF-8.
+pub fn provided_key(headers: &HeaderMap) -> Option<&str> {
+    headers.get(API_KEY_HEADER).and_then(|value| value.to_str().ok())
+}
+
+/// The `401` returned for a missing or unrecognised API key.
+pub fn unauthorized() -> Response {
+    unauthorized_with("Missing or invalid API key")
+}
+
+/// A `401` in the standard error envelope with a specific `message`.
+pub fn unauthorized_with(message: &str) -> Response {
+    (
+        StatusCode::UNAUTHORIZED,
+        Json(json!({
+            "success": false,
+            "message": message
+        })),
+    )
+        .into_response()
+}
+
+/// The `403` returned when an issued key targets an integration other than
+/// its own.
+pub fn foreign_integration() -> Response {
+    (
+        StatusCode::FORBIDDEN,
+        Json(json!({
+            "success": false,
+            "message": "api key does not belong to this integration"
+        })),
+    )
+        .into_response()
+}
+
+/// The `403` returned when an issued key calls a route that names no
+/// integration, and so is reserved for operator keys.
+pub fn operator_key_required() -> Response {
+    (
+        StatusCode::FORBIDDEN,
+        Json(json!({
+            "success": false,
+            "message": "route requires an operator api key"
+        })),
+    )
+        .into_response()
+}
+
+/// The `403` returned when a recognised key lacks the scope a route requires.
+pub fn insufficient_scope() -> Response {
+    (
+        StatusCode::FORBIDDEN,
+        Json(json!({
+            "success": false,
+            "message": "insufficient scope"
+        })),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn configured_keys_match_exactly() {
+        let keys = vec!["first-key".to_string(), "second-key".to_string()];
+        assert!(is_configured_key(&keys, "first-key"));
+        assert!(is_configured_key(&keys, "second-key"));
+        for wrong in ["", "first", "first-key ", "third-key"] {
+            assert!(!is_configured_key(&keys, wrong), "{wrong:?}");
+        }
+        assert!(!is_configured_key(&[], "first-key"));
+    }
+}
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// Default time-to-live for cached read responses.
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// A small shared cache of computed values that expire after a fixed TTL.
+///
+/// Cheap to clone; clones share the same entries.
+#[derive(Debug, Clone)]
+pub struct TtlCache<V> {
+    ttl: Duration,
+    entries: Arc<RwLock<HashMap<String, (Instant, V)>>>,
+}
+
+impl<V: Clone> Default for TtlCache<V> {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_TTL)
+    }
+}
+
+impl<V: Clone> TtlCache<V> {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// The cached value for `key`, unless it is missing or expired.
+    pub fn get(&self, key: &str) -> Option<V> {
+        let entries = self.entries.read().unwrap();
+        let (stored_at, value) = entries.get(key)?;
+        (stored_at.elapsed() < self.ttl).then(|| value.clone())
+    }
+
+    pub fn insert(&self, key: impl Into<String>, value: V) {
+        self.entries
+            .write()
+            .unwrap()
+            .insert(key.into(), (Instant::now(), value));
+    }
+
+    /// Drop every entry, so the next reads are computed afresh.
+    pub fn clear(&self) {
+        self.entries.write().unwrap().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hit_miss_and_expiry() {
+        let cache = TtlCache::new(Duration::from_secs(60));
+        assert_eq!(cache.get("a"), None);
+        cache.insert("a", 1);
+        assert_eq!(cache.clone().get("a"), Some(1));
+
+        let expired = TtlCache::new(Duration::ZERO);
+        expired.insert("a", 1);
+        assert_eq!(expired.get("a"), None);
+
+        cache.clear();
+        assert_eq!(cache.get("a"), None);
+    }
+}
@@ -0,0 +1,101 @@
+//! A tracing layer recording spans and events, for tests asserting on what
+//! a handler or middleware logs.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::subscriber::DefaultGuard;
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+
+/// A span opened or an event emitted while a [`Capture`] was installed.
+///
+/// `fields` hold strings as written and every other value in its `Debug`
+/// form, e.g. `("status", "200")`. For events, `scope` lists the names of
+/// the enclosing spans, innermost first.
+#[derive(Debug, Clone)]
+pub struct Captured {
+    pub name: String,
+    pub target: String,
+    pub scope: Vec<String>,
+    pub fields: Vec<(String, String)>,
+}
+
+impl Captured {
+    /// The value recorded for `key`, if any.
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+}
+
+/// Records every span and event on the current thread until its guard drops.
+///
+/// Cheap to clone; clones share the same records.
+#[derive(Clone, Default)]
+pub struct Capture {
+    spans: Arc<Mutex<Vec<Captured>>>,
+    events: Arc<Mutex<Vec<Captured>>>,
+}
+
+impl Capture {
+    /// Install a fresh capture as the thread's default subscriber.
+    pub fn install() -> (Self, DefaultGuard) {
+        let capture = Capture::default();
+        let guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+        (capture, guard)
+    }
+
+    pub fn spans(&self) -> Vec<Captured> {
+        self.spans.lock().unwrap().clone()
+    }
+
+    pub fn events(&self) -> Vec<Captured> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+struct FieldVisitor<'a>(&'a mut Vec<(String, String)>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.push((field.name().to_string(), format!("{:?}", value)));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push((field.name().to_string(), value.to_string()));
+    }
+}
+
+impl<S> Layer<S> for Capture
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+        let mut fields = Vec::new();
+        attrs.record(&mut FieldVisitor(&mut fields));
+        self.spans.lock().unwrap().push(Captured {
+            name: attrs.metadata().name().to_string(),
+            target: attrs.metadata().target().to_string(),
+            scope: Vec::new(),
+            fields,
+        });
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let scope = ctx
+            .event_scope(event)
+            .map(|scope| scope.map(|span| span.name().to_string()).collect())
+            .unwrap_or_default();
+        let mut fields = Vec::new();
+        event.record(&mut FieldVisitor(&mut fields));
+        self.events.lock().unwrap().push(Captured {
+            name: event.metadata().name().to_string(),
+            target: event.metadata().target().to_string(),
+            scope,
+            fields,
+        });
+    }
+}
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Solana cluster a request targets.
+///
+/// ### Example
+/// - `?cluster=mainnet` (default)
+/// - `?cluster=devnet`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Cluster {
+    #[default]
+    Mainnet,
+    Devnet,
+}
+
+impl Cluster {
+    pub const ALL: [Cluster; 2] = [Cluster::Mainnet, Cluster::Devnet];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Cluster::Mainnet => "mainnet",
+            Cluster::Devnet => "devnet",
+        }
+    }
+}
+
+impl fmt::Display for Cluster {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Cluster {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl FromStr for Cluster {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mainnet" => Ok(Cluster::Mainnet),
+            "devnet" => Ok(Cluster::Devnet),
+            other => Err(format!(
+                "Unknown cluster '{}'; expected one of mainnet, devnet",
+                other
+            )),
+        }
+    }
+}
+
+/// Query parameters for cluster, commitment and transaction format selection,
+/// shared by the mint and burn endpoints.
+///
+/// `commitment` overrides `REFLECT_RPC_COMMITMENT` for the RPC reads made
+/// while building the transaction. `encoding` (`base64` or `base58`, default
+/// `base64`) controls how the serialized transaction (or instruction data) is
+/// returned, `txVersion` (`legacy` or `v0`, default `legacy`) which format is
+/// built, and `format=instructions` returns the raw instructions instead of a
+/// serialized transaction.
+///
+/// ### Example
+/// - `?cluster=mainnet`
+/// - `?cluster=devnet&commitment=finalized&encoding=base58&txVersion=v0`
+/// - `?format=instructions`
+#[derive(Debug, Default, Deserialize)]
+pub struct ClusterQuery {
+    pub cluster: Option<String>,
+    pub commitment: Option<String>,
+    pub encoding: Option<String>,
+    #[serde(rename = "txVersion")]
+    pub tx_version: Option<String>,
+    pub format: Option<String>,
+}
@@ -0,0 +1,401 @@
+use regex::Regex;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::access_log::LogSampling;
+use crate::amount::BPS_DENOMINATOR;
+use crate::cluster::Cluster;
+use crate::integration::api_keys::{KeyOverlap, DEFAULT_API_KEY_PREFIX};
+use crate::json_case::JsonCase;
+use crate::rpc::Commitment;
+use crate::stablecoin::fees::FeeSchedule;
+use crate::stablecoin::transaction::{parse_pubkey, PUBKEY_LEN};
+
+/// Default pattern token symbols must match, see [`Config::symbol_pattern`].
+pub const DEFAULT_SYMBOL_PATTERN: &str = "^[A-Z0-9+]{2,10}$";
+
+/// Runtime configuration for the Reflect API server.
+///
+/// Every field can be overridden with a `REFLECT_*` environment variable;
+/// anything unset (or unparseable) falls back to the value in [`Config::default`].
+///
+/// ### Environment Variables
+/// - `REFLECT_MIN_RECEIVED_TOLERANCE_BPS`: How far (in basis points) `minimumReceived`
+///   may sit below the quoted amount before a slippage warning is attached (default: `500`).
+/// - `REFLECT_PROTOCOL_FEE_BPS`: Protocol fee taken from every mint and redeem (default: `10`).
+/// - `REFLECT_INTEGRATION_FEE_BPS`: Integration fee taken from every mint and redeem (default: `0`).
+/// - `REFLECT_PROTOCOL_FEE_BPS_MAINNET` / `REFLECT_PROTOCOL_FEE_BPS_DEVNET` and
+///   `REFLECT_INTEGRATION_FEE_BPS_MAINNET` / `REFLECT_INTEGRATION_FEE_BPS_DEVNET`: Fee components
+///   for requests targeting that `cluster`, e.g. `0` to make devnet free (default: the global fee).
+///   Every fee setting, and the two fees in effect for each cluster combined, must be at most
+///   `10000` (100%); the server refuses to start otherwise.
+/// - `REFLECT_ADMIN_API_KEYS`: Comma-separated API keys accepted on admin endpoints (default: none).
+/// - `REFLECT_INTEGRATION_API_KEYS`: Comma-separated API keys accepted on mutating `/integrations`
+///   endpoints (default: none).
+/// - `REFLECT_API_KEY_PREFIX`: Prefix of the integration API keys issued by
+///   `/integrations/api-key/rotate` (default: `rflk_`).
+/// - `REFLECT_MAX_ACTIVE_API_KEYS`: Most keys an integration holds at once; rotating past it drops
+///   the oldest (default: `2`).
+/// - `REFLECT_API_KEY_GRACE_SECS`: Seconds a rotated-out key keeps working alongside its
+///   replacement; `0` revokes it immediately (default: `86400`). A leaked key can be shut
+///   off before then through `/integrations/api-key/revoke`.
+/// - `REFLECT_MAX_BATCH_SIZE`: Maximum number of items accepted by array/batch endpoints (default: `100`).
+/// - `REFLECT_PER_USER_MINT_LIMIT`: Most a single signer may mint of one stablecoin, in
+///   smallest units; `0` disables the limit (default: `0`). While set, mint requests must
+///   carry a `signerProof` signed by the signer.
+/// - `REFLECT_MINT_COOLDOWN_SECS`: Seconds a signer must wait between mints; earlier
+///   attempts get 429 with `Retry-After`. `0` disables the cooldown (default: `0`). While
+///   set, mint requests must carry a `signerProof` signed by the signer.
+/// - `REFLECT_INTEGRATION_CREATION_LIMIT`: Most integrations one authority may create per
+///   creation window; further attempts get 429 with `Retry-After`. `0` disables the limit
+///   (default: `0`).
+/// - `REFLECT_INTEGRATION_CREATION_WINDOW_SECS`: Length of that window in seconds (default: `3600`).
+/// - `REFLECT_MAX_QUERY_LENGTH`: Longest query string, in bytes, accepted before a request is
+///   rejected with 414 (default: `2048`).
+/// - `REFLECT_MAX_CONCURRENT_REQUESTS`: Requests handled at once; more wait in a queue
+///   (default: `512`).
+/// - `REFLECT_MAX_QUEUED_REQUESTS`: Requests allowed to wait for a slot; once the queue is full,
+///   new requests get 503 and `/health/ready` reports not-ready (default: `1024`).
+/// - `REFLECT_MAX_RESPONSE_BYTES`: Largest JSON response body, in bytes, sent before the
+///   request is rejected with 400 instead (default: `10485760`, 10 MiB).
+/// - `REFLECT_RPC_URL_MAINNET` / `REFLECT_RPC_URL_DEVNET`: Solana JSON-RPC endpoint per cluster
+///   (default: none, RPC-backed endpoints report 503).
+/// - `REFLECT_RPC_FALLBACK_URL_MAINNET` / `REFLECT_RPC_FALLBACK_URL_DEVNET`: Secondary endpoint
+///   used when the primary keeps failing (default: none).
+/// - `REFLECT_RPC_RETRIES`: Extra attempts against the primary before failing over (default: `2`).
+/// - `REFLECT_RPC_REPROBE_SECS`: While failed over, seconds between single attempts at the
+///   primary to see whether it is back; other requests go straight to the fallback (default: `30`).
+/// - `REFLECT_HTTP_TIMEOUT_SECS`: Seconds an outbound RPC or upstream request may take in total
+///   before it fails, so a hung RPC endpoint fails over instead of stalling (default: `10`).
+/// - `REFLECT_HTTP_CONNECT_TIMEOUT_SECS`: Seconds allowed for connecting to an RPC endpoint or
+///   the upstream (default: `3`).
+/// - `REFLECT_PROGRAM_ID`: Address of the deployed Reflect program that mint / burn transactions
+///   call (default: none). While unset, a placeholder is used and those responses carry
+///   `"simulated": true`, as they do when the cluster has no RPC endpoint for a blockhash.
+/// - `REFLECT_RPC_COMMITMENT`: Commitment level for RPC reads: `processed`, `confirmed` or
+///   `finalized` (default: `confirmed`).
+/// - `REFLECT_UPSTREAM_URL`: Base URL of a live Reflect API (usually
+///   `https://prod.api.reflect.money`) that rate and APY reads are proxied to, falling back
+///   to simulated data when it is unreachable (default: none, always simulated).
+/// - `REFLECT_CACHE_TTL_SECS`: How long computed read responses are cached (default: `30`).
+/// - `REFLECT_RATE_STALE_AFTER_SECS`: Age of the newest exchange rate after which rate
+///   endpoints flag their data as `stale`; `0` disables the check (default: `0`).
+/// - `REFLECT_STATS_STREAM_INTERVAL_SECS`: Seconds between snapshots on `GET /stats/stream`
+///   (default: `5`).
+/// - `REFLECT_SYMBOL_PATTERN`: Regex token symbols must match on token initialization and
+///   metadata upload (default: `^[A-Z0-9+]{2,10}$`).
+/// - `REFLECT_JSON_CASE`: Field name casing for response bodies: `camel`, `snake`, or `preserve`
+///   to keep each endpoint's historical shape (default: `preserve`). Only struct fields are
+///   renamed; map keys such as integration ids are data and stay as they are. `/admin/export`
+///   is exempt, so backups always match what `/admin/import` reads.
+/// - `REFLECT_OUTBOUND_URL_ALLOWLIST`: Comma-separated hosts (subdomains included) that
+///   client-supplied URLs such as metadata `url` / `image_url` may point at; empty allows any
+///   public host (default: empty). Non-public hosts and non-http(s) schemes are always rejected.
+/// - `REFLECT_CORS_ALLOWED_ORIGINS`: Comma-separated origins allowed by CORS; empty or `*`
+///   allows any origin (default: any).
+/// - `REFLECT_CORS_ALLOW_CREDENTIALS`: Send `Access-Control-Allow-Credentials: true` so browsers
+///   include cookies; requires an explicit origin list (default: `false`).
+/// - `REFLECT_CORS_MAX_AGE_SECS`: How long browsers may cache a preflight response, sent as
+///   `Access-Control-Max-Age` (default: `600`).
+/// - `REFLECT_LOG_SAMPLING`: Comma-separated `route=N` pairs logging one in `N` successful
+///   requests to noisy routes, e.g. `/health=100`; errors are always logged (default: log all).
+/// - `REFLECT_READ_ONLY`: When `true`, every mutating request returns 503 while reads keep
+///   working (default: `false`).
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub min_received_tolerance_bps: u32,
+    pub protocol_fee_bps: u32,
+    pub integration_fee_bps: u32,
+    pub protocol_fee_bps_mainnet: Option<u32>,
+    pub protocol_fee_bps_devnet: Option<u32>,
+    pub integration_fee_bps_mainnet: Option<u32>,
+    pub integration_fee_bps_devnet: Option<u32>,
+    pub admin_api_keys: Vec<String>,
+    pub integration_api_keys: Vec<String>,
+    pub api_key_prefix: String,
+    pub max_active_api_keys: usize,
+    pub api_key_grace_secs: u64,
+    pub max_batch_size: usize,
+    pub per_user_mint_limit: u64,
+    pub mint_cooldown_secs: u64,
+    pub integration_creation_limit: usize,
+    pub integration_creation_window_secs: u64,
+    pub max_query_length: usize,
+    pub max_concurrent_requests: usize,
+    pub max_queued_requests: usize,
+    pub max_response_bytes: usize,
+    pub rpc_url_mainnet: Option<String>,
+    pub rpc_url_devnet: Option<String>,
+    pub rpc_fallback_url_mainnet: Option<String>,
+    pub rpc_fallback_url_devnet: Option<String>,
+    pub rpc_retries: u32,
+    pub rpc_reprobe_secs: u64,
+    pub rpc_commitment: Commitment,
+    pub program_id: Option<String>,
+    pub http_timeout_secs: u64,
+    pub http_connect_timeout_secs: u64,
+    pub upstream_url: Option<String>,
+    pub read_only: bool,
+    pub cache_ttl_secs: u64,
+    pub rate_stale_after_secs: u64,
+    pub stats_stream_interval_secs: u64,
+    pub symbol_pattern: Regex,
+    pub json_case: JsonCase,
+    pub outbound_url_allowlist: Vec<String>,
+    pub cors_allowed_origins: Vec<String>,
+    pub cors_allow_credentials: bool,
+    pub cors_max_age_secs: u64,
+    pub log_sampling: LogSampling,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            min_received_tolerance_bps: 500,
+            protocol_fee_bps: FeeSchedule::default().protocol_fee_bps,
+            integration_fee_bps: FeeSchedule::default().integration_fee_bps,
+            protocol_fee_bps_mainnet: None,
+            protocol_fee_bps_devnet: None,
+            integration_fee_bps_mainnet: None,
+            integration_fee_bps_devnet: None,
+            admin_api_keys: Vec::new(),
+            integration_api_keys: Vec::new(),
+            api_key_prefix: DEFAULT_API_KEY_PREFIX.to_string(),
+            max_active_api_keys: 2,
+            api_key_grace_secs: 86_400,
+            max_batch_size: 100,
+            per_user_mint_limit: 0,
+            mint_cooldown_secs: 0,
+            integration_creation_limit: 0,
+            integration_creation_window_secs: 3600,
+            max_query_length: 2048,
+            max_concurrent_requests: 512,
+            max_queued_requests: 1024,
+            max_response_bytes: 10 * 1024 * 1024,
+            rpc_url_mainnet: None,
+            rpc_url_devnet: None,
+            rpc_fallback_url_mainnet: None,
+            rpc_fallback_url_devnet: None,
+            rpc_retries: 2,
+            rpc_reprobe_secs: 30,
+            rpc_commitment: Commitment::default(),
+            program_id: None,
+            http_timeout_secs: 10,
+            http_connect_timeout_secs: 3,
+            upstream_url: None,
+            read_only: false,
+            cache_ttl_secs: 30,
+            rate_stale_after_secs: 0,
+            stats_stream_interval_secs: 5,
+            symbol_pattern: Regex::new(DEFAULT_SYMBOL_PATTERN).unwrap(),
+            json_case: JsonCase::default(),
+            outbound_url_allowlist: Vec::new(),
+            cors_allowed_origins: Vec::new(),
+            cors_allow_credentials: false,
+            cors_max_age_secs: 600,
+            log_sampling: LogSampling::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Build a configuration from the process environment.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        Self {
+            min_received_tolerance_bps: env_or(
+                "REFLECT_MIN_RECEIVED_TOLERANCE_BPS",
+                defaults.min_received_tolerance_bps,
+            ),
+            protocol_fee_bps: env_or("REFLECT_PROTOCOL_FEE_BPS", defaults.protocol_fee_bps),
+            integration_fee_bps: env_or("REFLECT_INTEGRATION_FEE_BPS", defaults.integration_fee_bps),
+            protocol_fee_bps_mainnet: env_parsed("REFLECT_PROTOCOL_FEE_BPS_MAINNET"),
+            protocol_fee_bps_devnet: env_parsed("REFLECT_PROTOCOL_FEE_BPS_DEVNET"),
+            integration_fee_bps_mainnet: env_parsed("REFLECT_INTEGRATION_FEE_BPS_MAINNET"),
+            integration_fee_bps_devnet: env_parsed("REFLECT_INTEGRATION_FEE_BPS_DEVNET"),
+            admin_api_keys: env_list("REFLECT_ADMIN_API_KEYS"),
+            integration_api_keys: env_list("REFLECT_INTEGRATION_API_KEYS"),
+            api_key_prefix: env_or("REFLECT_API_KEY_PREFIX", defaults.api_key_prefix),
+            max_active_api_keys: env_or("REFLECT_MAX_ACTIVE_API_KEYS", defaults.max_active_api_keys),
+            api_key_grace_secs: env_or("REFLECT_API_KEY_GRACE_SECS", defaults.api_key_grace_secs),
+            max_batch_size: env_or("REFLECT_MAX_BATCH_SIZE", defaults.max_batch_size),
+            per_user_mint_limit: env_or("REFLECT_PER_USER_MINT_LIMIT", defaults.per_user_mint_limit),
+            mint_cooldown_secs: env_or("REFLECT_MINT_COOLDOWN_SECS", defaults.mint_cooldown_secs),
+            integration_creation_limit: env_or(
+                "REFLECT_INTEGRATION_CREATION_LIMIT",
+                defaults.integration_creation_limit,
+            ),
+            integration_creation_window_secs: env_or(
+                "REFLECT_INTEGRATION_CREATION_WINDOW_SECS",
+                defaults.integration_creation_window_secs,
+            ),
+            max_query_length: env_or("REFLECT_MAX_QUERY_LENGTH", defaults.max_query_length),
+            max_concurrent_requests: env_or("REFLECT_MAX_CONCURRENT_REQUESTS", defaults.max_concurrent_requests),
+            max_queued_requests: env_or("REFLECT_MAX_QUEUED_REQUESTS", defaults.max_queued_requests),
+            max_response_bytes: env_or("REFLECT_MAX_RESPONSE_BYTES", defaults.max_response_bytes),
+            rpc_url_mainnet: env_opt("REFLECT_RPC_URL_MAINNET"),
+            rpc_url_devnet: env_opt("REFLECT_RPC_URL_DEVNET"),
+            rpc_fallback_url_mainnet: env_opt("REFLECT_RPC_FALLBACK_URL_MAINNET"),
+            rpc_fallback_url_devnet: env_opt("REFLECT_RPC_FALLBACK_URL_DEVNET"),
+            rpc_retries: env_or("REFLECT_RPC_RETRIES", defaults.rpc_retries),
+            rpc_reprobe_secs: env_or("REFLECT_RPC_REPROBE_SECS", defaults.rpc_reprobe_secs),
+            rpc_commitment: env_or("REFLECT_RPC_COMMITMENT", defaults.rpc_commitment),
+            program_id: env_opt("REFLECT_PROGRAM_ID"),
+            http_timeout_secs: env_or("REFLECT_HTTP_TIMEOUT_SECS", defaults.http_timeout_secs),
+            http_connect_timeout_secs: env_or("REFLECT_HTTP_CONNECT_TIMEOUT_SECS", defaults.http_connect_timeout_secs),
+            upstream_url: env_opt("REFLECT_UPSTREAM_URL"),
+            read_only: env_or("REFLECT_READ_ONLY", defaults.read_only),
+            cache_ttl_secs: env_or("REFLECT_CACHE_TTL_SECS", defaults.cache_ttl_secs),
+            rate_stale_after_secs: env_or("REFLECT_RATE_STALE_AFTER_SECS", defaults.rate_stale_after_secs),
+            stats_stream_interval_secs: env_or(
+                "REFLECT_STATS_STREAM_INTERVAL_SECS",
+                defaults.stats_stream_interval_secs,
+            ),
+            symbol_pattern: env_or("REFLECT_SYMBOL_PATTERN", defaults.symbol_pattern),
+            json_case: env_or("REFLECT_JSON_CASE", defaults.json_case),
+            outbound_url_allowlist: env_list("REFLECT_OUTBOUND_URL_ALLOWLIST"),
+            cors_allowed_origins: env_list("REFLECT_CORS_ALLOWED_ORIGINS"),
+            cors_allow_credentials: env_or("REFLECT_CORS_ALLOW_CREDENTIALS", defaults.cors_allow_credentials),
+            cors_max_age_secs: env_or("REFLECT_CORS_MAX_AGE_SECS", defaults.cors_max_age_secs),
+            log_sampling: env_or("REFLECT_LOG_SAMPLING", defaults.log_sampling),
+        }
+    }
+
+    /// Reject settings the server cannot run with; called once at startup.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.http_timeout_secs == 0 || self.http_connect_timeout_secs == 0 {
+            return Err("REFLECT_HTTP_TIMEOUT_SECS and REFLECT_HTTP_CONNECT_TIMEOUT_SECS must be positive".to_string());
+        }
+        if self.program_id.is_some() && self.program_id_bytes().is_none() {
+            return Err("REFLECT_PROGRAM_ID must be a base58 public key".to_string());
+        }
+        let fee_settings = [
+            ("REFLECT_PROTOCOL_FEE_BPS", Some(self.protocol_fee_bps)),
+            ("REFLECT_INTEGRATION_FEE_BPS", Some(self.integration_fee_bps)),
+            ("REFLECT_PROTOCOL_FEE_BPS_MAINNET", self.protocol_fee_bps_mainnet),
+            ("REFLECT_PROTOCOL_FEE_BPS_DEVNET", self.protocol_fee_bps_devnet),
+            ("REFLECT_INTEGRATION_FEE_BPS_MAINNET", self.integration_fee_bps_mainnet),
+            ("REFLECT_INTEGRATION_FEE_BPS_DEVNET", self.integration_fee_bps_devnet),
+        ];
+        if let Some((name, _)) = fee_settings.iter().find(|(_, bps)| bps.is_some_and(|bps| bps > BPS_DENOMINATOR)) {
+            return Err(format!("{} must be at most {}", name, BPS_DENOMINATOR));
+        }
+        for cluster in Cluster::ALL {
+            let fees = self.fees(cluster);
+            if fees.protocol_fee_bps + fees.integration_fee_bps > BPS_DENOMINATOR {
+                return Err(format!(
+                    "Protocol and integration fees for {} must add up to at most {} bps",
+                    cluster, BPS_DENOMINATOR
+                ));
+            }
+        }
+        crate::cors::validate(self)
+    }
+
+    /// The configured Reflect program id, decoded.
+    pub fn program_id_bytes(&self) -> Option<[u8; PUBKEY_LEN]> {
+        self.program_id.as_deref().and_then(parse_pubkey)
+    }
+
+    /// The client for outbound RPC and upstream requests, with the configured
+    /// timeouts.
+    pub fn http_client(&self) -> reqwest::Client {
+        reqwest::Client::builder()
+            .timeout(Duration::from_secs(self.http_timeout_secs))
+            .connect_timeout(Duration::from_secs(self.http_connect_timeout_secs))
+            .build()
+            .expect("HTTP client settings are valid")
+    }
+
+    /// The fee components for requests targeting `cluster`: its own
+    /// overrides where set, the global fees otherwise.
+    pub fn fees(&self, cluster: Cluster) -> FeeSchedule {
+        let (protocol_fee_bps, integration_fee_bps) = match cluster {
+            Cluster::Mainnet => (self.protocol_fee_bps_mainnet, self.integration_fee_bps_mainnet),
+            Cluster::Devnet => (self.protocol_fee_bps_devnet, self.integration_fee_bps_devnet),
+        };
+        FeeSchedule {
+            protocol_fee_bps: protocol_fee_bps.unwrap_or(self.protocol_fee_bps),
+            integration_fee_bps: integration_fee_bps.unwrap_or(self.integration_fee_bps),
+        }
+    }
+
+    /// How rotating an integration's API key overlaps with its previous keys.
+    pub fn key_overlap(&self) -> KeyOverlap {
+        KeyOverlap {
+            max_active: self.max_active_api_keys,
+            grace: Duration::from_secs(self.api_key_grace_secs),
+        }
+    }
+
+    /// Maximum age of the newest exchange rate before it is reported as stale,
+    /// or `None` when the check is disabled.
+    pub fn rate_stale_after(&self) -> Option<chrono::Duration> {
+        // Longer than chrono can represent means never stale, like 0
+        let secs = i64::try_from(self.rate_stale_after_secs).ok()?;
+        (secs > 0).then(|| chrono::TimeDelta::try_seconds(secs)).flatten()
+    }
+
+    /// The configured RPC endpoint for `cluster`, if any.
+    pub fn rpc_url(&self, cluster: Cluster) -> Option<&str> {
+        match cluster {
+            Cluster::Mainnet => self.rpc_url_mainnet.as_deref(),
+            Cluster::Devnet => self.rpc_url_devnet.as_deref(),
+        }
+    }
+
+    /// The configured secondary RPC endpoint for `cluster`, if any.
+    pub fn rpc_fallback_url(&self, cluster: Cluster) -> Option<&str> {
+        match cluster {
+            Cluster::Mainnet => self.rpc_fallback_url_mainnet.as_deref(),
+            Cluster::Devnet => self.rpc_fallback_url_devnet.as_deref(),
+        }
+    }
+}
+
+/// Read and parse an environment variable, falling back to `default` when it is
+/// missing or malformed. Malformed values are logged so misconfiguration is visible.
+fn env_or<T: FromStr>(key: &str, default: T) -> T {
+    match std::env::var(key) {
+        Ok(raw) => raw.trim().parse().unwrap_or_else(|_| {
+            tracing::warn!("Ignoring invalid value {:?} for {}", raw, key);
+            default
+        }),
+        Err(_) => default,
+    }
+}
+
+/// Read an optional string environment variable, treating blank values as unset.
+fn env_opt(key: &str) -> Option<String> {
+    std::env::var(key)
+        .ok()
+        .map(|raw| raw.trim().to_string())
+        .filter(|raw| !raw.is_empty())
+}
+
+/// Read and parse an optional environment variable: `None` when it is unset,
+/// blank or malformed (logged like [`env_or`]).
+fn env_parsed<T: FromStr>(key: &str) -> Option<T> {
+    let raw = env_opt(key)?;
+    match raw.parse() {
+        Ok(value) => Some(value),
+        Err(_) => {
+            tracing::warn!("Ignoring invalid value {:?} for {}", raw, key);
+            None
+        }
+    }
+}
+
+/// Read a comma-separated environment variable, dropping empty entries.
+fn env_list(key: &str) -> Vec<String> {
+    std::env::var(key)
+        .map(|raw| {
+            raw.split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
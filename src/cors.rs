@@ -0,0 +1,185 @@
+use axum::http::{
+    header::{AUTHORIZATION, CONTENT_TYPE},
+    HeaderName, HeaderValue, Method,
+};
+use serde::Serialize;
+use std::time::Duration;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+use crate::auth::API_KEY_HEADER;
+use crate::config::Config;
+use crate::data_source::DATA_SOURCE_HEADER;
+use crate::request_id::REQUEST_ID_HEADER;
+use crate::trace_context::TRACEPARENT_HEADER;
+
+/// Origin value meaning "any origin".
+pub const WILDCARD_ORIGIN: &str = "*";
+
+/// Methods browsers may use cross-origin.
+const ALLOWED_METHODS: [Method; 5] = [Method::GET, Method::POST, Method::PUT, Method::PATCH, Method::DELETE];
+
+/// The effective CORS configuration, reported under `data.cors` by
+/// `GET /health/ready` so integrators can check what the server accepts.
+///
+/// ### Example
+/// ```json
+/// {
+///   "allowed_origins": ["https://app.reflect.money"],
+///   "allowed_methods": ["GET", "POST", "PUT", "PATCH", "DELETE"],
+///   "allow_credentials": true,
+///   "max_age_secs": 600
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CorsSummary {
+    /// `["*"]` when any origin is allowed.
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<&'static str>,
+    pub allow_credentials: bool,
+    pub max_age_secs: u64,
+}
+
+/// Summarise the CORS settings as [`cors_layer`] applies them.
+pub fn summary(config: &Config) -> CorsSummary {
+    let allowed_origins = if allows_any_origin(config) {
+        vec![WILDCARD_ORIGIN.to_string()]
+    } else {
+        config.cors_allowed_origins.clone()
+    };
+    CorsSummary {
+        allowed_origins,
+        allowed_methods: ALLOWED_METHODS.iter().map(Method::as_str).collect(),
+        allow_credentials: config.cors_allow_credentials,
+        max_age_secs: config.cors_max_age_secs,
+    }
+}
+
+/// Check the CORS settings against the browser's rules.
+///
+/// Browsers refuse credentialed responses carrying
+/// `Access-Control-Allow-Origin: *`, so credentials mode needs an explicit
+/// origin list. Origins must also be valid header values.
+pub fn validate(config: &Config) -> Result<(), String> {
+    if config.cors_allow_credentials && allows_any_origin(config) {
+        return Err(
+            "REFLECT_CORS_ALLOW_CREDENTIALS requires explicit REFLECT_CORS_ALLOWED_ORIGINS, not `*`".to_string(),
+        );
+    }
+    if let Some(origin) = config
+        .cors_allowed_origins
+        .iter()
+        .find(|origin| HeaderValue::from_str(origin).is_err())
+    {
+        return Err(format!("Invalid CORS origin {:?}", origin));
+    }
+    Ok(())
+}
+
+fn allows_any_origin(config: &Config) -> bool {
+    config.cors_allowed_origins.is_empty() || config.cors_allowed_origins.iter().any(|origin| origin == WILDCARD_ORIGIN)
+}
+
+/// Build the CORS layer from `REFLECT_CORS_*`.
+///
+/// Methods and headers are listed explicitly rather than mirrored, which
+/// credentials mode requires anyway. Call [`validate`] first: an invalid
+/// combination makes `tower-http` panic on the first request.
+pub fn cors_layer(config: &Config) -> CorsLayer {
+    let origin = if allows_any_origin(config) {
+        AllowOrigin::any()
+    } else {
+        AllowOrigin::list(
+            config
+                .cors_allowed_origins
+                .iter()
+                .filter_map(|origin| HeaderValue::from_str(origin).ok()),
+        )
+    };
+
+    CorsLayer::new()
+        .allow_origin(origin)
+        .allow_methods(ALLOWED_METHODS)
+        .allow_headers([CONTENT_TYPE, AUTHORIZATION, HeaderName::from_static(API_KEY_HEADER)])
+        .expose_headers([REQUEST_ID_HEADER, DATA_SOURCE_HEADER, TRACEPARENT_HEADER])
+        .allow_credentials(config.cors_allow_credentials)
+        .max_age(Duration::from_secs(config.cors_max_age_secs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    use crate::{build_app, AppState};
+
+    fn preflight(origin: &str) -> Request<Body> {
+        Request::options("/stablecoins")
+            .header("origin", origin)
+            .header("access-control-request-method", "GET")
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn preflight_carries_max_age_and_credentials() {
+        let config = Config {
+            cors_allowed_origins: vec!["https://app.reflect.money".to_string()],
+            cors_allow_credentials: true,
+            cors_max_age_secs: 3600,
+            ..Config::default()
+        };
+        let response = build_app(AppState::new(config))
+            .oneshot(preflight("https://app.reflect.money"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let headers = response.headers();
+        assert_eq!(headers["access-control-max-age"], "3600");
+        assert_eq!(headers["access-control-allow-credentials"], "true");
+        assert_eq!(headers["access-control-allow-origin"], "https://app.reflect.money");
+    }
+
+    #[tokio::test]
+    async fn default_allows_any_origin_without_credentials() {
+        let response = build_app(AppState::default())
+            .oneshot(preflight("https://example.com"))
+            .await
+            .unwrap();
+
+        let headers = response.headers();
+        assert_eq!(headers["access-control-allow-origin"], "*");
+        assert_eq!(headers["access-control-max-age"], "600");
+        assert!(!headers.contains_key("access-control-allow-credentials"));
+    }
+
+    #[test]
+    fn credentials_with_wildcard_origin_is_rejected() {
+        let wildcard = Config {
+            cors_allow_credentials: true,
+            ..Config::default()
+        };
+        assert!(validate(&wildcard).unwrap_err().contains("REFLECT_CORS_ALLOW_CREDENTIALS"));
+
+        let explicit_star = Config {
+            cors_allowed_origins: vec!["https://app.reflect.money".to_string(), "*".to_string()],
+            ..wildcard.clone()
+        };
+        assert!(validate(&explicit_star).is_err());
+
+        let listed = Config {
+            cors_allowed_origins: vec!["https://app.reflect.money".to_string()],
+            ..wildcard
+        };
+        assert!(validate(&listed).is_ok());
+        assert!(validate(&Config::default()).is_ok());
+
+        let bad_origin = Config {
+            cors_allowed_origins: vec!["https://bad\norigin".to_string()],
+            ..Config::default()
+        };
+        assert!(validate(&bad_origin).is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use axum::{
+    body::{to_bytes, Body},
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::{IntoResponseParts, Response, ResponseParts},
+};
+use serde_json::Value;
+use std::convert::Infallible;
+
+/// Header reporting where a read endpoint's data came from.
+pub const DATA_SOURCE_HEADER: HeaderName = HeaderName::from_static("x-data-source");
+
+/// Upper bound on bodies rewritten for `?debugSource=true`.
+const DEBUG_BODY_LIMIT: usize = 4 * 1024 * 1024;
+
+/// Where the data in a read response came from, to help integrators debug staleness.
+///
+/// Returned from handlers as a response part, which sets `X-Data-Source`:
+///
+/// ```ignore
+/// (StatusCode::OK, DataSource::Cache, Json(body))
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataSource {
+    /// Served from the in-process response cache.
+    Cache,
+    /// Computed or read from local state for this request.
+    Local,
+    /// Fetched from an upstream service (e.g. the Solana RPC node).
+    Upstream,
+}
+
+impl DataSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DataSource::Cache => "cache",
+            DataSource::Local => "local",
+            DataSource::Upstream => "upstream",
+        }
+    }
+}
+
+impl IntoResponseParts for DataSource {
+    type Error = Infallible;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        res.headers_mut()
+            .insert(DATA_SOURCE_HEADER, HeaderValue::from_static(self.as_str()));
+        Ok(res)
+    }
+}
+
+/// Middleware adding a top-level `"source"` field to JSON bodies when the
+/// request carries `?debugSource=true` and the handler reported a data source.
+pub async fn debug_source(request: Request, next: Next) -> Response {
+    let wants_source = request
+        .uri()
+        .query()
+        .is_some_and(|query| query.split('&').any(|pair| pair == "debugSource=true"));
+
+    let response = next.run(request).await;
+    let Some(source) = response.headers().get(DATA_SOURCE_HEADER).cloned() else {
+        return response;
+    };
+    if !wants_source {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, DEBUG_BODY_LIMIT).await {
+        Ok(bytes) => bytes,
+        Err(_) => return Response::from_parts(parts, Body::empty()),
+    };
+
+    let body = match serde_json::from_slice::<Value>(&bytes) {
+        Ok(Value::Object(mut object)) => {
+            object.insert(
+                "source".to_string(),
+                Value::String(source.to_str().unwrap_or_default().to_string()),
+            );
+            parts.headers.remove(axum::http::header::CONTENT_LENGTH);
+            Body::from(Value::Object(object).to_string())
+        }
+        _ => Body::from(bytes),
+    };
+    Response::from_parts(parts, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::StatusCode;
+    use axum::routing::get;
+    use axum::{middleware, Json, Router};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    async fn send(uri: &str) -> (Option<String>, Value) {
+        let app = Router::new()
+            .route(
+                "/",
+                get(|| async { (StatusCode::OK, DataSource::Cache, Json(json!({ "success": true }))) }),
+            )
+            .layer(middleware::from_fn(debug_source));
+        let response = app
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        let source = response
+            .headers()
+            .get(DATA_SOURCE_HEADER)
+            .map(|value| value.to_str().unwrap().to_string());
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        (source, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn header_always_body_field_on_request() {
+        let (source, json) = send("/").await;
+        assert_eq!(source.as_deref(), Some("cache"));
+        assert!(json.get("source").is_none());
+
+        let (source, json) = send("/?debugSource=true").await;
+        assert_eq!(source.as_deref(), Some("cache"));
+        assert_eq!(json["source"], "cache");
+        assert_eq!(json["success"], true);
+    }
+}
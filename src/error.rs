@@ -0,0 +1,149 @@
+use axum::{
+    http::{header::RETRY_AFTER, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+use std::time::Duration;
+
+/// An error reported to the client in the standard `{ success: false, message }` envelope.
+///
+/// ### Status code policy
+/// - [`ApiError::BadRequest`] → **400**: the request is syntactically malformed and
+///   could not be parsed at all, e.g. unparseable JSON or `?limit=abc`.
+/// - [`ApiError::Unprocessable`] → **422**: the request parsed but its values are
+///   semantically invalid, e.g. a negative amount or an unknown stablecoin index.
+/// - [`ApiError::NotFound`] → **404**: nothing matched, e.g. a filtered list came
+///   back empty and the client asked for `?emptyIsError=true`.
+/// - [`ApiError::Internal`] → **500**: the server could not produce a response,
+///   e.g. a computed value failed to serialize. Details are logged, never sent.
+/// - [`ApiError::TooManyRequests`] → **429**: the caller is being throttled and
+///   may retry after `retry_after_secs`, sent as a `Retry-After` header.
+/// - [`ApiError::Upstream`] → **502**: a dependency such as the Solana RPC node
+///   failed or answered with something unusable.
+/// - [`ApiError::Rpc`] → **422 / 502 / 503**: the Solana RPC node reported a
+///   structured error; the status follows the code (see
+///   [`crate::rpc::rpc_error_status`]) and the body carries it as `rpcCode`.
+/// - [`ApiError::Unavailable`] → **503**: the server is deliberately not serving
+///   the request right now, e.g. RPC is not configured or a stablecoin is paused.
+/// - [`ApiError::NotImplemented`] → **501**: the request is valid but asks for
+///   something not built yet, e.g. a `swap` quote.
+///
+/// Handlers return `Result<_, ApiError>` and use `?`; the error renders as the
+/// standard envelope through [`IntoResponse`].
+///
+/// JSON bodies follow the same split before a handler runs: axum's `Json`
+/// extractor answers syntax errors with 400 and type mismatches or missing
+/// fields with 422.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiError {
+    BadRequest(String),
+    Unprocessable(String),
+    NotFound(String),
+    Internal,
+    TooManyRequests { message: String, retry_after_secs: u64 },
+    Upstream(String),
+    Unavailable(String),
+    NotImplemented(String),
+    Rpc { code: i64, message: String },
+}
+
+impl ApiError {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ApiError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            ApiError::Unprocessable(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::NotFound(_) => StatusCode::NOT_FOUND,
+            ApiError::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+            ApiError::TooManyRequests { .. } => StatusCode::TOO_MANY_REQUESTS,
+            ApiError::Upstream(_) => StatusCode::BAD_GATEWAY,
+            ApiError::Unavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+            ApiError::NotImplemented(_) => StatusCode::NOT_IMPLEMENTED,
+            ApiError::Rpc { code, .. } => crate::rpc::rpc_error_status(*code),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            ApiError::BadRequest(message)
+            | ApiError::Unprocessable(message)
+            | ApiError::NotFound(message)
+            | ApiError::Upstream(message)
+            | ApiError::Unavailable(message)
+            | ApiError::NotImplemented(message)
+            | ApiError::TooManyRequests { message, .. }
+            | ApiError::Rpc { message, .. } => message,
+            ApiError::Internal => "Internal server error",
+        }
+    }
+
+    /// A 429 for `reason` that may be retried once `remaining` has passed,
+    /// rounded up to whole seconds, e.g. "mint cooldown active, retry after 60s".
+    pub fn retry_after(reason: &str, remaining: Duration) -> Self {
+        let retry_after_secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+        ApiError::TooManyRequests {
+            message: format!("{}, retry after {}s", reason, retry_after_secs),
+            retry_after_secs,
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let mut body = json!({ "success": false, "message": self.message() });
+        if let ApiError::Rpc { code, .. } = self {
+            body["rpcCode"] = json!(code);
+        }
+        let mut response = (self.status(), Json(body)).into_response();
+        if let ApiError::TooManyRequests { retry_after_secs, .. } = self {
+            response.headers_mut().insert(RETRY_AFTER, retry_after_secs.into());
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::body::to_bytes;
+    use serde_json::Value;
+
+    async fn render(error: ApiError) -> (StatusCode, Value) {
+        let response = error.into_response();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn status_follows_policy() {
+        let (status, body) = render(ApiError::BadRequest("malformed".into())).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body, json!({ "success": false, "message": "malformed" }));
+
+        let (status, body) = render(ApiError::Unprocessable("invalid".into())).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["message"], "invalid");
+
+        let (status, body) = render(ApiError::Internal).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(body["message"], "Internal server error");
+
+        let (_, body) = render(ApiError::Rpc { code: -32002, message: "simulation failed".into() }).await;
+        assert_eq!(body["rpcCode"], -32002);
+
+        assert_eq!(ApiError::Upstream("rpc".into()).status(), StatusCode::BAD_GATEWAY);
+        assert_eq!(ApiError::Unavailable("paused".into()).status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(ApiError::NotImplemented("later".into()).status(), StatusCode::NOT_IMPLEMENTED);
+        assert_eq!(ApiError::NotFound("gone".into()).into_response().status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn too_many_requests_sets_retry_after() {
+        let error = ApiError::TooManyRequests { message: "slow down".into(), retry_after_secs: 7 };
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[RETRY_AFTER], "7");
+    }
+}
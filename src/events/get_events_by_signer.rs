@@ -1,10 +1,111 @@
-use axum::{response::IntoResponse, Json, extract::Query};
+use axum::{response::IntoResponse, Json, extract::{Query, State}, http::StatusCode};
 use serde::Deserialize;
 use serde_json::json;
+use crate::data_source::DataSource;
+use crate::error::ApiError;
+use crate::pagination::{paginate, EmptyParams, PageParams};
+use crate::validation::validate_pubkey;
+use crate::AppState;
 
+/// Query parameters for `GET /events/by-signer`.
+///
+/// - `signer`: Only return events signed by this base58 address (exact match);
+///   anything that is not a valid public key is rejected with 422.
+/// - `emptyIsError`: Answer 404 instead of an empty list ([`EmptyParams`]).
+///
+/// Paging uses the standard `limit` / `offset` parameters ([`PageParams`]).
 #[derive(Deserialize)]
 pub struct SignerQuery { pub signer: String }
 
-pub async fn get_events_by_signer(Query(_q): Query<SignerQuery>) -> impl IntoResponse {
-    Json(json!({"events": [{"id": "evt_1", "signer": "0xabc"}]}))
+pub async fn get_events_by_signer(State(state): State<AppState>, Query(q): Query<SignerQuery>, Query(page): Query<PageParams>, Query(empty): Query<EmptyParams>) -> Result<impl IntoResponse, ApiError> {
+    validate_pubkey("signer", &q.signer)?;
+    let page_query = page.parse()?;
+
+    let events = state.events.query(|e| e.signer == q.signer);
+    empty.check(&events, "events")?;
+    let page = paginate(events, &page_query);
+
+    Ok((StatusCode::OK, DataSource::Local, Json(json!({
+        "success": true,
+        "meta": state.cased(page.meta()),
+        "data": state.cased(page.items),
+    }))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use chrono::{TimeZone, Utc};
+    use serde_json::Value;
+    use crate::timestamp::Timestamp;
+
+    const SIGNER_A: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+    const SIGNER_B: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+    fn at(hour: u32) -> Timestamp {
+        Timestamp(Utc.with_ymd_and_hms(2025, 12, 18, hour, 0, 0).unwrap())
+    }
+
+    async fn call_with(state: AppState, signer: &str, page: PageParams, empty_is_error: bool) -> (StatusCode, Value) {
+        let response = get_events_by_signer(
+            State(state),
+            Query(SignerQuery { signer: signer.to_string() }),
+            Query(page),
+            Query(EmptyParams { empty_is_error }),
+        )
+        .await
+        .into_response();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, 64 * 1024).await.unwrap();
+        (parts.status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    async fn call(empty_is_error: bool) -> (StatusCode, Value) {
+        let state = AppState::default();
+        state.events.record("mint", SIGNER_A, None, None, Timestamp::now());
+        call_with(state, SIGNER_B, PageParams::default(), empty_is_error).await
+    }
+
+    #[tokio::test]
+    async fn test_only_matching_signer_paged() {
+        let state = AppState::default();
+        for hour in [1, 2, 3] {
+            state.events.record("mint", SIGNER_A, None, None, at(hour));
+            state.events.record("burn", SIGNER_B, None, None, at(hour));
+        }
+
+        let (status, json) = call_with(state.clone(), SIGNER_A, PageParams::default(), false).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["meta"]["total"], 3);
+        let events = json["data"].as_array().unwrap();
+        assert!(events.iter().all(|e| e["signer"] == SIGNER_A && e["type"] == "mint"));
+
+        let page = PageParams { limit: Some("2".into()), offset: Some("1".into()) };
+        let (_, json) = call_with(state, SIGNER_B, page, false).await;
+        assert_eq!(json["meta"]["has_more"], false);
+        let timestamps: Vec<&str> = json["data"].as_array().unwrap().iter().map(|e| e["timestamp"].as_str().unwrap()).collect();
+        assert_eq!(timestamps, vec!["2025-12-18T02:00:00.000Z", "2025-12-18T01:00:00.000Z"]);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_signer_is_unprocessable() {
+        let (status, json) = call_with(AppState::default(), "signer_a", PageParams::default(), false).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid request data: signer is not a valid public key");
+    }
+
+    #[tokio::test]
+    async fn test_no_match_is_empty_list_by_default() {
+        let (status, json) = call(false).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_no_match_with_empty_is_error() {
+        let (status, json) = call(true).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(json, json!({ "success": false, "message": "No events found" }));
+    }
 }
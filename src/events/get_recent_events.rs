@@ -1,6 +1,160 @@
-use axum::{response::IntoResponse, Json};
+use axum::{response::IntoResponse, Json, extract::{Query, State}, http::StatusCode};
+use serde::Deserialize;
 use serde_json::json;
+use crate::data_source::DataSource;
+use crate::error::ApiError;
+use crate::pagination::{paginate, PageParams};
+use crate::span::handler_span;
+use crate::validation::parse_time_range;
+use crate::AppState;
 
-pub async fn get_recent_events() -> impl IntoResponse {
-    Json(json!({"events": [{"id": "evt_1", "type": "mint"}, {"id": "evt_2", "type": "burn"}]}))
+/// Query parameters for `GET /events/recent`.
+///
+/// - `since` / `until`: Inclusive RFC 3339 time window, e.g.
+///   `?since=2025-12-18T00:00:00Z&until=2025-12-18T23:59:59Z`. Either may be omitted.
+///
+/// The number of events returned is capped by the standard `limit` / `offset`
+/// parameters ([`PageParams`]).
+#[derive(Debug, Default, Deserialize)]
+pub struct RecentEventsQuery {
+    pub since: Option<String>,
+    pub until: Option<String>,
+}
+
+/// Handler for `GET /events/recent`.
+///
+/// Returns the mints and burns recorded in the in-memory event buffer,
+/// newest first, optionally restricted to a time window.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request GET \
+///   --url "http://localhost:3000/events/recent?since=2025-12-18T00:00:00Z&limit=10"
+/// ```
+///
+/// Expected output:
+/// ```json
+/// {
+///   "success": true,
+///   "meta": { "total": 1, "limit": 10, "offset": 0, "has_more": false },
+///   "data": [
+///     {
+///       "id": "evt_1",
+///       "type": "mint",
+///       "timestamp": "2025-12-18T12:00:00.000Z",
+///       "signer": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+///     }
+///   ]
+/// }
+/// ```
+pub async fn get_recent_events(State(state): State<AppState>, Query(q): Query<RecentEventsQuery>, Query(page): Query<PageParams>) -> Result<impl IntoResponse, ApiError> {
+    let _span = handler_span!("GET /events/recent").entered();
+    let (since, until) = parse_time_range(("since", q.since.as_deref()), ("until", q.until.as_deref()))?;
+    let page_query = page.parse()?;
+
+    let events = state.events.query(|e| {
+        since.is_none_or(|since| e.timestamp >= since) && until.is_none_or(|until| e.timestamp <= until)
+    });
+    let page = paginate(events, &page_query);
+
+    Ok((StatusCode::OK, DataSource::Local, Json(json!({
+        "success": true,
+        "meta": state.cased(page.meta()),
+        "data": state.cased(page.items),
+    }))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timestamp::Timestamp;
+    use axum::body::to_bytes;
+    use chrono::{TimeZone, Utc};
+    use serde_json::Value;
+
+    fn at(hour: u32) -> Timestamp {
+        Timestamp(Utc.with_ymd_and_hms(2025, 12, 18, hour, 0, 0).unwrap())
+    }
+
+    fn seeded_state() -> AppState {
+        let state = AppState::default();
+        for (hour, event_type) in [(1, "mint"), (6, "burn"), (12, "mint"), (18, "burn"), (23, "mint")] {
+            state.record_event(event_type, "signer_a", None, None, at(hour));
+        }
+        state
+    }
+
+    async fn call(state: AppState, since: Option<&str>, until: Option<&str>, limit: Option<&str>) -> (StatusCode, Value) {
+        let query = RecentEventsQuery { since: since.map(String::from), until: until.map(String::from) };
+        let page = PageParams { limit: limit.map(String::from), ..PageParams::default() };
+        let response = get_recent_events(State(state), Query(query), Query(page)).await.into_response();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, 64 * 1024).await.unwrap();
+        (parts.status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    fn hours(json: &Value) -> Vec<&str> {
+        json["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| &e["timestamp"].as_str().unwrap()[11..13])
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_window_filter_newest_first() {
+        let (status, json) = call(seeded_state(), Some("2025-12-18T06:00:00Z"), Some("2025-12-18T18:00:00Z"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["meta"]["total"], 3);
+        assert_eq!(hours(&json), vec!["18", "12", "06"]);
+        assert_eq!(json["data"][0]["type"], "burn");
+
+        let (_, json) = call(seeded_state(), Some("2025-12-18T13:00:00Z"), None, None).await;
+        assert_eq!(hours(&json), vec!["23", "18"]);
+
+        let (_, json) = call(seeded_state(), None, Some("2025-12-18T05:59:59Z"), None).await;
+        assert_eq!(hours(&json), vec!["01"]);
+
+        let (_, json) = call(seeded_state(), None, None, Some("2")).await;
+        assert_eq!(json["meta"]["total"], 5);
+        assert_eq!(hours(&json), vec!["23", "18"]);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_window() {
+        let (status, json) = call(seeded_state(), Some("yesterday"), None, None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["message"], "Invalid `since`: expected an RFC 3339 timestamp");
+
+        let (status, json) = call(seeded_state(), Some("2025-12-18T18:00:00Z"), Some("2025-12-18T06:00:00Z"), None).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid time range: `since` must not be after `until`");
+    }
+
+    #[tokio::test]
+    async fn test_mint_and_burn_are_recorded() {
+        use crate::build_app;
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        let state = AppState::default();
+        let signer = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+        for route in ["/stablecoins/mint/tx", "/stablecoins/burn/tx"] {
+            let body = json!({ "stablecoinIndex": 0, "depositAmount": 1_000_000, "signer": signer, "minimumReceived": 1 });
+            let request = Request::post(route)
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap();
+            assert_eq!(build_app(state.clone()).oneshot(request).await.unwrap().status(), StatusCode::OK);
+        }
+
+        let (_, json) = call(state, None, None, None).await;
+        let types: Vec<&str> = json["data"].as_array().unwrap().iter().map(|e| e["type"].as_str().unwrap()).collect();
+        assert_eq!(types.len(), 2);
+        assert!(types.contains(&"mint") && types.contains(&"burn"));
+        assert_eq!(json["data"][0]["signer"], signer);
+    }
 }
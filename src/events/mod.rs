@@ -3,6 +3,7 @@ use crate::AppState;
 
 pub mod get_recent_events;
 pub mod get_events_by_signer;
+pub mod store;
 
 pub fn router() -> Router<AppState> {
     Router::new()
@@ -0,0 +1,163 @@
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+use crate::stats::aggregates::AggregateTotals;
+use crate::stats::ledger::Volumes;
+use crate::timestamp::Timestamp;
+
+/// Maximum number of events retained in memory; the oldest are evicted first.
+pub const EVENT_BUFFER_CAPACITY: usize = 10_000;
+
+/// A protocol event (mint, burn, ...) as recorded in memory.
+///
+/// ### Example
+/// ```json
+/// {
+///   "id": "evt_1",
+///   "type": "mint",
+///   "timestamp": "2025-12-19T16:55:42.407Z",
+///   "signer": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+///   "integration_id": "int_1"
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EventRecord {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub timestamp: Timestamp,
+    pub signer: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration_id: Option<String>,
+    /// What a mint or burn moved, for rebuilding the
+    /// [`crate::stats::ledger::VolumeLedger`]. Not sent to clients.
+    #[serde(skip)]
+    pub volume: Option<EventVolume>,
+}
+
+/// `amount` smallest units of stablecoin `index`, moved by one event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventVolume {
+    pub index: u32,
+    pub amount: u64,
+}
+
+/// Bounded, in-memory ring buffer of events shared through [`crate::AppState`].
+///
+/// Events evicted from the buffer are still counted in [`EventStore::totals`]
+/// and [`EventStore::volumes`].
+#[derive(Debug, Clone, Default)]
+pub struct EventStore {
+    events: Arc<RwLock<Buffer>>,
+    next_id: Arc<AtomicU64>,
+}
+
+#[derive(Debug, Default)]
+struct Buffer {
+    recent: VecDeque<EventRecord>,
+    /// Counts of every event evicted from `recent`.
+    evicted: AggregateTotals,
+    /// Amounts moved by every event evicted from `recent`.
+    evicted_volumes: Volumes,
+}
+
+impl EventStore {
+    /// Record a new event, assigning it the next id.
+    pub fn record(
+        &self,
+        event_type: &str,
+        signer: &str,
+        integration_id: Option<&str>,
+        volume: Option<EventVolume>,
+        timestamp: Timestamp,
+    ) -> EventRecord {
+        let id = format!("evt_{}", self.next_id.fetch_add(1, Ordering::Relaxed) + 1);
+        let event = EventRecord {
+            id,
+            event_type: event_type.to_string(),
+            timestamp,
+            signer: signer.to_string(),
+            integration_id: integration_id.map(String::from),
+            volume,
+        };
+
+        let mut events = self.events.write().unwrap();
+        if events.recent.len() == EVENT_BUFFER_CAPACITY {
+            if let Some(evicted) = events.recent.pop_front() {
+                events.evicted.apply(&evicted);
+                events.evicted_volumes.apply(&evicted);
+            }
+        }
+        events.recent.push_back(event.clone());
+        event
+    }
+
+    /// Mint / redeem counts over every event ever recorded, evicted ones
+    /// included.
+    pub fn totals(&self) -> AggregateTotals {
+        let events = self.events.read().unwrap();
+        let mut totals = events.evicted.clone();
+        for event in &events.recent {
+            totals.apply(event);
+        }
+        totals
+    }
+
+    /// Amounts moved per stablecoin over every event ever recorded, evicted
+    /// ones included.
+    pub fn volumes(&self) -> Volumes {
+        let events = self.events.read().unwrap();
+        let mut volumes = events.evicted_volumes.clone();
+        for event in &events.recent {
+            volumes.apply(event);
+        }
+        volumes
+    }
+
+    /// Events matching `filter`, newest first.
+    pub fn query(&self, filter: impl Fn(&EventRecord) -> bool) -> Vec<EventRecord> {
+        let mut events: Vec<EventRecord> = self
+            .events
+            .read()
+            .unwrap()
+            .recent
+            .iter()
+            .filter(|e| filter(e))
+            .cloned()
+            .collect();
+        events.sort_by_key(|e| Reverse(e.timestamp));
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::ledger::VolumeLedger;
+    use crate::stats::units::SmallestUnits;
+
+    #[test]
+    fn totals_still_count_evicted_events() {
+        let store = EventStore::default();
+        for _ in 0..EVENT_BUFFER_CAPACITY {
+            store.record("mint", "signer", Some("int_1"), Some(EventVolume { index: 0, amount: 10 }), Timestamp::now());
+        }
+        store.record("burn", "signer", Some("int_1"), None, Timestamp::now());
+        store.record("burn", "signer", None, Some(EventVolume { index: 0, amount: 5 }), Timestamp::now());
+
+        assert_eq!(store.query(|_| true).len(), EVENT_BUFFER_CAPACITY);
+        let totals = store.totals();
+        assert_eq!(totals.protocol.mints, EVENT_BUFFER_CAPACITY as u64);
+        assert_eq!(totals.protocol.redeems, 2);
+        assert_eq!(totals.integrations["int_1"].redeems, 1);
+
+        let ledger = VolumeLedger::default();
+        ledger.rebuild(store.volumes());
+        let volumes = ledger.totals();
+        assert_eq!(volumes.total_minted, SmallestUnits(EVENT_BUFFER_CAPACITY as u128 * 10));
+        assert_eq!(volumes.total_redeemed, SmallestUnits(5));
+    }
+}
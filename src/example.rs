@@ -0,0 +1,43 @@
+//! Example request bodies served next to the POST routes they belong to.
+//!
+//! Each example is built from the request struct itself and serialized, so
+//! it follows renames and new fields instead of drifting like a hand-written
+//! snippet would.
+
+use axum::Json;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+
+/// Wallet used as the signer in examples.
+pub const EXAMPLE_SIGNER: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+
+/// USDC mint, used as the collateral in examples.
+pub const EXAMPLE_COLLATERAL_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+/// A request body type that can produce a valid example of itself.
+///
+/// The example must deserialize back into `Self` and be accepted by the
+/// endpoint it documents.
+pub trait ExampleBody: Serialize + DeserializeOwned {
+    fn example() -> Self;
+}
+
+/// Handler for `GET .../example`, returning `T`'s example body.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request GET \
+///   --url http://localhost:3000/stablecoins/mint/tx/example
+/// ```
+pub async fn example_body<T: ExampleBody>() -> Json<Value> {
+    Json(json!({ "success": true, "data": T::example() }))
+}
+
+/// Assert that `T`'s example survives a JSON round trip unchanged.
+#[cfg(test)]
+pub fn assert_round_trips<T: ExampleBody>() {
+    let example = serde_json::to_value(T::example()).unwrap();
+    let parsed: T = serde_json::from_value(example.clone()).expect("example deserializes");
+    assert_eq!(serde_json::to_value(parsed).unwrap(), example);
+}
@@ -0,0 +1,62 @@
+//! Serialization that refuses non-finite floats.
+//!
+//! `serde_json` quietly writes `NaN` and `±inf` as `null`, and `json!` panics
+//! if a value fails to serialize. Float fields that reach the wire are marked
+//! `#[serde(serialize_with = "finite::serialize")]` so a bad computation fails
+//! loudly, and handlers build their body with [`to_json`] so that failure
+//! becomes a clean 500 instead of a panic.
+
+use serde::{ser::Error, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::error::ApiError;
+
+/// `serialize_with` for `f64` fields: errors on `NaN` and infinities.
+pub fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+    if !value.is_finite() {
+        return Err(S::Error::custom(format!("non-finite float {}", value)));
+    }
+    serializer.serialize_f64(*value)
+}
+
+/// `serialize_with` for `Option<f64>` fields: `None` stays `null`.
+pub fn serialize_opt<S: Serializer>(value: &Option<f64>, serializer: S) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => serialize(value, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Serialize `value` to JSON, mapping any failure to [`ApiError::Internal`].
+pub fn to_json<T: Serialize>(value: &T) -> Result<Value, ApiError> {
+    serde_json::to_value(value).map_err(|error| {
+        tracing::error!(%error, "Failed to serialize response");
+        ApiError::Internal
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Sample {
+        #[serde(serialize_with = "serialize")]
+        value: f64,
+        #[serde(serialize_with = "serialize_opt")]
+        optional: Option<f64>,
+    }
+
+    #[test]
+    fn finite_values_serialize() {
+        let json = to_json(&Sample { value: 1.5, optional: None }).unwrap();
+        assert_eq!(json, serde_json::json!({ "value": 1.5, "optional": null }));
+    }
+
+    #[test]
+    fn non_finite_values_are_internal_errors() {
+        for (value, optional) in [(f64::NAN, None), (1.0, Some(f64::INFINITY)), (f64::NEG_INFINITY, Some(1.0))] {
+            assert_eq!(to_json(&Sample { value, optional }), Err(ApiError::Internal));
+        }
+    }
+}
@@ -1,13 +1,15 @@
 use axum::response::{IntoResponse, Json};
-use chrono::Utc;
 use serde::Serialize;
 
+use crate::timestamp::Timestamp;
+
 /// Response structure for the `/health` endpoint, matching the official Reflect API.
 ///
 /// ### Fields
 /// - `success`: Always `true` when the server is healthy and responding.
 /// - `message`: Human-readable status message (e.g., `"API is running"`).
-/// - `timestamp`: Current UTC timestamp in ISO 8601 format with milliseconds and 'Z' suffix.
+/// - `timestamp`: Current UTC timestamp in ISO 8601 format with milliseconds and 'Z' suffix
+///   (whole seconds with `?timePrecision=seconds`).
 ///
 /// ### Example Successful Response (HTTP 200)
 /// ```json
@@ -25,7 +27,7 @@ use serde::Serialize;
 pub struct HealthResponse {
     success: bool,
     message: &'static str,
-    timestamp: String,
+    timestamp: Timestamp,
 }
 
 /// Handler for `GET /health`.
@@ -40,22 +42,22 @@ pub struct HealthResponse {
 ///
 /// # Examples
 ///
-/// ```
-/// use reflect_api_rs::health::health_check;
-/// use axum::response::IntoResponse;
+/// ```no_run
+/// use axum::{body::Body, http::Request};
+/// use reflect_api_rs::{build_app, AppState};
+/// use tower::ServiceExt;
 ///
-/// # tokio_test::block_on(async {
-/// let response = health_check().await.into_response();
+/// # async fn example() {
+/// let request = Request::get("/health").body(Body::empty()).unwrap();
+/// let response = build_app(AppState::default()).oneshot(request).await.unwrap();
 /// assert_eq!(response.status(), axum::http::StatusCode::OK);
-/// # });
+/// # }
 /// ```
 pub async fn health_check() -> impl IntoResponse {
-    let timestamp = Utc::now().format("%Y-%m-%dT%H:%M:%S.%3fZ").to_string();
-
     Json(HealthResponse {
         success: true,
         message: "API is running",
-        timestamp,
+        timestamp: Timestamp::now(),
     })
 }
 
@@ -102,4 +104,16 @@ mod tests {
             "Timestamp should be within 5 seconds of current time"
         );
     }
+
+    /// The timestamp keeps the official ISO 8601 millisecond shape.
+    #[tokio::test]
+    async fn health_timestamp_has_millisecond_precision() {
+        let response = health_check().await.into_response();
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+
+        let pattern = regex::Regex::new(r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}\.\d{3}Z$").unwrap();
+        let timestamp = json["timestamp"].as_str().unwrap();
+        assert!(pattern.is_match(timestamp), "{}", timestamp);
+    }
 }
@@ -2,7 +2,12 @@ use axum::Router;
 use crate::AppState;
 
 pub mod health_check;
+pub mod readiness;
+pub mod rpc_health;
 
 pub fn router() -> Router<AppState> {
-    Router::new().route("/", axum::routing::get(health_check::health_check))
+    Router::new()
+        .route("/", axum::routing::get(health_check::health_check))
+        .route("/ready", axum::routing::get(readiness::readiness))
+        .route("/rpc", axum::routing::get(rpc_health::rpc_health))
 }
@@ -0,0 +1,154 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::cors::{self, CorsSummary};
+use crate::load::LoadSnapshot;
+use crate::AppState;
+
+#[derive(Debug, Serialize)]
+struct ReadinessData {
+    #[serde(flatten)]
+    load: LoadSnapshot,
+    cors: CorsSummary,
+}
+
+/// Handler for `GET /health/ready`.
+///
+/// Readiness probe for load balancers. Reports the in-flight request count
+/// and queue depth, and answers 503 with `"ready": false` while the request
+/// queue is full so traffic is routed elsewhere until it drains. `data.cors`
+/// echoes the effective CORS configuration (see [`CorsSummary`]).
+///
+/// ### Example Response (HTTP 200 / 503)
+/// ```json
+/// {
+///   "success": true,
+///   "ready": true,
+///   "data": {
+///     "in_flight": 3, "queued": 0, "max_concurrent": 512, "max_queued": 1024,
+///     "cors": {
+///       "allowed_origins": ["*"],
+///       "allowed_methods": ["GET", "POST", "PUT", "PATCH", "DELETE"],
+///       "allow_credentials": false,
+///       "max_age_secs": 600
+///     }
+///   }
+/// }
+/// ```
+pub async fn readiness(State(state): State<AppState>) -> impl IntoResponse {
+    let load = state.load.snapshot();
+    let ready = !load.saturated();
+    let status = if ready { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
+    let data = ReadinessData { load, cors: cors::summary(&state.config) };
+
+    (status, Json(json!({ "success": ready, "ready": ready, "data": state.cased(data) })))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::{to_bytes, Body};
+    use axum::http::{Request, StatusCode};
+    use serde_json::Value;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::sync::Notify;
+    use tower::ServiceExt;
+
+    use crate::config::Config;
+    use crate::load::limit_concurrency;
+    use crate::{build_app, AppState};
+
+    async fn ready(state: &AppState) -> (StatusCode, Value) {
+        let response = build_app(state.clone())
+            .oneshot(Request::get("/health/ready").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 4096).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn readiness_flips_when_queue_saturates() {
+        let state = AppState::new(Config {
+            max_concurrent_requests: 1,
+            max_queued_requests: 1,
+            ..Config::default()
+        });
+        let release = Arc::new(Notify::new());
+        let blocking = {
+            let release = release.clone();
+            axum::Router::new()
+                .route(
+                    "/slow",
+                    axum::routing::get(move || async move { release.notified().await }),
+                )
+                .layer(axum::middleware::from_fn_with_state(state.clone(), limit_concurrency))
+        };
+        let send = || {
+            let app = blocking.clone();
+            tokio::spawn(async move {
+                app.oneshot(Request::get("/slow").body(Body::empty()).unwrap())
+                    .await
+                    .unwrap()
+                    .status()
+            })
+        };
+
+        let (status, json) = ready(&state).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["ready"], true);
+
+        // One request running, one waiting: the queue is full
+        let running = send();
+        let waiting = send();
+        while state.load.snapshot().queued < 1 {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        let (status, json) = ready(&state).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(json["ready"], false);
+        assert_eq!(json["data"]["in_flight"], 1);
+        assert_eq!(json["data"]["queued"], 1);
+
+        // Past the queue, requests are shed
+        assert_eq!(send().await.unwrap(), StatusCode::SERVICE_UNAVAILABLE);
+
+        release.notify_one();
+        assert_eq!(running.await.unwrap(), StatusCode::OK);
+        release.notify_one();
+        assert_eq!(waiting.await.unwrap(), StatusCode::OK);
+
+        let (status, json) = ready(&state).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["in_flight"], 0);
+        assert_eq!(json["data"]["queued"], 0);
+    }
+
+    #[tokio::test]
+    async fn readiness_reports_cors_configuration() {
+        let (_, json) = ready(&AppState::default()).await;
+        assert_eq!(json["data"]["cors"]["allowed_origins"], serde_json::json!(["*"]));
+        assert_eq!(json["data"]["cors"]["allow_credentials"], false);
+
+        let state = AppState::new(Config {
+            cors_allowed_origins: vec!["https://app.reflect.money".to_string(), "https://docs.reflect.money".to_string()],
+            cors_allow_credentials: true,
+            cors_max_age_secs: 3600,
+            ..Config::default()
+        });
+        let (status, json) = ready(&state).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            json["data"]["cors"],
+            serde_json::json!({
+                "allowed_origins": ["https://app.reflect.money", "https://docs.reflect.money"],
+                "allowed_methods": ["GET", "POST", "PUT", "PATCH", "DELETE"],
+                "allow_credentials": true,
+                "max_age_secs": 3600
+            })
+        );
+        assert_eq!(json["data"]["in_flight"], 0);
+    }
+}
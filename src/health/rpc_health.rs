@@ -0,0 +1,250 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Json},
+};
+use serde::Serialize;
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
+
+use crate::auth::AdminKey;
+use crate::cluster::Cluster;
+use crate::rpc::failover::ActiveEndpoint;
+use crate::rpc::{Commitment, RpcClient};
+use crate::AppState;
+
+/// How long a single endpoint may take to answer `getSlot` before it is reported as down.
+pub const RPC_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Health of the RPC endpoints for one cluster.
+///
+/// ### Fields
+/// - `cluster`: `mainnet` or `devnet`.
+/// - `status`: `ok`, `down`, or `unconfigured` when no endpoint is set for the cluster.
+/// - `latencyMs`: Round-trip time of the probe (absent when unconfigured).
+/// - `slot`: Current slot reported by the node (only when `ok`).
+/// - `error`: Why the probe failed (only when `down`).
+/// - `fallback`: The same fields for the fallback endpoint, when one is configured.
+/// - `active`: Which endpoint requests are currently sent to, `primary` or `secondary`
+///   (only with a fallback).
+///
+/// The top-level fields describe the primary endpoint alone, so a dead
+/// primary shows as `down` even while the fallback is serving requests.
+///
+/// ### Example
+/// ```json
+/// {
+///   "cluster": "mainnet",
+///   "status": "down",
+///   "latencyMs": 2000,
+///   "error": "RPC probe timed out",
+///   "fallback": { "status": "ok", "latencyMs": 87, "slot": 312345678 },
+///   "active": "secondary"
+/// }
+/// ```
+#[derive(Debug, Serialize)]
+pub struct ClusterRpcHealth {
+    pub cluster: Cluster,
+    #[serde(flatten)]
+    pub primary: EndpointHealth,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<EndpointHealth>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<ActiveEndpoint>,
+}
+
+/// Result of probing one RPC endpoint; see [`ClusterRpcHealth`].
+#[derive(Debug, Serialize)]
+pub struct EndpointHealth {
+    pub status: &'static str,
+    #[serde(rename = "latencyMs", skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slot: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Handler for `GET /health/rpc`.
+///
+/// Admin-only. Probes every configured RPC endpoint, primary and fallback
+/// alike, concurrently with `getSlot`, giving each at most
+/// [`RPC_PROBE_TIMEOUT`], and reports per-cluster status, latency and slot so
+/// a degraded cluster is easy to spot. Endpoints are probed directly rather
+/// than through failover. Always returns 200; `status` carries the verdict.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request GET \
+///   --url http://localhost:3000/health/rpc \
+///   --header 'x-api-key: <admin key>'
+/// ```
+///
+/// Expected output:
+/// ```json
+/// {
+///   "success": true,
+///   "data": [
+///     { "cluster": "mainnet", "status": "ok", "latencyMs": 87, "slot": 312345678 },
+///     { "cluster": "devnet", "status": "down", "latencyMs": 2000, "error": "RPC probe timed out" }
+///   ]
+/// }
+/// ```
+pub async fn rpc_health(_admin: AdminKey, State(state): State<AppState>) -> impl IntoResponse {
+    let commitment = state.config.rpc_commitment;
+    let mut probes = JoinSet::new();
+    for client in state.rpc.iter() {
+        for (position, endpoint) in client.endpoints().enumerate() {
+            probes.spawn(async move { (endpoint.cluster(), position, probe(endpoint, commitment).await) });
+        }
+    }
+
+    let mut results = Vec::new();
+    while let Some(result) = probes.join_next().await {
+        match result {
+            Ok(result) => results.push(result),
+            Err(e) => tracing::error!("RPC probe task failed: {}", e),
+        }
+    }
+    let mut take = |cluster: Cluster, position: usize| {
+        let index = results.iter().position(|(c, p, _)| *c == cluster && *p == position)?;
+        Some(results.swap_remove(index).2)
+    };
+
+    let data: Vec<ClusterRpcHealth> = Cluster::ALL
+        .into_iter()
+        .map(|cluster| ClusterRpcHealth {
+            cluster,
+            primary: take(cluster, 0).unwrap_or(EndpointHealth {
+                status: "unconfigured",
+                latency_ms: None,
+                slot: None,
+                error: None,
+            }),
+            fallback: take(cluster, 1),
+            active: state.rpc.get(cluster).and_then(RpcClient::active_endpoint),
+        })
+        .collect();
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({ "success": true, "data": state.cased(data) })),
+    )
+}
+
+async fn probe(endpoint: RpcClient, commitment: Commitment) -> EndpointHealth {
+    let started = Instant::now();
+    let result = tokio::time::timeout(RPC_PROBE_TIMEOUT, endpoint.get_slot(commitment)).await;
+    let latency_ms = Some(started.elapsed().as_millis() as u64);
+
+    let (status, slot, error) = match result {
+        Ok(Ok(slot)) => ("ok", Some(slot), None),
+        Ok(Err(e)) => ("down", None, Some(e.to_string())),
+        Err(_) => ("down", None, Some("RPC probe timed out".to_string())),
+    };
+    if let Some(error) = &error {
+        tracing::warn!(cluster = %endpoint.cluster(), "RPC health probe failed: {}", error);
+    }
+
+    EndpointHealth {
+        status,
+        latency_ms,
+        slot,
+        error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::rpc::mock::MockTransport;
+    use crate::rpc::failover::FailoverTransport;
+    use crate::rpc::{RpcClients, RpcError, RpcTransport};
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::get;
+    use axum::Router;
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    fn state(rpc: RpcClients) -> AppState {
+        AppState {
+            rpc,
+            ..AppState::new(Config {
+                admin_api_keys: vec!["admin-key".to_string()],
+                ..Config::default()
+            })
+        }
+    }
+
+    async fn send(state: AppState, key: Option<&str>) -> (StatusCode, Value) {
+        let app = Router::new().route("/rpc", get(rpc_health)).with_state(state);
+        let mut request = Request::get("/rpc");
+        if let Some(key) = key {
+            request = request.header("x-api-key", key);
+        }
+        let response = app.oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_one_cluster_down() {
+        let mainnet = MockTransport::new().respond("getSlot", json!(312345678));
+        let devnet = MockTransport::new().fail("getSlot", RpcError::Transport("connection refused".to_string()));
+        let rpc = RpcClients::default()
+            .with(RpcClient::new(Cluster::Mainnet, Arc::new(mainnet)))
+            .with(RpcClient::new(Cluster::Devnet, Arc::new(devnet)));
+
+        let (status, json) = send(state(rpc), Some("admin-key")).await;
+        assert_eq!(status, StatusCode::OK);
+
+        assert_eq!(json["data"][0]["cluster"], "mainnet");
+        assert_eq!(json["data"][0]["status"], "ok");
+        assert_eq!(json["data"][0]["slot"], 312345678);
+        assert!(json["data"][0]["latencyMs"].is_u64());
+
+        assert_eq!(json["data"][1]["cluster"], "devnet");
+        assert_eq!(json["data"][1]["status"], "down");
+        assert_eq!(json["data"][1]["error"], "RPC transport error: connection refused");
+        assert!(json["data"][1].get("slot").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_dead_primary_is_down_behind_a_healthy_fallback() {
+        let primary: Arc<dyn RpcTransport> =
+            Arc::new(MockTransport::new().fail("getSlot", RpcError::Transport("connection refused".to_string())));
+        let fallback: Arc<dyn RpcTransport> = Arc::new(MockTransport::new().respond("getSlot", json!(312345678)));
+        let failover = FailoverTransport::new(primary, fallback, 0, Duration::from_secs(60));
+        let client = RpcClient::with_failover(Cluster::Mainnet, failover);
+
+        // Requests still succeed through the fallback...
+        assert_eq!(client.get_slot(Commitment::default()).await.unwrap(), 312345678);
+
+        // ...but the primary is reported as it is
+        let (_, json) = send(state(RpcClients::default().with(client)), Some("admin-key")).await;
+        assert_eq!(json["data"][0]["status"], "down");
+        assert_eq!(json["data"][0]["error"], "RPC transport error: connection refused");
+        assert_eq!(json["data"][0]["fallback"]["status"], "ok");
+        assert_eq!(json["data"][0]["fallback"]["slot"], 312345678);
+        assert_eq!(json["data"][0]["active"], "secondary");
+        assert!(json["data"][1].get("fallback").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unconfigured_and_auth() {
+        let (status, json) = send(state(RpcClients::default()), Some("admin-key")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"][0]["status"], "unconfigured");
+        assert_eq!(json["data"][1]["status"], "unconfigured");
+        assert!(json["data"][0].get("active").is_none());
+
+        let (status, _) = send(state(RpcClients::default()), None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+}
@@ -0,0 +1,223 @@
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::{header, Method},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle, PrometheusRecorder};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::AppState;
+
+/// Route serving the Prometheus text exposition; never counted itself.
+pub const METRICS_ROUTE: &str = "/metrics";
+
+/// How often [`HttpMetrics::run_upkeep`] should run, so histogram samples are
+/// folded into buckets even when nothing scrapes `/metrics`.
+pub const UPKEEP_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Latency buckets, in seconds, for `reflect_http_request_duration_seconds`.
+const LATENCY_BUCKETS: &[f64] = &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// Prometheus registry shared through [`crate::AppState`].
+///
+/// [`record_requests`] writes per-request series into it and `GET /metrics`
+/// renders it. `main` also installs it as the global recorder, so the RPC
+/// and load metrics recorded through the facade show up on the same page.
+///
+/// Cheap to clone; clones share the same registry.
+#[derive(Clone)]
+pub struct HttpMetrics {
+    recorder: Arc<PrometheusRecorder>,
+    handle: PrometheusHandle,
+}
+
+impl Default for HttpMetrics {
+    fn default() -> Self {
+        let recorder = PrometheusBuilder::new()
+            .set_buckets(LATENCY_BUCKETS)
+            .expect("latency buckets are not empty")
+            .build_recorder();
+        let handle = recorder.handle();
+        Self { recorder: Arc::new(recorder), handle }
+    }
+}
+
+impl HttpMetrics {
+    /// Make this registry the process-wide recorder for the `metrics` facade.
+    pub fn install(&self) -> Result<(), metrics::SetRecorderError<Arc<PrometheusRecorder>>> {
+        metrics::set_global_recorder(self.recorder.clone())
+    }
+
+    /// Every series in the registry, in the Prometheus text format.
+    pub fn render(&self) -> String {
+        self.handle.render()
+    }
+
+    /// Fold pending histogram samples into their buckets. The recorder does
+    /// not do this on its own, so `main` calls it every [`UPKEEP_INTERVAL`];
+    /// without it samples pile up until the next scrape.
+    pub fn run_upkeep(&self) {
+        self.handle.run_upkeep();
+    }
+
+    /// Record one finished request to `route`.
+    fn record(&self, method: &str, route: &str, status: u16, seconds: f64) {
+        let labels = [("method", method.to_string()), ("route", route.to_string())];
+        metrics::with_local_recorder(&*self.recorder, || {
+            metrics::counter!("reflect_http_requests_total", &labels).increment(1);
+            metrics::histogram!("reflect_http_request_duration_seconds", &labels).record(seconds);
+            if let Some(class) = error_class(status) {
+                metrics::counter!("reflect_http_errors_total", "class" => class).increment(1);
+            }
+        });
+    }
+}
+
+/// The `method` label for `method`: extension methods all share `OTHER`, so
+/// arbitrary methods sent to unmatched routes cannot create new series.
+fn method_label(method: &Method) -> &'static str {
+    match *method {
+        Method::GET => "GET",
+        Method::POST => "POST",
+        Method::PUT => "PUT",
+        Method::PATCH => "PATCH",
+        Method::DELETE => "DELETE",
+        Method::HEAD => "HEAD",
+        Method::OPTIONS => "OPTIONS",
+        Method::CONNECT => "CONNECT",
+        Method::TRACE => "TRACE",
+        _ => "OTHER",
+    }
+}
+
+/// The status class an error response is counted under, if it is one.
+fn error_class(status: u16) -> Option<&'static str> {
+    match status {
+        400..=499 => Some("4xx"),
+        500..=599 => Some("5xx"),
+        _ => None,
+    }
+}
+
+/// Middleware counting requests and errors and timing handlers into
+/// [`HttpMetrics`].
+///
+/// Series are labelled with the route pattern rather than the literal path,
+/// so `/stablecoins/stablecoin/:index/apy` is one series however many indexes
+/// are asked for; requests that match no route share `route="unmatched"`.
+/// Scrapes of [`METRICS_ROUTE`] are not recorded.
+pub async fn record_requests(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or("unmatched", MatchedPath::as_str)
+        .to_string();
+    if route == METRICS_ROUTE {
+        return next.run(request).await;
+    }
+    let method = method_label(request.method());
+    let started = Instant::now();
+
+    let response = next.run(request).await;
+
+    state
+        .http_metrics
+        .record(method, &route, response.status().as_u16(), started.elapsed().as_secs_f64());
+    response
+}
+
+/// Handler for `GET /metrics`.
+///
+/// Renders the registry in the Prometheus text exposition format.
+///
+/// # Example
+///
+/// ```bash
+/// curl http://localhost:3000/metrics
+/// ```
+///
+/// Expected output (abridged):
+/// ```text
+/// # TYPE reflect_http_requests_total counter
+/// reflect_http_requests_total{method="GET",route="/health"} 3
+///
+/// # TYPE reflect_http_errors_total counter
+/// reflect_http_errors_total{class="4xx"} 1
+/// ```
+pub async fn render_metrics(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.http_metrics.render(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::StatusCode;
+    use axum::routing::get;
+    use axum::{middleware, Router};
+    use tower::ServiceExt;
+
+    fn app(state: AppState) -> Router {
+        Router::new()
+            .route("/items/:id", get(|| async { "item" }))
+            .route("/broken", get(|| async { StatusCode::INTERNAL_SERVER_ERROR }))
+            .route(METRICS_ROUTE, get(render_metrics))
+            .layer(middleware::from_fn_with_state(state.clone(), record_requests))
+            .with_state(state)
+    }
+
+    async fn get_status(state: &AppState, uri: &str) -> StatusCode {
+        let request = Request::get(uri).body(Body::empty()).unwrap();
+        app(state.clone()).oneshot(request).await.unwrap().status()
+    }
+
+    #[test]
+    fn extension_methods_share_one_label() {
+        assert_eq!(method_label(&Method::GET), "GET");
+        assert_eq!(method_label(&Method::DELETE), "DELETE");
+        assert_eq!(method_label(&Method::from_bytes(b"PURGE").unwrap()), "OTHER");
+        assert_eq!(method_label(&Method::from_bytes(b"X-RANDOM-123").unwrap()), "OTHER");
+    }
+
+    #[test]
+    fn classifies_error_statuses() {
+        assert_eq!(error_class(200), None);
+        assert_eq!(error_class(304), None);
+        assert_eq!(error_class(404), Some("4xx"));
+        assert_eq!(error_class(503), Some("5xx"));
+    }
+
+    #[tokio::test]
+    async fn counts_requests_per_route_and_errors_per_class() {
+        let state = AppState::default();
+        assert_eq!(get_status(&state, "/items/1").await, StatusCode::OK);
+        assert_eq!(get_status(&state, "/items/2").await, StatusCode::OK);
+        assert_eq!(get_status(&state, "/broken").await, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(get_status(&state, "/nowhere").await, StatusCode::NOT_FOUND);
+        let request = Request::builder().method("BREW").uri("/nowhere").body(Body::empty()).unwrap();
+        app(state.clone()).oneshot(request).await.unwrap();
+        state.http_metrics.run_upkeep();
+        assert_eq!(get_status(&state, METRICS_ROUTE).await, StatusCode::OK);
+
+        let request = Request::get(METRICS_ROUTE).body(Body::empty()).unwrap();
+        let response = app(state.clone()).oneshot(request).await.unwrap();
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "text/plain; version=0.0.4");
+        let body = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(body.contains(r#"reflect_http_requests_total{method="GET",route="/items/:id"} 2"#));
+        assert!(body.contains(r#"reflect_http_requests_total{method="GET",route="/broken"} 1"#));
+        assert!(body.contains(r#"reflect_http_requests_total{method="GET",route="unmatched"} 1"#));
+        assert!(body.contains(r#"reflect_http_requests_total{method="OTHER",route="unmatched"} 1"#));
+        assert!(!body.contains("BREW"));
+        assert!(body.contains(r#"reflect_http_errors_total{class="4xx"} 2"#));
+        assert!(body.contains(r#"reflect_http_errors_total{class="5xx"} 1"#));
+        assert!(body.contains(r#"reflect_http_request_duration_seconds_bucket{method="GET",route="/items/:id",le="+Inf"} 2"#));
+        assert!(!body.contains(METRICS_ROUTE));
+    }
+}
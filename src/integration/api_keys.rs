@@ -0,0 +1,362 @@
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::auth::Scope;
+use crate::timestamp::Timestamp;
+
+/// Random bytes in a freshly issued key, before base58 encoding.
+const KEY_BYTES: usize = 32;
+
+/// Prefix marking issued keys when `REFLECT_API_KEY_PREFIX` is not set.
+pub const DEFAULT_API_KEY_PREFIX: &str = "rflk_";
+
+/// Random bytes of salt mixed into each stored key hash.
+const SALT_BYTES: usize = 16;
+
+/// The stored side of an integration's API key.
+///
+/// The plaintext is handed out once, when the key is issued, and never kept.
+/// Requests are authenticated against `key_hash`, a SHA-256 of the key
+/// salted per record; `fingerprint` is the plain SHA-256 of the key, kept so
+/// support can look the key up without holding it. A key with an
+/// `expires_at` stops authenticating at that instant, and only reaches the
+/// routes its `scopes` allow. Only the fingerprint, creation time, expiry and
+/// scopes are serialized.
+///
+/// ### Example
+/// ```json
+/// {
+///   "fingerprint": "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08",
+///   "created_at": "2025-12-18T17:46:10.274Z",
+///   "expires_at": null,
+///   "scopes": ["read", "mint"]
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ApiKeyRecord {
+    pub fingerprint: String,
+    pub created_at: Timestamp,
+    pub expires_at: Option<Timestamp>,
+    pub scopes: Vec<Scope>,
+    #[serde(skip)]
+    salt: [u8; SALT_BYTES],
+    #[serde(skip)]
+    key_hash: [u8; 32],
+}
+
+impl ApiKeyRecord {
+    fn new(key: &str, expires_at: Option<Timestamp>, scopes: &[Scope]) -> Self {
+        let salt = rand::random::<[u8; SALT_BYTES]>();
+        Self {
+            fingerprint: fingerprint(key),
+            created_at: Timestamp::now(),
+            expires_at,
+            scopes: scopes.to_vec(),
+            salt,
+            key_hash: salted_hash(&salt, key),
+        }
+    }
+
+    /// Whether `key` is the key this record was issued for.
+    pub fn verify(&self, key: &str) -> bool {
+        let hash = salted_hash(&self.salt, key);
+        // Compare every byte so the time taken does not reveal how much matched
+        hash.iter().zip(self.key_hash).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+    }
+
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// Why [`ApiKeyStore::authenticate`] refused a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyRejection {
+    /// Not a key of any integration, or one already dropped.
+    Unknown,
+    /// A known key, past its `expires_at`.
+    Expired,
+}
+
+/// How a rotation treats the keys it supersedes (see
+/// `REFLECT_MAX_ACTIVE_API_KEYS` / `REFLECT_API_KEY_GRACE_SECS`).
+///
+/// Superseded keys keep authenticating for `grace`, or until their own
+/// expiry if that comes first, so clients can switch over without an outage.
+/// At most `max_active` keys are kept; issuing one more drops the oldest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyOverlap {
+    pub max_active: usize,
+    pub grace: Duration,
+}
+
+impl KeyOverlap {
+    /// Rotation revokes the previous key at once.
+    pub const NONE: KeyOverlap = KeyOverlap { max_active: 1, grace: Duration::ZERO };
+}
+
+/// Issued API keys by integration id, oldest first, shared through
+/// [`crate::AppState`].
+///
+/// Keys are also indexed by fingerprint, so authenticating a key hashes it
+/// once to find its only candidate record instead of trying every stored key.
+///
+/// Cloning is cheap; every clone points at the same underlying map.
+#[derive(Debug, Clone, Default)]
+pub struct ApiKeyStore {
+    inner: Arc<Mutex<KeyIndex>>,
+}
+
+#[derive(Debug, Default)]
+struct KeyIndex {
+    records: HashMap<String, Vec<ApiKeyRecord>>,
+    /// Fingerprint of every stored key to the integration holding it.
+    owners: HashMap<String, String>,
+}
+
+impl ApiKeyStore {
+    /// Issue a new key for `integration_id`. The key is `prefix` followed by
+    /// random base58, so it is recognisable in logs and secret scanners.
+    /// Previous keys stay active as `overlap` allows; expired ones are dropped.
+    /// Returns the plaintext key, which is not stored.
+    pub fn rotate(
+        &self,
+        integration_id: &str,
+        prefix: &str,
+        expires_at: Option<Timestamp>,
+        scopes: &[Scope],
+        overlap: KeyOverlap,
+    ) -> (String, ApiKeyRecord) {
+        let key = format!("{}{}", prefix, bs58::encode(rand::random::<[u8; KEY_BYTES]>()).into_string());
+        let record = ApiKeyRecord::new(&key, expires_at, scopes);
+
+        let grace_ends = chrono::Duration::from_std(overlap.grace)
+            .ok()
+            .and_then(|grace| record.created_at.0.checked_add_signed(grace))
+            .map(Timestamp);
+        let mut inner = self.inner.lock().unwrap();
+        let KeyIndex { records, owners } = &mut *inner;
+        let keys = records.entry(integration_id.to_string()).or_default();
+        for previous in keys.iter_mut() {
+            previous.expires_at = match (previous.expires_at, grace_ends) {
+                (Some(expires_at), Some(grace_ends)) => Some(expires_at.min(grace_ends)),
+                (expires_at, grace_ends) => expires_at.or(grace_ends),
+            };
+        }
+        let (mut active, mut dropped): (Vec<_>, Vec<_>) =
+            keys.drain(..).partition(|previous| !previous.is_expired(record.created_at));
+        active.push(record.clone());
+        let excess = active.len().saturating_sub(overlap.max_active.max(1));
+        dropped.extend(active.drain(..excess));
+        *keys = active;
+        for previous in &dropped {
+            owners.remove(&previous.fingerprint);
+        }
+        owners.insert(record.fingerprint.clone(), integration_id.to_string());
+        (key, record)
+    }
+
+    /// Every key issued to `integration_id` that has not been dropped yet,
+    /// oldest first. May include keys past their expiry.
+    pub fn keys(&self, integration_id: &str) -> Vec<ApiKeyRecord> {
+        self.inner.lock().unwrap().records.get(integration_id).cloned().unwrap_or_default()
+    }
+
+    /// The integration `key` was issued to, with the key's record, if it is
+    /// an active, unexpired key.
+    pub fn authenticate(&self, key: &str, now: Timestamp) -> Result<(String, ApiKeyRecord), KeyRejection> {
+        let (id, record) = self.find_by_fingerprint(&fingerprint(key)).ok_or(KeyRejection::Unknown)?;
+        if !record.verify(key) {
+            return Err(KeyRejection::Unknown);
+        }
+        if record.is_expired(now) {
+            return Err(KeyRejection::Expired);
+        }
+        Ok((id, record))
+    }
+
+    /// The integration holding a key with this fingerprint, with the key's record.
+    pub fn find_by_fingerprint(&self, fingerprint: &str) -> Option<(String, ApiKeyRecord)> {
+        let inner = self.inner.lock().unwrap();
+        let id = inner.owners.get(fingerprint)?;
+        let record = inner.records.get(id)?.iter().find(|record| record.fingerprint == fingerprint)?;
+        Some((id.clone(), record.clone()))
+    }
+
+    /// Drop the key of `integration_id` with this fingerprint at once,
+    /// whatever grace period it was in. Returns the dropped record, if any.
+    pub fn revoke(&self, integration_id: &str, fingerprint: &str) -> Option<ApiKeyRecord> {
+        let mut inner = self.inner.lock().unwrap();
+        let KeyIndex { records, owners } = &mut *inner;
+        let keys = records.get_mut(integration_id)?;
+        let position = keys.iter().position(|record| record.fingerprint == fingerprint)?;
+        owners.remove(fingerprint);
+        Some(keys.remove(position))
+    }
+
+    /// Drop every key of every integration.
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.records.clear();
+        inner.owners.clear();
+    }
+}
+
+/// Lowercase hex SHA-256 of `key`.
+pub fn fingerprint(key: &str) -> String {
+    Sha256::digest(key.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn salted_hash(salt: &[u8], key: &str) -> [u8; 32] {
+    Sha256::new().chain_update(salt).chain_update(key.as_bytes()).finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two keys at a time, superseded keys lasting an hour.
+    const OVERLAP: KeyOverlap = KeyOverlap { max_active: 2, grace: Duration::from_secs(3600) };
+
+    /// Who `key` authenticates as.
+    fn owner(store: &ApiKeyStore, key: &str, now: Timestamp) -> Result<String, KeyRejection> {
+        store.authenticate(key, now).map(|(id, _)| id)
+    }
+
+    fn hours(hours: i64) -> chrono::Duration {
+        chrono::Duration::hours(hours)
+    }
+
+    #[test]
+    fn rotation_without_overlap_replaces_the_key() {
+        let store = ApiKeyStore::default();
+        let (first, record) = store.rotate("int_1", DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, KeyOverlap::NONE);
+        let random = first.strip_prefix(DEFAULT_API_KEY_PREFIX).unwrap();
+        assert_eq!(bs58::decode(random).into_vec().unwrap().len(), KEY_BYTES);
+        assert_eq!(record.fingerprint, fingerprint(&first));
+        assert_eq!(store.keys("int_1"), vec![record]);
+
+        let (second, record) = store.rotate("int_1", DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, KeyOverlap::NONE);
+        assert_ne!(first, second);
+        assert_eq!(store.keys("int_1"), vec![record.clone()]);
+        assert_eq!(record.fingerprint.len(), 64);
+        assert_eq!(store.keys("int_2"), vec![]);
+    }
+
+    #[test]
+    fn stored_record_holds_no_plaintext() {
+        let store = ApiKeyStore::default();
+        let (key, record) = store.rotate("int_1", DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, KeyOverlap::NONE);
+
+        // Neither the serialized nor the in-memory record contains the key
+        assert!(!serde_json::to_string(&record).unwrap().contains(&key));
+        assert!(!format!("{:?}", record).contains(&key));
+        assert_ne!(record.key_hash, salted_hash(&[], &key));
+
+        // Salts differ, so the same key hashes differently per record
+        let other = ApiKeyRecord::new(&key, None, &Scope::ALL);
+        assert_ne!(other.key_hash, record.key_hash);
+        assert!(other.verify(&key));
+    }
+
+    #[test]
+    fn authenticates_only_active_keys() {
+        let store = ApiKeyStore::default();
+        let (old, _) = store.rotate("int_1", DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, KeyOverlap::NONE);
+        let (current, _) = store.rotate("int_1", DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, KeyOverlap::NONE);
+        let (other, _) = store.rotate("int_2", DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, KeyOverlap::NONE);
+
+        let now = Timestamp::now();
+        assert_eq!(owner(&store, &current, now), Ok("int_1".to_string()));
+        assert_eq!(owner(&store, &other, now), Ok("int_2".to_string()));
+        assert_eq!(owner(&store, &old, now), Err(KeyRejection::Unknown));
+        assert_eq!(owner(&store, &fingerprint(&current), now), Err(KeyRejection::Unknown));
+        assert_eq!(owner(&store, "", now), Err(KeyRejection::Unknown));
+    }
+
+    #[test]
+    fn keys_stop_at_their_expiry() {
+        let store = ApiKeyStore::default();
+        let now = Timestamp::now();
+        let expires_at = Timestamp(now.0 + hours(1));
+        let (key, record) = store.rotate("int_1", "test_", Some(expires_at), &Scope::ALL, KeyOverlap::NONE);
+        assert!(key.starts_with("test_"));
+        assert_eq!(record.expires_at, Some(expires_at));
+
+        assert_eq!(owner(&store, &key, now), Ok("int_1".to_string()));
+        assert_eq!(owner(&store, &key, expires_at), Err(KeyRejection::Expired));
+    }
+
+    #[test]
+    fn old_and_new_keys_overlap_until_the_grace_period_ends() {
+        let store = ApiKeyStore::default();
+        let (old, _) = store.rotate("int_1", DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, OVERLAP);
+        let (new, record) = store.rotate("int_1", DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, OVERLAP);
+        let grace_ends = Timestamp(record.created_at.0 + hours(1));
+
+        // Both authenticate during the overlap
+        let during = Timestamp(record.created_at.0 + hours(1) - chrono::Duration::seconds(1));
+        assert_eq!(owner(&store, &old, during), Ok("int_1".to_string()));
+        assert_eq!(owner(&store, &new, during), Ok("int_1".to_string()));
+
+        // The old key stops once the grace period is over; the new one keeps working
+        assert_eq!(owner(&store, &old, grace_ends), Err(KeyRejection::Expired));
+        assert_eq!(owner(&store, &new, grace_ends), Ok("int_1".to_string()));
+
+        let keys = store.keys("int_1");
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0].expires_at, Some(grace_ends));
+        assert_eq!(keys[1], record);
+    }
+
+    #[test]
+    fn overlap_keeps_earlier_expiries_and_caps_active_keys() {
+        let store = ApiKeyStore::default();
+        let now = Timestamp::now();
+        let soon = Timestamp(now.0 + chrono::Duration::minutes(5));
+        let (first, _) = store.rotate("int_1", DEFAULT_API_KEY_PREFIX, Some(soon), &Scope::ALL, OVERLAP);
+        let (second, _) = store.rotate("int_1", DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, OVERLAP);
+
+        // A key expiring before the grace period ends keeps its own expiry
+        assert_eq!(store.keys("int_1")[0].expires_at, Some(soon));
+        assert_eq!(owner(&store, &first, soon), Err(KeyRejection::Expired));
+
+        // A third key pushes out the oldest
+        let (third, _) = store.rotate("int_1", DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, OVERLAP);
+        assert_eq!(store.keys("int_1").len(), 2);
+        assert_eq!(owner(&store, &first, now), Err(KeyRejection::Unknown));
+        assert_eq!(owner(&store, &second, now), Ok("int_1".to_string()));
+        assert_eq!(owner(&store, &third, now), Ok("int_1".to_string()));
+        assert!(store.find_by_fingerprint(&fingerprint(&second)).is_some());
+        assert!(store.find_by_fingerprint(&fingerprint(&first)).is_none());
+    }
+
+    #[test]
+    fn revoke_drops_a_key_during_its_grace_period() {
+        let store = ApiKeyStore::default();
+        let (old, old_record) = store.rotate("int_1", DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, OVERLAP);
+        let (new, _) = store.rotate("int_1", DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, OVERLAP);
+        let now = Timestamp::now();
+
+        // Only the owning integration can revoke it
+        assert_eq!(store.revoke("int_2", &old_record.fingerprint), None);
+        assert_eq!(store.revoke("int_1", &old_record.fingerprint).map(|record| record.fingerprint), Some(old_record.fingerprint.clone()));
+        assert_eq!(owner(&store, &old, now), Err(KeyRejection::Unknown));
+        assert_eq!(owner(&store, &new, now), Ok("int_1".to_string()));
+        assert!(store.find_by_fingerprint(&old_record.fingerprint).is_none());
+        assert_eq!(store.revoke("int_1", &old_record.fingerprint), None);
+    }
+
+    #[test]
+    fn clear_drops_every_key() {
+        let store = ApiKeyStore::default();
+        let (key, record) = store.rotate("int_1", DEFAULT_API_KEY_PREFIX, None, &Scope::ALL, KeyOverlap::NONE);
+        store.clear();
+        assert_eq!(store.keys("int_1"), vec![]);
+        assert!(store.find_by_fingerprint(&record.fingerprint).is_none());
+        assert_eq!(owner(&store, &key, Timestamp::now()), Err(KeyRejection::Unknown));
+    }
+}
@@ -0,0 +1,98 @@
+use axum::{response::IntoResponse, Json, extract::State, http::StatusCode};
+use serde_json::json;
+use crate::error::ApiError;
+use crate::path::Path;
+use crate::AppState;
+
+pub async fn archive_integration(State(state): State<AppState>, Path(id): Path<String>) -> Result<impl IntoResponse, ApiError> {
+    let record = state
+        .integrations
+        .archive(&id)
+        .ok_or_else(|| ApiError::NotFound("Integration not found".to_string()))?;
+    Ok((StatusCode::OK, Json(json!({"result": "integration archived", "integration": state.cased(record)}))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integration::get_integrations_by_authority::get_integrations_by_authority;
+    use crate::integration::restore_integration::restore_integration;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::{get, post};
+    use axum::Router;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    fn app(state: AppState) -> Router {
+        Router::new()
+            .route("/by-authority", get(get_integrations_by_authority))
+            .route("/:id/archive", post(archive_integration))
+            .route("/:id/restore", post(restore_integration))
+            .with_state(state)
+    }
+
+    async fn send(state: &AppState, request: Request<Body>) -> (StatusCode, Value) {
+        let response = app(state.clone()).oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    async fn list_ids(state: &AppState, uri: &str) -> Vec<String> {
+        let (status, json) = send(state, Request::get(uri).body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::OK);
+        json["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|i| i["id"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_archive_hides_and_restore_brings_back() {
+        let state = AppState::default();
+        let kept = state.integrations.create("Kept".into(), "auth_1".into());
+        let archived = state.integrations.create("Archived".into(), "auth_1".into());
+
+        let (status, json) = send(
+            &state,
+            Request::post(format!("/{}/archive", archived.id)).body(Body::empty()).unwrap(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(json["integration"]["deleted_at"].is_string());
+
+        assert_eq!(list_ids(&state, "/by-authority?authority=auth_1").await, vec![kept.id.clone()]);
+        assert_eq!(
+            list_ids(&state, "/by-authority?authority=auth_1&includeArchived=true").await,
+            vec![kept.id.clone(), archived.id.clone()]
+        );
+
+        let (status, json) = send(
+            &state,
+            Request::post(format!("/{}/restore", archived.id)).body(Body::empty()).unwrap(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(json["integration"]["deleted_at"].is_null());
+
+        assert_eq!(
+            list_ids(&state, "/by-authority?authority=auth_1").await,
+            vec![kept.id, archived.id]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_archive_unknown_integration() {
+        let state = AppState::default();
+        let (status, json) = send(
+            &state,
+            Request::post("/int_404/archive").body(Body::empty()).unwrap(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(json["message"], "Integration not found");
+    }
+}
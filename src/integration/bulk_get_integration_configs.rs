@@ -0,0 +1,172 @@
+use axum::{
+    extract::{Json, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::BTreeMap;
+
+use super::store::IntegrationConfig;
+use crate::auth::AdminKey;
+use crate::error::ApiError;
+use crate::validation::ensure_batch_size;
+use crate::AppState;
+
+/// Request structure for `POST /integrations/configs`.
+///
+/// At most `REFLECT_MAX_BATCH_SIZE` ids may be requested at once.
+///
+/// ### Example Request
+/// ```json
+/// { "ids": ["int_1", "int_2", "int_404"] }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct BulkConfigRequest {
+    pub ids: Vec<String>,
+}
+
+/// Per-id result: either the integration's config, or a 404 marker.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum BulkConfigEntry {
+    Found(IntegrationConfig),
+    Missing { status: u16, message: &'static str },
+}
+
+/// Handler for `POST /integrations/configs`.
+///
+/// Admin-only. Looks up every requested id and returns a map of id to config,
+/// so admin panels can fetch many integrations in one round-trip. Unknown ids
+/// do not fail the request; they map to a `404` marker instead.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request POST \
+///   --url http://localhost:3000/integrations/configs \
+///   --header 'x-api-key: <admin key>' \
+///   --header 'Content-Type: application/json' \
+///   --data '{"ids": ["int_1", "int_404"]}'
+/// ```
+///
+/// Expected output:
+/// ```json
+/// {
+///   "success": true,
+///   "data": {
+///     "int_1": { "fee_percent": 0.5 },
+///     "int_404": { "status": 404, "message": "Integration not found" }
+///   }
+/// }
+/// ```
+pub async fn bulk_get_integration_configs(
+    _admin: AdminKey,
+    State(state): State<AppState>,
+    Json(req): Json<BulkConfigRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    ensure_batch_size(req.ids.len(), state.config.max_batch_size)?;
+
+    let data: BTreeMap<String, BulkConfigEntry> = req
+        .ids
+        .into_iter()
+        .map(|id| {
+            let entry = match state.integrations.get(&id) {
+                Some(record) => BulkConfigEntry::Found(record.config),
+                None => BulkConfigEntry::Missing {
+                    status: 404,
+                    message: "Integration not found",
+                },
+            };
+            (id, entry)
+        })
+        .collect();
+
+    Ok((StatusCode::OK, Json(json!({ "success": true, "data": state.cased(data) }))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::post;
+    use axum::Router;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    fn app(state: AppState) -> Router {
+        Router::new()
+            .route("/configs", post(bulk_get_integration_configs))
+            .with_state(state)
+    }
+
+    fn admin_state() -> AppState {
+        AppState::new(Config {
+            admin_api_keys: vec!["admin-key".to_string()],
+            ..Config::default()
+        })
+    }
+
+    async fn send(state: AppState, key: Option<&str>, body: Value) -> (StatusCode, Value) {
+        let mut request = Request::post("/configs").header("content-type", "application/json");
+        if let Some(key) = key {
+            request = request.header("x-api-key", key);
+        }
+        let response = app(state)
+            .oneshot(request.body(Body::from(body.to_string())).unwrap())
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_mix_of_existing_and_missing_ids() {
+        let state = admin_state();
+        let record = state
+            .integrations
+            .create("Acme".to_string(), "auth_1".to_string());
+
+        let (status, json) = send(
+            state,
+            Some("admin-key"),
+            json!({ "ids": [record.id, "int_404"] }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["success"], true);
+        assert_eq!(json["data"][&record.id]["fee_percent"], 0.5);
+        assert_eq!(json["data"]["int_404"]["status"], 404);
+        assert_eq!(json["data"]["int_404"]["message"], "Integration not found");
+    }
+
+    #[tokio::test]
+    async fn test_batch_size_boundary() {
+        let max = admin_state().config.max_batch_size;
+
+        let ids: Vec<String> = (0..max).map(|i| format!("int_{}", i)).collect();
+        let (status, _) = send(admin_state(), Some("admin-key"), json!({ "ids": ids })).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let ids: Vec<String> = (0..=max).map(|i| format!("int_{}", i)).collect();
+        let (status, json) = send(admin_state(), Some("admin-key"), json!({ "ids": ids })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["success"], false);
+        assert_eq!(json["message"], format!("batch too large (max {})", max));
+    }
+
+    #[tokio::test]
+    async fn test_requires_admin_key() {
+        let (status, json) = send(admin_state(), None, json!({ "ids": [] })).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(json["message"], "Missing or invalid API key");
+
+        let (status, _) = send(admin_state(), Some("wrong"), json!({ "ids": [] })).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// When each authority recently created integrations, shared through
+/// [`crate::AppState`] to enforce
+/// [`crate::config::Config::integration_creation_limit`].
+///
+/// Cheap to clone; clones share the same underlying map.
+#[derive(Debug, Clone, Default)]
+pub struct CreationLimits {
+    created: Arc<Mutex<HashMap<String, VecDeque<Instant>>>>,
+}
+
+impl CreationLimits {
+    /// Record a creation by `authority` at `now` unless it already created
+    /// `limit` integrations within the last `window`, in which case the time
+    /// until the oldest of those leaves the window is returned instead.
+    /// A `limit` of `0` always succeeds.
+    ///
+    /// Authorities with nothing left in the window are forgotten, so the map
+    /// only holds authorities that created something recently.
+    pub fn try_record(&self, authority: &str, limit: usize, window: Duration, now: Instant) -> Result<(), Duration> {
+        if limit == 0 {
+            return Ok(());
+        }
+        let expired = |at: &Instant| now.saturating_duration_since(*at) >= window;
+        let mut created = self.created.lock().unwrap();
+        created.retain(|_, recent| !recent.back().is_some_and(expired));
+        let recent = created.entry(authority.to_string()).or_default();
+        while recent.front().is_some_and(expired) {
+            recent.pop_front();
+        }
+        if recent.len() >= limit {
+            let oldest = recent[recent.len() - limit];
+            return Err(window - now.saturating_duration_since(oldest));
+        }
+        recent.push_back(now);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits_creations_within_the_window() {
+        let limits = CreationLimits::default();
+        let window = Duration::from_secs(60);
+        let start = Instant::now();
+
+        assert_eq!(limits.try_record("alice", 2, window, start), Ok(()));
+        assert_eq!(limits.try_record("alice", 2, window, start + Duration::from_secs(10)), Ok(()));
+        assert_eq!(
+            limits.try_record("alice", 2, window, start + Duration::from_secs(20)),
+            Err(Duration::from_secs(40))
+        );
+        assert_eq!(limits.try_record("bob", 2, window, start), Ok(()));
+
+        // The first creation leaves the window
+        assert_eq!(limits.try_record("alice", 2, window, start + window), Ok(()));
+        assert!(limits.try_record("alice", 2, window, start + window).is_err());
+
+        // Disabled
+        assert_eq!(limits.try_record("alice", 0, window, start + window), Ok(()));
+    }
+
+    #[test]
+    fn forgets_authorities_once_their_window_is_empty() {
+        let limits = CreationLimits::default();
+        let window = Duration::from_secs(60);
+        let start = Instant::now();
+
+        limits.try_record("alice", 2, window, start).unwrap();
+        limits.try_record("bob", 2, window, start + Duration::from_secs(30)).unwrap();
+        assert_eq!(limits.created.lock().unwrap().len(), 2);
+
+        limits.try_record("carol", 2, window, start + window).unwrap();
+        let created = limits.created.lock().unwrap();
+        let mut tracked: Vec<&str> = created.keys().map(String::as_str).collect();
+        tracked.sort();
+        assert_eq!(tracked, ["bob", "carol"]);
+    }
+}
@@ -1,11 +1,79 @@
 use axum::{response::IntoResponse, Json, extract::State};
 use serde::Deserialize;
-use serde_json::json;
+use serde_json::{json, Number};
+use crate::error::ApiError;
+use crate::timestamp::Timestamp;
+use crate::validation::parse_amount;
 use crate::AppState;
 
+/// `amount` is in smallest units and must be a positive whole number.
+/// `integration_id` names the integration minting, which the mint is
+/// counted towards (see `GET /integrations/stats`).
 #[derive(Deserialize)]
-pub struct IntMintReq { pub amount: f64, pub recipient: String }
+pub struct IntMintReq { pub integration_id: String, pub amount: Number, pub recipient: String }
 
-pub async fn generate_integration_mint_tx(State(_state): State<AppState>, Json(payload): Json<IntMintReq>) -> impl IntoResponse {
-    Json(json!({"tx": "0xintmint", "amount": payload.amount, "recipient": payload.recipient}))
+pub async fn generate_integration_mint_tx(State(state): State<AppState>, Json(payload): Json<IntMintReq>) -> Result<impl IntoResponse, ApiError> {
+    let amount = parse_amount("amount", &payload.amount)?;
+    if state.integrations.get(&payload.integration_id).is_none() {
+        return Err(ApiError::NotFound("Integration not found".to_string()));
+    }
+
+    state.record_event("mint", &payload.recipient, Some(&payload.integration_id), None, Timestamp::now());
+    Ok(Json(json!({"tx": "0xintmint", "amount": amount, "recipient": payload.recipient})))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::{Request, StatusCode};
+    use axum::routing::post;
+    use axum::Router;
+    use serde_json::{Number, Value};
+    use tower::ServiceExt;
+
+    fn state() -> AppState {
+        let state = AppState::default();
+        state.integrations.create("Acme".into(), "auth_1".into());
+        state
+    }
+
+    async fn call(amount: Number) -> (StatusCode, Value) {
+        let payload = IntMintReq {
+            integration_id: "int_1".to_string(),
+            amount,
+            recipient: "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM".to_string(),
+        };
+        let response = generate_integration_mint_tx(State(state()), Json(payload))
+            .await
+            .into_response();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, 1024).await.unwrap();
+        (parts.status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    /// The amount rules themselves are tested on `parse_amount`; this checks
+    /// a handler reports them in the standard envelope.
+    #[tokio::test]
+    async fn test_negative_and_nan_amounts_rejected() {
+        let (status, json) = call(Number::from(-5)).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "amount must be positive");
+
+        // JSON has no NaN literal, so a NaN amount never reaches the handler
+        let app = Router::new().route("/", post(generate_integration_mint_tx)).with_state(AppState::default());
+        let request = Request::post("/")
+            .header("content-type", "application/json")
+            .body(Body::from(r#"{"integration_id": "int_1", "amount": NaN, "recipient": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"}"#))
+            .unwrap();
+        assert_eq!(app.oneshot(request).await.unwrap().status(), StatusCode::BAD_REQUEST);
+
+        let (status, json) = call(Number::from_f64(1_000_000.5).unwrap()).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "amount must be a whole number");
+
+        let (status, json) = call(Number::from(1_000_000)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["amount"], 1_000_000);
+    }
 }
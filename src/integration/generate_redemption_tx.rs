@@ -1,11 +1,23 @@
 use axum::{response::IntoResponse, Json, extract::State};
 use serde::Deserialize;
-use serde_json::json;
+use serde_json::{json, Number};
+use crate::error::ApiError;
+use crate::timestamp::Timestamp;
+use crate::validation::parse_amount;
 use crate::AppState;
 
+/// `amount` is in smallest units and must be a positive whole number.
+/// `integration_id` names the integration redeeming, which the redemption
+/// is counted towards (see `GET /integrations/stats`).
 #[derive(Deserialize)]
-pub struct RedeemReq { pub amount: f64, pub holder: String }
+pub struct RedeemReq { pub integration_id: String, pub amount: Number, pub holder: String }
 
-pub async fn generate_redemption_tx(State(_state): State<AppState>, Json(payload): Json<RedeemReq>) -> impl IntoResponse {
-    Json(json!({"tx": "0xintredeem", "amount": payload.amount, "holder": payload.holder}))
+pub async fn generate_redemption_tx(State(state): State<AppState>, Json(payload): Json<RedeemReq>) -> Result<impl IntoResponse, ApiError> {
+    let amount = parse_amount("amount", &payload.amount)?;
+    if state.integrations.get(&payload.integration_id).is_none() {
+        return Err(ApiError::NotFound("Integration not found".to_string()));
+    }
+
+    state.record_event("redeem", &payload.holder, Some(&payload.integration_id), None, Timestamp::now());
+    Ok(Json(json!({"tx": "0xintredeem", "amount": amount, "holder": payload.holder})))
 }
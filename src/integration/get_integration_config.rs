@@ -1,6 +1,151 @@
-use axum::{response::IntoResponse, Json};
+use axum::{
+    response::IntoResponse,
+    Json,
+    extract::{Query, State},
+    http::{header::{IF_MODIFIED_SINCE, LAST_MODIFIED}, HeaderMap, StatusCode},
+};
+use serde::Deserialize;
 use serde_json::json;
+use crate::data_source::DataSource;
+use crate::error::ApiError;
+use crate::integration::store::IntegrationConfig;
+use crate::timestamp::Timestamp;
+use crate::AppState;
 
-pub async fn get_integration_config() -> impl IntoResponse {
-    Json(json!({"config": {"fee_percent": 0.5}}))
+#[derive(Deserialize)]
+pub struct ConfigQuery { pub id: Option<String> }
+
+/// Handler for `GET /integrations/config?id=<id>`.
+///
+/// Without `id` the default config is returned, as before integrations
+/// were stored; such responses carry no `Last-Modified`.
+///
+/// Supports conditional polling: 200 responses carry `Last-Modified` (the
+/// integration's `updated_at`), and a request whose `If-Modified-Since` is
+/// not older than that gets an empty 304 instead. Unparseable
+/// `If-Modified-Since` values are ignored.
+pub async fn get_integration_config(
+    State(state): State<AppState>,
+    Query(q): Query<ConfigQuery>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let Some(id) = q.id else {
+        return (DataSource::Local, Json(json!({"config": state.cased(IntegrationConfig::default())}))).into_response();
+    };
+    let Some(record) = state.integrations.get(&id) else {
+        let error = ApiError::NotFound("Integration not found".to_string());
+        return (DataSource::Local, error).into_response();
+    };
+
+    let last_modified = [(LAST_MODIFIED, record.updated_at.to_http_date())];
+    let since = headers
+        .get(IF_MODIFIED_SINCE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(Timestamp::parse_http_date);
+    if since.is_some_and(|since| !record.updated_at.modified_since(since)) {
+        return (StatusCode::NOT_MODIFIED, DataSource::Local, last_modified).into_response();
+    }
+
+    (DataSource::Local, last_modified, Json(json!({"config": state.cased(record.config)}))).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::{get, post};
+    use axum::Router;
+    use tower::ServiceExt;
+
+    use crate::integration::store::IntegrationRecord;
+    use crate::integration::update_integration_config::update_integration_config;
+
+    async fn fetch(state: &AppState, since: Option<&str>) -> (StatusCode, Option<String>, Vec<u8>) {
+        let app = Router::new()
+            .route("/config", get(get_integration_config))
+            .with_state(state.clone());
+        let mut request = Request::get("/config?id=int_1");
+        if let Some(since) = since {
+            request = request.header(IF_MODIFIED_SINCE, since);
+        }
+        let response = app.oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+        let status = response.status();
+        let last_modified = response
+            .headers()
+            .get(LAST_MODIFIED)
+            .map(|value| value.to_str().unwrap().to_string());
+        let body = to_bytes(response.into_body(), 1024).await.unwrap().to_vec();
+        (status, last_modified, body)
+    }
+
+    fn state() -> AppState {
+        let updated_at = Timestamp::parse_rfc3339("2026-01-01T00:00:00.250Z").unwrap();
+        let state = AppState::default();
+        state
+            .integrations
+            .import(vec![IntegrationRecord {
+                id: "int_1".to_string(),
+                name: "Polled".to_string(),
+                authority: "authority".to_string(),
+                config: IntegrationConfig::default(),
+                created_at: updated_at,
+                updated_at,
+                deleted_at: None,
+            }])
+            .unwrap();
+        state
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_config_is_304() {
+        let state = state();
+        let (status, last_modified, _) = fetch(&state, None).await;
+        assert_eq!(status, StatusCode::OK);
+        let last_modified = last_modified.unwrap();
+        assert_eq!(last_modified, "Thu, 01 Jan 2026 00:00:00 GMT");
+
+        let (status, header, body) = fetch(&state, Some(&last_modified)).await;
+        assert_eq!(status, StatusCode::NOT_MODIFIED);
+        assert_eq!(header.as_deref(), Some(last_modified.as_str()));
+        assert!(body.is_empty());
+
+        // Garbage validators are ignored rather than rejected
+        assert_eq!(fetch(&state, Some("not a date")).await.0, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_without_id_returns_the_default_config() {
+        let app = Router::new()
+            .route("/config", get(get_integration_config))
+            .with_state(AppState::default());
+        let response = app.oneshot(Request::get("/config").body(Body::empty()).unwrap()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(LAST_MODIFIED).is_none());
+        let body = to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json, json!({ "config": { "fee_percent": 0.5 } }));
+    }
+
+    #[tokio::test]
+    async fn test_changed_config_is_200() {
+        let state = state();
+        let (_, last_modified, _) = fetch(&state, None).await;
+        let last_modified = last_modified.unwrap();
+
+        let update = Request::post("/config/update")
+            .header("content-type", "application/json")
+            .body(Body::from(r#"{"id":"int_1","fee_percent":0.25}"#))
+            .unwrap();
+        let app = Router::new()
+            .route("/config/update", post(update_integration_config))
+            .with_state(state.clone());
+        assert_eq!(app.oneshot(update).await.unwrap().status(), StatusCode::OK);
+
+        let (status, header, body) = fetch(&state, Some(&last_modified)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_ne!(header.unwrap(), last_modified);
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["config"]["fee_percent"], 0.25);
+    }
 }
@@ -1,6 +1,157 @@
-use axum::{response::IntoResponse, Json};
+use axum::{response::IntoResponse, Json, extract::{Query, State}, http::StatusCode};
+use serde::Deserialize;
 use serde_json::json;
+use crate::error::ApiError;
+use crate::pagination::{paginate, EmptyParams, PageParams};
+use crate::validation::parse_time_range;
+use crate::data_source::DataSource;
+use crate::AppState;
 
-pub async fn get_integration_events() -> impl IntoResponse {
-    Json(json!({"events": [{"id": "evt_1", "type": "mint"}]}))
+/// Query parameters for `GET /integrations/events`.
+///
+/// - `integration_id`: Only return events for this integration.
+/// - `from` / `to`: Inclusive RFC 3339 time window, e.g. `?from=2025-12-18T00:00:00Z&to=2025-12-18T23:59:59Z`.
+///
+/// Paging uses the standard `limit` / `offset` parameters ([`PageParams`]), and
+/// `?emptyIsError=true` turns an empty result into a 404 ([`EmptyParams`]).
+#[derive(Debug, Default, Deserialize)]
+pub struct IntegrationEventsQuery {
+    pub integration_id: Option<String>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+pub async fn get_integration_events(State(state): State<AppState>, Query(q): Query<IntegrationEventsQuery>, Query(page): Query<PageParams>, Query(empty): Query<EmptyParams>) -> Result<impl IntoResponse, ApiError> {
+    let (from, to) = parse_time_range(("from", q.from.as_deref()), ("to", q.to.as_deref()))?;
+    let page_query = page.parse()?;
+
+    let events = state.events.query(|e| {
+        q.integration_id.as_deref().map_or(e.integration_id.is_some(), |id| e.integration_id.as_deref() == Some(id))
+            && from.is_none_or(|from| e.timestamp >= from)
+            && to.is_none_or(|to| e.timestamp <= to)
+    });
+    empty.check(&events, "events")?;
+    let page = paginate(events, &page_query);
+
+    Ok((StatusCode::OK, DataSource::Local, Json(json!({
+        "success": true,
+        "meta": state.cased(page.meta()),
+        "data": state.cased(page.items),
+    }))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timestamp::Timestamp;
+    use axum::body::to_bytes;
+    use chrono::{TimeZone, Utc};
+    use serde_json::Value;
+
+    fn at(hour: u32) -> Timestamp {
+        Timestamp(Utc.with_ymd_and_hms(2025, 12, 18, hour, 0, 0).unwrap())
+    }
+
+    fn seeded_state() -> AppState {
+        let state = AppState::default();
+        for hour in [1, 6, 12, 18, 23] {
+            state.events.record("mint", "signer_a", Some("int_1"), None, at(hour));
+        }
+        state.events.record("mint", "signer_b", Some("int_2"), None, at(12));
+        state
+    }
+
+    async fn call(state: AppState, query: IntegrationEventsQuery) -> (StatusCode, Value) {
+        call_paged(state, query, PageParams::default()).await
+    }
+
+    async fn call_paged(state: AppState, query: IntegrationEventsQuery, page: PageParams) -> (StatusCode, Value) {
+        let response = get_integration_events(State(state), Query(query), Query(page), Query(EmptyParams::default())).await.into_response();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, 64 * 1024).await.unwrap();
+        (parts.status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_window_filter() {
+        let (status, json) = call(seeded_state(), IntegrationEventsQuery {
+            integration_id: Some("int_1".into()),
+            from: Some("2025-12-18T06:00:00Z".into()),
+            to: Some("2025-12-18T18:00:00Z".into()),
+        })
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["meta"]["total"], 3);
+        let timestamps: Vec<&str> = json["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["timestamp"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            timestamps,
+            vec!["2025-12-18T18:00:00.000Z", "2025-12-18T12:00:00.000Z", "2025-12-18T06:00:00.000Z"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_window_with_pagination() {
+        let (status, json) = call_paged(seeded_state(), IntegrationEventsQuery {
+            integration_id: Some("int_1".into()),
+            from: Some("2025-12-18T00:00:00Z".into()),
+            ..Default::default()
+        }, PageParams { limit: Some("2".into()), offset: Some("2".into()) })
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["meta"]["total"], 5);
+        assert_eq!(json["data"].as_array().unwrap().len(), 2);
+        assert_eq!(json["data"][0]["timestamp"], "2025-12-18T12:00:00.000Z");
+        assert_eq!(json["meta"]["has_more"], true);
+    }
+
+    #[tokio::test]
+    async fn test_overflowing_and_non_numeric_pagination() {
+        let (status, json) = call_paged(seeded_state(), IntegrationEventsQuery::default(), PageParams {
+            offset: Some("999999999999999999999".into()),
+            ..Default::default()
+        })
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["message"], "offset out of range");
+
+        let (status, json) = call_paged(seeded_state(), IntegrationEventsQuery::default(), PageParams {
+            limit: Some("ten".into()),
+            ..Default::default()
+        })
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["message"], "limit must be a non-negative integer");
+    }
+
+    #[tokio::test]
+    async fn test_inverted_range_rejected() {
+        let (status, json) = call(seeded_state(), IntegrationEventsQuery {
+            from: Some("2025-12-18T18:00:00Z".into()),
+            to: Some("2025-12-18T06:00:00Z".into()),
+            ..Default::default()
+        })
+        .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid time range: `from` must not be after `to`");
+    }
+
+    #[tokio::test]
+    async fn test_malformed_bound_rejected() {
+        let (status, json) = call(seeded_state(), IntegrationEventsQuery {
+            from: Some("yesterday".into()),
+            ..Default::default()
+        })
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["message"], "Invalid `from`: expected an RFC 3339 timestamp");
+    }
 }
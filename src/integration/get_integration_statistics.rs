@@ -1,6 +1,38 @@
-use axum::{response::IntoResponse, Json};
+use axum::{response::IntoResponse, Json, extract::{Query, State}, http::StatusCode};
+use serde::Deserialize;
 use serde_json::json;
+use crate::error::ApiError;
+use crate::AppState;
 
-pub async fn get_integration_statistics() -> impl IntoResponse {
-    Json(json!({"stats": {"minted": 10000, "redeemed": 2000}}))
+#[derive(Deserialize)]
+pub struct StatsQuery { pub id: Option<String> }
+
+/// Handler for `GET /integrations/stats?id=<id>`.
+///
+/// Serves the running mint / redeem counters (see
+/// [`crate::stats::aggregates::StatsAggregates`]), fed by the integration
+/// mint and redeem endpoints and by the stablecoin mint and burn endpoints.
+/// With `id` only that integration's counts are returned; an integration
+/// with no activity yet has zero counts, an unknown one is `404`. Without
+/// `id` the protocol totals and every integration's counts are returned.
+///
+/// ### Example Response (`?id=int_1`)
+/// ```json
+/// { "success": true, "data": { "mints": 2, "redeems": 1 } }
+/// ```
+pub async fn get_integration_statistics(
+    State(state): State<AppState>,
+    Query(q): Query<StatsQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let mut totals = state.aggregates.snapshot();
+    let data = match q.id {
+        None => json!(state.cased(totals)),
+        Some(id) => {
+            if state.integrations.get(&id).is_none() {
+                return Err(ApiError::NotFound("Integration not found".to_string()));
+            }
+            json!(state.cased(totals.integrations.remove(&id).unwrap_or_default()))
+        }
+    };
+    Ok((StatusCode::OK, Json(json!({ "success": true, "data": data }))))
 }
@@ -1,10 +1,26 @@
-use axum::{response::IntoResponse, Json, extract::Query};
+use axum::{http::StatusCode, response::IntoResponse, Json, extract::{Query, State}};
 use serde::Deserialize;
 use serde_json::json;
+use crate::data_source::DataSource;
+use crate::error::ApiError;
+use crate::pagination::{paginate, EmptyParams, PageParams};
+use crate::AppState;
 
 #[derive(Deserialize)]
-pub struct ByAuthorityQuery { pub authority: String }
+pub struct ByAuthorityQuery {
+    pub authority: String,
+    #[serde(rename = "includeArchived", default)]
+    pub include_archived: bool,
+}
 
-pub async fn get_integrations_by_authority(Query(_q): Query<ByAuthorityQuery>) -> impl IntoResponse {
-    Json(json!({"integrations": [{"id": "int_1", "authority": "auth_1"}]}))
+pub async fn get_integrations_by_authority(State(state): State<AppState>, Query(q): Query<ByAuthorityQuery>, Query(page): Query<PageParams>, Query(empty): Query<EmptyParams>) -> Result<impl IntoResponse, ApiError> {
+    let page_query = page.parse()?;
+    let integrations = state.integrations.list_by_authority(&q.authority, q.include_archived);
+    empty.check(&integrations, "integrations")?;
+    let page = paginate(integrations, &page_query);
+    Ok((StatusCode::OK, DataSource::Local, Json(json!({
+        "success": true,
+        "meta": state.cased(page.meta()),
+        "data": state.cased(page.items),
+    }))))
 }
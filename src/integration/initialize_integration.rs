@@ -1,11 +1,128 @@
 use axum::{response::IntoResponse, Json, extract::State};
 use serde::Deserialize;
 use serde_json::json;
+use std::time::{Duration, Instant};
+use crate::error::ApiError;
+use crate::validation::{sanitize_name, validate_pubkey};
 use crate::AppState;
 
+/// Request body for `POST /integration/init`.
+///
+/// `name` is trimmed before it is stored; blank names and control characters are rejected with 422.
+/// `authority` is optional, as it was before integrations were stored; when given it must be a
+/// Solana address (422 otherwise), so the creation limit below cannot be sidestepped by varying it.
+/// An authority that already created `REFLECT_INTEGRATION_CREATION_LIMIT` integrations within
+/// the last `REFLECT_INTEGRATION_CREATION_WINDOW_SECS` gets 429 with `Retry-After`; requests
+/// without one share a single limit.
 #[derive(Deserialize)]
-pub struct InitRequest { pub name: String }
+pub struct InitRequest {
+    pub name: String,
+    #[serde(default)]
+    pub authority: Option<String>,
+}
+
+pub async fn initialize_integration(State(state): State<AppState>, Json(payload): Json<InitRequest>) -> Result<impl IntoResponse, ApiError> {
+    let name = sanitize_name(&payload.name)?;
+    if let Some(authority) = &payload.authority {
+        validate_pubkey("authority", authority)?;
+    }
+    let authority = payload.authority.unwrap_or_default();
+
+    let limit = state.config.integration_creation_limit;
+    let window = Duration::from_secs(state.config.integration_creation_window_secs);
+    state
+        .creation_limits
+        .try_record(&authority, limit, window, Instant::now())
+        .map_err(|remaining| ApiError::retry_after("integration creation limit reached", remaining))?;
+
+    let record = state.integrations.create(name, authority);
+    let authority = Some(record.authority).filter(|authority| !authority.is_empty());
+    Ok(Json(json!({"result": "integration initialized", "id": record.id, "name": record.name, "authority": authority})))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use axum::body::to_bytes;
+    use axum::http::{header::RETRY_AFTER, StatusCode};
+    use serde_json::Value;
+
+    async fn create(state: &AppState, name: &str, authority: &str) -> (StatusCode, Value) {
+        let payload = InitRequest { name: name.to_string(), authority: Some(authority.to_string()) };
+        let response = initialize_integration(State(state.clone()), Json(payload))
+            .await
+            .into_response();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, 1024).await.unwrap();
+        (parts.status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    const ALICE: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+    const BOB: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+    async fn call(name: &str) -> (StatusCode, Value) {
+        create(&AppState::default(), name, ALICE).await
+    }
+
+    #[tokio::test]
+    async fn test_name_is_sanitized() {
+        let (status, json) = call("  My Integration \n").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["name"], "My Integration");
+
+        let (status, json) = call(" \t ").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid request data: name must not be blank");
+
+        let (status, json) = call("My\u{7}Integration").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid request data: name must not contain control characters");
+    }
+
+    #[tokio::test]
+    async fn test_creation_limit_per_authority() {
+        let state = AppState::new(Config {
+            integration_creation_limit: 2,
+            integration_creation_window_secs: 60,
+            ..Config::default()
+        });
+
+        for name in ["First", "Second"] {
+            let (status, _) = create(&state, name, ALICE).await;
+            assert_eq!(status, StatusCode::OK);
+        }
+
+        let payload = InitRequest { name: "Third".to_string(), authority: Some(ALICE.to_string()) };
+        let response = initialize_integration(State(state.clone()), Json(payload)).await.into_response();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[RETRY_AFTER], "60");
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["message"], "integration creation limit reached, retry after 60s");
+        assert_eq!(state.integrations.list_by_authority(ALICE, true).len(), 2);
+
+        // Other authorities are unaffected
+        let (status, _) = create(&state, "Other", BOB).await;
+        assert_eq!(status, StatusCode::OK);
+
+        // Made-up authorities are refused before they count against anything
+        let (status, json) = create(&state, "Fourth", "alice-2").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid request data: authority is not a valid public key");
+        assert!(state.integrations.list_by_authority("alice-2", true).is_empty());
+    }
 
-pub async fn initialize_integration(State(_state): State<AppState>, Json(payload): Json<InitRequest>) -> impl IntoResponse {
-    Json(json!({"result": "integration initialized", "name": payload.name}))
+    #[tokio::test]
+    async fn test_authority_is_optional() {
+        let state = AppState::default();
+        let payload = InitRequest { name: "Legacy".to_string(), authority: None };
+        let response = initialize_integration(State(state.clone()), Json(payload)).await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["name"], "Legacy");
+        assert!(json["authority"].is_null());
+        assert!(state.integrations.get(json["id"].as_str().unwrap()).is_some());
+    }
 }
@@ -1,11 +1,92 @@
 use axum::{response::IntoResponse, Json, extract::State};
+use regex::Regex;
 use serde::Deserialize;
 use serde_json::json;
+use crate::error::ApiError;
+use crate::validation::{sanitize_field, sanitize_name, validate_name, validate_symbol};
 use crate::AppState;
 
+/// Request body for `POST /integration/token/init`.
+///
+/// Both fields are trimmed first. `symbol` must then match `REFLECT_SYMBOL_PATTERN`
+/// (default `^[A-Z0-9+]{2,10}$`); `name`, when given, must be 1-64 printable characters.
 #[derive(Deserialize)]
-pub struct TokenInit { pub symbol: String }
+pub struct TokenInit {
+    pub symbol: String,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// The trimmed symbol, once every field passes validation.
+fn validate(payload: &TokenInit, pattern: &Regex) -> Result<String, ApiError> {
+    let symbol = sanitize_field("symbol", &payload.symbol)?;
+    validate_symbol(&symbol, pattern)?;
+    if let Some(name) = payload.name.as_deref() {
+        validate_name(&sanitize_name(name)?)?;
+    }
+    Ok(symbol)
+}
+
+pub async fn initialize_stablecoin_token(
+    State(state): State<AppState>,
+    Json(payload): Json<TokenInit>,
+) -> Result<impl IntoResponse, ApiError> {
+    let symbol = validate(&payload, &state.config.symbol_pattern)?;
+    Ok(Json(json!({"result": "token initialized", "symbol": symbol})))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use axum::http::StatusCode;
+    use serde_json::Value;
+
+    async fn call(symbol: &str, name: Option<&str>) -> (StatusCode, Value) {
+        let payload = TokenInit { symbol: symbol.to_string(), name: name.map(String::from) };
+        let response = initialize_stablecoin_token(State(AppState::default()), Json(payload))
+            .await
+            .into_response();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, 1024).await.unwrap();
+        (parts.status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_symbol_validation() {
+        let (status, json) = call("USDC+", Some("Reflect USD+")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["symbol"], "USDC+");
+
+        let (status, json) = call("USDCPLUSTOKEN", None).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["success"], false);
+        assert_eq!(json["message"], "Invalid request data: symbol must match ^[A-Z0-9+]{2,10}$");
+
+        let (status, _) = call("us-dc", None).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let (status, json) = call("USDC+", Some(&"a".repeat(65))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid request data: name must be 1-64 printable characters");
+    }
+
+    #[tokio::test]
+    async fn test_whitespace_and_control_characters() {
+        let (status, json) = call(" USDC+ ", Some(" Reflect USD+ ")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["symbol"], "USDC+");
+
+        let (status, json) = call("USDC+", Some("")).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid request data: name must not be blank");
+
+        let (status, json) = call("   ", None).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid request data: symbol must not be blank");
 
-pub async fn initialize_stablecoin_token(State(_state): State<AppState>, Json(payload): Json<TokenInit>) -> impl IntoResponse {
-    Json(json!({"result": "token initialized", "symbol": payload.symbol}))
+        let (status, json) = call("USDC+", Some("Reflect\u{0}USD")).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid request data: name must not contain control characters");
+    }
 }
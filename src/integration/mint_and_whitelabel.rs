@@ -1,11 +1,15 @@
 use axum::{response::IntoResponse, Json, extract::State};
 use serde::Deserialize;
-use serde_json::json;
+use serde_json::{json, Number};
+use crate::error::ApiError;
+use crate::validation::parse_amount;
 use crate::AppState;
 
+/// `amount` is in smallest units and must be a positive whole number.
 #[derive(Deserialize)]
-pub struct MintWL { pub amount: f64, pub label: Option<String> }
+pub struct MintWL { pub amount: Number, pub label: Option<String> }
 
-pub async fn mint_and_whitelabel(State(_state): State<AppState>, Json(payload): Json<MintWL>) -> impl IntoResponse {
-    Json(json!({"result": "minted and whitelabeled", "amount": payload.amount, "label": payload.label}))
+pub async fn mint_and_whitelabel(State(_state): State<AppState>, Json(payload): Json<MintWL>) -> Result<impl IntoResponse, ApiError> {
+    let amount = parse_amount("amount", &payload.amount)?;
+    Ok(Json(json!({"result": "minted and whitelabeled", "amount": amount, "label": payload.label})))
 }
@@ -1,4 +1,16 @@
-use axum::Router;
+use axum::{
+    body::{to_bytes, Body},
+    extract::{FromRequestParts, RawPathParams, Request, State},
+    http::{Method, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post, MethodRouter},
+    Json, Router,
+};
+use serde_json::json;
+use crate::auth::{foreign_integration, insufficient_scope, is_configured_key, KeyOwner, operator_key_required, provided_key, unauthorized, unauthorized_with, GrantedScopes, Scope};
+use crate::integration::api_keys::KeyRejection;
+use crate::timestamp::Timestamp;
 use crate::AppState;
 
 pub mod initialize_integration;
@@ -11,6 +23,7 @@ pub mod get_integrations_by_authority;
 pub mod upload_integration_metadata;
 pub mod reveal_api_key;
 pub mod rotate_api_key;
+pub mod revoke_api_key;
 pub mod whitelist_users;
 pub mod get_integration_statistics;
 pub mod get_integration_events;
@@ -23,29 +36,440 @@ pub mod mint_and_whitelabel;
 pub mod generate_redemption_tx;
 pub mod redeem_whitelabeled;
 pub mod generate_claim_tx;
+pub mod bulk_get_integration_configs;
+pub mod archive_integration;
+pub mod restore_integration;
+pub mod api_keys;
+pub mod creation_limits;
+pub mod store;
+pub mod whitelist;
+
+/// Middleware guarding the mutating integration endpoints.
+///
+/// `POST` requests must carry an `x-api-key` header holding either one of
+/// `REFLECT_INTEGRATION_API_KEYS` or a key issued by
+/// `POST /integrations/api-key/rotate`, which is checked against its salted
+/// hash. Reads stay open. Rejected requests get the same `401` as admin
+/// endpoints, or `401` "api key expired" for an issued key past its expiry.
+/// The key's scopes are passed on as [`GrantedScopes`] for [`require_scope`];
+/// configured keys hold every scope.
+///
+/// An issued key only acts on its own integration: its owner is passed on as
+/// [`KeyOwner`], and a request whose `:id` path segment or JSON `id` /
+/// `integration_id` names another integration is `403`. Routes whose payload
+/// names no integration cannot be limited this way and are wrapped in
+/// [`operator_only`]. Configured keys are operator keys and may target any
+/// integration.
+pub async fn require_api_key(State(state): State<AppState>, mut request: Request, next: Next) -> Response {
+    if request.method() == Method::POST {
+        let Some(key) = provided_key(request.headers()) else {
+            return unauthorized();
+        };
+        let scopes = if is_configured_key(&state.config.integration_api_keys, key) {
+            Scope::ALL.to_vec()
+        } else {
+            let (owner, record) = match state.api_keys.authenticate(key, Timestamp::now()) {
+                Ok(authenticated) => authenticated,
+                Err(KeyRejection::Expired) => return unauthorized_with("api key expired"),
+                Err(KeyRejection::Unknown) => return unauthorized(),
+            };
+            request = match restrict_to_owner(&state, request, &owner).await {
+                Ok(request) => request,
+                Err(response) => return response,
+            };
+            request.extensions_mut().insert(KeyOwner(owner));
+            record.scopes
+        };
+        request.extensions_mut().insert(GrantedScopes(scopes));
+    }
+
+    next.run(request).await
+}
+
+/// Largest body buffered to check which integration a request targets; the
+/// same as axum's default JSON body limit.
+const OWNER_CHECK_BODY_LIMIT: usize = 2 * 1024 * 1024;
+
+/// `request`, unchanged, if every integration id it names is `owner`;
+/// otherwise the `403` to send instead. The body is buffered to read it and
+/// put back for the handler.
+async fn restrict_to_owner(state: &AppState, request: Request, owner: &str) -> Result<Request, Response> {
+    let (mut parts, body) = request.into_parts();
+    if let Ok(params) = RawPathParams::from_request_parts(&mut parts, state).await {
+        if params.iter().any(|(name, value)| name == "id" && value != owner) {
+            return Err(foreign_integration());
+        }
+    }
+
+    let bytes = to_bytes(body, OWNER_CHECK_BODY_LIMIT)
+        .await
+        .map_err(|_| {
+            let body = json!({ "success": false, "message": "Request body too large" });
+            (StatusCode::PAYLOAD_TOO_LARGE, Json(body)).into_response()
+        })?;
+    if let Ok(serde_json::Value::Object(fields)) = serde_json::from_slice(&bytes) {
+        let targets_other = ["id", "integration_id"]
+            .iter()
+            .filter_map(|field| fields.get(*field))
+            .any(|target| target.as_str() != Some(owner));
+        if targets_other {
+            return Err(foreign_integration());
+        }
+    }
+    Ok(Request::from_parts(parts, Body::from(bytes)))
+}
+
+/// Route middleware rejecting keys without `scope` with `403` "insufficient
+/// scope". Runs after [`require_api_key`]; a request it did not authenticate
+/// is `401`.
+pub async fn require_scope(State(scope): State<Scope>, request: Request, next: Next) -> Response {
+    match request.extensions().get::<GrantedScopes>() {
+        Some(granted) if granted.allows(scope) => next.run(request).await,
+        Some(_) => insufficient_scope(),
+        None => unauthorized(),
+    }
+}
+
+/// Route middleware rejecting issued keys with `403` "route requires an
+/// operator api key". Runs after [`require_api_key`], which marks issued keys
+/// with their [`KeyOwner`].
+pub async fn require_operator_key(request: Request, next: Next) -> Response {
+    if request.extensions().get::<KeyOwner>().is_some() {
+        return operator_key_required();
+    }
+    next.run(request).await
+}
+
+/// `route` restricted to keys holding `scope`.
+fn scoped(scope: Scope, route: MethodRouter<AppState>) -> MethodRouter<AppState> {
+    route.route_layer(middleware::from_fn_with_state(scope, require_scope))
+}
+
+/// `route` restricted to operator keys holding `scope`. Used for routes whose
+/// payload names no integration, so an issued key could not be held to its
+/// own.
+fn operator_only(scope: Scope, route: MethodRouter<AppState>) -> MethodRouter<AppState> {
+    scoped(scope, route).route_layer(middleware::from_fn(require_operator_key))
+}
+
+pub fn router(state: AppState) -> Router<AppState> {
+    use Scope::{Admin, Mint, Read};
 
-pub fn router() -> Router<AppState> {
     Router::new()
-        .route("/init", axum::routing::post(initialize_integration::initialize_integration))
-        .route("/token/init", axum::routing::post(initialize_stablecoin_token::initialize_stablecoin_token))
-        .route("/transfer-authority", axum::routing::post(transfer_mint_authority::transfer_mint_authority))
-        .route("/flow/init", axum::routing::post(initialize_integration_flow::initialize_flow))
-        .route("/config", axum::routing::get(get_integration_config::get_integration_config))
-        .route("/config/update", axum::routing::post(update_integration_config::update_integration_config))
-        .route("/by-authority", axum::routing::get(get_integrations_by_authority::get_integrations_by_authority))
-        .route("/metadata/upload", axum::routing::post(upload_integration_metadata::upload_integration_metadata))
-        .route("/api-key/reveal", axum::routing::post(reveal_api_key::reveal_api_key))
-        .route("/api-key/rotate", axum::routing::post(rotate_api_key::rotate_api_key))
-        .route("/whitelist", axum::routing::post(whitelist_users::whitelist_users))
-        .route("/stats", axum::routing::get(get_integration_statistics::get_integration_statistics))
-        .route("/events", axum::routing::get(get_integration_events::get_integration_events))
-        .route("/historical-stats", axum::routing::get(get_historical_integration_stats::get_historical_integration_stats))
-        .route("/exchange-rate", axum::routing::get(get_current_exchange_rate::get_current_exchange_rate))
-        .route("/vault/init", axum::routing::post(initialize_integration_vault::initialize_integration_vault))
-        .route("/user-token/init", axum::routing::post(initialize_user_branded_token::initialize_user_branded_token))
-        .route("/mint/tx", axum::routing::post(generate_integration_mint_tx::generate_integration_mint_tx))
-        .route("/mint-whitelabel", axum::routing::post(mint_and_whitelabel::mint_and_whitelabel))
-        .route("/redeem/tx", axum::routing::post(generate_redemption_tx::generate_redemption_tx))
-        .route("/redeem-whitelabel", axum::routing::post(redeem_whitelabeled::redeem_whitelabeled))
-        .route("/claim/tx", axum::routing::post(generate_claim_tx::generate_claim_tx))
+        .route("/init", operator_only(Admin, post(initialize_integration::initialize_integration)))
+        .route("/token/init", operator_only(Admin, post(initialize_stablecoin_token::initialize_stablecoin_token)))
+        .route("/transfer-authority", operator_only(Admin, post(transfer_mint_authority::transfer_mint_authority)))
+        .route("/flow/init", operator_only(Admin, post(initialize_integration_flow::initialize_flow)))
+        .route("/config", get(get_integration_config::get_integration_config))
+        .route("/config/update", scoped(Admin, post(update_integration_config::update_integration_config)))
+        .route("/by-authority", get(get_integrations_by_authority::get_integrations_by_authority))
+        .route("/metadata/upload", operator_only(Admin, post(upload_integration_metadata::upload_integration_metadata)))
+        .route("/api-key/reveal", scoped(Read, post(reveal_api_key::reveal_api_key)))
+        .route("/api-key/rotate", scoped(Admin, post(rotate_api_key::rotate_api_key)))
+        .route("/api-key/revoke", scoped(Admin, post(revoke_api_key::revoke_api_key)))
+        .route(
+            "/whitelist",
+            get(whitelist_users::get_whitelist).merge(scoped(Admin, post(whitelist_users::whitelist_users))),
+        )
+        .route("/stats", get(get_integration_statistics::get_integration_statistics))
+        .route("/events", get(get_integration_events::get_integration_events))
+        .route("/historical-stats", get(get_historical_integration_stats::get_historical_integration_stats))
+        .route("/exchange-rate", get(get_current_exchange_rate::get_current_exchange_rate))
+        .route("/vault/init", operator_only(Admin, post(initialize_integration_vault::initialize_integration_vault)))
+        .route("/user-token/init", operator_only(Admin, post(initialize_user_branded_token::initialize_user_branded_token)))
+        .route("/mint/tx", scoped(Mint, post(generate_integration_mint_tx::generate_integration_mint_tx)))
+        .route("/mint-whitelabel", operator_only(Mint, post(mint_and_whitelabel::mint_and_whitelabel)))
+        .route("/redeem/tx", scoped(Mint, post(generate_redemption_tx::generate_redemption_tx)))
+        .route("/redeem-whitelabel", operator_only(Mint, post(redeem_whitelabeled::redeem_whitelabeled)))
+        .route("/claim/tx", operator_only(Mint, post(generate_claim_tx::generate_claim_tx)))
+        .route("/:id/archive", scoped(Admin, post(archive_integration::archive_integration)))
+        .route("/:id/restore", scoped(Admin, post(restore_integration::restore_integration)))
+        .route_layer(middleware::from_fn_with_state(state, require_api_key))
+        // Guarded by `AdminKey` instead of the integration keys above
+        .route("/configs", post(bulk_get_integration_configs::bulk_get_integration_configs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::integration::api_keys::KeyOverlap;
+    use axum::body::{to_bytes, Body};
+    use axum::http::StatusCode;
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    fn state() -> AppState {
+        state_with(Config::default())
+    }
+
+    fn state_with(config: Config) -> AppState {
+        let state = AppState::new(Config {
+            integration_api_keys: vec!["integration-key".to_string()],
+            ..config
+        });
+        state.integrations.create("Acme".into(), "auth_1".into());
+        state
+    }
+
+    fn app() -> Router {
+        app_with(state())
+    }
+
+    fn app_with(state: AppState) -> Router {
+        router(state.clone()).with_state(state)
+    }
+
+    async fn rotate(key: Option<&str>) -> (StatusCode, Value) {
+        rotate_with(state(), key).await
+    }
+
+    async fn rotate_with(state: AppState, key: Option<&str>) -> (StatusCode, Value) {
+        let mut request = Request::post("/api-key/rotate").header("content-type", "application/json");
+        if let Some(key) = key {
+            request = request.header("x-api-key", key);
+        }
+        let response = app_with(state)
+            .oneshot(request.body(Body::from(json!({ "id": "int_1" }).to_string())).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn post_with_valid_key_reaches_handler() {
+        let (status, json) = rotate(Some("integration-key")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["id"], "int_1");
+    }
+
+    #[tokio::test]
+    async fn post_without_key_or_with_wrong_key_is_unauthorized() {
+        for key in [None, Some("wrong-key")] {
+            let (status, json) = rotate(key).await;
+            assert_eq!(status, StatusCode::UNAUTHORIZED, "{:?}", key);
+            assert_eq!(json, json!({ "success": false, "message": "Missing or invalid API key" }));
+        }
+    }
+
+    #[tokio::test]
+    async fn issued_key_authenticates_until_rotated() {
+        let state = state_with(Config { api_key_grace_secs: 0, ..Config::default() });
+        let (status, json) = rotate_with(state.clone(), Some("integration-key")).await;
+        assert_eq!(status, StatusCode::OK);
+        let issued = json["data"]["api_key"].as_str().unwrap().to_string();
+
+        let (status, json) = rotate_with(state.clone(), Some(&issued)).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = rotate_with(state.clone(), Some(&issued)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let current = json["data"]["api_key"].as_str().unwrap();
+        let (status, _) = rotate_with(state, Some(current)).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn rotated_key_keeps_working_during_the_overlap() {
+        let state = state();
+        let (_, json) = rotate_with(state.clone(), Some("integration-key")).await;
+        let first = json["data"]["api_key"].as_str().unwrap().to_string();
+
+        // Old and new key both authenticate after a rotation
+        let (status, json) = rotate_with(state.clone(), Some(&first)).await;
+        assert_eq!(status, StatusCode::OK);
+        let second = json["data"]["api_key"].as_str().unwrap().to_string();
+        let (status, _) = rotate_with(state.clone(), Some(&first)).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = rotate_with(state.clone(), Some(&second)).await;
+        assert_eq!(status, StatusCode::OK);
+
+        // Past REFLECT_MAX_ACTIVE_API_KEYS the oldest is dropped
+        let (status, _) = rotate_with(state, Some(&first)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn revoked_key_stops_working_during_the_overlap() {
+        let state = state();
+        let (_, json) = rotate_with(state.clone(), Some("integration-key")).await;
+        let leaked = json["data"]["api_key"].as_str().unwrap().to_string();
+        let (_, json) = rotate_with(state.clone(), Some("integration-key")).await;
+        let current = json["data"]["api_key"].as_str().unwrap().to_string();
+        let fingerprint = crate::integration::api_keys::fingerprint(&leaked);
+
+        let (status, json) = post_with(state.clone(), "/api-key/revoke", &current, json!({ "id": "int_1", "fingerprint": fingerprint })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"], json!({ "id": "int_1", "fingerprint": fingerprint }));
+
+        let (status, _) = rotate_with(state.clone(), Some(&leaked)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let (status, json) = post_with(state, "/api-key/revoke", &current, json!({ "id": "int_1", "fingerprint": fingerprint })).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(json["message"], "API key not found");
+    }
+
+    #[tokio::test]
+    async fn expired_key_is_rejected() {
+        let state = state();
+        let now = Timestamp::now();
+        let (valid, _) = state.api_keys.rotate("int_1", "rflk_", Some(Timestamp(now.0 + chrono::Duration::hours(1))), &Scope::ALL, KeyOverlap::NONE);
+        let (status, _) = rotate_with(state.clone(), Some(&valid)).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (expired, _) = state.api_keys.rotate("int_1", "rflk_", Some(Timestamp(now.0 - chrono::Duration::seconds(1))), &Scope::ALL, KeyOverlap::NONE);
+        let (status, json) = rotate_with(state, Some(&expired)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(json, json!({ "success": false, "message": "api key expired" }));
+    }
+
+    async fn post_with(state: AppState, uri: &str, key: &str, body: Value) -> (StatusCode, Value) {
+        let request = Request::post(uri)
+            .header("content-type", "application/json")
+            .header("x-api-key", key)
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app_with(state).oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn read_only_key_is_limited_to_read_routes() {
+        let state = state();
+        let (read_only, _) = state.api_keys.rotate("int_1", "rflk_", None, &[Scope::Read], KeyOverlap::NONE);
+
+        let (status, json) = post_with(state.clone(), "/config/update", &read_only, json!({ "id": "int_1" })).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(json, json!({ "success": false, "message": "insufficient scope" }));
+        let (status, _) = post_with(state.clone(), "/mint/tx", &read_only, json!({})).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, json) = post_with(state, "/api-key/reveal", &read_only, json!({ "id": "int_1" })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["scopes"], json!(["read"]));
+    }
+
+    #[tokio::test]
+    async fn admin_scope_grants_every_route() {
+        let state = state();
+        let (mint_only, _) = state.api_keys.rotate("int_1", "rflk_", None, &[Scope::Mint], KeyOverlap::NONE);
+        let (status, _) = post_with(state.clone(), "/api-key/reveal", &mint_only, json!({ "id": "int_1" })).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (admin, _) = state.api_keys.rotate("int_1", "rflk_", None, &[Scope::Admin], KeyOverlap::NONE);
+        let (status, _) = post_with(state.clone(), "/api-key/reveal", &admin, json!({ "id": "int_1" })).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, json) = post_with(state, "/api-key/rotate", &admin, json!({ "id": "int_1", "scopes": [] })).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "scopes must not be empty");
+    }
+
+    #[tokio::test]
+    async fn issued_key_only_acts_on_its_own_integration() {
+        let state = state();
+        state.integrations.create("Other".into(), "auth_2".into());
+        let (own, _) = state.api_keys.rotate("int_1", "rflk_", None, &[Scope::Admin], KeyOverlap::NONE);
+
+        let forbidden = json!({ "success": false, "message": "api key does not belong to this integration" });
+        let (status, json) = post_with(state.clone(), "/api-key/rotate", &own, json!({ "id": "int_2" })).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(json, forbidden);
+        assert!(state.api_keys.keys("int_2").is_empty());
+        let (status, _) = post_with(state.clone(), "/whitelist", &own, json!({ "integration_id": "int_2", "users": [] })).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, json) = post_with(state.clone(), "/int_2/archive", &own, json!({})).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(json, forbidden);
+        assert!(!state.integrations.get("int_2").unwrap().is_archived());
+
+        // Its own integration, and any integration with an operator key, still work
+        let (status, _) = post_with(state.clone(), "/api-key/reveal", &own, json!({ "id": "int_1" })).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = post_with(state, "/api-key/rotate", "integration-key", json!({ "id": "int_2" })).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn issued_key_is_refused_on_routes_naming_no_integration() {
+        let state = state();
+        let (foreign, _) = state.api_keys.rotate("int_1", "rflk_", None, &[Scope::Admin], KeyOverlap::NONE);
+
+        let (status, json) = post_with(state.clone(), "/transfer-authority", &foreign, json!({ "to": "attacker" })).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(json, json!({ "success": false, "message": "route requires an operator api key" }));
+        let (status, _) = post_with(state.clone(), "/init", &foreign, json!({ "name": "Rogue" })).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert!(state.integrations.get("int_2").is_none());
+
+        let (status, _) = post_with(state, "/transfer-authority", "integration-key", json!({ "to": "new_authority" })).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    async fn get_json(state: AppState, uri: &str) -> (StatusCode, Value) {
+        let response = app_with(state).oneshot(Request::get(uri).body(Body::empty()).unwrap()).await.unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn integration_mints_and_redeems_are_served_as_stats() {
+        let state = state();
+        state.integrations.create("Other".into(), "auth_2".into());
+        let (mint_key, _) = state.api_keys.rotate("int_1", "rflk_", None, &[Scope::Mint], KeyOverlap::NONE);
+        let holder = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+
+        let mint = json!({ "integration_id": "int_1", "amount": 1_000_000, "recipient": holder });
+        for key in [mint_key.as_str(), "integration-key"] {
+            let (status, _) = post_with(state.clone(), "/mint/tx", key, mint.clone()).await;
+            assert_eq!(status, StatusCode::OK);
+        }
+        let redeem = json!({ "integration_id": "int_1", "amount": 500_000, "holder": holder });
+        let (status, _) = post_with(state.clone(), "/redeem/tx", &mint_key, redeem).await;
+        assert_eq!(status, StatusCode::OK);
+
+        // An issued key cannot mint for another integration
+        let foreign = json!({ "integration_id": "int_2", "amount": 1_000_000, "recipient": holder });
+        let (status, _) = post_with(state.clone(), "/mint/tx", &mint_key, foreign).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, json) = get_json(state.clone(), "/stats?id=int_1").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json, json!({ "success": true, "data": { "mints": 2, "redeems": 1 } }));
+        let (_, json) = get_json(state.clone(), "/stats?id=int_2").await;
+        assert_eq!(json["data"], json!({ "mints": 0, "redeems": 0 }));
+        let (_, json) = get_json(state.clone(), "/stats").await;
+        assert_eq!(json["data"]["protocol"], json!({ "mints": 2, "redeems": 1 }));
+        let (status, json) = get_json(state, "/stats?id=int_9").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(json["message"], "Integration not found");
+    }
+
+    #[tokio::test]
+    async fn oversized_body_from_issued_key_is_413_in_the_envelope() {
+        let state = state();
+        let (own, _) = state.api_keys.rotate("int_1", "rflk_", None, &[Scope::Admin], KeyOverlap::NONE);
+        let request = Request::post("/api-key/rotate")
+            .header("x-api-key", own)
+            .body(Body::from(vec![b' '; OWNER_CHECK_BODY_LIMIT + 1]))
+            .unwrap();
+        let response = app_with(state).oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json, json!({ "success": false, "message": "Request body too large" }));
+    }
+
+    #[tokio::test]
+    async fn reads_stay_open() {
+        let response = app()
+            .oneshot(Request::get("/config?id=missing").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
 }
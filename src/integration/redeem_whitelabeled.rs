@@ -1,11 +1,15 @@
 use axum::{response::IntoResponse, Json, extract::State};
 use serde::Deserialize;
-use serde_json::json;
+use serde_json::{json, Number};
+use crate::error::ApiError;
+use crate::validation::parse_amount;
 use crate::AppState;
 
+/// `amount` is in smallest units and must be a positive whole number.
 #[derive(Deserialize)]
-pub struct RedeemWL { pub amount: f64, pub label: Option<String> }
+pub struct RedeemWL { pub amount: Number, pub label: Option<String> }
 
-pub async fn redeem_whitelabeled(State(_state): State<AppState>, Json(payload): Json<RedeemWL>) -> impl IntoResponse {
-    Json(json!({"result": "redeemed whitelabeled", "amount": payload.amount, "label": payload.label}))
+pub async fn redeem_whitelabeled(State(_state): State<AppState>, Json(payload): Json<RedeemWL>) -> Result<impl IntoResponse, ApiError> {
+    let amount = parse_amount("amount", &payload.amount)?;
+    Ok(Json(json!({"result": "redeemed whitelabeled", "amount": amount, "label": payload.label})))
 }
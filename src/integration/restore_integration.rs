@@ -0,0 +1,13 @@
+use axum::{response::IntoResponse, Json, extract::State, http::StatusCode};
+use serde_json::json;
+use crate::error::ApiError;
+use crate::path::Path;
+use crate::AppState;
+
+pub async fn restore_integration(State(state): State<AppState>, Path(id): Path<String>) -> Result<impl IntoResponse, ApiError> {
+    let record = state
+        .integrations
+        .restore(&id)
+        .ok_or_else(|| ApiError::NotFound("Integration not found".to_string()))?;
+    Ok((StatusCode::OK, Json(json!({"result": "integration restored", "integration": state.cased(record)}))))
+}
@@ -1,10 +1,212 @@
-use axum::{response::IntoResponse, Json};
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
 use serde::Deserialize;
 use serde_json::json;
 
+use crate::error::ApiError;
+use crate::json_body::JsonBody;
+use crate::timestamp::Timestamp;
+use crate::AppState;
+
 #[derive(Deserialize)]
 pub struct ApiKeyReveal { pub id: String }
 
-pub async fn reveal_api_key(Json(_payload): Json<ApiKeyReveal>) -> impl IntoResponse {
-    Json(json!({"api_key": "REDACTED-KEY"}))
+/// Handler for `POST /integrations/api-key/reveal`.
+///
+/// Returns the metadata of every active key of the integration, oldest
+/// first, in `keys`: its fingerprint, when it was issued, when it expires
+/// and its scopes.
+/// Keys rotated out but still within their grace period are listed with the
+/// expiry that grace period gave them. The newest key is also reported at the
+/// top level. The plaintext is never returned; a lost key has to be rotated.
+/// Unknown integrations, and integrations without an active key, are `404`.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request POST \
+///   --url http://localhost:3000/integrations/api-key/reveal \
+///   --header 'x-api-key: <integration key>' \
+///   --header 'Content-Type: application/json' \
+///   --data '{"id": "int_1"}'
+/// ```
+///
+/// Expected output:
+/// ```json
+/// {
+///   "success": true,
+///   "data": {
+///     "id": "int_1",
+///     "fingerprint": "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08",
+///     "created_at": "2025-12-18T17:46:10.274Z",
+///     "expires_at": null,
+///     "scopes": ["read", "mint", "admin"],
+///     "keys": [
+///       {
+///         "fingerprint": "60303ae22b998861bce3b28f33eec1be758a213c86c93c076dbe9f558c11c752",
+///         "created_at": "2025-12-17T09:12:44.051Z",
+///         "expires_at": "2025-12-19T17:46:10.274Z",
+///         "scopes": ["read", "mint", "admin"]
+///       },
+///       {
+///         "fingerprint": "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08",
+///         "created_at": "2025-12-18T17:46:10.274Z",
+///         "expires_at": null,
+///         "scopes": ["read", "mint", "admin"]
+///       }
+///     ]
+///   }
+/// }
+/// ```
+pub async fn reveal_api_key(
+    State(state): State<AppState>,
+    JsonBody(payload): JsonBody<ApiKeyReveal>,
+) -> Result<impl IntoResponse, ApiError> {
+    if state.integrations.get(&payload.id).is_none() {
+        return Err(ApiError::NotFound("Integration not found".to_string()));
+    }
+    let now = Timestamp::now();
+    let mut keys = state.api_keys.keys(&payload.id);
+    keys.retain(|record| !record.is_expired(now));
+    let record = keys
+        .last()
+        .cloned()
+        .ok_or_else(|| ApiError::NotFound("No active API key for this integration".to_string()))?;
+
+    Ok((
+        StatusCode::OK,
+        Json(json!({
+            "success": true,
+            "data": {
+                "id": payload.id,
+                "fingerprint": record.fingerprint,
+                "created_at": record.created_at,
+                "expires_at": record.expires_at,
+                "scopes": record.scopes,
+                "keys": keys,
+            }
+        })),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integration::api_keys::fingerprint;
+    use crate::integration::rotate_api_key::rotate_api_key;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::post;
+    use axum::Router;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    async fn send(state: &AppState, uri: &str, id: &str) -> (StatusCode, Value) {
+        send_body(state, uri, json!({ "id": id })).await
+    }
+
+    async fn send_body(state: &AppState, uri: &str, body: Value) -> (StatusCode, Value) {
+        let app = Router::new()
+            .route("/api-key/reveal", post(reveal_api_key))
+            .route("/api-key/rotate", post(rotate_api_key))
+            .with_state(state.clone());
+        let request = Request::post(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_rotate_then_reveal_never_returns_plaintext() {
+        let state = AppState::default();
+        let integration = state.integrations.create("Acme".into(), "auth_1".into());
+
+        let (status, rotated) = send(&state, "/api-key/rotate", &integration.id).await;
+        assert_eq!(status, StatusCode::OK);
+        let key = rotated["data"]["api_key"].as_str().unwrap().to_string();
+        assert_eq!(rotated["data"]["fingerprint"], fingerprint(&key));
+
+        let (status, revealed) = send(&state, "/api-key/reveal", &integration.id).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(revealed["data"]["fingerprint"], rotated["data"]["fingerprint"]);
+        assert_eq!(revealed["data"]["created_at"], rotated["data"]["created_at"]);
+        assert!(revealed["data"].get("api_key").is_none());
+        assert!(!revealed.to_string().contains(&key));
+
+        // Rotating again issues a different key; both are listed during the overlap
+        let (_, again) = send(&state, "/api-key/rotate", &integration.id).await;
+        assert_ne!(again["data"]["api_key"], key.as_str());
+        let (_, revealed) = send(&state, "/api-key/reveal", &integration.id).await;
+        assert_eq!(revealed["data"]["fingerprint"], again["data"]["fingerprint"]);
+        let keys = revealed["data"]["keys"].as_array().unwrap();
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0]["fingerprint"], rotated["data"]["fingerprint"]);
+        assert!(keys[0]["expires_at"].is_string());
+        assert_eq!(keys[1]["fingerprint"], again["data"]["fingerprint"]);
+        assert!(!revealed.to_string().contains(again["data"]["api_key"].as_str().unwrap()));
+    }
+
+    #[tokio::test]
+    async fn test_rotation_without_grace_lists_one_key() {
+        let state = AppState::new(crate::config::Config { api_key_grace_secs: 0, ..Default::default() });
+        let integration = state.integrations.create("Acme".into(), "auth_1".into());
+        send(&state, "/api-key/rotate", &integration.id).await;
+        let (_, again) = send(&state, "/api-key/rotate", &integration.id).await;
+
+        let (_, revealed) = send(&state, "/api-key/reveal", &integration.id).await;
+        assert_eq!(revealed["data"]["keys"], json!([{
+            "fingerprint": again["data"]["fingerprint"],
+            "created_at": again["data"]["created_at"],
+            "expires_at": null,
+            "scopes": ["read", "mint", "admin"],
+        }]));
+    }
+
+    #[tokio::test]
+    async fn test_rotate_with_prefix_and_expiry() {
+        let state = AppState::default();
+        let integration = state.integrations.create("Acme".into(), "auth_1".into());
+
+        let (status, rotated) = send_body(
+            &state,
+            "/api-key/rotate",
+            json!({ "id": integration.id, "expires_at": "2999-01-01T00:00:00Z" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(rotated["data"]["api_key"].as_str().unwrap().starts_with("rflk_"));
+        assert_eq!(rotated["data"]["expires_at"], "2999-01-01T00:00:00.000Z");
+
+        let (_, revealed) = send(&state, "/api-key/reveal", &integration.id).await;
+        assert_eq!(revealed["data"]["expires_at"], "2999-01-01T00:00:00.000Z");
+
+        let (status, json) = send_body(
+            &state,
+            "/api-key/rotate",
+            json!({ "id": integration.id, "expires_at": "2020-01-01T00:00:00Z" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "expires_at must be in the future");
+        let (_, revealed) = send(&state, "/api-key/reveal", &integration.id).await;
+        assert_eq!(revealed["data"]["fingerprint"], rotated["data"]["fingerprint"]);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_integration_or_no_key_is_not_found() {
+        let state = AppState::default();
+        for uri in ["/api-key/rotate", "/api-key/reveal"] {
+            let (status, json) = send(&state, uri, "int_404").await;
+            assert_eq!(status, StatusCode::NOT_FOUND);
+            assert_eq!(json["message"], "Integration not found");
+        }
+
+        let integration = state.integrations.create("Acme".into(), "auth_1".into());
+        let (status, json) = send(&state, "/api-key/reveal", &integration.id).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(json["message"], "No active API key for this integration");
+    }
 }
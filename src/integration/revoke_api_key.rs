@@ -0,0 +1,68 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::error::ApiError;
+use crate::json_body::JsonBody;
+use crate::AppState;
+
+/// Request body for `POST /integrations/api-key/revoke`.
+///
+/// - `id`: The integration holding the key.
+/// - `fingerprint`: Fingerprint of the key to revoke, as listed by
+///   `/integrations/api-key/reveal`.
+#[derive(Deserialize)]
+pub struct ApiKeyRevoke {
+    pub id: String,
+    pub fingerprint: String,
+}
+
+/// Handler for `POST /integrations/api-key/revoke`.
+///
+/// Stops a key authenticating at once, including a key rotated out but still
+/// within its `REFLECT_API_KEY_GRACE_SECS` grace period, so a leaked key can
+/// be shut off without waiting for the grace period to end. Unknown
+/// integrations, and fingerprints that are not one of the integration's
+/// keys, are `404`. Requires the `admin` scope.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request POST \
+///   --url http://localhost:3000/integrations/api-key/revoke \
+///   --header 'x-api-key: <integration key>' \
+///   --header 'Content-Type: application/json' \
+///   --data '{"id": "int_1", "fingerprint": "60303ae22b998861bce3b28f33eec1be758a213c86c93c076dbe9f558c11c752"}'
+/// ```
+///
+/// Expected output:
+/// ```json
+/// {
+///   "success": true,
+///   "data": {
+///     "id": "int_1",
+///     "fingerprint": "60303ae22b998861bce3b28f33eec1be758a213c86c93c076dbe9f558c11c752"
+///   }
+/// }
+/// ```
+pub async fn revoke_api_key(
+    State(state): State<AppState>,
+    JsonBody(payload): JsonBody<ApiKeyRevoke>,
+) -> Result<impl IntoResponse, ApiError> {
+    if state.integrations.get(&payload.id).is_none() {
+        return Err(ApiError::NotFound("Integration not found".to_string()));
+    }
+    let record = state
+        .api_keys
+        .revoke(&payload.id, &payload.fingerprint)
+        .ok_or_else(|| ApiError::NotFound("API key not found".to_string()))?;
+
+    tracing::info!(integration_id = %payload.id, fingerprint = %record.fingerprint, "API key revoked");
+    Ok((
+        StatusCode::OK,
+        Json(json!({
+            "success": true,
+            "data": { "id": payload.id, "fingerprint": record.fingerprint }
+        })),
+    ))
+}
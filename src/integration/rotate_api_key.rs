@@ -1,10 +1,100 @@
-use axum::{response::IntoResponse, Json};
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
 use serde::Deserialize;
 use serde_json::json;
 
+use crate::auth::Scope;
+use crate::error::ApiError;
+use crate::json_body::JsonBody;
+use crate::timestamp::Timestamp;
+use crate::AppState;
+
+/// Request body for `POST /integrations/api-key/rotate`.
+///
+/// - `id`: The integration to issue a key for.
+/// - `expires_at`: Optional RFC 3339 instant after which the key stops
+///   authenticating; must be in the future. Omit for a key that never expires.
+/// - `scopes`: Optional non-empty list of `read`, `mint` and `admin`
+///   limiting what the key may do. Omit for a key with every scope.
 #[derive(Deserialize)]
-pub struct ApiKeyRotate { pub id: String }
+pub struct ApiKeyRotate {
+    pub id: String,
+    #[serde(default)]
+    pub expires_at: Option<Timestamp>,
+    #[serde(default)]
+    pub scopes: Option<Vec<Scope>>,
+}
+
+/// Handler for `POST /integrations/api-key/rotate`.
+///
+/// Issues a fresh random key for the integration. Previous keys keep working
+/// for `REFLECT_API_KEY_GRACE_SECS` so clients can switch over (unless revoked
+/// through `POST /integrations/api-key/revoke`), and at most
+/// `REFLECT_MAX_ACTIVE_API_KEYS` are kept, dropping the oldest. Keys start
+/// with `REFLECT_API_KEY_PREFIX` (`rflk_` by default). The
+/// plaintext `api_key` is only ever returned here; afterwards just its
+/// fingerprint is kept. Unknown integrations are `404`, an `expires_at` that
+/// is not in the future or an empty `scopes` list `422`. Requires the
+/// `admin` scope.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request POST \
+///   --url http://localhost:3000/integrations/api-key/rotate \
+///   --header 'x-api-key: <integration key>' \
+///   --header 'Content-Type: application/json' \
+///   --data '{"id": "int_1", "expires_at": "2026-12-18T00:00:00Z", "scopes": ["read", "mint"]}'
+/// ```
+///
+/// Expected output:
+/// ```json
+/// {
+///   "success": true,
+///   "data": {
+///     "id": "int_1",
+///     "api_key": "rflk_4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi",
+///     "fingerprint": "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08",
+///     "created_at": "2025-12-18T17:46:10.274Z",
+///     "expires_at": "2026-12-18T00:00:00.000Z",
+///     "scopes": ["read", "mint"]
+///   }
+/// }
+/// ```
+pub async fn rotate_api_key(
+    State(state): State<AppState>,
+    JsonBody(payload): JsonBody<ApiKeyRotate>,
+) -> Result<impl IntoResponse, ApiError> {
+    if state.integrations.get(&payload.id).is_none() {
+        return Err(ApiError::NotFound("Integration not found".to_string()));
+    }
+    if payload.expires_at.is_some_and(|expires_at| expires_at <= Timestamp::now()) {
+        return Err(ApiError::Unprocessable("expires_at must be in the future".to_string()));
+    }
+    let scopes = payload.scopes.unwrap_or_else(|| Scope::ALL.to_vec());
+    if scopes.is_empty() {
+        return Err(ApiError::Unprocessable("scopes must not be empty".to_string()));
+    }
 
-pub async fn rotate_api_key(Json(_payload): Json<ApiKeyRotate>) -> impl IntoResponse {
-    Json(json!({"result": "api key rotated"}))
+    let (api_key, record) = state.api_keys.rotate(
+        &payload.id,
+        &state.config.api_key_prefix,
+        payload.expires_at,
+        &scopes,
+        state.config.key_overlap(),
+    );
+    tracing::info!(integration_id = %payload.id, "API key rotated");
+    Ok((
+        StatusCode::OK,
+        Json(json!({
+            "success": true,
+            "data": {
+                "id": payload.id,
+                "api_key": api_key,
+                "fingerprint": record.fingerprint,
+                "created_at": record.created_at,
+                "expires_at": record.expires_at,
+                "scopes": record.scopes,
+            }
+        })),
+    ))
 }
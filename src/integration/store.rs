@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+use crate::timestamp::Timestamp;
+
+/// Configurable settings of an integration.
+///
+/// ### Example
+/// ```json
+/// { "fee_percent": 0.5 }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntegrationConfig {
+    pub fee_percent: f64,
+}
+
+impl Default for IntegrationConfig {
+    fn default() -> Self {
+        Self { fee_percent: 0.5 }
+    }
+}
+
+/// An integration as held in memory.
+///
+/// Integrations are never hard-deleted: archiving sets `deleted_at`, and
+/// restoring clears it again.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntegrationRecord {
+    pub id: String,
+    pub name: String,
+    pub authority: String,
+    pub config: IntegrationConfig,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+    pub deleted_at: Option<Timestamp>,
+}
+
+impl IntegrationRecord {
+    pub fn is_archived(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+}
+
+/// In-memory integration store shared through [`crate::AppState`].
+///
+/// Cloning is cheap; every clone points at the same underlying map.
+#[derive(Debug, Clone, Default)]
+pub struct IntegrationStore {
+    records: Arc<RwLock<HashMap<String, IntegrationRecord>>>,
+    next_id: Arc<AtomicU64>,
+}
+
+impl IntegrationStore {
+    /// Create a new integration with the default config and return it.
+    pub fn create(&self, name: String, authority: String) -> IntegrationRecord {
+        let id = format!("int_{}", self.next_id.fetch_add(1, Ordering::Relaxed) + 1);
+        let now = Timestamp::now();
+        let record = IntegrationRecord {
+            id: id.clone(),
+            name,
+            authority,
+            config: IntegrationConfig::default(),
+            created_at: now,
+            updated_at: now,
+            deleted_at: None,
+        };
+
+        self.records.write().unwrap().insert(id, record.clone());
+        record
+    }
+
+    pub fn get(&self, id: &str) -> Option<IntegrationRecord> {
+        self.records.read().unwrap().get(id).cloned()
+    }
+
+    /// All integrations owned by `authority`, oldest first. Archived
+    /// integrations are skipped unless `include_archived` is set.
+    pub fn list_by_authority(&self, authority: &str, include_archived: bool) -> Vec<IntegrationRecord> {
+        let mut records: Vec<IntegrationRecord> = self
+            .records
+            .read()
+            .unwrap()
+            .values()
+            .filter(|r| r.authority == authority && (include_archived || !r.is_archived()))
+            .cloned()
+            .collect();
+        records.sort_by(|a, b| a.created_at.cmp(&b.created_at).then_with(|| a.id.cmp(&b.id)));
+        records
+    }
+
+    /// Replace an integration's config, bumping `updated_at` when it changes.
+    pub fn update_config(&self, id: &str, config: IntegrationConfig) -> Option<IntegrationRecord> {
+        let mut records = self.records.write().unwrap();
+        let record = records.get_mut(id)?;
+        if record.config != config {
+            record.config = config;
+            record.updated_at = Timestamp::now();
+        }
+        Some(record.clone())
+    }
+
+    /// Soft-delete an integration. Archiving twice keeps the original `deleted_at`.
+    pub fn archive(&self, id: &str) -> Option<IntegrationRecord> {
+        let mut records = self.records.write().unwrap();
+        let record = records.get_mut(id)?;
+        if record.deleted_at.is_none() {
+            let now = Timestamp::now();
+            record.deleted_at = Some(now);
+            record.updated_at = now;
+        }
+        Some(record.clone())
+    }
+
+    /// Undo [`IntegrationStore::archive`].
+    pub fn restore(&self, id: &str) -> Option<IntegrationRecord> {
+        let mut records = self.records.write().unwrap();
+        let record = records.get_mut(id)?;
+        if record.deleted_at.take().is_some() {
+            record.updated_at = Timestamp::now();
+        }
+        Some(record.clone())
+    }
+
+    /// Every integration, archived ones included, ordered by id number.
+    pub fn export(&self) -> Vec<IntegrationRecord> {
+        let mut records: Vec<IntegrationRecord> = self.records.read().unwrap().values().cloned().collect();
+        records.sort_by_key(|record| id_number(&record.id));
+        records
+    }
+
+    /// Replace every integration with `records`, e.g. from a backup.
+    ///
+    /// Ids must look like `int_<n>` and be unique; new integrations are
+    /// numbered after the highest imported id, and never reuse an id handed
+    /// out before. Nothing changes on error.
+    pub fn import(&self, records: Vec<IntegrationRecord>) -> Result<usize, String> {
+        let mut imported = HashMap::with_capacity(records.len());
+        let mut highest = 0;
+        for record in records {
+            let Some(number) = id_number(&record.id) else {
+                return Err(format!("invalid integration id '{}'", record.id));
+            };
+            highest = highest.max(number);
+            if let Some(duplicate) = imported.insert(record.id.clone(), record) {
+                return Err(format!("duplicate integration id '{}'", duplicate.id));
+            }
+        }
+
+        let count = imported.len();
+        *self.records.write().unwrap() = imported;
+        self.next_id.fetch_max(highest, Ordering::Relaxed);
+        Ok(count)
+    }
+}
+
+/// The `<n>` of an `int_<n>` id.
+fn id_number(id: &str) -> Option<u64> {
+    id.strip_prefix("int_")?.parse().ok()
+}
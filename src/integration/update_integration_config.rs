@@ -1,11 +1,96 @@
-use axum::{response::IntoResponse, Json, extract::State};
+use axum::{response::IntoResponse, Json, extract::State, http::StatusCode};
 use serde::Deserialize;
 use serde_json::json;
+use crate::error::ApiError;
+use crate::integration::store::IntegrationConfig;
+use crate::validation::{clamp_param, ClampMode};
 use crate::AppState;
 
+/// Request body for `POST /integrations/config/update`.
+///
+/// ### Example
+/// ```json
+/// { "id": "int_1", "fee_percent": 0.25 }
+/// ```
 #[derive(Deserialize)]
-pub struct UpdateConfig { pub fee_percent: f64 }
+pub struct UpdateConfig {
+    pub id: String,
+    pub fee_percent: f64,
+}
+
+/// Handler for `POST /integrations/config/update`.
+///
+/// Stores the new config and bumps the integration's `updated_at`, so
+/// conditional fetches of `GET /integrations/config` see the change.
+/// `fee_percent` must be between 0 and 100; unknown integrations are a 404.
+pub async fn update_integration_config(
+    State(state): State<AppState>,
+    Json(payload): Json<UpdateConfig>,
+) -> Result<impl IntoResponse, ApiError> {
+    let fee_percent = clamp_param("fee_percent", payload.fee_percent, 0.0, 100.0, ClampMode::Reject)?;
+    let record = state
+        .integrations
+        .update_config(&payload.id, IntegrationConfig { fee_percent })
+        .ok_or_else(|| ApiError::NotFound("Integration not found".to_string()))?;
+    Ok((
+        StatusCode::OK,
+        Json(json!({"result": "config updated", "fee_percent": fee_percent, "config": state.cased(record.config)})),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::post;
+    use axum::Router;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    async fn update(state: &AppState, body: Value) -> (StatusCode, Value) {
+        let app = Router::new()
+            .route("/config/update", post(update_integration_config))
+            .with_state(state.clone());
+        let request = Request::post("/config/update")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_update_persists_and_bumps_updated_at() {
+        let state = AppState::default();
+        let created = state.integrations.create("Fees".into(), "auth_1".into());
+
+        let (status, json) = update(&state, json!({ "id": created.id, "fee_percent": 1.5 })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["config"]["fee_percent"], 1.5);
+        let stored = state.integrations.get(&created.id).unwrap();
+        assert_eq!(stored.config.fee_percent, 1.5);
+        assert!(stored.updated_at > created.updated_at);
+
+        // Writing the same config again is not a change
+        update(&state, json!({ "id": created.id, "fee_percent": 1.5 })).await;
+        assert_eq!(state.integrations.get(&created.id).unwrap().updated_at, stored.updated_at);
+    }
+
+    #[tokio::test]
+    async fn test_update_rejects_bad_fee_and_unknown_integration() {
+        let state = AppState::default();
+        let created = state.integrations.create("Fees".into(), "auth_1".into());
+
+        let (status, json) = update(&state, json!({ "id": created.id, "fee_percent": -1.0 })).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid request data: fee_percent must be between 0 and 100");
+        assert_eq!(state.integrations.get(&created.id).unwrap(), created);
 
-pub async fn update_integration_config(State(_state): State<AppState>, Json(payload): Json<UpdateConfig>) -> impl IntoResponse {
-    Json(json!({"result": "config updated", "fee_percent": payload.fee_percent}))
+        let (status, json) = update(&state, json!({ "id": "int_404", "fee_percent": 1.0 })).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(json["message"], "Integration not found");
+    }
 }
@@ -1,10 +1,85 @@
-use axum::{response::IntoResponse, Json};
+use axum::{response::IntoResponse, Json, extract::State};
 use serde::Deserialize;
 use serde_json::json;
+use crate::config::Config;
+use crate::error::ApiError;
+use crate::outbound::validate_outbound_url;
+use crate::validation::{sanitize_field, sanitize_name, validate_name, validate_symbol};
+use crate::AppState;
 
+/// Request body for `POST /integration/metadata/upload`.
+///
+/// `name` and `symbol` are optional, but validated like token initialization when present.
+/// Every field is trimmed; blank values and control characters are rejected with 422.
+/// `url` and `image_url` must be public http(s) URLs allowed by `REFLECT_OUTBOUND_URL_ALLOWLIST`.
 #[derive(Deserialize)]
-pub struct Metadata { pub url: String }
+pub struct Metadata {
+    pub url: String,
+    #[serde(default)]
+    pub image_url: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub symbol: Option<String>,
+}
+
+fn validate(payload: &Metadata, config: &Config) -> Result<(), ApiError> {
+    let url = sanitize_field("url", &payload.url)?;
+    validate_outbound_url("url", &url, &config.outbound_url_allowlist)?;
+    if let Some(image_url) = payload.image_url.as_deref() {
+        let image_url = sanitize_field("image_url", image_url)?;
+        validate_outbound_url("image_url", &image_url, &config.outbound_url_allowlist)?;
+    }
+    if let Some(symbol) = payload.symbol.as_deref() {
+        validate_symbol(&sanitize_field("symbol", symbol)?, &config.symbol_pattern)?;
+    }
+    if let Some(name) = payload.name.as_deref() {
+        validate_name(&sanitize_name(name)?)?;
+    }
+    Ok(())
+}
+
+pub async fn upload_integration_metadata(
+    State(state): State<AppState>,
+    Json(payload): Json<Metadata>,
+) -> Result<impl IntoResponse, ApiError> {
+    validate(&payload, &state.config)?;
+    Ok(Json(json!({"result": "metadata uploaded"})))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use axum::http::StatusCode;
+    use serde_json::Value;
+
+    async fn upload(url: &str, image_url: Option<&str>) -> (StatusCode, Value) {
+        let payload = Metadata {
+            url: url.to_string(),
+            image_url: image_url.map(String::from),
+            name: None,
+            symbol: None,
+        };
+        let response = upload_integration_metadata(State(AppState::default()), Json(payload))
+            .await
+            .into_response();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, 1024).await.unwrap();
+        (parts.status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_internal_urls_rejected() {
+        let (status, json) = upload("http://169.254.169.254/", None).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid request data: url must point to a public host");
+
+        let (status, json) = upload("https://example.com/meta.json", Some("file:///etc/passwd")).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid request data: image_url must use http or https");
 
-pub async fn upload_integration_metadata(Json(_payload): Json<Metadata>) -> impl IntoResponse {
-    Json(json!({"result": "metadata uploaded"}))
+        let (status, _) = upload("https://example.com/meta.json", Some("https://example.com/logo.png")).await;
+        assert_eq!(status, StatusCode::OK);
+    }
 }
@@ -0,0 +1,87 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+/// Whitelisted user addresses by integration id, shared through
+/// [`crate::AppState`].
+///
+/// Cloning is cheap; every clone points at the same underlying map.
+#[derive(Debug, Clone, Default)]
+pub struct WhitelistStore {
+    entries: Arc<RwLock<HashMap<String, HashSet<String>>>>,
+}
+
+impl WhitelistStore {
+    /// Add `users` to the integration's whitelist, ignoring addresses already
+    /// on it. Returns the whitelist's new size.
+    pub fn add(&self, integration_id: &str, users: impl IntoIterator<Item = String>) -> usize {
+        let mut entries = self.entries.write().unwrap();
+        let whitelist = entries.entry(integration_id.to_string()).or_default();
+        whitelist.extend(users);
+        whitelist.len()
+    }
+
+    /// The integration's whitelisted addresses, sorted.
+    pub fn get(&self, integration_id: &str) -> Vec<String> {
+        let entries = self.entries.read().unwrap();
+        let mut users: Vec<String> = entries
+            .get(integration_id)
+            .map(|whitelist| whitelist.iter().cloned().collect())
+            .unwrap_or_default();
+        users.sort();
+        users
+    }
+
+    /// Every non-empty whitelist, by integration id, each sorted.
+    pub fn export(&self) -> BTreeMap<String, Vec<String>> {
+        let entries = self.entries.read().unwrap();
+        entries
+            .iter()
+            .filter(|(_, whitelist)| !whitelist.is_empty())
+            .map(|(id, whitelist)| {
+                let mut users: Vec<String> = whitelist.iter().cloned().collect();
+                users.sort();
+                (id.clone(), users)
+            })
+            .collect()
+    }
+
+    /// Replace every whitelist with `whitelists`, e.g. from a backup.
+    pub fn replace(&self, whitelists: BTreeMap<String, Vec<String>>) {
+        *self.entries.write().unwrap() = whitelists
+            .into_iter()
+            .map(|(id, users)| (id, users.into_iter().collect()))
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn whitelists_are_per_integration_and_deduplicated() {
+        let store = WhitelistStore::default();
+        assert_eq!(store.add("int_1", ["b".to_string(), "a".to_string(), "a".to_string()]), 2);
+        assert_eq!(store.add("int_1", ["a".to_string(), "c".to_string()]), 3);
+        assert_eq!(store.add("int_2", ["a".to_string()]), 1);
+
+        assert_eq!(store.get("int_1"), vec!["a", "b", "c"]);
+        assert_eq!(store.get("int_2"), vec!["a"]);
+        assert!(store.get("int_3").is_empty());
+    }
+
+    #[test]
+    fn export_and_replace_round_trip() {
+        let store = WhitelistStore::default();
+        store.add("int_1", ["b".to_string(), "a".to_string()]);
+        store.add("int_2", []);
+        let exported = store.export();
+        assert_eq!(exported, BTreeMap::from([("int_1".to_string(), vec!["a".to_string(), "b".to_string()])]));
+
+        let other = WhitelistStore::default();
+        other.add("int_3", ["c".to_string()]);
+        other.replace(exported.clone());
+        assert_eq!(other.export(), exported);
+        assert!(other.get("int_3").is_empty());
+    }
+}
@@ -1,11 +1,151 @@
-use axum::{response::IntoResponse, Json, extract::State};
+use axum::{response::IntoResponse, Json, extract::{Query, State}, http::StatusCode};
 use serde::Deserialize;
 use serde_json::json;
+use crate::error::ApiError;
+use crate::validation::{ensure_batch_size, validate_pubkey};
 use crate::AppState;
 
+/// Request structure for `POST /integrations/whitelist`.
+///
+/// ### Example Request
+/// ```json
+/// {
+///   "integration_id": "int_1",
+///   "users": ["9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"]
+/// }
+/// ```
 #[derive(Deserialize)]
-pub struct Whitelist { pub users: Vec<String> }
+pub struct Whitelist {
+    pub integration_id: String,
+    pub users: Vec<String>,
+}
+
+/// Handler for `POST /integrations/whitelist`.
+///
+/// Adds base58 wallet addresses to an integration's whitelist. Addresses
+/// already on it are ignored, and `total` is the whitelist's size afterwards.
+/// Any invalid address rejects the whole request with `422`; unknown
+/// integrations are `404`.
+pub async fn whitelist_users(
+    State(state): State<AppState>,
+    Json(payload): Json<Whitelist>,
+) -> Result<impl IntoResponse, ApiError> {
+    ensure_batch_size(payload.users.len(), state.config.max_batch_size)?;
+    if state.integrations.get(&payload.integration_id).is_none() {
+        return Err(ApiError::NotFound("Integration not found".to_string()));
+    }
+    for (i, user) in payload.users.iter().enumerate() {
+        validate_pubkey(&format!("users[{}]", i), user)?;
+    }
+
+    let count = payload.users.len();
+    let total = state.whitelists.add(&payload.integration_id, payload.users);
+    Ok((StatusCode::OK, Json(json!({"result": "users whitelisted", "count": count, "total": total}))))
+}
+
+#[derive(Deserialize)]
+pub struct WhitelistQuery { pub integration_id: String }
+
+/// Handler for `GET /integrations/whitelist?integration_id=<id>`.
+///
+/// Returns the integration's whitelisted addresses, sorted. Unknown
+/// integrations are `404`.
+pub async fn get_whitelist(
+    State(state): State<AppState>,
+    Query(q): Query<WhitelistQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    if state.integrations.get(&q.integration_id).is_none() {
+        return Err(ApiError::NotFound("Integration not found".to_string()));
+    }
+    let users = state.whitelists.get(&q.integration_id);
+    Ok((StatusCode::OK, Json(json!({"success": true, "data": {"total": users.len(), "users": users}}))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use axum::body::to_bytes;
+    use serde_json::Value;
+
+    fn state(max_batch_size: usize) -> AppState {
+        let state = AppState::new(Config { max_batch_size, ..Config::default() });
+        state.integrations.create("Acme".into(), "auth_1".into());
+        state
+    }
+
+    fn address(i: u8) -> String {
+        bs58::encode([i + 1; 32]).into_string()
+    }
+
+    async fn call(state: AppState, users: Vec<String>) -> (StatusCode, Value) {
+        let payload = Whitelist { integration_id: "int_1".to_string(), users };
+        let response = whitelist_users(State(state), Json(payload)).await.into_response();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, 1024).await.unwrap();
+        (parts.status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    async fn read(state: AppState, integration_id: &str) -> (StatusCode, Value) {
+        let query = WhitelistQuery { integration_id: integration_id.to_string() };
+        let response = get_whitelist(State(state), Query(query)).await.into_response();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, 4096).await.unwrap();
+        (parts.status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_batch_size_boundary() {
+        let (status, json) = call(state(5), (0..5).map(address).collect()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["count"], 5);
+
+        let (status, json) = call(state(5), (0..6).map(address).collect()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["message"], "batch too large (max 5)");
+    }
+
+    #[tokio::test]
+    async fn test_whitelist_persists_and_deduplicates() {
+        let state = state(100);
+
+        let (status, json) = call(state.clone(), vec![address(0), address(1), address(0)]).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["count"], 3);
+        assert_eq!(json["total"], 2);
+
+        let (_, json) = call(state.clone(), vec![address(1), address(2)]).await;
+        assert_eq!(json["total"], 3);
+
+        let (status, json) = read(state, "int_1").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["total"], 3);
+        let mut expected = vec![address(0), address(1), address(2)];
+        expected.sort();
+        assert_eq!(json["data"]["users"], json!(expected));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_address_rejects_the_batch() {
+        let state = state(100);
+
+        let (status, json) = call(state.clone(), vec![address(0), "not-a-pubkey".to_string()]).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid request data: users[1] is not a valid public key");
+
+        let (_, json) = read(state, "int_1").await;
+        assert_eq!(json["data"]["total"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_integration() {
+        let state = state(100);
+        let payload = Whitelist { integration_id: "int_404".to_string(), users: vec![address(0)] };
+        let response = whitelist_users(State(state.clone()), Json(payload)).await.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
 
-pub async fn whitelist_users(State(_state): State<AppState>, Json(payload): Json<Whitelist>) -> impl IntoResponse {
-    Json(json!({"result": "users whitelisted", "count": payload.users.len()}))
+        let (status, json) = read(state, "int_404").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(json["message"], "Integration not found");
+    }
 }
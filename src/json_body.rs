@@ -0,0 +1,217 @@
+use axum::{
+    async_trait,
+    body::Bytes,
+    extract::{FromRequest, Request},
+    http::{header::CONTENT_TYPE, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::fmt;
+
+/// JSON request body extractor that reports *which* field is wrong.
+///
+/// Use in place of [`axum::Json`] on the request side. Failures are rejected
+/// with a [`JsonBodyError`] in the standard `{ success: false, message }`
+/// envelope, following the 400 / 422 policy of [`crate::error::ApiError`].
+#[derive(Debug)]
+pub struct JsonBody<T>(pub T);
+
+/// Why a JSON body was rejected.
+///
+/// ### Example Error Responses
+/// - `415`: `"Expected request with `Content-Type: application/json`"`
+/// - `400`: `"Invalid JSON body: EOF while parsing an object"`
+/// - `422`: `"field `signer` is required"`
+/// - `422`: `"field `signer` is invalid: not base58"`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonBodyError {
+    /// The `Content-Type` is not JSON.
+    ContentType,
+    /// The body is not well-formed JSON.
+    Syntax(String),
+    /// A required field is absent. `field` is the dotted path from the root.
+    Missing { field: String },
+    /// A field is present but has the wrong type or fails validation.
+    Invalid { field: String, reason: String },
+    /// The body could not be read.
+    Other(String),
+}
+
+impl JsonBodyError {
+    fn status(&self) -> StatusCode {
+        match self {
+            JsonBodyError::ContentType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            JsonBodyError::Syntax(_) | JsonBodyError::Other(_) => StatusCode::BAD_REQUEST,
+            JsonBodyError::Missing { .. } | JsonBodyError::Invalid { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+        }
+    }
+
+    fn from_serde(error: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        let path = error.path().to_string();
+        let inner = error.into_inner();
+        let message = strip_position(&inner);
+
+        if !inner.is_data() {
+            return JsonBodyError::Syntax(message);
+        }
+        if let Some(field) = message
+            .strip_prefix("missing field `")
+            .and_then(|rest| rest.strip_suffix('`'))
+        {
+            let field = if path == "." { field.to_string() } else { format!("{}.{}", path, field) };
+            return JsonBodyError::Missing { field };
+        }
+        JsonBodyError::Invalid { field: path, reason: message }
+    }
+}
+
+/// serde_json's message without the trailing " at line X column Y".
+fn strip_position(error: &serde_json::Error) -> String {
+    let message = error.to_string();
+    match message.rfind(" at line ") {
+        Some(at) => message[..at].to_string(),
+        None => message,
+    }
+}
+
+impl fmt::Display for JsonBodyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonBodyError::ContentType => {
+                write!(f, "Expected request with `Content-Type: application/json`")
+            }
+            JsonBodyError::Syntax(message) => write!(f, "Invalid JSON body: {}", message),
+            JsonBodyError::Missing { field } => write!(f, "field `{}` is required", field),
+            JsonBodyError::Invalid { field, reason } => write!(f, "field `{}` is invalid: {}", field, reason),
+            JsonBodyError::Other(message) => write!(f, "Invalid request body: {}", message),
+        }
+    }
+}
+
+impl IntoResponse for JsonBodyError {
+    fn into_response(self) -> Response {
+        (
+            self.status(),
+            Json(json!({ "success": false, "message": self.to_string() })),
+        )
+            .into_response()
+    }
+}
+
+/// `application/json` or any `application/*+json`, ignoring parameters such as `charset`.
+fn is_json(request: &Request) -> bool {
+    let Some(content_type) = request.headers().get(CONTENT_TYPE).and_then(|value| value.to_str().ok()) else {
+        return false;
+    };
+    let essence = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+    essence == "application/json" || (essence.starts_with("application/") && essence.ends_with("+json"))
+}
+
+#[async_trait]
+impl<T, S> FromRequest<S> for JsonBody<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = JsonBodyError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        if !is_json(&request) {
+            return Err(JsonBodyError::ContentType);
+        }
+        let bytes = Bytes::from_request(request, state)
+            .await
+            .map_err(|rejection| JsonBodyError::Other(rejection.body_text()))?;
+
+        let mut deserializer = serde_json::Deserializer::from_slice(&bytes);
+        let value = serde_path_to_error::deserialize(&mut deserializer).map_err(JsonBodyError::from_serde)?;
+        deserializer
+            .end()
+            .map_err(|error| JsonBodyError::Syntax(strip_position(&error)))?;
+        Ok(JsonBody(value))
+    }
+}
+
+/// Deserialize one already parsed JSON `value` into `T`, reporting failures
+/// the way [`JsonBody`] does. For batch endpoints that validate each item on
+/// its own, so one bad item does not reject the whole body.
+pub fn from_value<T: DeserializeOwned>(value: Value) -> Result<T, JsonBodyError> {
+    serde_path_to_error::deserialize(value).map_err(JsonBodyError::from_serde)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::routing::post;
+    use axum::Router;
+    use serde::Deserialize;
+    use tower::ServiceExt;
+
+    #[derive(Deserialize)]
+    struct Outer {
+        #[allow(dead_code)]
+        name: String,
+        #[allow(dead_code)]
+        inner: Inner,
+    }
+
+    #[derive(Deserialize)]
+    struct Inner {
+        #[allow(dead_code)]
+        amount: u64,
+    }
+
+    async fn handler(JsonBody(_): JsonBody<Outer>) -> &'static str {
+        "ok"
+    }
+
+    async fn send(content_type: &str, body: &'static str) -> (StatusCode, Value) {
+        let app = Router::new().route("/", post(handler));
+        let request = Request::post("/")
+            .header(CONTENT_TYPE, content_type)
+            .body(Body::from(body))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+    }
+
+    #[tokio::test]
+    async fn missing_and_invalid_fields() {
+        let (status, json) = send("application/json", r#"{"inner": {"amount": 1}}"#).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "field `name` is required");
+
+        let (status, json) = send("application/json", r#"{"name": "x", "inner": {}}"#).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "field `inner.amount` is required");
+
+        let (status, json) = send("application/json", r#"{"name": "x", "inner": {"amount": -1}}"#).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            json["message"],
+            "field `inner.amount` is invalid: invalid value: integer `-1`, expected u64"
+        );
+    }
+
+    #[tokio::test]
+    async fn syntax_and_content_type() {
+        let (status, json) = send("application/json", r#"{"name": "#).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["success"], false);
+        assert!(json["message"].as_str().unwrap().starts_with("Invalid JSON body: "));
+
+        let (status, _) = send("application/json", r#"{"name": "x", "inner": {"amount": 1}} trailing"#).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = send("text/plain", r#"{"name": "x", "inner": {"amount": 1}}"#).await;
+        assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+        let (status, _) = send("application/json; charset=utf-8", r#"{"name": "x", "inner": {"amount": 1}}"#).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+}
@@ -0,0 +1,503 @@
+use serde::ser::{
+    Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant, Serializer,
+};
+use std::borrow::Cow;
+use std::str::FromStr;
+
+/// Casing applied to the field names of response structs (see
+/// `REFLECT_JSON_CASE`).
+///
+/// `Preserve` keeps each endpoint's historical shape (e.g. `depositAmount` next
+/// to `base_usd_value_bps`), so existing clients keep working.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JsonCase {
+    #[default]
+    Preserve,
+    Camel,
+    Snake,
+}
+
+impl JsonCase {
+    /// `key` in this casing.
+    pub fn apply<'a>(self, key: &'a str) -> Cow<'a, str> {
+        match self {
+            JsonCase::Preserve => Cow::Borrowed(key),
+            JsonCase::Camel => to_camel(key),
+            JsonCase::Snake => to_snake(key),
+        }
+    }
+}
+
+impl FromStr for JsonCase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" => Ok(JsonCase::Preserve),
+            "camel" => Ok(JsonCase::Camel),
+            "snake" => Ok(JsonCase::Snake),
+            other => Err(format!(
+                "Unknown JSON case '{}'; expected one of preserve, camel, snake",
+                other
+            )),
+        }
+    }
+}
+
+fn to_camel(key: &str) -> Cow<'_, str> {
+    if !key.contains('_') {
+        return Cow::Borrowed(key);
+    }
+    let mut out = String::with_capacity(key.len());
+    let mut upper = false;
+    for c in key.chars() {
+        if c == '_' {
+            upper = !out.is_empty();
+        } else if upper {
+            out.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    Cow::Owned(out)
+}
+
+fn to_snake(key: &str) -> Cow<'_, str> {
+    if !key.chars().any(char::is_uppercase) {
+        return Cow::Borrowed(key);
+    }
+    let mut out = String::with_capacity(key.len() + 4);
+    for c in key.chars() {
+        if c.is_uppercase() {
+            if !out.is_empty() && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// A response struct serialized with its field names in `case`: a
+/// `#[serde(rename_all)]` picked at runtime rather than at compile time.
+///
+/// Only names declared on structs are renamed, nested structs included. Map
+/// keys are data (integration ids, per-day buckets) and are written as they
+/// are. Handlers wrap what they respond with through
+/// [`crate::AppState::cased`], e.g. `Json(state.cased(response))`.
+#[derive(Debug, Clone)]
+pub struct Cased<T> {
+    value: T,
+    case: JsonCase,
+}
+
+impl<T> Cased<T> {
+    pub fn new(value: T, case: JsonCase) -> Self {
+        Self { value, case }
+    }
+}
+
+impl<T: Serialize> Serialize for Cased<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        cased(&self.value, self.case).serialize(serializer)
+    }
+}
+
+/// `value`, serialized through [`CasedSerializer`].
+struct CasedRef<'a, T: ?Sized> {
+    value: &'a T,
+    case: JsonCase,
+}
+
+fn cased<T: ?Sized>(value: &T, case: JsonCase) -> CasedRef<'_, T> {
+    CasedRef { value, case }
+}
+
+impl<T: Serialize + ?Sized> Serialize for CasedRef<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.case {
+            JsonCase::Preserve => self.value.serialize(serializer),
+            case => self.value.serialize(CasedSerializer { inner: serializer, case }),
+        }
+    }
+}
+
+/// Serializer forwarding to `inner`, writing structs as maps with re-cased
+/// field names. Renamed names are not `'static`, which
+/// [`Serializer::serialize_struct`] needs, hence the map.
+struct CasedSerializer<S> {
+    inner: S,
+    case: JsonCase,
+}
+
+/// A compound value of `inner` whose elements are re-cased in turn.
+struct Compound<C> {
+    inner: C,
+    case: JsonCase,
+}
+
+impl<C> Compound<C> {
+    fn new(inner: C, case: JsonCase) -> Self {
+        Self { inner, case }
+    }
+}
+
+macro_rules! forward {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<S::Ok, S::Error> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<S: Serializer> Serializer for CasedSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Compound<S::SerializeSeq>;
+    type SerializeTuple = Compound<S::SerializeTuple>;
+    type SerializeTupleStruct = Compound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<S::SerializeTupleVariant>;
+    type SerializeMap = Compound<S::SerializeMap>;
+    type SerializeStruct = Compound<S::SerializeMap>;
+    type SerializeStructVariant = Compound<S::SerializeStructVariant>;
+
+    forward! {
+        serialize_bool(bool);
+        serialize_i8(i8);
+        serialize_i16(i16);
+        serialize_i32(i32);
+        serialize_i64(i64);
+        serialize_i128(i128);
+        serialize_u8(u8);
+        serialize_u16(u16);
+        serialize_u32(u32);
+        serialize_u64(u64);
+        serialize_u128(u128);
+        serialize_f32(f32);
+        serialize_f64(f64);
+        serialize_char(char);
+        serialize_str(&str);
+        serialize_bytes(&[u8]);
+        serialize_unit_struct(&'static str);
+    }
+
+    fn serialize_none(self) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_none()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_some(&cased(value, self.case))
+    }
+
+    fn serialize_unit(self) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_unit()
+    }
+
+    fn serialize_unit_variant(self, name: &'static str, index: u32, variant: &'static str) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_unit_variant(name, index, variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, name: &'static str, value: &T) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_newtype_struct(name, &cased(value, self.case))
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_newtype_variant(name, index, variant, &cased(value, self.case))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, S::Error> {
+        Ok(Compound::new(self.inner.serialize_seq(len)?, self.case))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, S::Error> {
+        Ok(Compound::new(self.inner.serialize_tuple(len)?, self.case))
+    }
+
+    fn serialize_tuple_struct(self, name: &'static str, len: usize) -> Result<Self::SerializeTupleStruct, S::Error> {
+        Ok(Compound::new(self.inner.serialize_tuple_struct(name, len)?, self.case))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, S::Error> {
+        Ok(Compound::new(self.inner.serialize_tuple_variant(name, index, variant, len)?, self.case))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, S::Error> {
+        Ok(Compound::new(self.inner.serialize_map(len)?, self.case))
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeStruct, S::Error> {
+        Ok(Compound::new(self.inner.serialize_map(Some(len))?, self.case))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, S::Error> {
+        Ok(Compound::new(self.inner.serialize_struct_variant(name, index, variant, len)?, self.case))
+    }
+}
+
+impl<C: SerializeSeq> SerializeSeq for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_element(&cased(value, self.case))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTuple> SerializeTuple for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_element(&cased(value, self.case))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTupleStruct> SerializeTupleStruct for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_field(&cased(value, self.case))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTupleVariant> SerializeTupleVariant for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_field(&cased(value, self.case))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+/// Map keys are passed through as they are; only values are re-cased.
+impl<C: SerializeMap> SerializeMap for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), C::Error> {
+        self.inner.serialize_key(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_value(&cased(value, self.case))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+/// A struct written as a map, keyed by its re-cased field names.
+impl<C: SerializeMap> SerializeStruct for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_entry(&*self.case.apply(key), &cased(value, self.case))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+/// Struct variant fields keep their names: the inner serializer needs them
+/// `'static`, and no response uses one.
+impl<C: SerializeStructVariant> SerializeStructVariant for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_field(key, &cased(value, self.case))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cluster::Cluster;
+    use crate::config::Config;
+    use crate::rpc::mock::MockTransport;
+    use crate::rpc::{RpcClient, RpcClients};
+    use crate::stablecoin::generate_burn_transaction::generate_burn_transaction;
+    use crate::AppState;
+    use axum::body::{to_bytes, Body};
+    use axum::http::{header::CONTENT_TYPE, Request, StatusCode};
+    use axum::routing::post;
+    use axum::Router;
+    use serde::Serialize;
+    use serde_json::{json, Value};
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    #[test]
+    fn key_conversion() {
+        assert_eq!(JsonCase::Camel.apply("base_usd_value_bps"), "baseUsdValueBps");
+        assert_eq!(JsonCase::Camel.apply("recentBlockhash"), "recentBlockhash");
+        assert_eq!(JsonCase::Snake.apply("lastValidBlockHeight"), "last_valid_block_height");
+        assert_eq!(JsonCase::Snake.apply("has_more"), "has_more");
+        assert_eq!(JsonCase::Preserve.apply("depositAmount"), "depositAmount");
+    }
+
+    async fn burn(case: JsonCase) -> (StatusCode, Value) {
+        let transport = MockTransport::new().with_blockhash("EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N", 3090);
+        let state = AppState {
+            rpc: RpcClients::default().with(RpcClient::new(Cluster::Mainnet, Arc::new(transport))),
+            ..AppState::new(Config { json_case: case, ..Config::default() })
+        };
+        let app = Router::new().route("/burn/tx", post(generate_burn_transaction)).with_state(state);
+
+        let body = json!({
+            "stablecoin_index": 0,
+            "deposit_amount": 1_000_000,
+            "signer": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+            "minimum_received": 999_000
+        });
+        let response = app
+            .oneshot(
+                Request::post("/burn/tx")
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn burn_response_in_each_mode() {
+        let blockhash = "EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N";
+
+        let (status, json) = burn(JsonCase::Preserve).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["recentBlockhash"], blockhash);
+
+        let (status, json) = burn(JsonCase::Camel).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["success"], true);
+        assert_eq!(json["data"]["recentBlockhash"], blockhash);
+
+        let (status, json) = burn(JsonCase::Snake).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["recent_blockhash"], blockhash);
+        assert!(json["data"].get("recentBlockhash").is_none());
+    }
+
+    async fn get(case: JsonCase, uri: &str) -> Value {
+        let app = crate::build_app(AppState::new(Config { json_case: case, ..Config::default() }));
+        let response = app.oneshot(Request::get(uri).body(Body::empty()).unwrap()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn read_responses_in_each_mode() {
+        let json = get(JsonCase::Preserve, "/stablecoins/exchange-rates").await;
+        assert_eq!(json["data"][0]["base_usd_value_bps"], 1016789908);
+        assert_eq!(json["meta"]["has_more"], false);
+
+        let json = get(JsonCase::Camel, "/stablecoins/exchange-rates").await;
+        assert_eq!(json["data"][0]["baseUsdValueBps"], 1016789908);
+        assert_eq!(json["data"][0]["receiptUsdValueBps"], 1016791576);
+        assert!(json["data"][0].get("base_usd_value_bps").is_none());
+        assert_eq!(json["meta"]["hasMore"], false);
+
+        let uri = "/stablecoins/stablecoin/0/max-mint?signer=9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+        let json = get(JsonCase::Snake, uri).await;
+        assert!(json["data"]["max_mintable"].is_u64());
+        assert!(json["data"].get("maxMintable").is_none());
+    }
+
+    #[derive(Serialize)]
+    struct Inner {
+        base_usd_value_bps: u32,
+    }
+
+    #[derive(Serialize)]
+    struct Outer {
+        #[serde(rename = "recentBlockhash")]
+        recent_blockhash: &'static str,
+        items: Vec<Inner>,
+        by_integration: BTreeMap<&'static str, Inner>,
+        maybe_inner: Option<Inner>,
+    }
+
+    #[test]
+    fn only_struct_fields_are_renamed() {
+        let value = Outer {
+            recent_blockhash: "abc",
+            items: vec![Inner { base_usd_value_bps: 1 }],
+            by_integration: BTreeMap::from([("int_1", Inner { base_usd_value_bps: 2 })]),
+            maybe_inner: Some(Inner { base_usd_value_bps: 3 }),
+        };
+
+        let camel = serde_json::to_value(Cased::new(&value, JsonCase::Camel)).unwrap();
+        assert_eq!(
+            camel,
+            json!({
+                "recentBlockhash": "abc",
+                "items": [{ "baseUsdValueBps": 1 }],
+                "byIntegration": { "int_1": { "baseUsdValueBps": 2 } },
+                "maybeInner": { "baseUsdValueBps": 3 }
+            })
+        );
+
+        let snake = serde_json::to_value(Cased::new(&value, JsonCase::Snake)).unwrap();
+        assert_eq!(snake["recent_blockhash"], "abc");
+        assert_eq!(snake["by_integration"]["int_1"]["base_usd_value_bps"], 2);
+
+        let preserved = serde_json::to_value(Cased::new(&value, JsonCase::Preserve)).unwrap();
+        assert_eq!(preserved, serde_json::to_value(&value).unwrap());
+    }
+}
@@ -0,0 +1,346 @@
+// src/lib.rs
+
+use axum::{
+    middleware,
+    routing::get,
+    Json, Router,
+};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use tower_http::normalize_path::NormalizePath;
+
+mod access_log;
+mod amount;
+mod auth;
+mod cache;
+#[cfg(test)]
+mod capture;
+mod cluster;
+pub mod config;
+mod cors;
+mod data_source;
+mod error;
+mod example;
+mod finite;
+pub mod http_metrics;
+mod json_body;
+mod json_case;
+mod load;
+mod options;
+mod outbound;
+mod pagination;
+mod path;
+mod query_limit;
+mod read_only;
+mod request_id;
+mod response_limit;
+mod rpc;
+#[cfg(test)]
+mod seed;
+mod span;
+mod timestamp;
+mod trace_context;
+mod upstream;
+mod validation;
+
+// Import module routers
+mod admin;
+mod health;
+mod stablecoin;
+mod integration;
+mod stats;
+mod events;
+
+use cache::TtlCache;
+use config::Config;
+use http_metrics::HttpMetrics;
+use events::store::{EventRecord, EventStore, EventVolume};
+use integration::api_keys::ApiKeyStore;
+use integration::creation_limits::CreationLimits;
+use integration::store::IntegrationStore;
+use integration::whitelist::WhitelistStore;
+use json_case::Cased;
+use load::RequestLoad;
+use stablecoin::apy::ApyStore;
+use stablecoin::get_network_info::NetworkInfoCache;
+use stablecoin::mint_limits::{MintCooldowns, MintLimits};
+use stablecoin::rates::ExchangeRateStore;
+use stablecoin::registry::StablecoinRegistry;
+use stablecoin::supply::SupplyStore;
+use stats::aggregates::StatsAggregates;
+use stats::ledger::VolumeLedger;
+use timestamp::Timestamp;
+use upstream::Upstream;
+use rpc::{failover::FailoverTransport, http::HttpTransport, RpcClient, RpcClients, RpcTransport};
+
+/// Global application state shared across routes.
+///
+/// Add database pools, API clients, configuration, etc. here.
+#[derive(Clone, Default)]
+pub struct AppState {
+    pub config: Arc<Config>,
+    pub integrations: IntegrationStore,
+    pub api_keys: ApiKeyStore,
+    pub whitelists: WhitelistStore,
+    pub creation_limits: CreationLimits,
+    pub events: EventStore,
+    pub aggregates: StatsAggregates,
+    pub ledger: VolumeLedger,
+    pub rpc: RpcClients,
+    pub upstream: Option<Upstream>,
+    pub stablecoins: StablecoinRegistry,
+    pub rates: ExchangeRateStore,
+    pub apys: ApyStore,
+    pub supplies: SupplyStore,
+    pub mint_limits: MintLimits,
+    pub mint_cooldowns: MintCooldowns,
+    pub stats_cache: TtlCache<serde_json::Value>,
+    pub network_cache: NetworkInfoCache,
+    pub load: RequestLoad,
+    pub http_metrics: HttpMetrics,
+}
+
+impl AppState {
+    pub fn new(config: Config) -> Self {
+        let http = config.http_client();
+        let rpc = cluster::Cluster::ALL
+            .into_iter()
+            .filter_map(|cluster| {
+                let url = config.rpc_url(cluster)?;
+                let primary: Arc<dyn RpcTransport> = Arc::new(HttpTransport::new(http.clone(), url));
+                let Some(fallback) = config.rpc_fallback_url(cluster) else {
+                    return Some(RpcClient::new(cluster, primary));
+                };
+                let fallback: Arc<dyn RpcTransport> = Arc::new(HttpTransport::new(http.clone(), fallback));
+                let failover = FailoverTransport::new(
+                    primary,
+                    fallback,
+                    config.rpc_retries,
+                    Duration::from_secs(config.rpc_reprobe_secs),
+                );
+                Some(RpcClient::with_failover(cluster, failover))
+            })
+            .fold(RpcClients::default(), RpcClients::with);
+
+        let upstream = config.upstream_url.as_deref().map(|url| Upstream::new(http.clone(), url));
+
+        Self {
+            stats_cache: TtlCache::new(Duration::from_secs(config.cache_ttl_secs)),
+            load: RequestLoad::new(&config),
+            config: Arc::new(config),
+            integrations: IntegrationStore::default(),
+            api_keys: ApiKeyStore::default(),
+            whitelists: WhitelistStore::default(),
+            creation_limits: CreationLimits::default(),
+            events: EventStore::default(),
+            aggregates: StatsAggregates::default(),
+            ledger: VolumeLedger::default(),
+            rpc,
+            upstream,
+            stablecoins: StablecoinRegistry::default(),
+            rates: ExchangeRateStore::default(),
+            apys: ApyStore::default(),
+            supplies: SupplyStore::default(),
+            mint_limits: MintLimits::default(),
+            mint_cooldowns: MintCooldowns::default(),
+            network_cache: NetworkInfoCache::default(),
+            http_metrics: HttpMetrics::default(),
+        }
+    }
+
+    /// `value` serialized with its field names in the configured
+    /// `REFLECT_JSON_CASE`; every handler wraps the structs it responds with.
+    pub(crate) fn cased<T>(&self, value: T) -> Cased<T> {
+        Cased::new(value, self.config.json_case)
+    }
+
+    /// Record an event and count it towards the running stats aggregates
+    /// and, when it moved an amount, the volume ledger.
+    pub fn record_event(
+        &self,
+        event_type: &str,
+        signer: &str,
+        integration_id: Option<&str>,
+        volume: Option<EventVolume>,
+        timestamp: Timestamp,
+    ) -> EventRecord {
+        self.aggregates.record(|| {
+            let event = self.events.record(event_type, signer, integration_id, volume, timestamp);
+            self.ledger.apply(&event);
+            event
+        })
+    }
+}
+
+/// Assemble the full application: every route namespace, the middleware
+/// stack and the shared state.
+///
+/// Routes are canonically written without a trailing slash; a trailing slash
+/// on any request path is stripped before routing, so `/stablecoins/` and
+/// `/stablecoins` reach the same handler.
+///
+/// `main` serves this router; integration tests drive it with `oneshot`.
+pub fn build_app(state: AppState) -> Router {
+    let routes = Router::new()
+        // Grouped route namespaces
+        .nest("/health", health::router())
+        .nest("/stablecoins", stablecoin::router())
+        .nest("/integrations", integration::router(state.clone()))
+        .nest("/stats", stats::router())
+        .nest("/events", events::router())
+        .nest("/admin", admin::router())
+
+        // Prometheus scrape endpoint
+        .route(http_metrics::METRICS_ROUTE, get(http_metrics::render_metrics))
+
+        // Root endpoint
+        .route(
+            "/",
+            get(|| async {
+                Json(json!({
+                    "status": "reflect api running"
+                }))
+            }),
+        );
+
+    let app = routes
+        .clone()
+        // Refuse to ship JSON bodies over `REFLECT_MAX_RESPONSE_BYTES`
+        .layer(middleware::from_fn_with_state(state.clone(), response_limit::reject_large_responses))
+
+        // Serialize timestamps per `?timePrecision=seconds|millis`
+        .layer(middleware::from_fn(timestamp::apply_time_precision))
+
+        // Echo `X-Data-Source` into the body for `?debugSource=true`
+        .layer(middleware::from_fn(data_source::debug_source))
+
+        // Block mutations while in read-only mode
+        .layer(middleware::from_fn_with_state(state.clone(), read_only::reject_mutations))
+
+        // Cap concurrent requests, queueing and then shedding the excess
+        .layer(middleware::from_fn_with_state(state.clone(), load::limit_concurrency))
+
+        // Reject abusive query strings before anything parses them
+        .layer(middleware::from_fn_with_state(state.clone(), query_limit::reject_long_queries))
+
+        // One access log line per request, sampled per `REFLECT_LOG_SAMPLING`
+        .layer(middleware::from_fn_with_state(
+            Arc::new(access_log::AccessLog::new(&state.config.log_sampling)),
+            access_log::log_requests,
+        ))
+
+        // Count requests and errors and time handlers for `GET /metrics`
+        .layer(middleware::from_fn_with_state(state.clone(), http_metrics::record_requests))
+
+        // Continue the caller's W3C trace (or start one) for logs and outbound calls
+        .layer(middleware::from_fn(trace_context::propagate_trace))
+
+        // Tag every request (and its logs) with an `X-Request-Id`
+        .layer(middleware::from_fn(request_id::assign_request_id))
+
+        // Answer CORS preflights per `REFLECT_CORS_*`
+        .layer(cors::cors_layer(&state.config))
+
+        // Answer other `OPTIONS` requests with the route's `Allow` list
+        .layer(middleware::from_fn_with_state(routes.with_state(state.clone()), options::answer_options))
+
+        // Attach shared state
+        .with_state(state);
+
+    // Path normalization has to happen before routing, so it wraps the whole
+    // router instead of being added as a layer
+    Router::new().fallback_service(NormalizePath::trim_trailing_slash(app))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn build_app_answers_health() {
+        let response = build_app(AppState::default())
+            .oneshot(Request::get("/health").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        // Layers are part of the assembled app, not bolted on in `main`
+        assert!(response.headers().contains_key("x-request-id"));
+    }
+
+    #[tokio::test]
+    async fn trailing_slash_is_ignored() {
+        for uri in ["/stablecoins", "/stablecoins/", "/health", "/health/", "/stats/protocol/"] {
+            let response = build_app(AppState::default())
+                .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK, "{}", uri);
+        }
+    }
+
+    #[tokio::test]
+    async fn hung_primary_rpc_times_out_and_fails_over() {
+        // Accepts connections but never answers
+        let hung = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let hung_addr = hung.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Ok((socket, _)) = hung.accept().await {
+                held.push(socket);
+            }
+        });
+
+        let fallback = axum::Router::new().route(
+            "/",
+            axum::routing::post(|| async { axum::Json(serde_json::json!({ "jsonrpc": "2.0", "id": 1, "result": 42 })) }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let fallback_addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, fallback).await.unwrap() });
+
+        let state = AppState::new(Config {
+            rpc_url_devnet: Some(format!("http://{}", hung_addr)),
+            rpc_fallback_url_devnet: Some(format!("http://{}", fallback_addr)),
+            rpc_retries: 0,
+            http_timeout_secs: 1,
+            ..Config::default()
+        });
+        let client = state.rpc.get(cluster::Cluster::Devnet).unwrap();
+        let slot = tokio::time::timeout(Duration::from_secs(5), client.get_slot(rpc::Commitment::default())).await;
+        assert_eq!(slot.expect("the RPC call should time out, not hang").unwrap(), 42);
+    }
+
+    #[test]
+    fn zero_timeouts_are_rejected() {
+        assert!(Config { http_timeout_secs: 0, ..Config::default() }.validate().is_err());
+        assert!(Config { http_connect_timeout_secs: 0, ..Config::default() }.validate().is_err());
+        assert!(Config::default().validate().is_ok());
+    }
+
+    #[test]
+    fn fees_above_100_percent_are_rejected() {
+        assert!(Config { protocol_fee_bps: 10_001, ..Config::default() }.validate().is_err());
+        assert!(Config { integration_fee_bps_devnet: Some(10_001), ..Config::default() }.validate().is_err());
+
+        // Each fee fits, but not together
+        let combined = Config { protocol_fee_bps: 6_000, integration_fee_bps: 4_001, ..Config::default() };
+        assert!(combined.validate().is_err());
+        let mainnet = Config { protocol_fee_bps: 6_000, integration_fee_bps_mainnet: Some(4_001), ..Config::default() };
+        assert!(mainnet.validate().is_err());
+
+        let full = Config { protocol_fee_bps: 6_000, integration_fee_bps: 4_000, ..Config::default() };
+        assert!(full.validate().is_ok());
+    }
+
+    #[test]
+    fn malformed_program_id_is_rejected() {
+        let config = |program_id: &str| Config { program_id: Some(program_id.to_string()), ..Config::default() };
+        assert!(config("not-a-program").validate().is_err());
+        assert!(config("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").validate().is_ok());
+    }
+}
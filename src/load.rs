@@ -0,0 +1,192 @@
+use axum::{
+    extract::{Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+use crate::config::Config;
+use crate::AppState;
+
+/// Concurrency limit and queue shared through [`crate::AppState`].
+///
+/// At most `REFLECT_MAX_CONCURRENT_REQUESTS` requests run at once; the rest
+/// wait in a queue of up to `REFLECT_MAX_QUEUED_REQUESTS`. Both figures are
+/// exported as gauges and reported by `GET /health/ready`.
+///
+/// Cheap to clone; clones share the same counters.
+#[derive(Debug, Clone)]
+pub struct RequestLoad {
+    permits: Arc<Semaphore>,
+    in_flight: Arc<AtomicUsize>,
+    queued: Arc<AtomicUsize>,
+    max_concurrent: usize,
+    max_queued: usize,
+}
+
+impl Default for RequestLoad {
+    fn default() -> Self {
+        Self::new(&Config::default())
+    }
+}
+
+/// Current load, as reported by `GET /health/ready`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LoadSnapshot {
+    pub in_flight: usize,
+    pub queued: usize,
+    pub max_concurrent: usize,
+    pub max_queued: usize,
+}
+
+impl LoadSnapshot {
+    /// New requests are being turned away: the queue is full or, with no
+    /// queue configured, every slot is taken.
+    pub fn saturated(&self) -> bool {
+        if self.max_queued == 0 {
+            self.in_flight >= self.max_concurrent
+        } else {
+            self.queued >= self.max_queued
+        }
+    }
+}
+
+impl RequestLoad {
+    pub fn new(config: &Config) -> Self {
+        let max_concurrent = config.max_concurrent_requests.clamp(1, Semaphore::MAX_PERMITS);
+        Self {
+            permits: Arc::new(Semaphore::new(max_concurrent)),
+            in_flight: Arc::default(),
+            queued: Arc::default(),
+            max_concurrent,
+            max_queued: config.max_queued_requests,
+        }
+    }
+
+    pub fn snapshot(&self) -> LoadSnapshot {
+        LoadSnapshot {
+            in_flight: self.in_flight.load(Ordering::Relaxed),
+            queued: self.queued.load(Ordering::Relaxed),
+            max_concurrent: self.max_concurrent,
+            max_queued: self.max_queued,
+        }
+    }
+
+    fn publish(&self) {
+        metrics::gauge!("reflect_requests_in_flight").set(self.in_flight.load(Ordering::Relaxed) as f64);
+        metrics::gauge!("reflect_request_queue_depth").set(self.queued.load(Ordering::Relaxed) as f64);
+    }
+}
+
+/// Decrements `counter` (and republishes the gauges) when dropped, so
+/// cancelled requests are not counted forever.
+struct Tracked<'a> {
+    load: &'a RequestLoad,
+    counter: &'a AtomicUsize,
+}
+
+impl<'a> Tracked<'a> {
+    fn new(load: &'a RequestLoad, counter: &'a AtomicUsize) -> Self {
+        counter.fetch_add(1, Ordering::Relaxed);
+        load.publish();
+        Self { load, counter }
+    }
+
+    /// Like [`Tracked::new`], unless `counter` is already at `max`. The check
+    /// and the increment are one atomic step, so racing requests cannot
+    /// overshoot `max`.
+    fn try_new(load: &'a RequestLoad, counter: &'a AtomicUsize, max: usize) -> Option<Self> {
+        counter
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |count| (count < max).then_some(count + 1))
+            .ok()?;
+        load.publish();
+        Some(Self { load, counter })
+    }
+}
+
+impl Drop for Tracked<'_> {
+    fn drop(&mut self) {
+        self.counter.fetch_sub(1, Ordering::Relaxed);
+        self.load.publish();
+    }
+}
+
+/// Middleware enforcing the concurrency limit.
+///
+/// Requests over the limit wait for a slot; once the queue is full they are
+/// rejected straight away. Health checks bypass the limit so load balancers
+/// can still see the server's state while it is saturated.
+///
+/// ### Error Response (HTTP 503)
+/// ```json
+/// {
+///   "success": false,
+///   "message": "server is overloaded, retry later"
+/// }
+/// ```
+pub async fn limit_concurrency(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    if request.uri().path().starts_with("/health") {
+        return next.run(request).await;
+    }
+    let load = &state.load;
+
+    let permit = match load.permits.clone().try_acquire_owned() {
+        Ok(permit) => permit,
+        Err(_) => {
+            let Some(_queued) = Tracked::try_new(load, &load.queued, load.max_queued) else {
+                tracing::warn!(queued = load.max_queued, "Request queue full, rejecting request");
+                return (
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    Json(json!({ "success": false, "message": "server is overloaded, retry later" })),
+                )
+                    .into_response();
+            };
+            load.permits
+                .clone()
+                .acquire_owned()
+                .await
+                .expect("request semaphore is never closed")
+        }
+    };
+
+    let _in_flight = Tracked::new(load, &load.in_flight);
+    let response = next.run(request).await;
+    drop(permit);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(in_flight: usize, queued: usize, max_queued: usize) -> LoadSnapshot {
+        LoadSnapshot { in_flight, queued, max_concurrent: 2, max_queued }
+    }
+
+    #[test]
+    fn saturation_without_a_queue_follows_in_flight() {
+        assert!(!snapshot(0, 0, 0).saturated());
+        assert!(!snapshot(1, 0, 0).saturated());
+        assert!(snapshot(2, 0, 0).saturated());
+
+        assert!(!snapshot(2, 0, 1).saturated());
+        assert!(snapshot(2, 1, 1).saturated());
+    }
+
+    #[test]
+    fn queue_admission_never_overshoots() {
+        let load = RequestLoad::new(&Config { max_queued_requests: 3, ..Config::default() });
+        let admitted: Vec<_> = (0..5).filter_map(|_| Tracked::try_new(&load, &load.queued, 3)).collect();
+        assert_eq!(admitted.len(), 3);
+        assert_eq!(load.snapshot().queued, 3);
+        drop(admitted);
+        assert_eq!(load.snapshot().queued, 0);
+        assert!(Tracked::try_new(&load, &load.queued, 0).is_none());
+    }
+}
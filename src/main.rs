@@ -1,55 +1,39 @@
 // src/main.rs
 
-use axum::{
-    routing::get,
-    Json, Router,
-};
-use serde_json::json;
 use std::net::SocketAddr;
 use tokio::net::TcpListener;
-use tracing_subscriber;
 
-// Import module routers
-mod health;
-mod stablecoin;
-mod integration;
-mod stats;
-mod events;
-
-/// Global application state shared across routes.
-///
-/// Add database pools, API clients, configuration, etc. here.
-#[derive(Clone)]
-pub struct AppState {}
+use reflect_api_rs::{build_app, config::Config, http_metrics, AppState};
 
 #[tokio::main]
 async fn main() {
     // Initialize tracing/logging
     tracing_subscriber::fmt::init();
 
-    let state = AppState {};
-
-    // Build the main router
-    let app = Router::new()
-        // Grouped route namespaces
-        .nest("/health", health::router())
-        .nest("/stablecoins", stablecoin::router())
-        .nest("/integrations", integration::router())
-        .nest("/stats", stats::router())
-        .nest("/events", events::router())
-
-        // Root endpoint
-        .route(
-            "/",
-            get(|| async {
-                Json(json!({
-                    "status": "reflect api running"
-                }))
-            }),
-        )
-
-        // Attach shared state
-        .with_state(state);
+    let config = Config::from_env();
+    if let Err(message) = config.validate() {
+        tracing::error!("Invalid configuration: {}", message);
+        std::process::exit(1);
+    }
+
+    let state = AppState::new(config);
+
+    // Route the facade metrics (RPC calls, load) into the `/metrics` registry too
+    if state.http_metrics.install().is_err() {
+        tracing::warn!("A metrics recorder is already installed; /metrics shows HTTP series only");
+    }
+
+    // Drain histogram samples into buckets between scrapes
+    let metrics = state.http_metrics.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(http_metrics::UPKEEP_INTERVAL);
+        loop {
+            interval.tick().await;
+            metrics.run_upkeep();
+        }
+    });
+
+    let app = build_app(state);
 
     // Bind to 0.0.0.0:3000
     let addr: SocketAddr = SocketAddr::from(([0, 0, 0, 0], 3000));
@@ -0,0 +1,97 @@
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::{header::ALLOW, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Router,
+};
+use tower::ServiceExt;
+
+/// Request header marking an `OPTIONS` request as a CORS preflight.
+const PREFLIGHT_HEADER: &str = "access-control-request-method";
+
+/// Middleware answering plain `OPTIONS` requests with the methods the route accepts.
+///
+/// `routes` is the bare router, without middleware. No route registers
+/// `OPTIONS`, so it answers with a `405` whose `Allow` header lists the
+/// route's methods; that list (plus `OPTIONS`) is returned in an empty `204`,
+/// so it is generated from the router rather than maintained by hand.
+/// Unknown paths get the router's `404`. CORS preflights are passed on to
+/// the CORS layer, which answers them itself.
+pub async fn answer_options(State(routes): State<Router>, request: Request, next: Next) -> Response {
+    if request.method() != Method::OPTIONS || request.headers().contains_key(PREFLIGHT_HEADER) {
+        return next.run(request).await;
+    }
+
+    let probe = Request::options(request.uri().clone()).body(Body::empty()).expect("probe request is valid");
+    let response = routes.oneshot(probe).await.unwrap_or_else(|never| match never {});
+    if response.status() != StatusCode::METHOD_NOT_ALLOWED {
+        return response;
+    }
+    let allow = match response.headers().get(ALLOW).and_then(|value| value.to_str().ok()) {
+        Some(methods) if !methods.is_empty() => format!("{},OPTIONS", methods),
+        _ => "OPTIONS".to_string(),
+    };
+    let allow = HeaderValue::from_str(&allow).expect("method names are header-safe");
+
+    (StatusCode::NO_CONTENT, [(ALLOW, allow)]).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{header::ALLOW, Request, StatusCode};
+    use tower::ServiceExt;
+
+    use crate::{build_app, AppState};
+
+    async fn options(uri: &str) -> (StatusCode, Option<String>) {
+        let response = build_app(AppState::default())
+            .oneshot(Request::options(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let allow = response.headers().get(ALLOW).map(|value| value.to_str().unwrap().to_string());
+        (response.status(), allow)
+    }
+
+    #[tokio::test]
+    async fn options_lists_allowed_methods() {
+        let (status, allow) = options("/stablecoins/quote/mint").await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert_eq!(allow.as_deref(), Some("POST,OPTIONS"));
+
+        let (status, allow) = options("/stablecoins").await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert_eq!(allow.as_deref(), Some("GET,HEAD,OPTIONS"));
+
+        let (status, _) = options("/no/such/route").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn wrong_method_still_405_with_allow() {
+        let response = build_app(AppState::default())
+            .oneshot(Request::delete("/stablecoins/quote/mint").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(response.headers()[ALLOW], "POST");
+    }
+
+    #[tokio::test]
+    async fn cors_preflight_is_untouched() {
+        let response = build_app(AppState::default())
+            .oneshot(
+                Request::options("/stablecoins/quote/mint")
+                    .header("origin", "https://example.com")
+                    .header("access-control-request-method", "POST")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().contains_key("access-control-allow-methods"));
+    }
+}
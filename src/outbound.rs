@@ -0,0 +1,142 @@
+use reqwest::Url;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::error::ApiError;
+
+/// Check a client-supplied URL before the server might fetch it (SSRF guard).
+///
+/// Only `http` and `https` are accepted, and the host may not be loopback, a
+/// private or link-local range (e.g. `169.254.169.254`, the cloud metadata
+/// endpoint) or any other non-public address. When `allowlist` is non-empty
+/// (see `REFLECT_OUTBOUND_URL_ALLOWLIST`) the host must also equal one of its
+/// entries or be a subdomain of one.
+///
+/// Hostnames are checked as written; whatever performs the fetch must still
+/// refuse connections to non-public addresses after DNS resolution.
+pub fn validate_outbound_url(field: &str, raw: &str, allowlist: &[String]) -> Result<Url, ApiError> {
+    let invalid = |reason: &str| {
+        Err(ApiError::Unprocessable(format!("Invalid request data: {} {}", field, reason)))
+    };
+
+    let Ok(url) = Url::parse(raw.trim()) else {
+        return invalid("is not a valid URL");
+    };
+    if !matches!(url.scheme(), "http" | "https") {
+        return invalid("must use http or https");
+    }
+    let Some(host) = url.host_str() else {
+        return invalid("must include a host");
+    };
+
+    let host = host.trim_start_matches('[').trim_end_matches(']').trim_end_matches('.').to_ascii_lowercase();
+    let public = match host.parse::<IpAddr>() {
+        Ok(ip) => is_public(ip),
+        Err(_) => host != "localhost" && !host.ends_with(".localhost"),
+    };
+    if !public {
+        return invalid("must point to a public host");
+    }
+
+    let allowed = allowlist.is_empty()
+        || allowlist.iter().any(|entry| {
+            let entry = entry.trim_end_matches('.').to_ascii_lowercase();
+            host == entry || host.ends_with(&format!(".{}", entry))
+        });
+    if !allowed {
+        return invalid("host is not on the allowlist");
+    }
+    Ok(url)
+}
+
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_public_v4(mapped),
+            None => is_public_v6(ip),
+        },
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // 0.0.0.0/8, 100.64.0.0/10 (carrier-grade NAT), 198.18.0.0/15 (benchmarking), 240.0.0.0/4
+        || a == 0
+        || (a == 100 && (64..128).contains(&b))
+        || (a == 198 && (18..20).contains(&b))
+        || a >= 240)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // fc00::/7 unique local, fe80::/10 link-local
+        || (first & 0xfe00) == 0xfc00
+        || (first & 0xffc0) == 0xfe80)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(raw: &str) -> Result<Url, String> {
+        validate_outbound_url("url", raw, &[]).map_err(|error| error.message().to_string())
+    }
+
+    #[test]
+    fn rejects_internal_targets_and_other_schemes() {
+        assert_eq!(
+            check("http://169.254.169.254/").unwrap_err(),
+            "Invalid request data: url must point to a public host"
+        );
+        assert_eq!(
+            check("file:///etc/passwd").unwrap_err(),
+            "Invalid request data: url must use http or https"
+        );
+        for internal in [
+            "http://127.0.0.1:8080/admin",
+            "http://localhost/",
+            "http://10.0.0.5/",
+            "http://192.168.1.1/",
+            "http://172.16.0.1/",
+            "http://0.0.0.0/",
+            "http://[::1]/",
+            "http://[::ffff:127.0.0.1]/",
+            "http://[fe80::1]/",
+            "http://[fd00::1]/",
+            "http://100.64.0.1/",
+        ] {
+            assert!(check(internal).is_err(), "{}", internal);
+        }
+        assert!(check("gopher://example.com/").is_err());
+        assert!(check("not a url").is_err());
+    }
+
+    #[test]
+    fn accepts_public_hosts_and_applies_allowlist() {
+        assert!(check("https://example.com/token.json").is_ok());
+        assert!(check("http://8.8.8.8/").is_ok());
+
+        let allowlist = vec!["arweave.net".to_string()];
+        assert!(validate_outbound_url("url", "https://arweave.net/abc", &allowlist).is_ok());
+        assert!(validate_outbound_url("url", "https://gw.arweave.net/abc", &allowlist).is_ok());
+        assert_eq!(
+            validate_outbound_url("image_url", "https://evilarweave.net/abc", &allowlist)
+                .unwrap_err()
+                .message(),
+            "Invalid request data: image_url host is not on the allowlist"
+        );
+        // The allowlist never re-admits internal addresses
+        let internal = vec!["169.254.169.254".to_string()];
+        assert!(validate_outbound_url("url", "http://169.254.169.254/", &internal).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::ApiError;
+use crate::validation::{clamp_param, parse_int, ClampMode};
+
+/// Page size used when a list endpoint is called without `limit`.
+pub const DEFAULT_LIMIT: usize = 100;
+
+/// Largest page size any list endpoint will return.
+pub const MAX_LIMIT: usize = 1000;
+
+/// Standard `limit` / `offset` query parameters for list endpoints, as sent.
+///
+/// Kept as strings so malformed or overflowing values can be reported in the
+/// standard envelope; see [`PageParams::parse`].
+///
+/// ### Example
+/// - `?limit=50&offset=100`
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct PageParams {
+    pub limit: Option<String>,
+    pub offset: Option<String>,
+}
+
+impl PageParams {
+    /// Parse both parameters, clamping `limit` to `1..=MAX_LIMIT`.
+    pub fn parse(&self) -> Result<PageQuery, ApiError> {
+        let limit = match parse_int("limit", self.limit.as_deref())? {
+            Some(limit) => Some(clamp_param("limit", limit, 1, MAX_LIMIT, ClampMode::Clamp)?),
+            None => None,
+        };
+        Ok(PageQuery {
+            limit,
+            offset: parse_int("offset", self.offset.as_deref())?,
+        })
+    }
+}
+
+/// Opt-in `?emptyIsError=true` for filtered list endpoints.
+///
+/// By default an empty result is `200` with `data: []`; with the flag set it
+/// is a `404` in the standard envelope instead.
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct EmptyParams {
+    #[serde(rename = "emptyIsError", default)]
+    pub empty_is_error: bool,
+}
+
+impl EmptyParams {
+    /// Reject an empty result with `"No <what> found"` when the client asked to.
+    pub fn check<T>(&self, items: &[T], what: &str) -> Result<(), ApiError> {
+        if self.empty_is_error && items.is_empty() {
+            return Err(ApiError::NotFound(format!("No {} found", what)));
+        }
+        Ok(())
+    }
+}
+
+/// Parsed `limit` / `offset` for [`paginate`].
+#[derive(Debug, Default, Clone)]
+pub struct PageQuery {
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+/// One page of a larger result set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: usize,
+    pub limit: usize,
+    pub offset: usize,
+    pub has_more: bool,
+}
+
+/// Paging information returned as `meta` next to `data` by every list endpoint.
+///
+/// ### Example
+/// ```json
+/// { "total": 250, "limit": 100, "offset": 200, "has_more": false }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PageMeta {
+    pub total: usize,
+    pub limit: usize,
+    pub offset: usize,
+    pub has_more: bool,
+}
+
+impl<T> Page<T> {
+    pub fn meta(&self) -> PageMeta {
+        PageMeta {
+            total: self.total,
+            limit: self.limit,
+            offset: self.offset,
+            has_more: self.has_more,
+        }
+    }
+}
+
+/// Slice `items` according to `query` (already bounded by [`PageParams::parse`]).
+pub fn paginate<T>(items: Vec<T>, query: &PageQuery) -> Page<T> {
+    let total = items.len();
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT);
+    let offset = query.offset.unwrap_or(0);
+
+    let items: Vec<T> = items.into_iter().skip(offset).take(limit).collect();
+    let has_more = offset.saturating_add(items.len()) < total;
+
+    Page {
+        items,
+        total,
+        limit,
+        offset,
+        has_more,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(limit: Option<usize>, offset: Option<usize>) -> PageQuery {
+        PageQuery { limit, offset }
+    }
+
+    #[test]
+    fn slices_and_reports_has_more() {
+        let page = paginate((0..10).collect(), &query(Some(3), Some(3)));
+        assert_eq!(page.items, vec![3, 4, 5]);
+        assert_eq!(page.total, 10);
+        assert!(page.has_more);
+
+        let last = paginate((0..10).collect(), &query(Some(3), Some(9)));
+        assert_eq!(last.items, vec![9]);
+        assert!(!last.has_more);
+    }
+
+    #[test]
+    fn offset_past_end_is_empty() {
+        let page = paginate((0..10).collect::<Vec<_>>(), &query(None, Some(50)));
+        assert!(page.items.is_empty());
+        assert!(!page.has_more);
+    }
+
+    #[test]
+    fn meta_has_more_at_and_past_last_page() {
+        let before_last = paginate((0..10).collect::<Vec<_>>(), &query(Some(5), Some(4)));
+        assert!(before_last.meta().has_more);
+
+        let at_last = paginate((0..10).collect::<Vec<_>>(), &query(Some(5), Some(5)));
+        assert_eq!(
+            at_last.meta(),
+            PageMeta { total: 10, limit: 5, offset: 5, has_more: false }
+        );
+
+        let past_last = paginate((0..10).collect::<Vec<_>>(), &query(Some(5), Some(10)));
+        assert!(!past_last.meta().has_more);
+        assert!(past_last.items.is_empty());
+    }
+
+    #[test]
+    fn params_are_parsed() {
+        let params = PageParams {
+            limit: Some("5".to_string()),
+            offset: None,
+        };
+        let parsed = params.parse().unwrap();
+        assert_eq!((parsed.limit, parsed.offset), (Some(5), None));
+
+        let params = PageParams {
+            limit: None,
+            offset: Some("99999999999999999999999".to_string()),
+        };
+        assert!(params.parse().is_err());
+    }
+
+    #[test]
+    fn limit_is_clamped() {
+        let params = |limit: &str| PageParams { limit: Some(limit.to_string()), offset: None }.parse().unwrap();
+
+        let page = paginate((0..2000).collect::<Vec<_>>(), &params("5000"));
+        assert_eq!(page.limit, MAX_LIMIT);
+        assert_eq!(page.items.len(), MAX_LIMIT);
+
+        let page = paginate((0..10).collect::<Vec<_>>(), &params("0"));
+        assert_eq!(page.limit, 1);
+    }
+}
@@ -0,0 +1,195 @@
+use axum::{
+    async_trait,
+    extract::{path::ErrorKind, rejection::PathRejection, FromRequestParts, RawPathParams},
+    http::{request::Parts, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use std::fmt;
+
+/// Drop-in replacement for [`axum::extract::Path`] that rejects with a
+/// [`PathError`] in the standard `{ success: false, message }` envelope.
+///
+/// Blank segments (e.g. `%20`) are rejected before deserialization, so
+/// handlers never see an empty id or label.
+#[derive(Debug)]
+pub struct Path<T>(pub T);
+
+/// Why a path parameter was rejected. Always reported as HTTP 400.
+///
+/// ### Example Error Response (HTTP 400)
+/// ```json
+/// {
+///   "success": false,
+///   "message": "Invalid path parameter `index`: expected a non-negative integer, got `abc`"
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathError {
+    /// The segment is empty or whitespace only.
+    Empty { field: String },
+    /// The segment could not be parsed as the expected type.
+    Invalid { field: String, value: String, expected: &'static str },
+    /// Anything else axum reports (wrong parameter count, bad UTF-8, ...).
+    Other(String),
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::Empty { field } => {
+                write!(f, "Invalid path parameter `{}`: must not be empty", field)
+            }
+            PathError::Invalid { field, value, expected } => write!(
+                f,
+                "Invalid path parameter `{}`: expected {}, got `{}`",
+                field, expected, value
+            ),
+            PathError::Other(message) => write!(f, "Invalid path parameter: {}", message),
+        }
+    }
+}
+
+impl IntoResponse for PathError {
+    fn into_response(self) -> Response {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "success": false, "message": self.to_string() })),
+        )
+            .into_response()
+    }
+}
+
+/// Human-readable name for the type axum failed to parse into.
+fn describe(expected_type: &'static str) -> &'static str {
+    match expected_type {
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => "a non-negative integer",
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => "an integer",
+        "bool" => "`true` or `false`",
+        other => other,
+    }
+}
+
+impl PathError {
+    fn from_rejection(rejection: PathRejection, names: &[String]) -> Self {
+        let PathRejection::FailedToDeserializePathParams(error) = rejection else {
+            return PathError::Other(rejection.body_text());
+        };
+
+        let field_at = |index: usize| names.get(index).cloned().unwrap_or_default();
+        match error.into_kind() {
+            ErrorKind::ParseErrorAtKey { key, value, expected_type } => PathError::Invalid {
+                field: key,
+                value,
+                expected: describe(expected_type),
+            },
+            ErrorKind::ParseErrorAtIndex { index, value, expected_type } => PathError::Invalid {
+                field: field_at(index),
+                value,
+                expected: describe(expected_type),
+            },
+            ErrorKind::ParseError { value, expected_type } => PathError::Invalid {
+                field: field_at(0),
+                value,
+                expected: describe(expected_type),
+            },
+            other => PathError::Other(other.to_string()),
+        }
+    }
+}
+
+#[async_trait]
+impl<T, S> FromRequestParts<S> for Path<T>
+where
+    T: DeserializeOwned + Send,
+    S: Send + Sync,
+{
+    type Rejection = PathError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let raw = RawPathParams::from_request_parts(parts, state)
+            .await
+            .map_err(|rejection| PathError::Other(rejection.body_text()))?;
+
+        let mut names = Vec::new();
+        for (name, value) in &raw {
+            if value.trim().is_empty() {
+                return Err(PathError::Empty { field: name.to_string() });
+            }
+            names.push(name.to_string());
+        }
+
+        axum::extract::Path::<T>::from_request_parts(parts, state)
+            .await
+            .map(|axum::extract::Path(value)| Path(value))
+            .map_err(|rejection| PathError::from_rejection(rejection, &names))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::get;
+    use axum::Router;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    async fn index(Path(index): Path<u32>) -> String {
+        index.to_string()
+    }
+
+    async fn label(Path(label): Path<String>) -> String {
+        label
+    }
+
+    async fn send(uri: &str) -> (StatusCode, Value) {
+        let app = Router::new()
+            .route("/stablecoin/:index/apy", get(index))
+            .route("/labels/:label", get(label));
+        let response = app
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        let body = serde_json::from_slice(&bytes).unwrap_or_else(|_| json!(String::from_utf8_lossy(&bytes)));
+        (status, body)
+    }
+
+    #[tokio::test]
+    async fn bad_numeric_index() {
+        let (status, json) = send("/stablecoin/abc/apy").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["success"], false);
+        assert_eq!(
+            json["message"],
+            "Invalid path parameter `index`: expected a non-negative integer, got `abc`"
+        );
+
+        let (status, json) = send("/stablecoin/-1/apy").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            json["message"],
+            "Invalid path parameter `index`: expected a non-negative integer, got `-1`"
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_segment() {
+        let (status, json) = send("/labels/%20").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["success"], false);
+        assert_eq!(json["message"], "Invalid path parameter `label`: must not be empty");
+    }
+
+    #[tokio::test]
+    async fn valid_params_pass_through() {
+        assert_eq!(send("/stablecoin/7/apy").await, (StatusCode::OK, json!(7)));
+        assert_eq!(send("/labels/usdc").await, (StatusCode::OK, json!("usdc")));
+    }
+}
@@ -0,0 +1,89 @@
+use axum::{
+    extract::{Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+
+use crate::AppState;
+
+/// Middleware enforcing `REFLECT_MAX_QUERY_LENGTH`.
+///
+/// Requests whose raw (still percent-encoded) query string is longer than the
+/// limit are rejected before any extractor parses it.
+///
+/// ### Error Response (HTTP 414)
+/// ```json
+/// {
+///   "success": false,
+///   "message": "query string too long (max 2048 bytes)"
+/// }
+/// ```
+pub async fn reject_long_queries(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    let max = state.config.max_query_length;
+    let len = request.uri().query().map_or(0, str::len);
+    if len > max {
+        tracing::warn!(len, max, path = request.uri().path(), "Rejected over-long query string");
+        return (
+            StatusCode::URI_TOO_LONG,
+            Json(json!({
+                "success": false,
+                "message": format!("query string too long (max {} bytes)", max),
+            })),
+        )
+            .into_response();
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::integration::get_integration_events::get_integration_events;
+    use axum::body::{to_bytes, Body};
+    use axum::routing::get;
+    use axum::{middleware, Router};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    async fn send(uri: &str) -> (StatusCode, Value) {
+        let state = AppState::new(Config {
+            max_query_length: 64,
+            ..Config::default()
+        });
+        let app = Router::new()
+            .route("/events", get(get_integration_events))
+            .layer(middleware::from_fn_with_state(state.clone(), reject_long_queries))
+            .with_state(state);
+
+        let response = app
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn over_long_query_rejected() {
+        let uri = format!("/events?integration_id={}", "a".repeat(64));
+        let (status, json) = send(&uri).await;
+        assert_eq!(status, StatusCode::URI_TOO_LONG);
+        assert_eq!(json["success"], false);
+        assert_eq!(json["message"], "query string too long (max 64 bytes)");
+    }
+
+    #[tokio::test]
+    async fn query_at_limit_allowed() {
+        let query = format!("integration_id={}", "a".repeat(64 - "integration_id=".len()));
+        assert_eq!(query.len(), 64);
+        let (status, json) = send(&format!("/events?{}", query)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["success"], true);
+    }
+}
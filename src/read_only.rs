@@ -0,0 +1,85 @@
+use axum::{
+    extract::{Request, State},
+    http::{Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+
+use crate::AppState;
+
+/// Middleware enforcing `REFLECT_READ_ONLY`.
+///
+/// While read-only mode is on, every mutating request (anything other than
+/// `GET`, `HEAD` or `OPTIONS`) is rejected before reaching its handler. This
+/// covers transaction building as well as admin writes.
+///
+/// ### Error Response (HTTP 503)
+/// ```json
+/// {
+///   "success": false,
+///   "message": "service is read-only"
+/// }
+/// ```
+pub async fn reject_mutations(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    let safe = matches!(*request.method(), Method::GET | Method::HEAD | Method::OPTIONS);
+    if state.config.read_only && !safe {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(json!({ "success": false, "message": "service is read-only" })),
+        )
+            .into_response();
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use axum::body::{to_bytes, Body};
+    use axum::routing::get;
+    use axum::{middleware, Router};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    fn app(read_only: bool) -> Router {
+        let state = AppState::new(Config {
+            read_only,
+            ..Config::default()
+        });
+        Router::new()
+            .route("/", get(|| async { "read" }).post(|| async { "write" }))
+            .layer(middleware::from_fn_with_state(state.clone(), reject_mutations))
+            .with_state(state)
+    }
+
+    async fn send(app: Router, method: Method) -> (StatusCode, Vec<u8>) {
+        let request = Request::builder().method(method).uri("/").body(Body::empty()).unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        (status, to_bytes(response.into_body(), 1024).await.unwrap().to_vec())
+    }
+
+    #[tokio::test]
+    async fn post_rejected_get_allowed_when_read_only() {
+        let (status, body) = send(app(true), Method::POST).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        let json: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["success"], false);
+        assert_eq!(json["message"], "service is read-only");
+
+        let (status, body) = send(app(true), Method::GET).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, b"read");
+    }
+
+    #[tokio::test]
+    async fn post_allowed_by_default() {
+        let (status, body) = send(app(false), Method::POST).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, b"write");
+    }
+}
@@ -0,0 +1,67 @@
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use tracing::Instrument;
+
+/// Header carrying the request id, accepted from the caller or generated.
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Longest caller-supplied request id that is reused as-is.
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// Middleware tagging each request with an id.
+///
+/// A caller-supplied `X-Request-Id` is kept when it is short, visible ASCII;
+/// otherwise a UUID v4 is generated. The id is recorded on a `request` span
+/// wrapping the whole request, so every log line (including validation
+/// failures) carries it, and echoed back in the response header.
+pub async fn assign_request_id(mut request: Request, next: Next) -> Response {
+    let id = request
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| !id.is_empty() && id.len() <= MAX_REQUEST_ID_LEN && id.chars().all(|c| c.is_ascii_graphic()))
+        .map(String::from)
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+    let value = HeaderValue::from_str(&id).expect("request ids are visible ASCII");
+    request.headers_mut().insert(REQUEST_ID_HEADER, value.clone());
+
+    let span = tracing::info_span!("request", request_id = %id);
+    let mut response = next.run(request).instrument(span).await;
+    response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::routing::get;
+    use axum::{middleware, Router};
+    use tower::ServiceExt;
+
+    async fn request_id(header: Option<&str>) -> String {
+        let app = Router::new()
+            .route("/", get(|| async { "ok" }))
+            .layer(middleware::from_fn(assign_request_id));
+        let mut request = Request::get("/");
+        if let Some(header) = header {
+            request = request.header(REQUEST_ID_HEADER, header);
+        }
+        let response = app.oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+        response.headers()[REQUEST_ID_HEADER].to_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn caller_id_echoed_or_generated() {
+        assert_eq!(request_id(Some("abc-123")).await, "abc-123");
+
+        let generated = request_id(None).await;
+        assert!(uuid::Uuid::parse_str(&generated).is_ok());
+        assert_ne!(request_id(Some(&"x".repeat(200))).await, "x".repeat(200));
+    }
+}
@@ -0,0 +1,97 @@
+use axum::{
+    body::{to_bytes, Body},
+    extract::{Request, State},
+    http::header::{CONTENT_LENGTH, CONTENT_TYPE},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::error::ApiError;
+use crate::AppState;
+
+/// Middleware enforcing `REFLECT_MAX_RESPONSE_BYTES`.
+///
+/// JSON responses are buffered and measured before they are sent; anything
+/// over the limit is replaced by a 400 asking the client to request less.
+/// Other content types (e.g. the `text/event-stream` of `/stats/stream`) are
+/// passed through untouched.
+///
+/// ### Error Response (HTTP 400)
+/// ```json
+/// {
+///   "success": false,
+///   "message": "response too large, narrow your query"
+/// }
+/// ```
+pub async fn reject_large_responses(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    let max = state.config.max_response_bytes;
+    let path = request.uri().path().to_string();
+    let response = next.run(request).await;
+    let is_json = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"));
+    if !is_json {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    match to_bytes(body, max).await {
+        Ok(bytes) => {
+            parts.headers.insert(CONTENT_LENGTH, bytes.len().into());
+            Response::from_parts(parts, Body::from(bytes))
+        }
+        Err(_) => {
+            tracing::warn!(max, path, "Rejected over-large response");
+            ApiError::BadRequest("response too large, narrow your query".to_string()).into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::stats::get_historical_tvl_and_volume::get_historical_tvl_and_volume;
+    use axum::http::StatusCode;
+    use axum::routing::get;
+    use axum::{middleware, Router};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    async fn send(uri: &str) -> (StatusCode, Value) {
+        let state = AppState::new(Config {
+            max_response_bytes: 4096,
+            ..Config::default()
+        });
+        let app = Router::new()
+            .route("/stats/historical", get(get_historical_tvl_and_volume))
+            .layer(middleware::from_fn_with_state(state.clone(), reject_large_responses))
+            .with_state(state);
+
+        let response = app
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn over_large_response_rejected() {
+        // A week of hourly points for every metric is far beyond 4 KiB
+        let (status, json) = send("/stats/historical?granularity=hour").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["success"], false);
+        assert_eq!(json["message"], "response too large, narrow your query");
+    }
+
+    #[tokio::test]
+    async fn response_within_limit_passes() {
+        let (status, json) = send("/stats/historical?granularity=day&metrics=tvl").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["success"], true);
+    }
+}
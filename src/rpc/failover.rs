@@ -0,0 +1,203 @@
+use async_trait::async_trait;
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::{RpcError, RpcTransport};
+
+/// Which endpoint of a [`FailoverTransport`] requests are currently sent to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActiveEndpoint {
+    Primary,
+    Secondary,
+}
+
+/// Transport that retries a primary endpoint and falls back to a secondary one.
+///
+/// Only transport-level failures (unreachable node, timeouts, garbage
+/// responses) trigger retries and failover. A JSON-RPC `error` object is a
+/// valid answer from a healthy node and is returned as-is.
+///
+/// Once the primary has failed, requests go straight to the secondary
+/// instead of paying for the retries again. Every `reprobe_interval` one
+/// request tries the primary once more, and traffic moves back as soon as
+/// it answers.
+pub struct FailoverTransport {
+    primary: Arc<dyn RpcTransport>,
+    secondary: Arc<dyn RpcTransport>,
+    retries: u32,
+    reprobe_interval: Duration,
+    /// When the primary was last tried and failed; `None` while it is healthy.
+    primary_failed_at: Mutex<Option<Instant>>,
+}
+
+impl FailoverTransport {
+    pub fn new(
+        primary: Arc<dyn RpcTransport>,
+        secondary: Arc<dyn RpcTransport>,
+        retries: u32,
+        reprobe_interval: Duration,
+    ) -> Self {
+        Self {
+            primary,
+            secondary,
+            retries,
+            reprobe_interval,
+            primary_failed_at: Mutex::new(None),
+        }
+    }
+
+    pub fn primary(&self) -> Arc<dyn RpcTransport> {
+        self.primary.clone()
+    }
+
+    pub fn secondary(&self) -> Arc<dyn RpcTransport> {
+        self.secondary.clone()
+    }
+
+    /// The endpoint requests are currently sent to.
+    pub fn active_endpoint(&self) -> ActiveEndpoint {
+        if self.primary_failed_at.lock().unwrap().is_none() {
+            ActiveEndpoint::Primary
+        } else {
+            ActiveEndpoint::Secondary
+        }
+    }
+
+    /// How many attempts this request should make against the primary: all
+    /// of them while it is healthy, one when a re-probe is due (claimed so
+    /// concurrent requests do not probe too), none otherwise.
+    fn primary_attempts(&self) -> u32 {
+        let mut failed_at = self.primary_failed_at.lock().unwrap();
+        match *failed_at {
+            None => self.retries + 1,
+            Some(at) if at.elapsed() >= self.reprobe_interval => {
+                *failed_at = Some(Instant::now());
+                1
+            }
+            Some(_) => 0,
+        }
+    }
+}
+
+#[async_trait]
+impl RpcTransport for FailoverTransport {
+    async fn call(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        let attempts = self.primary_attempts();
+        if attempts == 0 {
+            return self.secondary.call(method, params).await;
+        }
+
+        let mut last_error = None;
+        for _ in 0..attempts {
+            match self.primary.call(method, params.clone()).await {
+                Err(RpcError::Transport(message)) => last_error = Some(message),
+                result => {
+                    if self.primary_failed_at.lock().unwrap().take().is_some() {
+                        tracing::info!(method, "Primary RPC endpoint recovered; failing back");
+                    }
+                    return result;
+                }
+            }
+        }
+
+        let was_healthy = self.primary_failed_at.lock().unwrap().replace(Instant::now()).is_none();
+        if was_healthy {
+            tracing::warn!(
+                method,
+                error = last_error.as_deref().unwrap_or_default(),
+                "Primary RPC endpoint failed after {} attempt(s); failing over to secondary",
+                attempts
+            );
+        } else {
+            tracing::debug!(
+                method,
+                error = last_error.as_deref().unwrap_or_default(),
+                "Primary RPC endpoint still down"
+            );
+        }
+
+        self.secondary.call(method, params).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mock::MockTransport;
+    use super::*;
+    use serde_json::json;
+
+    fn failover(primary: &MockTransport, secondary: &MockTransport, reprobe_interval: Duration) -> FailoverTransport {
+        FailoverTransport::new(Arc::new(primary.clone()), Arc::new(secondary.clone()), 2, reprobe_interval)
+    }
+
+    #[tokio::test]
+    async fn fails_over_to_secondary_after_retries() {
+        let primary = MockTransport::new().fail("getSlot", RpcError::Transport("timed out".into()));
+        let secondary = MockTransport::new().respond("getSlot", json!(7));
+        let transport = failover(&primary, &secondary, Duration::from_secs(60));
+
+        assert_eq!(transport.call("getSlot", json!([])).await.unwrap(), json!(7));
+        assert_eq!(primary.calls().len(), 3);
+        assert_eq!(secondary.calls().len(), 1);
+        assert_eq!(transport.active_endpoint(), ActiveEndpoint::Secondary);
+
+        // Until the re-probe is due, the primary is not retried at all
+        assert_eq!(transport.call("getSlot", json!([])).await.unwrap(), json!(7));
+        assert_eq!(primary.calls().len(), 3);
+        assert_eq!(secondary.calls().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn reprobes_the_primary_once_per_interval() {
+        let primary = MockTransport::new().fail("getSlot", RpcError::Transport("timed out".into()));
+        let secondary = MockTransport::new().respond("getSlot", json!(7));
+        let transport = failover(&primary, &secondary, Duration::ZERO);
+
+        transport.call("getSlot", json!([])).await.unwrap();
+        assert_eq!(primary.calls().len(), 3);
+
+        // A due re-probe is a single attempt, then the secondary answers
+        assert_eq!(transport.call("getSlot", json!([])).await.unwrap(), json!(7));
+        assert_eq!(primary.calls().len(), 4);
+        assert_eq!(secondary.calls().len(), 2);
+        assert_eq!(transport.active_endpoint(), ActiveEndpoint::Secondary);
+    }
+
+    #[tokio::test]
+    async fn fails_back_once_the_primary_answers_a_reprobe() {
+        let primary = MockTransport::new().respond("getSlot", json!(1));
+        let secondary = MockTransport::new().respond("getSlot", json!(2));
+        let transport = failover(&primary, &secondary, Duration::ZERO);
+        *transport.primary_failed_at.lock().unwrap() = Some(Instant::now());
+
+        assert_eq!(transport.call("getSlot", json!([])).await.unwrap(), json!(1));
+        assert_eq!(transport.active_endpoint(), ActiveEndpoint::Primary);
+        assert!(secondary.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn healthy_primary_is_used_directly() {
+        let primary = MockTransport::new().respond("getSlot", json!(1));
+        let secondary = MockTransport::new().respond("getSlot", json!(2));
+        let transport = failover(&primary, &secondary, Duration::from_secs(60));
+
+        assert_eq!(transport.call("getSlot", json!([])).await.unwrap(), json!(1));
+        assert!(secondary.calls().is_empty());
+        assert_eq!(transport.active_endpoint(), ActiveEndpoint::Primary);
+    }
+
+    #[tokio::test]
+    async fn rpc_errors_do_not_trigger_failover() {
+        let error = RpcError::Rpc { code: -32002, message: "insufficient funds".into() };
+        let primary = MockTransport::new().fail("sendTransaction", error.clone());
+        let secondary = MockTransport::new().respond("sendTransaction", json!("sig"));
+        let transport = failover(&primary, &secondary, Duration::from_secs(60));
+
+        assert_eq!(transport.call("sendTransaction", json!([])).await, Err(error));
+        assert_eq!(primary.calls().len(), 1);
+        assert!(secondary.calls().is_empty());
+    }
+}
@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+use super::{RpcError, RpcTransport};
+use crate::trace_context;
+
+/// JSON-RPC 2.0 over HTTP, as spoken by Solana RPC nodes.
+#[derive(Debug, Clone)]
+pub struct HttpTransport {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl HttpTransport {
+    pub fn new(client: reqwest::Client, url: impl Into<String>) -> Self {
+        Self {
+            client,
+            url: url.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl RpcTransport for HttpTransport {
+    async fn call(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let response: Value = trace_context::inject(self.client.post(&self.url))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| RpcError::Transport(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| RpcError::Transport(e.to_string()))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(RpcError::Rpc {
+                code: error["code"].as_i64().unwrap_or_default(),
+                message: error["message"].as_str().unwrap_or_default().to_string(),
+            });
+        }
+
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| RpcError::InvalidResponse("missing `result`".to_string()))
+    }
+}
@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use super::{RpcError, RpcTransport};
+
+/// Test transport returning canned results per method and recording every call.
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    responses: HashMap<String, Result<Value, RpcError>>,
+    calls: Arc<Mutex<Vec<(String, Value)>>>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn respond(mut self, method: &str, result: Value) -> Self {
+        self.responses.insert(method.to_string(), Ok(result));
+        self
+    }
+
+    pub fn fail(mut self, method: &str, error: RpcError) -> Self {
+        self.responses.insert(method.to_string(), Err(error));
+        self
+    }
+
+    /// Answer `getLatestBlockhash` with a fixed blockhash.
+    pub fn with_blockhash(self, blockhash: &str, last_valid_block_height: u64) -> Self {
+        self.respond(
+            "getLatestBlockhash",
+            serde_json::json!({
+                "context": { "slot": 1 },
+                "value": {
+                    "blockhash": blockhash,
+                    "lastValidBlockHeight": last_valid_block_height,
+                }
+            }),
+        )
+    }
+
+    /// Every `(method, params)` pair received so far.
+    pub fn calls(&self) -> Vec<(String, Value)> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl RpcTransport for MockTransport {
+    async fn call(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push((method.to_string(), params));
+
+        self.responses
+            .get(method)
+            .cloned()
+            .unwrap_or_else(|| Err(RpcError::Rpc {
+                code: -32601,
+                message: "Method not found".to_string(),
+            }))
+    }
+}
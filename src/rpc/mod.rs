@@ -0,0 +1,359 @@
+use async_trait::async_trait;
+use axum::http::StatusCode;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::cluster::Cluster;
+use crate::error::ApiError;
+use failover::{ActiveEndpoint, FailoverTransport};
+
+pub mod failover;
+pub mod http;
+#[cfg(test)]
+pub mod mock;
+
+/// Errors returned by a Solana JSON-RPC call.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RpcError {
+    /// The endpoint could not be reached or returned a non-JSON-RPC response.
+    Transport(String),
+    /// The node answered with a JSON-RPC `error` object.
+    Rpc { code: i64, message: String },
+    /// The `result` did not have the expected shape.
+    InvalidResponse(String),
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RpcError::Transport(message) => write!(f, "RPC transport error: {}", message),
+            RpcError::Rpc { code, message } => write!(f, "RPC error {}: {}", code, message),
+            RpcError::InvalidResponse(message) => write!(f, "Invalid RPC response: {}", message),
+        }
+    }
+}
+
+impl RpcError {
+    /// The client-facing error for a failed call made to do `context`
+    /// (e.g. "Failed to fetch a recent blockhash from the RPC node").
+    ///
+    /// Errors the node reported itself keep their code, see [`ApiError::Rpc`];
+    /// transport and decoding failures are a plain 502.
+    pub fn to_api_error(&self, context: &str) -> ApiError {
+        match self {
+            RpcError::Rpc { code, message } => ApiError::Rpc {
+                code: *code,
+                message: format!("{}: {}", context, describe_rpc_error(*code, message)),
+            },
+            RpcError::Transport(_) | RpcError::InvalidResponse(_) => ApiError::Upstream(context.to_string()),
+        }
+    }
+}
+
+/// A readable explanation of a Solana JSON-RPC error, falling back to the
+/// node's own message for codes without one.
+fn describe_rpc_error(code: i64, message: &str) -> String {
+    let lower = message.to_ascii_lowercase();
+    let described = match code {
+        -32002 if lower.contains("insufficient funds") => "insufficient funds for the transaction",
+        -32002 if lower.contains("blockhash not found") => "blockhash not found; fetch a new one and retry",
+        -32002 => "transaction simulation failed",
+        -32003 => "transaction signature verification failed",
+        -32004 | -32007 | -32009 => "the requested slot or block is not available",
+        -32005 => "the RPC node is behind and cannot serve the request",
+        -32014 => "block status is not yet available",
+        -32015 => "transaction version is not supported",
+        -32016 => "the minimum context slot has not been reached",
+        -32601 => "the RPC node does not support this method",
+        -32602 => "the RPC node rejected the request parameters",
+        _ => return format!("RPC error {}: {}", code, message),
+    };
+    described.to_string()
+}
+
+/// Status for a Solana JSON-RPC error code: rejected transactions are the
+/// client's to fix (422), a node that is not ready yet is a 503, and anything
+/// else is a bad gateway.
+pub fn rpc_error_status(code: i64) -> StatusCode {
+    match code {
+        -32002 | -32003 | -32015 => StatusCode::UNPROCESSABLE_ENTITY,
+        -32004 | -32005 | -32007 | -32009 | -32014 | -32016 => StatusCode::SERVICE_UNAVAILABLE,
+        _ => StatusCode::BAD_GATEWAY,
+    }
+}
+
+/// Solana commitment level used for RPC reads.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Commitment {
+    Processed,
+    #[default]
+    Confirmed,
+    Finalized,
+}
+
+impl Commitment {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Commitment::Processed => "processed",
+            Commitment::Confirmed => "confirmed",
+            Commitment::Finalized => "finalized",
+        }
+    }
+}
+
+impl FromStr for Commitment {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "processed" => Ok(Commitment::Processed),
+            "confirmed" => Ok(Commitment::Confirmed),
+            "finalized" => Ok(Commitment::Finalized),
+            other => Err(format!(
+                "Unknown commitment '{}'; expected one of processed, confirmed, finalized",
+                other
+            )),
+        }
+    }
+}
+
+/// Result of `getLatestBlockhash`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatestBlockhash {
+    pub blockhash: String,
+    pub last_valid_block_height: u64,
+}
+
+/// Result of `getEpochInfo`, reduced to what clients ask for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EpochInfo {
+    pub slot: u64,
+    pub epoch: u64,
+    pub block_height: u64,
+}
+
+/// Something that can execute a raw JSON-RPC request.
+///
+/// Implemented by [`http::HttpTransport`] for real nodes and by
+/// `mock::MockTransport` in tests.
+#[async_trait]
+pub trait RpcTransport: Send + Sync {
+    async fn call(&self, method: &str, params: Value) -> Result<Value, RpcError>;
+}
+
+/// A Solana RPC client bound to one cluster.
+///
+/// Every call is recorded through the `metrics` facade:
+/// - `reflect_rpc_requests_total{cluster, method}`
+/// - `reflect_rpc_errors_total{cluster, method}`
+/// - `reflect_rpc_request_duration_seconds{cluster, method}` (histogram)
+///
+/// These are exported alongside the HTTP metrics by whichever recorder the
+/// binary installs.
+#[derive(Clone)]
+pub struct RpcClient {
+    cluster: Cluster,
+    transport: Arc<dyn RpcTransport>,
+    endpoints: Vec<Arc<dyn RpcTransport>>,
+    failover: Option<Arc<FailoverTransport>>,
+}
+
+impl fmt::Debug for RpcClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RpcClient").field("cluster", &self.cluster).finish()
+    }
+}
+
+impl RpcClient {
+    pub fn new(cluster: Cluster, transport: Arc<dyn RpcTransport>) -> Self {
+        Self {
+            cluster,
+            endpoints: vec![transport.clone()],
+            transport,
+            failover: None,
+        }
+    }
+
+    /// A client sending requests through `failover`. Its endpoints are kept
+    /// individually too, primary first, so health checks can probe each one
+    /// rather than whichever is answering.
+    pub fn with_failover(cluster: Cluster, failover: FailoverTransport) -> Self {
+        let failover = Arc::new(failover);
+        Self {
+            cluster,
+            endpoints: vec![failover.primary(), failover.secondary()],
+            transport: failover.clone(),
+            failover: Some(failover),
+        }
+    }
+
+    /// The endpoint requests currently go to, or `None` when the cluster has
+    /// no fallback.
+    pub fn active_endpoint(&self) -> Option<ActiveEndpoint> {
+        self.failover.as_ref().map(|failover| failover.active_endpoint())
+    }
+
+    /// This cluster's endpoints, primary first, each as a client of its own.
+    pub fn endpoints(&self) -> impl Iterator<Item = RpcClient> + '_ {
+        self.endpoints
+            .iter()
+            .map(|endpoint| RpcClient::new(self.cluster, endpoint.clone()))
+    }
+
+    pub fn cluster(&self) -> Cluster {
+        self.cluster
+    }
+
+    /// Execute `method` with `params`, recording count, latency and errors.
+    pub async fn call(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        let labels = [
+            ("cluster", self.cluster.as_str().to_string()),
+            ("method", method.to_string()),
+        ];
+
+        let started = Instant::now();
+        let result = self.transport.call(method, params).await;
+
+        metrics::counter!("reflect_rpc_requests_total", &labels).increment(1);
+        metrics::histogram!("reflect_rpc_request_duration_seconds", &labels)
+            .record(started.elapsed().as_secs_f64());
+        if result.is_err() {
+            metrics::counter!("reflect_rpc_errors_total", &labels).increment(1);
+        }
+
+        result
+    }
+
+    /// Fetch a recent blockhash at the given commitment level.
+    pub async fn get_latest_blockhash(&self, commitment: Commitment) -> Result<LatestBlockhash, RpcError> {
+        let result = self
+            .call("getLatestBlockhash", json!([{ "commitment": commitment.as_str() }]))
+            .await?;
+
+        let value = &result["value"];
+        match (value["blockhash"].as_str(), value["lastValidBlockHeight"].as_u64()) {
+            (Some(blockhash), Some(last_valid_block_height)) => Ok(LatestBlockhash {
+                blockhash: blockhash.to_string(),
+                last_valid_block_height,
+            }),
+            _ => Err(RpcError::InvalidResponse(
+                "getLatestBlockhash: missing blockhash or lastValidBlockHeight".to_string(),
+            )),
+        }
+    }
+
+    /// Fetch the current slot at the given commitment level.
+    pub async fn get_slot(&self, commitment: Commitment) -> Result<u64, RpcError> {
+        let result = self
+            .call("getSlot", json!([{ "commitment": commitment.as_str() }]))
+            .await?;
+
+        result
+            .as_u64()
+            .ok_or_else(|| RpcError::InvalidResponse("getSlot: expected an integer slot".to_string()))
+    }
+
+    /// Fetch the current slot, epoch and block height at the given commitment level.
+    pub async fn get_epoch_info(&self, commitment: Commitment) -> Result<EpochInfo, RpcError> {
+        let result = self
+            .call("getEpochInfo", json!([{ "commitment": commitment.as_str() }]))
+            .await?;
+
+        match (
+            result["absoluteSlot"].as_u64(),
+            result["epoch"].as_u64(),
+            result["blockHeight"].as_u64(),
+        ) {
+            (Some(slot), Some(epoch), Some(block_height)) => Ok(EpochInfo { slot, epoch, block_height }),
+            _ => Err(RpcError::InvalidResponse(
+                "getEpochInfo: missing absoluteSlot, epoch or blockHeight".to_string(),
+            )),
+        }
+    }
+}
+
+/// The RPC clients configured for each cluster. A cluster without a
+/// configured endpoint simply has no client.
+#[derive(Debug, Clone, Default)]
+pub struct RpcClients {
+    clients: HashMap<Cluster, RpcClient>,
+}
+
+impl RpcClients {
+    pub fn with(mut self, client: RpcClient) -> Self {
+        self.clients.insert(client.cluster(), client);
+        self
+    }
+
+    pub fn get(&self, cluster: Cluster) -> Option<&RpcClient> {
+        self.clients.get(&cluster)
+    }
+
+    /// Every configured client, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &RpcClient> {
+        self.clients.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::MockTransport;
+    use super::*;
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder, Snapshot};
+    use metrics_util::CompositeKey;
+    use serde_json::json;
+
+    type Entries = Vec<(CompositeKey, Option<metrics::Unit>, Option<metrics::SharedString>, DebugValue)>;
+
+    fn counter_value(entries: &Entries, name: &str, method: &str) -> Option<u64> {
+        entries.iter().find_map(|(key, _, _, value)| {
+            let key = key.key();
+            let matches = key.name() == name
+                && key.labels().any(|l| l.key() == "cluster" && l.value() == "devnet")
+                && key.labels().any(|l| l.key() == "method" && l.value() == method);
+            match value {
+                DebugValue::Counter(count) if matches => Some(*count),
+                _ => None,
+            }
+        })
+    }
+
+    #[test]
+    fn rpc_calls_are_recorded_as_metrics() {
+        let recorder = DebuggingRecorder::new();
+        let transport = MockTransport::new()
+            .respond("getSlot", json!(42))
+            .fail("getBalance", RpcError::Transport("connection refused".into()));
+        let client = RpcClient::new(Cluster::Devnet, Arc::new(transport));
+
+        metrics::with_local_recorder(&recorder, || {
+            let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+            runtime.block_on(async {
+                assert_eq!(client.call("getSlot", json!([])).await.unwrap(), json!(42));
+                client.call("getSlot", json!([])).await.unwrap();
+                assert!(client.call("getBalance", json!([])).await.is_err());
+            });
+        });
+
+        let entries = Snapshot::into_vec(recorder.snapshotter().snapshot());
+        assert_eq!(counter_value(&entries, "reflect_rpc_requests_total", "getSlot"), Some(2));
+        assert_eq!(counter_value(&entries, "reflect_rpc_errors_total", "getSlot"), None);
+        assert_eq!(counter_value(&entries, "reflect_rpc_requests_total", "getBalance"), Some(1));
+        assert_eq!(counter_value(&entries, "reflect_rpc_errors_total", "getBalance"), Some(1));
+    }
+
+    #[test]
+    fn rpc_errors_map_to_api_errors() {
+        let error = RpcError::Rpc { code: -32002, message: "Attempt to debit an account but found no record of a prior credit: insufficient funds".into() };
+        let api_error = error.to_api_error("Failed to simulate");
+        assert_eq!(api_error.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(api_error.message(), "Failed to simulate: insufficient funds for the transaction");
+
+        let api_error = RpcError::Transport("refused".into()).to_api_error("Failed to simulate");
+        assert_eq!(api_error, ApiError::Upstream("Failed to simulate".into()));
+    }
+}
@@ -0,0 +1,101 @@
+//! Known fixtures for tests that need a populated [`AppState`].
+
+use crate::stablecoin::apy::ApyStore;
+use crate::stablecoin::get_all_apy::ApyData;
+use crate::stablecoin::get_latest_exchange_rates::ExchangeRateData;
+use crate::stablecoin::rates::ExchangeRateStore;
+use crate::stablecoin::registry::{StablecoinInfo, StablecoinRegistry, USDC_MINT, USDC_PLUS_MINT};
+use crate::stablecoin::supply::{SupplyInfo, SupplyStore};
+use crate::timestamp::Timestamp;
+use crate::AppState;
+
+/// Signer / authority used throughout the fixtures.
+pub const SEED_AUTHORITY: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+
+/// Mint address of the seeded second stablecoin.
+pub const USDT_PLUS_MINT: &str = "6p2RJu3sreYUznWeWh422HNbzf6JvU3X46KS1grXFejo";
+
+/// USDT mint, the collateral behind the seeded second stablecoin.
+pub const USDT_MINT: &str = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB";
+
+/// Data preloaded by [`AppState::with_seed`].
+///
+/// `Seed::default()` holds two stablecoins with a rate, an APY and supply
+/// figures each, and one integration (`int_1`) owned by [`SEED_AUTHORITY`];
+/// override fields with struct update syntax when a test needs something else.
+#[derive(Debug, Clone)]
+pub struct Seed {
+    pub stablecoins: Vec<StablecoinInfo>,
+    pub rates: Vec<ExchangeRateData>,
+    pub apys: Vec<ApyData>,
+    pub supplies: Vec<SupplyInfo>,
+    /// `(name, authority)` pairs, created in order as `int_1`, `int_2`, ...
+    pub integrations: Vec<(String, String)>,
+}
+
+impl Default for Seed {
+    fn default() -> Self {
+        Self {
+            stablecoins: vec![
+                StablecoinInfo {
+                    index: 0,
+                    name: "USDC+".to_string(),
+                    mint: USDC_PLUS_MINT.to_string(),
+                    collateral_mint: USDC_MINT.to_string(),
+                    decimals: 6,
+                    enabled: true,
+                },
+                StablecoinInfo {
+                    index: 1,
+                    name: "USDT+".to_string(),
+                    mint: USDT_PLUS_MINT.to_string(),
+                    collateral_mint: USDT_MINT.to_string(),
+                    decimals: 6,
+                    enabled: true,
+                },
+            ],
+            rates: vec![
+                ExchangeRateData {
+                    id: 200001,
+                    stablecoin: 0,
+                    base_usd_value_bps: 1020000000,
+                    timestamp: Timestamp::parse_rfc3339("2026-01-01T00:00:00.000Z").unwrap(),
+                    receipt_usd_value_bps: 1020000500,
+                },
+                ExchangeRateData {
+                    id: 200002,
+                    stablecoin: 1,
+                    base_usd_value_bps: 1010000000,
+                    timestamp: Timestamp::parse_rfc3339("2026-01-01T00:00:00.000Z").unwrap(),
+                    receipt_usd_value_bps: 1010000300,
+                },
+            ],
+            apys: vec![
+                ApyData { index: 0, apy: 224, timestamp: Timestamp::parse_rfc3339("2026-01-01T00:00:00.000Z").unwrap() },
+                ApyData { index: 1, apy: 310, timestamp: Timestamp::parse_rfc3339("2026-01-01T00:00:00.000Z").unwrap() },
+            ],
+            supplies: vec![
+                SupplyInfo { index: 0, supply_cap: 1_000_000_000, current_supply: 500_000_000 },
+                SupplyInfo { index: 1, supply_cap: 100_000_000, current_supply: 10_000_000 },
+            ],
+            integrations: vec![("Seeded Integration".to_string(), SEED_AUTHORITY.to_string())],
+        }
+    }
+}
+
+impl AppState {
+    /// Default state with the in-memory stores populated from `seed`.
+    pub fn with_seed(seed: Seed) -> Self {
+        let state = AppState {
+            stablecoins: StablecoinRegistry::new(seed.stablecoins),
+            rates: ExchangeRateStore::new(seed.rates),
+            apys: ApyStore::new(seed.apys),
+            supplies: SupplyStore::new(seed.supplies),
+            ..AppState::default()
+        };
+        for (name, authority) in seed.integrations {
+            state.integrations.create(name, authority);
+        }
+        state
+    }
+}
@@ -0,0 +1,71 @@
+/// Open the tracing span for a handler.
+///
+/// The span is named after the route and carries whatever key request fields
+/// are passed (`stablecoin_index`, `cluster`, `amount`, ...), so every log line
+/// emitted while handling the request can be filtered by them. Wallet
+/// addresses and API keys must not be passed as fields.
+///
+/// Handlers without `.await` points can hold the span with `.entered()`;
+/// async work should be wrapped with [`tracing::Instrument::instrument`].
+///
+/// # Example
+///
+/// ```ignore
+/// let _span = handler_span!("GET /stablecoins/:index/apy", stablecoin_index = index).entered();
+/// ```
+macro_rules! handler_span {
+    ($route:literal $(, $field:ident = $value:expr)* $(,)?) => {
+        tracing::info_span!(target: "reflect_api::handler", $route $(, $field = $value)*)
+    };
+}
+
+pub(crate) use handler_span;
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Query, State};
+    use axum::response::IntoResponse;
+
+    use crate::capture::Capture;
+    use crate::json_body::JsonBody;
+    use crate::cluster::ClusterQuery;
+    use crate::stablecoin::generate_mint_transaction::{generate_mint_transaction, MintRequest};
+    use crate::AppState;
+
+    #[test]
+    fn mint_span_has_route_name_and_fields() {
+        let (capture, _guard) = Capture::install();
+
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        runtime.block_on(async {
+            let req = MintRequest {
+                stablecoin_index: 0,
+                deposit_amount: crate::amount::Amount::new(1_000_000).unwrap(),
+                signer: "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM".to_string(),
+                minimum_received: 999000,
+                collateral_mint: None,
+                fee_payer: None,
+                signer_proof: None,
+            };
+            let response = generate_mint_transaction(
+                State(AppState::default()),
+                Query(ClusterQuery { cluster: Some("devnet".to_string()), ..Default::default() }),
+                JsonBody(req),
+            )
+            .await
+            .into_response();
+            assert!(response.status().is_success());
+        });
+
+        let spans = capture.spans();
+        let span = spans
+            .iter()
+            .find(|span| span.name == "POST /stablecoins/mint/tx")
+            .expect("mint span was not opened");
+        assert_eq!(span.target, "reflect_api::handler");
+        assert_eq!(span.field("stablecoin_index"), Some("0"));
+        assert_eq!(span.field("cluster"), Some("devnet"));
+        assert_eq!(span.field("amount"), Some("1000000"));
+        assert!(span.fields.iter().all(|(key, _)| key != "signer" && key != "fee_payer"));
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Arc, RwLock};
+
+use super::get_all_apy::ApyData;
+use crate::error::ApiError;
+use crate::timestamp::Timestamp;
+use crate::validation::{clamp_param, require_int, ClampMode};
+
+/// Snapshots kept per stablecoin; the oldest are dropped beyond this (ten
+/// years of daily snapshots).
+pub const APY_HISTORY_CAPACITY: usize = 3650;
+
+/// Latest APY snapshot per stablecoin plus the newest
+/// [`APY_HISTORY_CAPACITY`] snapshots of each, shared through
+/// [`crate::AppState`].
+///
+/// Cheap to clone; clones share the same underlying maps.
+#[derive(Debug, Clone)]
+pub struct ApyStore {
+    latest: Arc<RwLock<BTreeMap<u32, ApyData>>>,
+    history: Arc<RwLock<BTreeMap<u32, VecDeque<ApyData>>>>,
+}
+
+impl Default for ApyStore {
+    fn default() -> Self {
+        Self::new(vec![ApyData {
+            index: 0,
+            apy: 224,
+            timestamp: Timestamp::parse_rfc3339("2025-12-19T16:55:42.407Z").unwrap(),
+        }])
+    }
+}
+
+impl ApyStore {
+    pub fn new(apys: Vec<ApyData>) -> Self {
+        let store = Self {
+            latest: Arc::default(),
+            history: Arc::default(),
+        };
+        for apy in apys {
+            store.set(apy);
+        }
+        store
+    }
+
+    /// Replace the latest snapshot for the APY's stablecoin and append it to
+    /// that stablecoin's history, dropping the oldest snapshot when full.
+    pub fn set(&self, apy: ApyData) {
+        let mut history = self.history.write().unwrap();
+        let samples = history.entry(apy.index).or_default();
+        if samples.len() == APY_HISTORY_CAPACITY {
+            samples.pop_front();
+        }
+        samples.push_back(apy.clone());
+        self.latest.write().unwrap().insert(apy.index, apy);
+    }
+
+    /// Every snapshot kept for `index`, oldest first.
+    pub fn history(&self, index: u32) -> Vec<ApyData> {
+        self.history
+            .read()
+            .unwrap()
+            .get(&index)
+            .map(|samples| samples.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Number of snapshots kept for `index` taken at or after `since`.
+    pub fn count_since(&self, index: u32, since: Timestamp) -> usize {
+        self.history
+            .read()
+            .unwrap()
+            .get(&index)
+            .map_or(0, |samples| samples.iter().rev().take_while(|sample| sample.timestamp >= since).count())
+    }
+
+    /// Mean, in basis points, of the newest `window` snapshots of `index`,
+    /// with the timestamp of the newest one. `None` when `window` is zero or
+    /// exceeds the history.
+    pub fn moving_average(&self, index: u32, window: usize) -> Option<(f64, Timestamp)> {
+        let history = self.history.read().unwrap();
+        let samples = history.get(&index)?;
+        if window == 0 || window > samples.len() {
+            return None;
+        }
+        let trailing = samples.range(samples.len() - window..);
+        let sum: f64 = trailing.clone().map(|sample| sample.apy as f64).sum();
+        Some((sum / window as f64, trailing.last()?.timestamp))
+    }
+
+    /// The latest snapshot of every stablecoin, ordered by stablecoin index.
+    pub fn latest(&self) -> Vec<ApyData> {
+        self.latest.read().unwrap().values().cloned().collect()
+    }
+}
+
+/// Resolve a `?smooth=N` parameter for `index` to the trailing N-sample
+/// moving average and the timestamp of its newest sample. The average is in
+/// percent, like the raw APY responses.
+///
+/// Only snapshots taken at or after `since` count, and `N` must be between
+/// 1 and the number of them (422 otherwise); a stablecoin with no snapshots
+/// in the window is a 404.
+pub fn smoothed_apy(store: &ApyStore, index: u32, smooth: &str, since: Timestamp) -> Result<(f64, Timestamp), ApiError> {
+    let window = require_int::<usize>("smooth", Some(smooth))?;
+    let available = store.count_since(index, since);
+    if available == 0 {
+        return Err(ApiError::NotFound(format!("No APY history for stablecoin {}", index)));
+    }
+    let window = clamp_param("smooth", window, 1, available, ClampMode::Reject)?;
+    let (bps, timestamp) = store
+        .moving_average(index, window)
+        .ok_or(ApiError::Internal)?;
+    Ok((bps / 100.0, timestamp))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(apys: &[i64]) -> ApyStore {
+        ApyStore::new(
+            apys.iter()
+                .enumerate()
+                .map(|(day, &apy)| ApyData {
+                    index: 0,
+                    apy,
+                    timestamp: Timestamp::parse_rfc3339(&format!("2026-01-{:02}T00:00:00Z", day + 1)).unwrap(),
+                })
+                .collect(),
+        )
+    }
+
+    fn since(day: u32) -> Timestamp {
+        Timestamp::parse_rfc3339(&format!("2026-01-{:02}T00:00:00Z", day)).unwrap()
+    }
+
+    #[test]
+    fn history_keeps_every_snapshot() {
+        let store = series(&[200, 220, 240]);
+        assert_eq!(store.history(0).len(), 3);
+        assert_eq!(store.latest()[0].apy, 240);
+        assert!(store.history(1).is_empty());
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let store = ApyStore::new(Vec::new());
+        let start = since(1);
+        for i in 0..APY_HISTORY_CAPACITY as i64 + 5 {
+            store.set(ApyData { index: 0, apy: i, timestamp: Timestamp(start.0 + chrono::Duration::hours(i)) });
+        }
+        let history = store.history(0);
+        assert_eq!(history.len(), APY_HISTORY_CAPACITY);
+        assert_eq!(history[0].apy, 5);
+        assert_eq!(store.latest()[0].apy, APY_HISTORY_CAPACITY as i64 + 4);
+    }
+
+    #[test]
+    fn moving_average_uses_trailing_samples() {
+        let store = series(&[100, 200, 300, 400]);
+        let (raw, _) = store.moving_average(0, 1).unwrap();
+        assert_eq!(raw, 400.0);
+
+        let (smoothed, timestamp) = store.moving_average(0, 3).unwrap();
+        assert_eq!(smoothed, 300.0);
+        assert_eq!(timestamp, Timestamp::parse_rfc3339("2026-01-04T00:00:00Z").unwrap());
+
+        assert_eq!(store.moving_average(0, 4).unwrap().0, 250.0);
+        assert_eq!(store.moving_average(0, 5), None);
+        assert_eq!(store.moving_average(0, 0), None);
+    }
+
+    #[test]
+    fn count_since_only_counts_the_window() {
+        let store = series(&[100, 200, 300, 400]);
+        assert_eq!(store.count_since(0, since(1)), 4);
+        assert_eq!(store.count_since(0, since(3)), 2);
+        assert_eq!(store.count_since(0, since(5)), 0);
+        assert_eq!(store.count_since(1, since(1)), 0);
+    }
+
+    #[test]
+    fn smooth_is_in_percent_and_validated_against_the_window() {
+        let store = series(&[100, 200]);
+        assert_eq!(smoothed_apy(&store, 0, "1", since(1)).unwrap().0, 2.0);
+        assert_eq!(smoothed_apy(&store, 0, "2", since(1)).unwrap().0, 1.5);
+
+        let error = smoothed_apy(&store, 0, "3", since(1)).unwrap_err();
+        assert_eq!(error.status(), axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(error.message(), "Invalid request data: smooth must be between 1 and 2");
+
+        // Snapshots before `since` do not count
+        let error = smoothed_apy(&store, 0, "2", since(2)).unwrap_err();
+        assert_eq!(error.message(), "Invalid request data: smooth must be between 1 and 1");
+
+        let error = smoothed_apy(&store, 0, "0", since(1)).unwrap_err();
+        assert_eq!(error.status(), axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+
+        let error = smoothed_apy(&store, 0, "x", since(1)).unwrap_err();
+        assert_eq!(error.status(), axum::http::StatusCode::BAD_REQUEST);
+
+        let error = smoothed_apy(&store, 7, "1", since(1)).unwrap_err();
+        assert_eq!(error.status(), axum::http::StatusCode::NOT_FOUND);
+        assert_eq!(error.message(), "No APY history for stablecoin 7");
+
+        let error = smoothed_apy(&store, 0, "1", since(3)).unwrap_err();
+        assert_eq!(error.status(), axum::http::StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,129 @@
+use serde::Serialize;
+
+use crate::amount::{Amount, BPS_DENOMINATOR};
+
+/// Fee components applied to every mint and redeem, in basis points of the
+/// gross amount (see `REFLECT_PROTOCOL_FEE_BPS` / `REFLECT_INTEGRATION_FEE_BPS`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeSchedule {
+    pub protocol_fee_bps: u32,
+    pub integration_fee_bps: u32,
+}
+
+impl Default for FeeSchedule {
+    fn default() -> Self {
+        Self {
+            protocol_fee_bps: 10,
+            integration_fee_bps: 0,
+        }
+    }
+}
+
+/// Where a quoted amount goes. `net` is what the user receives.
+///
+/// Each fee is rounded down and `net` is whatever is left, so
+/// `gross == protocol_fee + integration_fee + net` always holds and rounding
+/// never shorts the user. `dust` is how many smallest units rounding moved
+/// from the fees into `net`: the exact fees rounded up, minus the fees
+/// charged. It is only reported when a client asks for it (`includeDust`).
+///
+/// ### Example
+/// ```json
+/// {
+///   "gross": 1000000,
+///   "protocol_fee": 1000,
+///   "integration_fee": 0,
+///   "net": 999000
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct FeeBreakdown {
+    pub gross: Amount,
+    pub protocol_fee: u64,
+    pub integration_fee: u64,
+    pub net: u64,
+    #[serde(skip)]
+    pub dust: u64,
+}
+
+impl FeeSchedule {
+    /// Split `gross` into fees and net, rounding each fee down.
+    ///
+    /// Returns `None` when the fees exceed 100%.
+    pub fn breakdown(&self, gross: Amount) -> Option<FeeBreakdown> {
+        let fee_bps = self.protocol_fee_bps.checked_add(self.integration_fee_bps)?;
+        if fee_bps > BPS_DENOMINATOR {
+            return None;
+        }
+
+        let protocol_fee = gross.share(self.protocol_fee_bps)?;
+        let integration_fee = gross.share(self.integration_fee_bps)?;
+        let fees = protocol_fee + integration_fee;
+        let net = gross.checked_sub(fees)?;
+        let dust = gross.share_rounded_up(fee_bps)? - fees;
+
+        Some(FeeBreakdown {
+            gross,
+            protocol_fee,
+            integration_fee,
+            net,
+            dust,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn amount(units: u64) -> Amount {
+        Amount::new(units).unwrap()
+    }
+
+    #[test]
+    fn default_schedule_matches_flat_fee() {
+        let breakdown = FeeSchedule::default().breakdown(amount(1_000_000)).unwrap();
+        assert_eq!(
+            breakdown,
+            FeeBreakdown { gross: amount(1_000_000), protocol_fee: 1_000, integration_fee: 0, net: 999_000, dust: 0 }
+        );
+    }
+
+    #[test]
+    fn breakdown_sums_to_gross() {
+        let fees = FeeSchedule { protocol_fee_bps: 10, integration_fee_bps: 25 };
+        for gross in [1, 999, 1_000_000, 123_456_789, 7_777_777_777] {
+            let b = fees.breakdown(amount(gross)).unwrap();
+            assert_eq!(b.net + b.protocol_fee + b.integration_fee, b.gross.get(), "gross {}", gross);
+        }
+    }
+
+    #[test]
+    fn rounding_favours_the_user() {
+        // 999 at 10 bps: the 0.999 fee rounds down to 0 and the user keeps it all
+        let b = FeeSchedule::default().breakdown(amount(999)).unwrap();
+        assert_eq!((b.protocol_fee, b.net, b.dust), (0, 999, 1));
+
+        // 123.457 + 308.6425 in fees are charged as 123 + 308
+        let fees = FeeSchedule { protocol_fee_bps: 10, integration_fee_bps: 25 };
+        let b = fees.breakdown(amount(123_457)).unwrap();
+        assert_eq!((b.protocol_fee, b.integration_fee, b.net), (123, 308, 123_026));
+        assert_eq!(b.dust, 2);
+        assert_eq!(b.protocol_fee + b.integration_fee + b.net, 123_457);
+    }
+
+    #[test]
+    fn large_amounts_do_not_overflow() {
+        let b = FeeSchedule::default().breakdown(amount(1_000_000_000_000_000)).unwrap();
+        assert_eq!((b.protocol_fee, b.net), (1_000_000_000_000, 999_000_000_000_000));
+
+        let b = FeeSchedule { protocol_fee_bps: 10_000, integration_fee_bps: 0 }.breakdown(amount(u64::MAX)).unwrap();
+        assert_eq!((b.protocol_fee, b.net, b.dust), (u64::MAX, 0, 0));
+    }
+
+    #[test]
+    fn excessive_fees_rejected() {
+        let fees = FeeSchedule { protocol_fee_bps: 6_000, integration_fee_bps: 5_000 };
+        assert_eq!(fees.breakdown(amount(1_000_000)), None);
+    }
+}
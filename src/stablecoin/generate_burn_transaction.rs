@@ -1,121 +1,211 @@
 use axum::{
-    extract::{Query, Json},
+    extract::{Query, Json, State},
     http::StatusCode,
     response::IntoResponse,
 };
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde_json::{json, Value};
+use tracing::Instrument;
+
+use crate::amount::Amount;
+use crate::cluster::{Cluster, ClusterQuery};
+use crate::error::ApiError;
+use crate::events::store::EventVolume;
+use crate::example::{ExampleBody, EXAMPLE_COLLATERAL_MINT, EXAMPLE_SIGNER};
+use crate::json_body::JsonBody;
+use crate::span::handler_span;
+use crate::timestamp::Timestamp;
+use crate::validation::{invalid_field, malformed_field, parse_param, validate_pubkey};
+use crate::AppState;
+use super::get_mint_redeem_quote::compute_quote;
+use super::slippage::{check_minimum_received, SlippageCheck};
+use super::transaction::{deserialize_pubkey, parse_pubkey, BuiltTx, Instruction, TransactionSpec, TxEncoding, TxFormat, TxVersion};
 
 /// Request structure for the `/stablecoin/burn` endpoint.
 ///
 /// ### Fields
-/// - `stablecoin_index`: Index of the stablecoin (e.g., 0 for USDC+).
-/// - `deposit_amount`: Amount to burn in smallest unit. Must be positive.
+/// - `stablecoinIndex`: Index of the stablecoin (e.g., 0 for USDC+).
+/// - `depositAmount`: Amount to burn in smallest unit. Must be a positive whole number.
 /// - `signer`: User's Solana wallet address.
-/// - `minimum_received`: Minimum amount to receive (slippage protection).
-/// - `collateral_mint`: Optional collateral mint address.
+/// - `minimumReceived`: Minimum amount to receive (slippage protection). Must not be
+///   negative or exceed the quoted amount; values far below it are accepted with a warning.
+/// - `collateralMint`: Optional collateral mint address.
+/// - `feePayer`: Optional account paying the transaction fee (defaults to `signer`).
+///
+/// The snake_case spellings this endpoint originally documented
+/// (`stablecoin_index`, `deposit_amount`, ...) are still accepted as aliases.
 ///
 /// ### Example Request
 /// ```json
 /// {
-///   "stablecoin_index": 0,
-///   "deposit_amount": 1000000,
+///   "stablecoinIndex": 0,
+///   "depositAmount": 1000000,
 ///   "signer": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
-///   "minimum_received": 999000,
-///   "collateral_mint": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+///   "minimumReceived": 999000,
+///   "collateralMint": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
 /// }
 /// ```
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct BurnRequest {
+    #[serde(alias = "stablecoin_index")]
     pub stablecoin_index: u32,
-    pub deposit_amount: i64,
+    #[serde(alias = "deposit_amount")]
+    pub deposit_amount: Amount,
+    #[serde(deserialize_with = "deserialize_pubkey")]
     pub signer: String,
+    #[serde(alias = "minimum_received")]
     pub minimum_received: i64,
+    #[serde(alias = "collateral_mint")]
     pub collateral_mint: Option<String>,
+    #[serde(alias = "fee_payer", skip_serializing_if = "Option::is_none")]
+    pub fee_payer: Option<String>,
 }
 
-/// Query parameter for cluster selection.
-///
-/// ### Example
-/// - `?cluster=mainnet`
-/// - `?cluster=devnet`
-#[derive(Debug, Deserialize)]
-pub struct ClusterQuery {
-    pub cluster: Option<String>,
+impl ExampleBody for BurnRequest {
+    fn example() -> Self {
+        Self {
+            stablecoin_index: 0,
+            deposit_amount: Amount::new(1_000_000).unwrap(),
+            signer: EXAMPLE_SIGNER.to_string(),
+            minimum_received: 999_000,
+            collateral_mint: Some(EXAMPLE_COLLATERAL_MINT.to_string()),
+            fee_payer: None,
+        }
+    }
 }
 
+/// Instruction discriminator for a Reflect burn.
+const BURN_INSTRUCTION: u8 = 1;
+
 /// Success response structure for burn transaction.
+///
+/// `warnings` is omitted when empty.
 #[derive(Debug, Serialize)]
 pub struct BurnSuccessResponse {
     pub success: bool,
     pub data: TransactionData,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct TransactionData {
-    pub transaction: String,
-}
-
-/// Error response structure for burn transaction.
-#[derive(Debug, Serialize)]
-pub struct BurnErrorResponse {
-    pub success: bool,
-    pub message: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<Vec<Instruction>>,
+    #[serde(rename = "recentBlockhash", skip_serializing_if = "Option::is_none")]
+    pub recent_blockhash: Option<String>,
+    /// The cluster the transaction targets, so clients can confirm the network.
+    pub cluster: Cluster,
+    /// Set when the transaction was built from a placeholder program id or
+    /// blockhash and cannot be submitted (see `REFLECT_PROGRAM_ID`).
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub simulated: bool,
 }
 
 /// Handler for `POST /stablecoin/burn`.
 ///
-/// Supports `cluster` query parameter (`mainnet` or `devnet`).  
-/// Validates the request and returns either a simulated transaction or an error.
+/// Supports `cluster` (`mainnet` or `devnet`), `commitment` (`processed`,
+/// `confirmed` or `finalized`), `encoding` (`base64` or `base58`), `txVersion`
+/// (`legacy` or `v0`) and `format` (`transaction` or `instructions`) query parameters.  
+/// Validates the request and returns either an unsigned transaction or an error.
 pub async fn generate_burn_transaction(
-    Query(cluster): Query<ClusterQuery>,
-    Json(req): Json<BurnRequest>,
-) -> impl IntoResponse {
-    // Validate deposit amount
-    if req.deposit_amount <= 0 {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(json!(BurnErrorResponse {
-                success: false,
-                message: "Invalid request data: depositAmount must be positive",
-            })),
-        );
+    State(state): State<AppState>,
+    Query(query): Query<ClusterQuery>,
+    JsonBody(req): JsonBody<BurnRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let span = handler_span!(
+        "POST /stablecoins/burn/tx",
+        stablecoin_index = req.stablecoin_index,
+        cluster = query.cluster.as_deref(),
+        amount = req.deposit_amount.get(),
+    );
+    burn_transaction(state, query, req).instrument(span).await
+}
+
+async fn burn_transaction(state: AppState, query: ClusterQuery, req: BurnRequest) -> Result<(StatusCode, Json<Value>), ApiError> {
+    let cluster = parse_param("cluster", query.cluster.as_deref(), Cluster::default())?;
+    let commitment = parse_param("commitment", query.commitment.as_deref(), state.config.rpc_commitment)?;
+    let encoding = parse_param("encoding", query.encoding.as_deref(), TxEncoding::default())?;
+    let format = parse_param("format", query.format.as_deref(), TxFormat::default())?;
+    let version = parse_param("txVersion", query.tx_version.as_deref(), TxVersion::default())?;
+
+    // Validate stablecoin index and availability
+    state.stablecoins.ensure_available(req.stablecoin_index)?;
+
+    // The signer was checked when the body was parsed; validate the optional
+    // collateral mint and fee payer
+    let signer = parse_pubkey(&req.signer).ok_or(ApiError::Internal)?;
+    if let Some(mint) = req.collateral_mint.as_deref() {
+        validate_pubkey("collateralMint", mint)?;
     }
+    let fee_payer = req.fee_payer.as_deref().map(|fee_payer| validate_pubkey("feePayer", fee_payer)).transpose()?;
 
-    // Validate stablecoin index
-    if req.stablecoin_index != 0 {
-        return (
-            StatusCode::NOT_FOUND,
-            Json(json!(BurnErrorResponse {
-                success: false,
-                message: "Stablecoin with the specified index not found",
-            })),
-        );
+    // Validate slippage protection against the quoted output. A negative
+    // minimum would be encoded as a huge unsigned one on chain
+    if req.minimum_received < 0 {
+        return Err(invalid_field("minimumReceived", "Invalid request data: minimumReceived must not be negative"));
     }
+    let mut warnings = Vec::new();
+    let quoted = compute_quote(&state.config.fees(cluster), req.deposit_amount)
+        .ok_or_else(|| invalid_field("depositAmount", "Invalid request data: depositAmount out of range"))?;
+    match check_minimum_received(quoted, req.minimum_received, state.config.min_received_tolerance_bps) {
+        SlippageCheck::Ok => {}
+        SlippageCheck::Loose(warning) => warnings.push(warning),
+        SlippageCheck::ExceedsQuote => {
+            let message = format!("quote below minimumReceived: expected {}, required {}", quoted, req.minimum_received);
+            return Err(malformed_field("minimumReceived", message));
+        }
+    }
+
+    // Fetch a recent blockhash when the cluster has an RPC endpoint configured
+    let recent_blockhash = match state.rpc.get(cluster) {
+        Some(client) => {
+            let latest = client.get_latest_blockhash(commitment).await.map_err(|e| {
+                tracing::warn!(%cluster, error = %e, "Failed to fetch recent blockhash");
+                e.to_api_error("Failed to fetch a recent blockhash from the RPC node")
+            })?;
+            Some(latest.blockhash)
+        }
+        None => None,
+    };
 
-    // Simulated transaction string
-    let tx = "AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAED...";
+    // Build the unsigned transaction
+    let mut instruction_data = vec![BURN_INSTRUCTION];
+    instruction_data.extend_from_slice(&req.stablecoin_index.to_le_bytes());
+    instruction_data.extend_from_slice(&req.deposit_amount.get().to_le_bytes());
+    instruction_data.extend_from_slice(&req.minimum_received.to_le_bytes());
+    let BuiltTx { transaction, instructions, simulated } = BuiltTx::build(
+        &TransactionSpec {
+            version,
+            signer,
+            fee_payer,
+            program_id: state.config.program_id_bytes(),
+            recent_blockhash: recent_blockhash.as_deref().and_then(parse_pubkey),
+            instruction_data,
+        },
+        format,
+        encoding,
+    );
+    let volume = EventVolume { index: req.stablecoin_index, amount: req.deposit_amount.get() };
+    state.record_event("burn", &req.signer, None, Some(volume), Timestamp::now());
 
-    (
+    Ok((
         StatusCode::OK,
-        Json(json!(BurnSuccessResponse {
+        Json(json!(state.cased(BurnSuccessResponse {
             success: true,
             data: TransactionData {
-                transaction: tx.to_string(),
+                transaction,
+                instructions,
+                recent_blockhash,
+                cluster,
+                simulated,
             },
-        })),
-    )
-}
-
-/// Example error handler for internal server errors.
-pub async fn generate_burn_transaction_error() -> impl IntoResponse {
-    (
-        StatusCode::INTERNAL_SERVER_ERROR,
-        Json(json!(BurnErrorResponse {
-            success: false,
-            message: "Internal server error",
-        })),
-    )
+            warnings,
+        }))),
+    ))
 }
 
 #[cfg(test)]
@@ -123,15 +213,145 @@ mod tests {
     use super::*;
     use axum::body::to_bytes;
     use axum::response::IntoResponse;
+    use base64::Engine;
     use serde_json::Value;
+    use std::sync::Arc;
 
-    fn make_request(stablecoin_index: u32, deposit_amount: i64) -> BurnRequest {
+    use crate::cluster::Cluster;
+    use crate::config::Config;
+    use crate::rpc::mock::MockTransport;
+    use crate::rpc::{RpcClient, RpcClients};
+    use crate::stablecoin::transaction::fee_payer_of;
+
+    fn make_request(stablecoin_index: u32, deposit_amount: u64) -> BurnRequest {
         BurnRequest {
             stablecoin_index,
-            deposit_amount,
-            signer: "test_signer".to_string(),
+            deposit_amount: Amount::new(deposit_amount).unwrap(),
+            signer: "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM".to_string(),
             minimum_received: 999000,
-            collateral_mint: Some("test_mint".to_string()),
+            collateral_mint: Some("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string()),
+            fee_payer: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_commitment_threaded_into_rpc() {
+        let transport = MockTransport::new().with_blockhash("EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N", 100);
+        let state = AppState {
+            rpc: RpcClients::default().with(RpcClient::new(Cluster::Devnet, Arc::new(transport.clone()))),
+            ..AppState::default()
+        };
+
+        let response = generate_burn_transaction(
+            State(state),
+            Query(ClusterQuery {
+                cluster: Some("devnet".to_string()),
+                commitment: Some("finalized".to_string()),
+                ..Default::default()
+            }),
+            JsonBody(make_request(0, 1_000_000)),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        assert_eq!(
+            transport.calls(),
+            vec![("getLatestBlockhash".to_string(), json!([{ "commitment": "finalized" }]))]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_base58_encoding() {
+        let response = generate_burn_transaction(
+            State(AppState::default()),
+            Query(ClusterQuery { encoding: Some("base58".to_string()), ..Default::default() }),
+            JsonBody(make_request(0, 1_000_000)),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        let tx = bs58::decode(json["data"]["transaction"].as_str().unwrap()).into_vec().unwrap();
+        assert_eq!(tx[0], 1);
+    }
+
+    #[tokio::test]
+    async fn test_v0_transaction() {
+        let response = generate_burn_transaction(
+            State(AppState::default()),
+            Query(ClusterQuery {
+                encoding: Some("base58".to_string()),
+                tx_version: Some("v0".to_string()),
+                ..Default::default()
+            }),
+            JsonBody(make_request(0, 1_000_000)),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        let tx = bs58::decode(json["data"]["transaction"].as_str().unwrap()).into_vec().unwrap();
+        assert_eq!(tx[1 + 64], 0x80);
+    }
+
+    #[tokio::test]
+    async fn test_fee_payer_override() {
+        let sponsor = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+        for (fee_payer, expected) in [
+            (Some(sponsor), sponsor),
+            (None, "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"),
+        ] {
+            let req = BurnRequest {
+                fee_payer: fee_payer.map(String::from),
+                ..make_request(0, 1_000_000)
+            };
+            let response = generate_burn_transaction(
+                State(AppState::default()),
+                Query(ClusterQuery { encoding: Some("base58".to_string()), ..Default::default() }),
+                JsonBody(req),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+            let json: Value = serde_json::from_slice(&bytes).unwrap();
+            let tx = bs58::decode(json["data"]["transaction"].as_str().unwrap()).into_vec().unwrap();
+            assert_eq!(fee_payer_of(&tx), parse_pubkey(expected).unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_accepts_both_casings() {
+        for body in [
+            json!({
+                "stablecoinIndex": 0,
+                "depositAmount": 1_000_000,
+                "signer": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+                "minimumReceived": 999000,
+                "feePayer": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+            }),
+            json!({
+                "stablecoin_index": 0,
+                "deposit_amount": 1_000_000,
+                "signer": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+                "minimum_received": 999000,
+                "fee_payer": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+            }),
+        ] {
+            let req: BurnRequest = serde_json::from_value(body).unwrap();
+            assert_eq!(req.deposit_amount.get(), 1_000_000);
+            assert!(req.fee_payer.is_some());
+
+            let response = generate_burn_transaction(State(AppState::default()), Query(ClusterQuery::default()), JsonBody(req))
+                .await
+                .into_response();
+            assert_eq!(response.status(), StatusCode::OK);
         }
     }
 
@@ -139,8 +359,9 @@ mod tests {
     async fn test_burn_success() {
         let req = make_request(0, 1_000_000);
         let response = generate_burn_transaction(
-            Query(ClusterQuery { cluster: Some("mainnet".to_string()) }),
-            Json(req),
+            State(AppState::default()),
+            Query(ClusterQuery { cluster: Some("mainnet".to_string()), ..Default::default() }),
+            JsonBody(req),
         )
         .await
         .into_response();
@@ -152,21 +373,83 @@ mod tests {
         let json: Value = serde_json::from_slice(&bytes).unwrap();
 
         assert_eq!(json["success"], true);
-        assert!(json["data"]["transaction"].is_string());
+        // No REFLECT_PROGRAM_ID and no RPC for a blockhash
+        assert_eq!(json["data"]["simulated"], true);
+
+        // The single instruction closes the message: data length, then the data
+        let tx = base64::engine::general_purpose::STANDARD
+            .decode(json["data"]["transaction"].as_str().unwrap())
+            .unwrap();
+        let data = &tx[tx.len() - 21..];
+        assert_eq!(tx[tx.len() - 22], 21);
+        assert_eq!(data[0], BURN_INSTRUCTION);
+        assert_eq!(&data[1..5], &0u32.to_le_bytes());
+        assert_eq!(&data[5..13], &1_000_000u64.to_le_bytes());
+        assert_eq!(&data[13..21], &999_000u64.to_le_bytes());
+    }
+
+    #[tokio::test]
+    async fn test_configured_program_is_not_simulated() {
+        let program_id = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+        let transport = MockTransport::new().with_blockhash("EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N", 100);
+        let state = AppState {
+            rpc: RpcClients::default().with(RpcClient::new(Cluster::Mainnet, Arc::new(transport))),
+            ..AppState::new(Config { program_id: Some(program_id.to_string()), ..Config::default() })
+        };
+        let response = generate_burn_transaction(
+            State(state),
+            Query(ClusterQuery { format: Some("instructions".to_string()), ..Default::default() }),
+            JsonBody(make_request(0, 1_000_000)),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = to_bytes(response.into_body(), 2048).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert!(json["data"].get("simulated").is_none());
+        assert_eq!(json["data"]["instructions"][0]["programId"], program_id);
     }
 
     #[tokio::test]
     async fn test_invalid_deposit_amount() {
-        let req = make_request(0, -100);
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        for amount in [json!(-100), json!(0)] {
+            let app = axum::Router::new()
+                .route("/", axum::routing::post(generate_burn_transaction))
+                .with_state(AppState::default());
+            let mut body = serde_json::to_value(make_request(0, 1_000_000)).unwrap();
+            body["depositAmount"] = amount;
+            let request = Request::post("/?cluster=mainnet")
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap();
+            let response = app.oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+            let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+            let json: Value = serde_json::from_slice(&bytes).unwrap();
+            assert_eq!(json["success"], false);
+            assert_eq!(json["message"], "field `depositAmount` is invalid: must be positive");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_index() {
+        let req = make_request(99, 1_000_000);
         let response = generate_burn_transaction(
-            Query(ClusterQuery { cluster: Some("mainnet".to_string()) }),
-            Json(req),
+            State(AppState::default()),
+            Query(ClusterQuery { cluster: Some("mainnet".to_string()), ..Default::default() }),
+            JsonBody(req),
         )
         .await
         .into_response();
 
         let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
 
         let bytes = to_bytes(body, 1024).await.unwrap();
         let json: Value = serde_json::from_slice(&bytes).unwrap();
@@ -174,22 +457,64 @@ mod tests {
         assert_eq!(json["success"], false);
         assert_eq!(
             json["message"],
-            "Invalid request data: depositAmount must be positive"
+            "Stablecoin with the specified index not found"
         );
     }
 
     #[tokio::test]
-    async fn test_invalid_index() {
-        let req = make_request(99, 1_000_000);
+    async fn test_minimum_received_too_low_warns() {
+        let mut req = make_request(0, 1_000_000);
+        req.minimum_received = 0;
         let response = generate_burn_transaction(
-            Query(ClusterQuery { cluster: Some("mainnet".to_string()) }),
-            Json(req),
+            State(AppState::default()),
+            Query(ClusterQuery::default()),
+            JsonBody(req),
         )
         .await
         .into_response();
 
         let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::NOT_FOUND);
+        assert_eq!(parts.status, StatusCode::OK);
+
+        let bytes = to_bytes(body, 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+
+        assert_eq!(json["success"], true);
+        assert_eq!(json["warnings"].as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cluster_resolved_and_echoed() {
+        let call = |cluster: Option<&str>| {
+            let query = ClusterQuery { cluster: cluster.map(str::to_string), ..Default::default() };
+            generate_burn_transaction(State(AppState::default()), Query(query), JsonBody(make_request(0, 1_000_000)))
+        };
+        for (cluster, expected) in [(None, "mainnet"), (Some("devnet"), "devnet")] {
+            let (parts, body) = call(cluster).await.into_response().into_parts();
+            assert_eq!(parts.status, StatusCode::OK);
+            let json: Value = serde_json::from_slice(&to_bytes(body, 4096).await.unwrap()).unwrap();
+            assert_eq!(json["data"]["cluster"], expected);
+        }
+
+        let (parts, body) = call(Some("banana")).await.into_response().into_parts();
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        let json: Value = serde_json::from_slice(&to_bytes(body, 1024).await.unwrap()).unwrap();
+        assert_eq!(json["message"], "Unknown cluster 'banana'; expected one of mainnet, devnet");
+    }
+
+    #[tokio::test]
+    async fn test_minimum_received_boundary() {
+        let call = |minimum_received| {
+            let mut req = make_request(0, 1_000_000);
+            req.minimum_received = minimum_received;
+            generate_burn_transaction(State(AppState::default()), Query(ClusterQuery::default()), JsonBody(req))
+        };
+
+        let response = call(999_000).await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let (parts, body) = call(999_001).await.into_response().into_parts();
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
 
         let bytes = to_bytes(body, 1024).await.unwrap();
         let json: Value = serde_json::from_slice(&bytes).unwrap();
@@ -197,20 +522,26 @@ mod tests {
         assert_eq!(json["success"], false);
         assert_eq!(
             json["message"],
-            "Stablecoin with the specified index not found"
+            "quote below minimumReceived: expected 999000, required 999001"
         );
     }
 
     #[tokio::test]
-    async fn test_internal_server_error() {
-        let response = generate_burn_transaction_error().await.into_response();
-        let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    async fn test_negative_minimum_received_rejected() {
+        let mut req = make_request(0, 1_000_000);
+        req.minimum_received = -1;
+        let response = generate_burn_transaction(State(AppState::default()), Query(ClusterQuery::default()), JsonBody(req))
+            .await
+            .into_response();
 
-        let bytes = to_bytes(body, 1024).await.unwrap();
-        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        let (parts, body) = response.into_parts();
+        assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+        let json: Value = serde_json::from_slice(&to_bytes(body, 1024).await.unwrap()).unwrap();
+        assert_eq!(json["message"], "Invalid request data: minimumReceived must not be negative");
+    }
 
-        assert_eq!(json["success"], false);
-        assert_eq!(json["message"], "Internal server error");
+    #[test]
+    fn example_body_round_trips() {
+        crate::example::assert_round_trips::<BurnRequest>();
     }
 }
@@ -1,19 +1,43 @@
 use axum::{
-    extract::{Query, Json},
+    extract::{Query, Json, State},
     http::StatusCode,
-    response::IntoResponse,
+    response::{IntoResponse, Response},
 };
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::time::{Duration, Instant};
+use tracing::Instrument;
+
+use crate::amount::Amount;
+use crate::cluster::{Cluster, ClusterQuery};
+use crate::error::ApiError;
+use crate::events::store::EventVolume;
+use crate::example::{ExampleBody, EXAMPLE_COLLATERAL_MINT, EXAMPLE_SIGNER};
+use crate::json_body::JsonBody;
+use crate::span::handler_span;
+use crate::timestamp::Timestamp;
+use crate::validation::{invalid_field, malformed_field, parse_param, validate_pubkey};
+use crate::AppState;
+use super::get_mint_redeem_quote::compute_quote;
+use super::signer_proof::{verify_signer_proof, SignerProof};
+use super::slippage::{check_minimum_received, SlippageCheck};
+use super::transaction::{deserialize_pubkey, parse_pubkey, BuiltTx, Instruction, TransactionSpec, TxEncoding, TxFormat, TxVersion};
 
 /// Request structure for the `/stablecoin/mint` endpoint.
 ///
 /// ### Fields
 /// - `stablecoinIndex`: Index of the stablecoin (e.g., 0 for USDC+).
-/// - `depositAmount`: Amount to mint in smallest unit. Must be positive.
+/// - `depositAmount`: Amount to mint in smallest unit. Must be a positive whole number.
 /// - `signer`: User's Solana wallet address.
-/// - `minimumReceived`: Minimum amount to receive (slippage protection).
-/// - `collateralMint`: Optional collateral mint address.
+/// - `minimumReceived`: Minimum amount to receive (slippage protection). Must not be
+///   negative or exceed the quoted amount; values far below it are accepted with a warning.
+/// - `collateralMint`: Optional collateral mint address (defaults to the stablecoin's registered collateral).
+/// - `feePayer`: Optional account paying the transaction fee (defaults to `signer`).
+/// - `signerProof`: A [`SignerProof`] that the caller holds `signer`'s key. Required
+///   while `REFLECT_PER_USER_MINT_LIMIT` or `REFLECT_MINT_COOLDOWN_SECS` is set.
+///
+/// The snake_case spellings used by the burn endpoint (`stablecoin_index`,
+/// `deposit_amount`, ...) are accepted as aliases.
 ///
 /// ### Example Request
 /// ```json
@@ -25,66 +49,100 @@ use serde_json::json;
 ///   "collateralMint": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
 /// }
 /// ```
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MintRequest {
-    pub stablecoinIndex: u32,
-    pub depositAmount: i64,
+    #[serde(alias = "stablecoin_index")]
+    pub stablecoin_index: u32,
+    #[serde(alias = "deposit_amount")]
+    pub deposit_amount: Amount,
+    #[serde(deserialize_with = "deserialize_pubkey")]
     pub signer: String,
-    pub minimumReceived: i64,
-    pub collateralMint: Option<String>,
+    #[serde(alias = "minimum_received")]
+    pub minimum_received: i64,
+    #[serde(alias = "collateral_mint")]
+    pub collateral_mint: Option<String>,
+    #[serde(alias = "fee_payer", skip_serializing_if = "Option::is_none")]
+    pub fee_payer: Option<String>,
+    #[serde(alias = "signer_proof", skip_serializing_if = "Option::is_none")]
+    pub signer_proof: Option<SignerProof>,
 }
 
-/// Query parameter for cluster selection.
-///
-/// ### Example
-/// - `?cluster=mainnet`
-/// - `?cluster=devnet`
-#[derive(Debug, Deserialize)]
-pub struct ClusterQuery {
-    pub cluster: Option<String>,
+impl ExampleBody for MintRequest {
+    fn example() -> Self {
+        Self {
+            stablecoin_index: 0,
+            deposit_amount: Amount::new(1_000_000).unwrap(),
+            signer: EXAMPLE_SIGNER.to_string(),
+            minimum_received: 999_000,
+            collateral_mint: Some(EXAMPLE_COLLATERAL_MINT.to_string()),
+            fee_payer: None,
+            signer_proof: None,
+        }
+    }
 }
 
+/// Instruction discriminator for a Reflect mint.
+const MINT_INSTRUCTION: u8 = 0;
+
 /// Success response structure for mint transaction.
 ///
+/// `warnings` is omitted when empty.
+///
 /// ### Example Success Response (HTTP 200)
 /// ```json
 /// {
 ///   "success": true,
 ///   "data": {
-///     "transaction": "AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAED..."
-///   }
+///     "transaction": "AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAED...",
+///     "cluster": "mainnet",
+///     "simulated": true,
+///     "collateralMint": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+///     "stablecoinMint": "6k78AbasGMFFrhG95Pj6jQbqkVt7FQMhVgemxJovWKR6"
+///   },
+///   "warnings": [
+///     "minimumReceived is more than 5% below the quoted amount; slippage protection is effectively disabled"
+///   ]
 /// }
 /// ```
 #[derive(Debug, Serialize)]
 pub struct MintSuccessResponse {
     success: bool,
     data: TransactionData,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct TransactionData {
-    transaction: String,
-}
-
-/// Error response structure for mint transaction.
-///
-/// ### Example Error Response (HTTP 400/404/500)
-/// ```json
-/// {
-///   "success": false,
-///   "message": "Invalid request data: depositAmount must be positive"
-/// }
-/// ```
-#[derive(Debug, Serialize)]
-pub struct MintErrorResponse {
-    success: bool,
-    message: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transaction: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instructions: Option<Vec<Instruction>>,
+    #[serde(rename = "recentBlockhash", skip_serializing_if = "Option::is_none")]
+    recent_blockhash: Option<String>,
+    /// The cluster the transaction targets, so clients can confirm the network.
+    cluster: Cluster,
+    /// Set when the transaction was built from a placeholder program id or
+    /// blockhash (see `REFLECT_PROGRAM_ID`) or `stablecoinMint` is a
+    /// placeholder, so it cannot be submitted as is.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    simulated: bool,
+    /// The collateral deposited: the request's `collateralMint`, or the
+    /// stablecoin's registered collateral when it was omitted.
+    #[serde(rename = "collateralMint")]
+    collateral_mint: String,
+    /// The stablecoin minted, so wallets can show what is being signed.
+    #[serde(rename = "stablecoinMint")]
+    stablecoin_mint: String,
 }
 
 /// Handler for `POST /stablecoin/mint`.
 ///
-/// Supports `cluster` query parameter (`mainnet` or `devnet`).  
-/// Validates the request and returns either a simulated transaction or an error.
+/// Supports `cluster` (`mainnet` or `devnet`), `commitment` (`processed`,
+/// `confirmed` or `finalized`), `encoding` (`base64` or `base58`), `txVersion`
+/// (`legacy` or `v0`) and `format` (`transaction` or `instructions`) query parameters.  
+/// Validates the request and returns either an unsigned transaction or an error.
 ///
 /// # Examples
 ///
@@ -101,48 +159,135 @@ pub struct MintErrorResponse {
 ///   }'
 /// ```
 pub async fn generate_mint_transaction(
-    Query(cluster): Query<ClusterQuery>,
-    Json(req): Json<MintRequest>,
-) -> impl IntoResponse {
-    // Validate deposit amount
-    if req.depositAmount <= 0 {
-        let error = MintErrorResponse {
-            success: false,
-            message: "Invalid request data: depositAmount must be positive",
-        };
-        return (StatusCode::BAD_REQUEST, Json(json!(error)));
+    State(state): State<AppState>,
+    Query(query): Query<ClusterQuery>,
+    JsonBody(req): JsonBody<MintRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let span = handler_span!(
+        "POST /stablecoins/mint/tx",
+        stablecoin_index = req.stablecoin_index,
+        cluster = query.cluster.as_deref(),
+        amount = req.deposit_amount.get(),
+    );
+    mint_transaction(state, query, req).instrument(span).await
+}
+
+async fn mint_transaction(state: AppState, query: ClusterQuery, req: MintRequest) -> Result<Response, ApiError> {
+    let cluster = parse_param("cluster", query.cluster.as_deref(), Cluster::default())?;
+    let commitment = parse_param("commitment", query.commitment.as_deref(), state.config.rpc_commitment)?;
+    let encoding = parse_param("encoding", query.encoding.as_deref(), TxEncoding::default())?;
+    let format = parse_param("format", query.format.as_deref(), TxFormat::default())?;
+    let version = parse_param("txVersion", query.tx_version.as_deref(), TxVersion::default())?;
+
+    // Validate stablecoin index and availability
+    let stablecoin = state.stablecoins.ensure_available(req.stablecoin_index)?;
+
+    // The signer was checked when the body was parsed; validate the optional
+    // collateral mint and fee payer
+    let signer = parse_pubkey(&req.signer).ok_or(ApiError::Internal)?;
+    if let Some(mint) = req.collateral_mint.as_deref() {
+        validate_pubkey("collateralMint", mint)?;
     }
+    let fee_payer = req.fee_payer.as_deref().map(|fee_payer| validate_pubkey("feePayer", fee_payer)).transpose()?;
 
-    // Validate stablecoin index
-    if req.stablecoinIndex != 0 {
-        let error = MintErrorResponse {
-            success: false,
-            message: "Stablecoin with the specified index not found",
-        };
-        return (StatusCode::NOT_FOUND, Json(json!(error)));
+    // Validate slippage protection against the quoted output. A negative
+    // minimum would be encoded as a huge unsigned one on chain
+    if req.minimum_received < 0 {
+        return Err(invalid_field("minimumReceived", "Invalid request data: minimumReceived must not be negative"));
+    }
+    let mut warnings = Vec::new();
+    let quoted = compute_quote(&state.config.fees(cluster), req.deposit_amount)
+        .ok_or_else(|| invalid_field("depositAmount", "Invalid request data: depositAmount out of range"))?;
+    match check_minimum_received(quoted, req.minimum_received, state.config.min_received_tolerance_bps) {
+        SlippageCheck::Ok => {}
+        SlippageCheck::Loose(warning) => warnings.push(warning),
+        SlippageCheck::ExceedsQuote => {
+            let message = format!("quote below minimumReceived: expected {}, required {}", quoted, req.minimum_received);
+            return Err(malformed_field("minimumReceived", message));
+        }
+    }
+
+    // Fetch a recent blockhash when the cluster has an RPC endpoint configured
+    let recent_blockhash = match state.rpc.get(cluster) {
+        Some(client) => {
+            let latest = client.get_latest_blockhash(commitment).await.map_err(|e| {
+                tracing::warn!(%cluster, error = %e, "Failed to fetch recent blockhash");
+                e.to_api_error("Failed to fetch a recent blockhash from the RPC node")
+            })?;
+            Some(latest.blockhash)
+        }
+        None => None,
+    };
+
+    // Per-signer limits only count mints the signer asked for
+    if state.config.mint_cooldown_secs > 0 || state.config.per_user_mint_limit > 0 {
+        verify_signer_proof(
+            req.signer_proof.as_ref(),
+            &req.signer,
+            &signer,
+            req.stablecoin_index,
+            req.deposit_amount.get(),
+            Timestamp::now(),
+        )?;
     }
 
-    // Simulated transaction string
-    let tx = "AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAED...";
+    // Check the supply cap and cooldown before recording anything, so a
+    // rejected mint neither starts a cooldown nor counts towards the limit.
+    // The cap is only checked: building a transaction does not mint
+    if !state.supplies.fits(req.stablecoin_index, req.deposit_amount) {
+        return Err(ApiError::Unprocessable("supply cap exceeded".to_string()));
+    }
+    let cooldown = Duration::from_secs(state.config.mint_cooldown_secs);
+    let now = Instant::now();
+    if let Some(remaining) = state.mint_cooldowns.remaining(&req.signer, cooldown, now) {
+        return Err(ApiError::retry_after("mint cooldown active", remaining));
+    }
+    let limit = state.config.per_user_mint_limit;
+    if !state.mint_limits.try_record(req.stablecoin_index, &req.signer, req.deposit_amount, limit) {
+        return Err(ApiError::BadRequest("per-user mint limit exceeded".to_string()));
+    }
+    if let Err(remaining) = state.mint_cooldowns.try_start(&req.signer, cooldown, now) {
+        // A concurrent mint by the same signer started the cooldown first
+        state.mint_limits.release(req.stablecoin_index, &req.signer, req.deposit_amount);
+        return Err(ApiError::retry_after("mint cooldown active", remaining));
+    }
+
+    // Build the unsigned transaction
+    let mut instruction_data = vec![MINT_INSTRUCTION];
+    instruction_data.extend_from_slice(&req.stablecoin_index.to_le_bytes());
+    instruction_data.extend_from_slice(&req.deposit_amount.get().to_le_bytes());
+    instruction_data.extend_from_slice(&req.minimum_received.to_le_bytes());
+    let BuiltTx { transaction, instructions, simulated } = BuiltTx::build(
+        &TransactionSpec {
+            version,
+            signer,
+            fee_payer,
+            program_id: state.config.program_id_bytes(),
+            recent_blockhash: recent_blockhash.as_deref().and_then(parse_pubkey),
+            instruction_data,
+        },
+        format,
+        encoding,
+    );
+
+    let volume = EventVolume { index: req.stablecoin_index, amount: quoted as u64 };
+    state.record_event("mint", &req.signer, None, Some(volume), Timestamp::now());
 
     let response = MintSuccessResponse {
         success: true,
         data: TransactionData {
-            transaction: tx.to_string(),
+            transaction,
+            instructions,
+            recent_blockhash,
+            cluster,
+            simulated: simulated || stablecoin.is_simulated(),
+            collateral_mint: req.collateral_mint.unwrap_or(stablecoin.collateral_mint),
+            stablecoin_mint: stablecoin.mint,
         },
+        warnings,
     };
 
-    (StatusCode::OK, Json(json!(response)))
-}
-
-/// Example error handler for internal server errors.
-pub async fn generate_mint_transaction_error() -> impl IntoResponse {
-    let response = MintErrorResponse {
-        success: false,
-        message: "Internal server error",
-    };
-
-    (StatusCode::INTERNAL_SERVER_ERROR, Json(json!(response)))
+    Ok((StatusCode::OK, Json(json!(state.cased(response)))).into_response())
 }
 
 #[cfg(test)]
@@ -151,20 +296,569 @@ mod tests {
     use axum::body::to_bytes;
     use axum::http::StatusCode;
     use axum::response::IntoResponse;
+    use base64::Engine;
     use serde_json::Value;
+    use std::sync::Arc;
+
+    use crate::capture::Capture;
+    use crate::cluster::Cluster;
+    use crate::config::Config;
+    use crate::rpc::mock::MockTransport;
+    use crate::rpc::{Commitment, RpcClient, RpcClients};
+    use crate::stablecoin::mint_limits::MintCooldowns;
+    use crate::stablecoin::signer_proof::test_signer::TestSigner;
+    use crate::stablecoin::transaction::{fee_payer_of, PLACEHOLDER_PROGRAM_ID};
+
+    fn rpc_state(transport: &MockTransport, commitment: Commitment) -> AppState {
+        AppState {
+            config: Arc::new(Config {
+                rpc_commitment: commitment,
+                ..Config::default()
+            }),
+            rpc: RpcClients::default().with(RpcClient::new(Cluster::Mainnet, Arc::new(transport.clone()))),
+            ..AppState::default()
+        }
+    }
+
+    fn valid_request() -> MintRequest {
+        MintRequest {
+            stablecoin_index: 0,
+            deposit_amount: Amount::new(1_000_000).unwrap(),
+            signer: "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM".to_string(),
+            minimum_received: 999000,
+            collateral_mint: None,
+            fee_payer: None,
+            signer_proof: None,
+        }
+    }
+
+    /// A request by `signer`, with its proof.
+    fn proven_request(signer: &TestSigner, amount: u64) -> MintRequest {
+        MintRequest {
+            deposit_amount: Amount::new(amount).unwrap(),
+            signer: signer.address(),
+            minimum_received: 0,
+            signer_proof: Some(signer.prove(0, amount)),
+            ..valid_request()
+        }
+    }
+
+    async fn mint_with(state: &AppState, request: MintRequest) -> Response {
+        generate_mint_transaction(State(state.clone()), Query(ClusterQuery::default()), JsonBody(request))
+            .await
+            .into_response()
+    }
+
+    #[tokio::test]
+    async fn test_per_user_mint_limit() {
+        let state = AppState {
+            config: Arc::new(Config { per_user_mint_limit: 2_500_000, ..Config::default() }),
+            ..AppState::default()
+        };
+        let signer = TestSigner::generate();
+
+        // Up to the limit
+        assert_eq!(mint_with(&state, proven_request(&signer, 1_000_000)).await.status(), StatusCode::OK);
+        assert_eq!(mint_with(&state, proven_request(&signer, 1_500_000)).await.status(), StatusCode::OK);
+
+        // Past it
+        let (parts, body) = mint_with(&state, proven_request(&signer, 1)).await.into_parts();
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        let bytes = to_bytes(body, 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["message"], "per-user mint limit exceeded");
+        assert_eq!(state.mint_limits.minted(0, &signer.address()), 2_500_000);
+    }
+
+    #[tokio::test]
+    async fn test_unproven_mints_do_not_use_the_signers_quota() {
+        let state = AppState {
+            config: Arc::new(Config { per_user_mint_limit: 2_500_000, mint_cooldown_secs: 60, ..Config::default() }),
+            ..AppState::default()
+        };
+        let victim = TestSigner::generate();
+        let attacker = TestSigner::generate();
+
+        // Posting the victim's address without, or with someone else's, proof
+        let unproven = MintRequest { signer_proof: None, ..proven_request(&victim, 2_500_000) };
+        let forged = MintRequest { signer_proof: Some(attacker.prove(0, 2_500_000)), ..proven_request(&victim, 2_500_000) };
+        for request in [unproven, forged] {
+            let (parts, body) = mint_with(&state, request).await.into_parts();
+            assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+            let bytes = to_bytes(body, 1024).await.unwrap();
+            let json: Value = serde_json::from_slice(&bytes).unwrap();
+            assert!(json["message"].as_str().unwrap().contains("signerProof"), "{}", json);
+        }
+
+        assert_eq!(state.mint_limits.minted(0, &victim.address()), 0);
+        assert_eq!(mint_with(&state, proven_request(&victim, 2_500_000)).await.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_mint_cooldown() {
+        let state = AppState {
+            config: Arc::new(Config { mint_cooldown_secs: 60, ..Config::default() }),
+            ..AppState::default()
+        };
+        let signer = TestSigner::generate();
+
+        assert_eq!(mint_with(&state, proven_request(&signer, 1_000_000)).await.status(), StatusCode::OK);
+
+        // Blocked during the cooldown
+        let (parts, body) = mint_with(&state, proven_request(&signer, 1_000_000)).await.into_parts();
+        assert_eq!(parts.status, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(parts.headers["retry-after"], "60");
+        let bytes = to_bytes(body, 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["message"], "mint cooldown active, retry after 60s");
+
+        // Allowed once it has elapsed
+        let cooldowns = MintCooldowns::default();
+        let earlier = Instant::now() - Duration::from_secs(61);
+        cooldowns.try_start(&signer.address(), Duration::from_secs(60), earlier).unwrap();
+        let state = AppState { mint_cooldowns: cooldowns, ..state };
+        assert_eq!(mint_with(&state, proven_request(&signer, 1_000_000)).await.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_supply_cap_is_checked_not_consumed() {
+        use crate::path::Path;
+        use crate::stablecoin::get_max_mint::{get_max_mint, MaxMintQuery};
+        use crate::stablecoin::supply::{SupplyInfo, SupplyStore};
+
+        let state = AppState {
+            supplies: SupplyStore::new(vec![SupplyInfo { index: 0, supply_cap: 1_500_000, current_supply: 500_000 }]),
+            ..AppState::default()
+        };
+        let max_mintable = || async {
+            let query = MaxMintQuery { signer: Some(valid_request().signer) };
+            let response = get_max_mint(State(state.clone()), Path(0), Query(query)).await.into_response();
+            let json: Value = serde_json::from_slice(&to_bytes(response.into_body(), 2048).await.unwrap()).unwrap();
+            json["data"]["maxMintable"].as_u64().unwrap()
+        };
+        let request = |amount| MintRequest { deposit_amount: Amount::new(amount).unwrap(), minimum_received: 0, ..valid_request() };
+
+        // Two large builds in a row: neither uses up the cap
+        assert_eq!(mint_with(&state, request(1_000_000)).await.status(), StatusCode::OK);
+        assert_eq!(mint_with(&state, request(1_000_000)).await.status(), StatusCode::OK);
+        assert_eq!(state.supplies.get(0).unwrap().current_supply, 500_000);
+        assert_eq!(max_mintable().await, 1_000_000);
+
+        // Past the cap
+        let (parts, body) = mint_with(&state, request(1_000_001)).await.into_parts();
+        assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+        let json: Value = serde_json::from_slice(&to_bytes(body, 1024).await.unwrap()).unwrap();
+        assert_eq!(json["message"], "supply cap exceeded");
+    }
+
+    #[tokio::test]
+    async fn test_limit_rejection_does_not_start_the_cooldown() {
+        let state = AppState {
+            config: Arc::new(Config { per_user_mint_limit: 1_000_000, mint_cooldown_secs: 60, ..Config::default() }),
+            ..AppState::default()
+        };
+        let signer = TestSigner::generate();
+
+        let response = mint_with(&state, proven_request(&signer, 2_000_000)).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(state.mint_cooldowns.remaining(&signer.address(), Duration::from_secs(60), Instant::now()), None);
+
+        // A cooldown rejection does not count towards the limit either
+        assert_eq!(mint_with(&state, proven_request(&signer, 500_000)).await.status(), StatusCode::OK);
+        let response = mint_with(&state, proven_request(&signer, 500_000)).await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(state.mint_limits.minted(0, &signer.address()), 500_000);
+    }
+
+    #[tokio::test]
+    async fn test_commitment_threaded_into_rpc() {
+        let transport = MockTransport::new().with_blockhash("EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N", 100);
+
+        // Configured default
+        let response = generate_mint_transaction(
+            State(rpc_state(&transport, Commitment::Finalized)),
+            Query(ClusterQuery::default()),
+            JsonBody(valid_request()),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // Per-request override
+        let response = generate_mint_transaction(
+            State(rpc_state(&transport, Commitment::Finalized)),
+            Query(ClusterQuery { commitment: Some("processed".to_string()), ..Default::default() }),
+            JsonBody(valid_request()),
+        )
+        .await
+        .into_response();
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["data"]["recentBlockhash"], "EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N");
+
+        let calls = transport.calls();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].0, "getLatestBlockhash");
+        assert_eq!(calls[0].1, json!([{ "commitment": "finalized" }]));
+        assert_eq!(calls[1].1, json!([{ "commitment": "processed" }]));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_commitment_rejected() {
+        let transport = MockTransport::new().with_blockhash("EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N", 100);
+        let response = generate_mint_transaction(
+            State(rpc_state(&transport, Commitment::Confirmed)),
+            Query(ClusterQuery { commitment: Some("recent".to_string()), ..Default::default() }),
+            JsonBody(valid_request()),
+        )
+        .await
+        .into_response();
+
+        let (parts, body) = response.into_parts();
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        let bytes = to_bytes(body, 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            json["message"],
+            "Unknown commitment 'recent'; expected one of processed, confirmed, finalized"
+        );
+        assert!(transport.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_instructions_format() {
+        let response = generate_mint_transaction(
+            State(AppState::default()),
+            Query(ClusterQuery { format: Some("instructions".to_string()), ..Default::default() }),
+            JsonBody(valid_request()),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = to_bytes(response.into_body(), 2048).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert!(json["data"].get("transaction").is_none());
+
+        let instructions = json["data"]["instructions"].as_array().unwrap();
+        assert_eq!(instructions.len(), 1);
+        assert_eq!(instructions[0]["programId"], bs58::encode(PLACEHOLDER_PROGRAM_ID).into_string());
+        assert_eq!(
+            instructions[0]["keys"],
+            json!([{ "pubkey": valid_request().signer, "isSigner": true, "isWritable": true }])
+        );
+
+        // Discriminator, stablecoin index, deposit amount, minimum received
+        let data = base64::engine::general_purpose::STANDARD
+            .decode(instructions[0]["data"].as_str().unwrap())
+            .unwrap();
+        assert_eq!(data.len(), 1 + 4 + 8 + 8);
+        assert_eq!(data[0], MINT_INSTRUCTION);
+        assert_eq!(&data[5..13], &1_000_000i64.to_le_bytes());
+
+        let response = generate_mint_transaction(
+            State(AppState::default()),
+            Query(ClusterQuery { format: Some("ix".to_string()), ..Default::default() }),
+            JsonBody(valid_request()),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_encodings_decode_to_same_bytes() {
+        let mut transactions = Vec::new();
+        for encoding in ["base64", "base58"] {
+            let response = generate_mint_transaction(
+                State(AppState::default()),
+                Query(ClusterQuery { encoding: Some(encoding.to_string()), ..Default::default() }),
+                JsonBody(valid_request()),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+            let json: Value = serde_json::from_slice(&bytes).unwrap();
+            transactions.push(json["data"]["transaction"].as_str().unwrap().to_string());
+        }
+
+        let from_base64 = base64::engine::general_purpose::STANDARD.decode(&transactions[0]).unwrap();
+        let from_base58 = bs58::decode(&transactions[1]).into_vec().unwrap();
+        assert_eq!(from_base64, from_base58);
+    }
+
+    #[tokio::test]
+    async fn test_tx_version_changes_prefix() {
+        let mut transactions = Vec::new();
+        for version in [None, Some("legacy"), Some("v0")] {
+            let response = generate_mint_transaction(
+                State(AppState::default()),
+                Query(ClusterQuery { tx_version: version.map(String::from), ..Default::default() }),
+                JsonBody(valid_request()),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+            let json: Value = serde_json::from_slice(&bytes).unwrap();
+            let tx = json["data"]["transaction"].as_str().unwrap();
+            transactions.push(base64::engine::general_purpose::STANDARD.decode(tx).unwrap());
+        }
+
+        // Message starts after the signature count and one 64-byte signature slot
+        let message_start = 1 + 64;
+        assert_eq!(transactions[0], transactions[1]);
+        assert_eq!(transactions[1][message_start], 1);
+        assert_eq!(transactions[2][message_start], 0x80);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_tx_version_rejected() {
+        let response = generate_mint_transaction(
+            State(AppState::default()),
+            Query(ClusterQuery { tx_version: Some("v1".to_string()), ..Default::default() }),
+            JsonBody(valid_request()),
+        )
+        .await
+        .into_response();
+
+        let (parts, body) = response.into_parts();
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        let bytes = to_bytes(body, 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["message"], "Unknown txVersion 'v1'; expected one of legacy, v0");
+    }
+
+    async fn built_transaction(req: MintRequest) -> Vec<u8> {
+        let response = generate_mint_transaction(
+            State(AppState::default()),
+            Query(ClusterQuery::default()),
+            JsonBody(req),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        let tx = json["data"]["transaction"].as_str().unwrap();
+        base64::engine::general_purpose::STANDARD.decode(tx).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fee_payer_override() {
+        let sponsor = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+        let tx = built_transaction(MintRequest {
+            fee_payer: Some(sponsor.to_string()),
+            ..valid_request()
+        })
+        .await;
+        assert_eq!(fee_payer_of(&tx), parse_pubkey(sponsor).unwrap());
+
+        let tx = built_transaction(valid_request()).await;
+        assert_eq!(fee_payer_of(&tx), parse_pubkey(&valid_request().signer).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_fee_payer_rejected() {
+        let req = MintRequest {
+            fee_payer: Some("0OIl".to_string()),
+            ..valid_request()
+        };
+        let response = generate_mint_transaction(
+            State(AppState::default()),
+            Query(ClusterQuery::default()),
+            JsonBody(req),
+        )
+        .await
+        .into_response();
+
+        let (parts, body) = response.into_parts();
+        assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let bytes = to_bytes(body, 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["message"], "Invalid request data: feePayer is not a valid public key");
+    }
+
+    #[tokio::test]
+    async fn test_invalid_encoding_rejected() {
+        let response = generate_mint_transaction(
+            State(AppState::default()),
+            Query(ClusterQuery { encoding: Some("hex".to_string()), ..Default::default() }),
+            JsonBody(valid_request()),
+        )
+        .await
+        .into_response();
+
+        let (parts, body) = response.into_parts();
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        let bytes = to_bytes(body, 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["message"], "Unknown encoding 'hex'; expected one of base64, base58");
+    }
+
+    #[tokio::test]
+    async fn test_cluster_resolved_and_echoed() {
+        let call = |cluster: Option<&str>| {
+            let query = ClusterQuery { cluster: cluster.map(str::to_string), ..Default::default() };
+            generate_mint_transaction(State(AppState::default()), Query(query), JsonBody(valid_request()))
+        };
+        for (cluster, expected) in [(None, "mainnet"), (Some("devnet"), "devnet")] {
+            let (parts, body) = call(cluster).await.into_response().into_parts();
+            assert_eq!(parts.status, StatusCode::OK);
+            let json: Value = serde_json::from_slice(&to_bytes(body, 4096).await.unwrap()).unwrap();
+            assert_eq!(json["data"]["cluster"], expected);
+        }
+
+        let (parts, body) = call(Some("banana")).await.into_response().into_parts();
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        let json: Value = serde_json::from_slice(&to_bytes(body, 1024).await.unwrap()).unwrap();
+        assert_eq!(json["message"], "Unknown cluster 'banana'; expected one of mainnet, devnet");
+    }
+
+    #[tokio::test]
+    async fn test_slippage_checked_against_cluster_fee() {
+        let state = AppState::new(Config { protocol_fee_bps_devnet: Some(0), ..Config::default() });
+        let call = |cluster: &str| {
+            let query = ClusterQuery { cluster: Some(cluster.to_string()), ..Default::default() };
+            let req = MintRequest { minimum_received: 1_000_000, ..valid_request() };
+            generate_mint_transaction(State(state.clone()), Query(query), JsonBody(req))
+        };
+
+        assert_eq!(call("devnet").await.into_response().status(), StatusCode::OK);
+        assert_eq!(call("mainnet").await.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_negative_minimum_received_rejected() {
+        let req = MintRequest { minimum_received: -1, ..valid_request() };
+        let response = generate_mint_transaction(State(AppState::default()), Query(ClusterQuery::default()), JsonBody(req))
+            .await
+            .into_response();
+
+        let (parts, body) = response.into_parts();
+        assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+        let json: Value = serde_json::from_slice(&to_bytes(body, 1024).await.unwrap()).unwrap();
+        assert_eq!(json["message"], "Invalid request data: minimumReceived must not be negative");
+    }
+
+    #[tokio::test]
+    async fn test_invalid_signer_rejected() {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        let app = axum::Router::new()
+            .route("/", axum::routing::post(generate_mint_transaction))
+            .with_state(AppState::default());
+        let mut body = serde_json::to_value(valid_request()).unwrap();
+        body["signer"] = json!("not-a-pubkey");
+        let request = Request::post("/")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["message"], "field `signer` is invalid: not base58");
+    }
+
+    #[tokio::test]
+    async fn test_collateral_mint_must_be_pubkey() {
+        let request = |mint: &str| MintRequest {
+            collateral_mint: Some(mint.to_string()),
+            ..valid_request()
+        };
+        let call = |req| generate_mint_transaction(State(AppState::default()), Query(ClusterQuery::default()), JsonBody(req));
+
+        let response = call(request("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v")).await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let (parts, body) = call(request("test_mint")).await.into_response().into_parts();
+        assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+        let bytes = to_bytes(body, 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["message"], "Invalid request data: collateralMint is not a valid public key");
+    }
+
+    #[tokio::test]
+    async fn test_resolved_mints_in_response() {
+        use crate::stablecoin::registry::{USDC_MINT, USDC_PLUS_MINT};
+
+        let call = |collateral_mint: Option<&str>| {
+            let req = MintRequest { collateral_mint: collateral_mint.map(str::to_string), ..valid_request() };
+            generate_mint_transaction(State(AppState::default()), Query(ClusterQuery::default()), JsonBody(req))
+        };
+
+        // Omitted collateral falls back to the stablecoin's registered one
+        let (parts, body) = call(None).await.into_response().into_parts();
+        assert_eq!(parts.status, StatusCode::OK);
+        let json: Value = serde_json::from_slice(&to_bytes(body, 4096).await.unwrap()).unwrap();
+        assert_eq!(json["data"]["collateralMint"], USDC_MINT);
+        assert_eq!(json["data"]["stablecoinMint"], USDC_PLUS_MINT);
+        assert_eq!(json["data"]["simulated"], true);
+        assert!(json["data"]["transaction"].is_string());
+
+        let other = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB";
+        let (_, body) = call(Some(other)).await.into_response().into_parts();
+        let json: Value = serde_json::from_slice(&to_bytes(body, 4096).await.unwrap()).unwrap();
+        assert_eq!(json["data"]["collateralMint"], other);
+        assert_eq!(json["data"]["stablecoinMint"], USDC_PLUS_MINT);
+    }
+
+    #[tokio::test]
+    async fn test_accepts_both_casings() {
+        for body in [
+            json!({
+                "stablecoinIndex": 0,
+                "depositAmount": 1_000_000,
+                "signer": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+                "minimumReceived": 999000,
+                "feePayer": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+            }),
+            json!({
+                "stablecoin_index": 0,
+                "deposit_amount": 1_000_000,
+                "signer": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+                "minimum_received": 999000,
+                "fee_payer": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+            }),
+        ] {
+            let req: MintRequest = serde_json::from_value(body).unwrap();
+            assert_eq!(req.deposit_amount.get(), 1_000_000);
+            assert!(req.fee_payer.is_some());
+
+            let response = generate_mint_transaction(State(AppState::default()), Query(ClusterQuery::default()), JsonBody(req))
+                .await
+                .into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+    }
 
     #[tokio::test]
     async fn test_mint_success() {
         let req = MintRequest {
-            stablecoinIndex: 0,
-            depositAmount: 1_000_000,
-            signer: "test_signer".to_string(),
-            minimumReceived: 999000,
-            collateralMint: Some("test_mint".to_string()),
+            stablecoin_index: 0,
+            deposit_amount: Amount::new(1_000_000).unwrap(),
+            signer: "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM".to_string(),
+            minimum_received: 999000,
+            collateral_mint: Some("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string()),
+            fee_payer: None,
+            signer_proof: None,
         };
         let response = generate_mint_transaction(
-            Query(ClusterQuery { cluster: Some("mainnet".to_string()) }),
-            Json(req),
+            State(AppState::default()),
+            Query(ClusterQuery { cluster: Some("mainnet".to_string()), ..Default::default() }),
+            JsonBody(req),
         )
         .await
         .into_response();
@@ -177,25 +871,57 @@ mod tests {
 
         assert_eq!(json["success"], Value::Bool(true));
         assert!(json["data"]["transaction"].is_string());
+        assert!(json.get("warnings").is_none());
     }
 
     #[tokio::test]
-    async fn test_invalid_deposit_amount() {
+    async fn test_minimum_received_too_low_warns() {
         let req = MintRequest {
-            stablecoinIndex: 0,
-            depositAmount: -100,
-            signer: "test_signer".to_string(),
-            minimumReceived: 999000,
-            collateralMint: None,
+            stablecoin_index: 0,
+            deposit_amount: Amount::new(1_000_000).unwrap(),
+            signer: "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM".to_string(),
+            minimum_received: 1,
+            collateral_mint: None,
+            fee_payer: None,
+            signer_proof: None,
         };
         let response = generate_mint_transaction(
-            Query(ClusterQuery { cluster: Some("mainnet".to_string()) }),
-            Json(req),
+            State(AppState::default()),
+            Query(ClusterQuery::default()),
+            JsonBody(req),
         )
         .await
         .into_response();
 
         let (parts, body) = response.into_parts();
+        assert_eq!(parts.status, StatusCode::OK);
+
+        let bytes = to_bytes(body, 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+
+        assert_eq!(json["success"], Value::Bool(true));
+        assert_eq!(json["warnings"].as_array().unwrap().len(), 1);
+        assert!(json["warnings"][0]
+            .as_str()
+            .unwrap()
+            .contains("slippage protection is effectively disabled"));
+    }
+
+    #[tokio::test]
+    async fn test_minimum_received_boundary() {
+        let call = |minimum_received| {
+            let req = MintRequest {
+                minimum_received,
+                ..valid_request()
+            };
+            generate_mint_transaction(State(AppState::default()), Query(ClusterQuery::default()), JsonBody(req))
+        };
+
+        // 1_000_000 less the 0.1% fee quotes 999_000
+        let response = call(999_000).await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let (parts, body) = call(999_001).await.into_response().into_parts();
         assert_eq!(parts.status, StatusCode::BAD_REQUEST);
 
         let bytes = to_bytes(body, 1024).await.unwrap();
@@ -204,49 +930,194 @@ mod tests {
         assert_eq!(json["success"], Value::Bool(false));
         assert_eq!(
             json["message"],
-            Value::String("Invalid request data: depositAmount must be positive".into())
+            Value::String("quote below minimumReceived: expected 999000, required 999001".into())
         );
     }
 
+    #[test]
+    fn test_fractional_deposit_amount_does_not_deserialize() {
+        let body = json!({
+            "stablecoinIndex": 0,
+            "depositAmount": 1000000.5,
+            "signer": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+            "minimumReceived": 999000
+        });
+        assert!(serde_json::from_value::<MintRequest>(body).is_err());
+    }
+
     #[tokio::test]
-    async fn test_invalid_index() {
-        let req = MintRequest {
-            stablecoinIndex: 99,
-            depositAmount: 1_000_000,
-            signer: "test_signer".to_string(),
-            minimumReceived: 999000,
-            collateralMint: None,
+    async fn test_validation_failure_logs_field() {
+        let (capture, _guard) = Capture::install();
+
+        let req = MintRequest { fee_payer: Some("not-a-key".to_string()), ..valid_request() };
+        let response = generate_mint_transaction(State(AppState::default()), Query(ClusterQuery::default()), JsonBody(req))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let events = capture.events();
+        let event = events
+            .iter()
+            .find(|event| event.field("field").is_some())
+            .expect("validation failure was not logged");
+        assert_eq!(event.field("field"), Some("feePayer"));
+        assert!(event.scope.iter().any(|name| name == "POST /stablecoins/mint/tx"));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_deposit_amount() {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        for (amount, reason) in [(json!(-100), "must be positive"), (json!(1e20), "out of range")] {
+            let app = axum::Router::new()
+                .route("/", axum::routing::post(generate_mint_transaction))
+                .with_state(AppState::default());
+            let mut body = serde_json::to_value(valid_request()).unwrap();
+            body["depositAmount"] = amount;
+            let request = Request::post("/?cluster=mainnet")
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap();
+            let response = app.oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+            let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+            let json: Value = serde_json::from_slice(&bytes).unwrap();
+            assert_eq!(json["success"], Value::Bool(false));
+            assert_eq!(json["message"], format!("field `depositAmount` is invalid: {}", reason));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_malformed_body_is_400_and_invalid_amount_is_422() {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        let send = |body: &'static str| {
+            let app = axum::Router::new()
+                .route("/", axum::routing::post(generate_mint_transaction))
+                .with_state(AppState::default());
+            let request = Request::post("/")
+                .header("content-type", "application/json")
+                .body(Body::from(body))
+                .unwrap();
+            async move { app.oneshot(request).await.unwrap().status() }
+        };
+
+        assert_eq!(send(r#"{"stablecoinIndex": 0,"#).await, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            send(r#"{"stablecoinIndex": 0, "depositAmount": -5, "signer": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM", "minimumReceived": 0}"#).await,
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_and_invalid_signer_are_distinguished() {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        let send = |body: &'static str| {
+            let app = axum::Router::new()
+                .route("/", axum::routing::post(generate_mint_transaction))
+                .with_state(AppState::default());
+            let request = Request::post("/")
+                .header("content-type", "application/json")
+                .body(Body::from(body))
+                .unwrap();
+            async move {
+                let response = app.oneshot(request).await.unwrap();
+                let status = response.status();
+                let json: Value = serde_json::from_slice(&to_bytes(response.into_body(), usize::MAX).await.unwrap()).unwrap();
+                (status, json["message"].clone())
+            }
         };
+
+        let (status, message) = send(r#"{"stablecoinIndex": 0, "depositAmount": 1000000, "minimumReceived": 0}"#).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(message, "field `signer` is required");
+
+        let (status, message) =
+            send(r#"{"stablecoinIndex": 0, "depositAmount": 1000000, "signer": "0OIl", "minimumReceived": 0}"#).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(message, "field `signer` is invalid: not base58");
+
+        let (status, message) =
+            send(r#"{"stablecoinIndex": 0, "depositAmount": 1000000, "signer": "abc", "minimumReceived": 0}"#).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(message, "field `signer` is invalid: not a 32-byte public key");
+    }
+
+    #[tokio::test]
+    async fn test_disabled_stablecoin() {
+        let state = AppState::default();
+        state.stablecoins.set_enabled(0, false);
+
         let response = generate_mint_transaction(
-            Query(ClusterQuery { cluster: Some("mainnet".to_string()) }),
-            Json(req),
+            State(state.clone()),
+            Query(ClusterQuery::default()),
+            JsonBody(valid_request()),
         )
         .await
         .into_response();
 
         let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::NOT_FOUND);
+        assert_eq!(parts.status, StatusCode::SERVICE_UNAVAILABLE);
 
         let bytes = to_bytes(body, 1024).await.unwrap();
         let json: Value = serde_json::from_slice(&bytes).unwrap();
-
         assert_eq!(json["success"], Value::Bool(false));
-        assert_eq!(
-            json["message"],
-            Value::String("Stablecoin with the specified index not found".into())
-        );
+        assert_eq!(json["message"], Value::String("stablecoin temporarily disabled".into()));
+
+        // Re-enabling restores minting
+        state.stablecoins.set_enabled(0, true);
+        let response = generate_mint_transaction(
+            State(state),
+            Query(ClusterQuery::default()),
+            JsonBody(valid_request()),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
     }
 
     #[tokio::test]
-    async fn test_internal_server_error() {
-        let response = generate_mint_transaction_error().await.into_response();
+    async fn test_invalid_index() {
+        let req = MintRequest {
+            stablecoin_index: 99,
+            deposit_amount: Amount::new(1_000_000).unwrap(),
+            signer: "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM".to_string(),
+            minimum_received: 999000,
+            collateral_mint: None,
+            fee_payer: None,
+            signer_proof: None,
+        };
+        let response = generate_mint_transaction(
+            State(AppState::default()),
+            Query(ClusterQuery { cluster: Some("mainnet".to_string()), ..Default::default() }),
+            JsonBody(req),
+        )
+        .await
+        .into_response();
+
         let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
 
         let bytes = to_bytes(body, 1024).await.unwrap();
         let json: Value = serde_json::from_slice(&bytes).unwrap();
 
         assert_eq!(json["success"], Value::Bool(false));
-        assert_eq!(json["message"], Value::String("Internal server error".into()));
+        assert_eq!(
+            json["message"],
+            Value::String("Stablecoin with the specified index not found".into())
+        );
+    }
+
+    #[test]
+    fn example_body_round_trips() {
+        crate::example::assert_round_trips::<MintRequest>();
     }
 }
@@ -1,10 +1,20 @@
 use axum::{
+    extract::{Query, State},
     http::StatusCode,
-    response::IntoResponse,
+    response::{IntoResponse, Response},
     Json,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use tracing::Instrument;
+
+use crate::data_source::DataSource;
+use crate::error::ApiError;
+use crate::pagination::{paginate, PageMeta, PageParams};
+use crate::span::handler_span;
+use crate::timestamp::Timestamp;
+use crate::upstream::fetch_or_fallback;
+use crate::AppState;
 
 /// APY data structure for a stablecoin.
 ///
@@ -21,11 +31,11 @@ use serde_json::json;
 ///   "timestamp": "2025-12-19T16:55:42.407Z"
 /// }
 /// ```
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ApyData {
     pub index: u32,
     pub apy: i64,
-    pub timestamp: String,
+    pub timestamp: Timestamp,
 }
 
 /// Success response structure for APY retrieval.
@@ -40,33 +50,23 @@ pub struct ApyData {
 ///       "apy": 224,
 ///       "timestamp": "2025-12-19T16:55:42.407Z"
 ///     }
-///   ]
+///   ],
+///   "meta": { "total": 1, "limit": 100, "offset": 0, "has_more": false }
 /// }
 /// ```
 #[derive(Debug, Serialize)]
 pub struct ApySuccessResponse {
     pub success: bool,
     pub data: Vec<ApyData>,
-}
-
-/// Error response structure for APY retrieval.
-///
-/// ### Example Error Response (HTTP 404/500)
-/// ```json
-/// {
-///   "success": false,
-///   "message": "Internal server error"
-/// }
-/// ```
-#[derive(Debug, Serialize)]
-pub struct ApyErrorResponse {
-    pub success: bool,
-    pub message: &'static str,
+    pub meta: PageMeta,
 }
 
 /// Handler for `GET /stablecoin/apy`.
 ///
-/// Returns simulated APY data or an error.
+/// Returns the latest APY of every stablecoin, proxied from the live Reflect
+/// API when `REFLECT_UPSTREAM_URL` is set and from the shared APY store
+/// otherwise (or when the upstream is unreachable). `X-Data-Source` says which.
+/// Supports the standard `limit` / `offset` paging parameters.
 ///
 /// # Example
 ///
@@ -74,43 +74,29 @@ pub struct ApyErrorResponse {
 /// curl --request GET \
 ///   --url http://localhost:3000/stablecoin/apy
 /// ```
-pub async fn get_all_apy() -> impl IntoResponse {
-    // Simulated APY data
-    let apy_data = vec![ApyData {
-        index: 0,
-        apy: 224,
-        timestamp: "2025-12-19T16:55:42.407Z".to_string(),
-    }];
-
-    (
-        StatusCode::OK,
-        Json(json!(ApySuccessResponse {
-            success: true,
-            data: apy_data,
-        })),
-    )
+pub async fn get_all_apy(State(state): State<AppState>, Query(page): Query<PageParams>) -> Result<impl IntoResponse, ApiError> {
+    let span = handler_span!("GET /stablecoins/apy");
+    all_apy(state, page).instrument(span).await
 }
 
-/// Example error handler for not found.
-pub async fn get_all_apy_not_found() -> impl IntoResponse {
-    (
-        StatusCode::NOT_FOUND,
-        Json(json!(ApyErrorResponse {
-            success: false,
-            message: "Invalid request data: depositAmount must be positive",
-        })),
-    )
-}
+async fn all_apy(state: AppState, page: PageParams) -> Result<Response, ApiError> {
+    let page_query = page.parse()?;
+    let (apys, source) = match fetch_or_fallback::<Vec<ApyData>>(state.upstream.as_ref(), "/stablecoin/apy").await {
+        Some(apys) => (apys, DataSource::Upstream),
+        None => (state.apys.latest(), DataSource::Local),
+    };
+    let page = paginate(apys, &page_query);
 
-/// Example error handler for internal server errors.
-pub async fn get_all_apy_error() -> impl IntoResponse {
-    (
-        StatusCode::INTERNAL_SERVER_ERROR,
-        Json(json!(ApyErrorResponse {
-            success: false,
-            message: "Internal server error",
-        })),
+    Ok((
+        StatusCode::OK,
+        source,
+        Json(json!(state.cased(ApySuccessResponse {
+            success: true,
+            meta: page.meta(),
+            data: page.items,
+        }))),
     )
+        .into_response())
 }
 
 #[cfg(test)]
@@ -120,9 +106,13 @@ mod tests {
     use axum::response::IntoResponse;
     use serde_json::Value;
 
+    use crate::upstream;
+
     #[tokio::test]
     async fn test_get_all_apy_success() {
-        let response = get_all_apy().await.into_response();
+        let response = get_all_apy(State(AppState::default()), Query(PageParams::default()))
+            .await
+            .into_response();
 
         let (parts, body) = response.into_parts();
         assert_eq!(parts.status, StatusCode::OK);
@@ -136,31 +126,19 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_get_all_apy_not_found() {
-        let response = get_all_apy_not_found().await.into_response();
-        let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::NOT_FOUND);
-
-        let bytes = to_bytes(body, 1024).await.unwrap();
-        let json: Value = serde_json::from_slice(&bytes).unwrap();
-
-        assert_eq!(json["success"], false);
-        assert_eq!(
-            json["message"],
-            "Invalid request data: depositAmount must be positive"
-        );
-    }
-
-    #[tokio::test]
-    async fn test_get_all_apy_internal_error() {
-        let response = get_all_apy_error().await.into_response();
-        let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
-
-        let bytes = to_bytes(body, 1024).await.unwrap();
-        let json: Value = serde_json::from_slice(&bytes).unwrap();
-
-        assert_eq!(json["success"], false);
-        assert_eq!(json["message"], "Internal server error");
+    async fn test_get_all_apy_from_upstream_and_fallback() {
+        let live = json!([{ "index": 0, "apy": 512, "timestamp": "2026-02-01T00:00:00.000Z" }]);
+        let upstream = upstream::tests::serve("/stablecoin/apy", json!({ "success": true, "data": live })).await;
+
+        for (upstream, source, apy) in [(upstream, "upstream", 512), (upstream::tests::unreachable(), "local", 224)] {
+            let state = AppState { upstream: Some(upstream), ..AppState::default() };
+            let response = get_all_apy(State(state), Query(PageParams::default())).await.into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.headers()["x-data-source"], source);
+
+            let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+            let json: Value = serde_json::from_slice(&bytes).unwrap();
+            assert_eq!(json["data"][0]["apy"], apy);
+        }
     }
 }
@@ -0,0 +1,206 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::error::ApiError;
+use crate::finite;
+use crate::span::handler_span;
+use crate::AppState;
+
+/// Aggregate APY statistics across every stablecoin, in basis points.
+///
+/// All statistics are `null` (and `count` is `0`) when no stablecoin has an APY.
+/// `median` averages the two middle values for an even count.
+/// `weighted_average` weights each APY by the stablecoin's current supply so
+/// tiny stablecoins don't skew it; it is `null` when no supply is outstanding.
+///
+/// ### Example
+/// ```json
+/// {
+///   "count": 3,
+///   "min": 180,
+///   "max": 310,
+///   "average": 238.0,
+///   "weighted_average": 226.5,
+///   "median": 224.0
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ApySummary {
+    pub count: usize,
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+    #[serde(serialize_with = "finite::serialize_opt")]
+    pub average: Option<f64>,
+    #[serde(serialize_with = "finite::serialize_opt")]
+    pub weighted_average: Option<f64>,
+    #[serde(serialize_with = "finite::serialize_opt")]
+    pub median: Option<f64>,
+}
+
+impl ApySummary {
+    /// Summarize `(apy, current_supply)` pairs, one per stablecoin.
+    pub fn from_apys(entries: Vec<(i64, u64)>) -> Self {
+        let total_supply: u128 = entries.iter().map(|&(_, supply)| supply as u128).sum();
+        let weighted_average = (total_supply > 0).then(|| {
+            let weighted: f64 = entries.iter().map(|&(apy, supply)| apy as f64 * supply as f64).sum();
+            weighted / total_supply as f64
+        });
+
+        let mut apys: Vec<i64> = entries.into_iter().map(|(apy, _)| apy).collect();
+        apys.sort_unstable();
+        let count = apys.len();
+        let median = match count {
+            0 => None,
+            n if n % 2 == 1 => Some(apys[n / 2] as f64),
+            n => Some((apys[n / 2 - 1] as f64 + apys[n / 2] as f64) / 2.0),
+        };
+
+        Self {
+            count,
+            min: apys.first().copied(),
+            max: apys.last().copied(),
+            average: (count > 0).then(|| apys.iter().map(|&apy| apy as f64).sum::<f64>() / count as f64),
+            weighted_average,
+            median,
+        }
+    }
+}
+
+/// Handler for `GET /stablecoins/apy/summary`.
+///
+/// Summarizes the latest APY of every stablecoin as min / max / average /
+/// supply-weighted average / median. Stablecoins without supply figures count
+/// towards every statistic except the weighted average.
+///
+/// ### Example Success Response (HTTP 200)
+/// ```json
+/// {
+///   "success": true,
+///   "data": {
+///     "count": 1,
+///     "min": 224,
+///     "max": 224,
+///     "average": 224.0,
+///     "weighted_average": 224.0,
+///     "median": 224.0
+///   }
+/// }
+/// ```
+///
+/// # Example
+///
+/// ```bash
+/// curl --request GET \
+///   --url http://localhost:3000/stablecoins/apy/summary
+/// ```
+pub async fn get_apy_summary(State(state): State<AppState>) -> Result<impl IntoResponse, ApiError> {
+    let _span = handler_span!("GET /stablecoins/apy/summary").entered();
+    let apys = state
+        .apys
+        .latest()
+        .into_iter()
+        .map(|data| {
+            let supply = state.supplies.get(data.index).map_or(0, |supply| supply.current_supply);
+            (data.apy, supply)
+        })
+        .collect();
+
+    let data = finite::to_json(&state.cased(ApySummary::from_apys(apys)))?;
+    Ok((StatusCode::OK, Json(json!({ "success": true, "data": data }))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use serde_json::Value;
+
+    use crate::seed::Seed;
+    use crate::stablecoin::get_all_apy::ApyData;
+    use crate::stablecoin::supply::SupplyInfo;
+    use crate::timestamp::Timestamp;
+
+    /// Seed one stablecoin per `(apy, current_supply)` pair.
+    async fn summary(entries: &[(i64, u64)]) -> Value {
+        let seed = Seed {
+            apys: entries
+                .iter()
+                .enumerate()
+                .map(|(index, &(apy, _))| ApyData {
+                    index: index as u32,
+                    apy,
+                    timestamp: Timestamp::parse_rfc3339("2026-01-01T00:00:00.000Z").unwrap(),
+                })
+                .collect(),
+            supplies: entries
+                .iter()
+                .enumerate()
+                .map(|(index, &(_, current_supply))| SupplyInfo {
+                    index: index as u32,
+                    supply_cap: u64::MAX,
+                    current_supply,
+                })
+                .collect(),
+            ..Seed::default()
+        };
+        let response = get_apy_summary(State(AppState::with_seed(seed))).await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        json["data"].clone()
+    }
+
+    #[tokio::test]
+    async fn test_summary_of_seeded_apys() {
+        let data = summary(&[(310, 1), (180, 1), (224, 1), (250, 1)]).await;
+        assert_eq!(
+            data,
+            json!({
+                "count": 4,
+                "min": 180,
+                "max": 310,
+                "average": 241.0,
+                "weighted_average": 241.0,
+                "median": 237.0
+            })
+        );
+
+        let data = summary(&[(310, 1), (180, 1), (224, 1)]).await;
+        assert_eq!(data["median"], 224.0);
+    }
+
+    #[tokio::test]
+    async fn test_summary_without_apys() {
+        let data = summary(&[]).await;
+        assert_eq!(
+            data,
+            json!({
+                "count": 0,
+                "min": null,
+                "max": null,
+                "average": null,
+                "weighted_average": null,
+                "median": null
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_weighting_by_supply() {
+        // A tiny stablecoin with a high APY barely moves the weighted average
+        let data = summary(&[(200, 9_900), (1_200, 100)]).await;
+        assert_eq!(data["average"], 700.0);
+        assert_eq!(data["weighted_average"], 210.0);
+
+        let data = summary(&[(200, 0), (1_200, 0)]).await;
+        assert_eq!(data["average"], 700.0);
+        assert_eq!(data["weighted_average"], Value::Null);
+    }
+}
@@ -1,7 +1,14 @@
+use axum::extract::{Query, State};
 use axum::response::{IntoResponse, Json};
 use axum::http::StatusCode;
 use serde::Serialize;
 
+use crate::data_source::DataSource;
+use crate::error::ApiError;
+use crate::pagination::{paginate, PageMeta, PageParams};
+use crate::span::handler_span;
+use crate::AppState;
+
 /// Response structure for the `/stablecoin/types` endpoint, matching the official Reflect API.
 ///
 /// ### Success Response (HTTP 200)
@@ -11,9 +18,14 @@ use serde::Serialize;
 ///   "data": [
 ///     {
 ///       "index": 0,
-///       "name": "USDC+"
+///       "name": "USDC+",
+///       "mint": "6k78AbasGMFFrhG95Pj6jQbqkVt7FQMhVgemxJovWKR6",
+///       "decimals": 6,
+///       "enabled": true,
+///       "simulated": true
 ///     }
-///   ]
+///   ],
+///   "meta": { "total": 1, "limit": 100, "offset": 0, "has_more": false }
 /// }
 /// ```
 ///
@@ -37,75 +49,92 @@ use serde::Serialize;
 /// {
 ///   "success": true,
 ///   "data": [
-///     { "index": 0, "name": "USDC+" }
-///   ]
+///     {
+///       "index": 0,
+///       "name": "USDC+",
+///       "mint": "6k78AbasGMFFrhG95Pj6jQbqkVt7FQMhVgemxJovWKR6",
+///       "decimals": 6,
+///       "enabled": true,
+///       "simulated": true
+///     }
+///   ],
+///   "meta": { "total": 1, "limit": 100, "offset": 0, "has_more": false }
 /// }
 /// ```
 #[derive(Debug, Serialize)]
 pub struct StablecoinSuccessResponse {
     success: bool,
     data: Vec<Stablecoin>,
+    meta: PageMeta,
 }
 
 #[derive(Debug, Serialize)]
 pub struct Stablecoin {
     index: u32,
     name: String,
-}
-
-#[derive(Debug, Serialize)]
-pub struct StablecoinErrorResponse {
-    success: bool,
-    message: &'static str,
+    mint: String,
+    decimals: u8,
+    enabled: bool,
+    /// Set when `mint` is a placeholder rather than the deployed token mint.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    simulated: bool,
 }
 
 /// Handler for `GET /stablecoin/types`.
 ///
-/// Returns a JSON response with available stablecoins.  
-/// In this scaffold, we always return a successful response with a static list.
-/// In production, you would query a database or external service.
+/// Returns a JSON response with the stablecoins in the registry.  
+/// Stablecoins are always ordered by `index` ascending. Disabled stablecoins
+/// are still listed, with `enabled: false`.
+/// Supports the standard `limit` / `offset` paging parameters.
 ///
 /// # Examples
 ///
-/// ```
-/// use reflect_api_rs::stablecoin::get_available_stablecoins;
-/// use axum::response::IntoResponse;
+/// ```no_run
+/// use axum::{body::Body, http::Request};
+/// use reflect_api_rs::{build_app, AppState};
+/// use tower::ServiceExt;
 ///
-/// # tokio_test::block_on(async {
-/// let response = get_available_stablecoins().await.into_response();
+/// # async fn example() {
+/// let request = Request::get("/stablecoins/").body(Body::empty()).unwrap();
+/// let response = build_app(AppState::default()).oneshot(request).await.unwrap();
 /// assert_eq!(response.status(), axum::http::StatusCode::OK);
-/// # });
+/// # }
 /// ```
-pub async fn get_available_stablecoins() -> impl IntoResponse {
-    // Only USDC+ is available in this scaffold
-    let stablecoins = vec![Stablecoin {
-        index: 0,
-        name: "USDC+".to_string(),
-    }];
+pub async fn get_available_stablecoins(
+    State(state): State<AppState>,
+    Query(page): Query<PageParams>,
+) -> Result<impl IntoResponse, ApiError> {
+    let _span = handler_span!("GET /stablecoins").entered();
+    let page_query = page.parse()?;
+    // `StablecoinRegistry::list` is already ordered by index
+    let stablecoins: Vec<Stablecoin> = state
+        .stablecoins
+        .list()
+        .into_iter()
+        .map(|info| Stablecoin {
+            simulated: info.is_simulated(),
+            index: info.index,
+            name: info.name,
+            mint: info.mint,
+            decimals: info.decimals,
+            enabled: info.enabled,
+        })
+        .collect();
+    let page = paginate(stablecoins, &page_query);
 
     let response = StablecoinSuccessResponse {
         success: true,
-        data: stablecoins,
+        meta: page.meta(),
+        data: page.items,
     };
 
-    (StatusCode::OK, Json(response))
-}
-
-/// Example error handler for `/stablecoin/types`.
-///
-/// In production, you might return this if a database query fails.
-pub async fn get_available_stablecoins_error() -> impl IntoResponse {
-    let response = StablecoinErrorResponse {
-        success: false,
-        message: "Internal server error",
-    };
-
-    (StatusCode::INTERNAL_SERVER_ERROR, Json(response))
+    Ok((StatusCode::OK, DataSource::Local, Json(state.cased(response))))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::stablecoin::registry::{StablecoinInfo, StablecoinRegistry, USDC_MINT, USDC_PLUS_MINT};
     use axum::response::IntoResponse;
     use axum::http::StatusCode;
     use axum::body::to_bytes;
@@ -114,7 +143,7 @@ mod tests {
     /// Unit test: ensure `get_available_stablecoins` returns a 200 response with correct JSON structure.
     #[tokio::test]
     async fn get_available_stablecoins_success() {
-        let response = get_available_stablecoins().await.into_response();
+        let response = get_available_stablecoins(State(AppState::default()), Query(PageParams::default())).await.into_response();
         let (parts, body) = response.into_parts();
         assert_eq!(parts.status, StatusCode::OK);
 
@@ -125,19 +154,53 @@ mod tests {
         assert!(json["data"].is_array());
         assert_eq!(json["data"][0]["index"], Value::Number(0.into()));
         assert_eq!(json["data"][0]["name"], Value::String("USDC+".into()));
+        assert_eq!(json["data"][0]["mint"], USDC_PLUS_MINT);
+        assert_eq!(json["data"][0]["decimals"], 6);
+        assert_eq!(json["data"][0]["enabled"], Value::Bool(true));
+        assert_eq!(json["data"][0]["simulated"], Value::Bool(true));
     }
 
-    /// Unit test: ensure `get_available_stablecoins_error` returns a 500 response with correct JSON structure.
+    /// Unit test: disabled stablecoins stay listed with `enabled: false`.
     #[tokio::test]
-    async fn get_available_stablecoins_error_test() {
-        let response = get_available_stablecoins_error().await.into_response();
-        let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    async fn get_available_stablecoins_lists_disabled() {
+        let state = AppState::default();
+        state.stablecoins.set_enabled(0, false);
 
-        let bytes = to_bytes(body, 1024).await.unwrap();
+        let response = get_available_stablecoins(State(state), Query(PageParams::default())).await.into_response();
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+
+        assert_eq!(json["data"][0]["name"], Value::String("USDC+".into()));
+        assert_eq!(json["data"][0]["enabled"], Value::Bool(false));
+    }
+
+    /// Unit test: output is sorted by index even when the registry was seeded
+    /// out of order. Guards the registry's ordering, which the handler relies on.
+    #[tokio::test]
+    async fn get_available_stablecoins_sorted_by_index() {
+        let info = |index: u32, name: &str| StablecoinInfo {
+            index,
+            name: name.to_string(),
+            mint: USDC_PLUS_MINT.to_string(),
+            collateral_mint: USDC_MINT.to_string(),
+            decimals: 6,
+            enabled: true,
+        };
+        let state = AppState {
+            stablecoins: StablecoinRegistry::new(vec![info(2, "EURC+"), info(0, "USDC+"), info(1, "USDT+")]),
+            ..AppState::default()
+        };
+
+        let response = get_available_stablecoins(State(state), Query(PageParams::default())).await.into_response();
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
         let json: Value = serde_json::from_slice(&bytes).unwrap();
 
-        assert_eq!(json["success"], Value::Bool(false));
-        assert_eq!(json["message"], Value::String("Internal server error".into()));
+        let indexes: Vec<u64> = json["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|stablecoin| stablecoin["index"].as_u64().unwrap())
+            .collect();
+        assert_eq!(indexes, vec![0, 1, 2]);
     }
 }
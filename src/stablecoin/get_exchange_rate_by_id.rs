@@ -0,0 +1,112 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::error::ApiError;
+use crate::path::Path;
+use crate::span::handler_span;
+use crate::AppState;
+use super::get_latest_exchange_rates::ExchangeRateData;
+
+/// Success response structure for a single exchange rate record.
+///
+/// ### Example Success Response (HTTP 200)
+/// ```json
+/// {
+///   "success": true,
+///   "data": {
+///     "id": 104135,
+///     "stablecoin": 0,
+///     "base_usd_value_bps": 1016733625,
+///     "timestamp": "2025-12-18T17:46:10.274Z",
+///     "receipt_usd_value_bps": 1016733625
+///   }
+/// }
+/// ```
+///
+/// ### Example Error Response (HTTP 404)
+/// ```json
+/// {
+///   "success": false,
+///   "message": "Exchange rate record 42 not found"
+/// }
+/// ```
+#[derive(Debug, Serialize)]
+pub struct ExchangeRateRecordResponse {
+    pub success: bool,
+    pub data: ExchangeRateData,
+}
+
+/// Handler for:
+///
+/// ### `GET /stablecoins/exchange-rates/{id}`
+///
+/// Retrieves one exchange rate record by the `id` carried in latest and
+/// historical responses, so clients can deep-link to a data point.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request GET \
+///   --url "http://localhost:3000/stablecoins/exchange-rates/104135"
+/// ```
+pub async fn get_exchange_rate_by_id(
+    State(state): State<AppState>,
+    Path(id): Path<u64>,
+) -> impl IntoResponse {
+    let _span = handler_span!("GET /stablecoins/exchange-rates/:id", record_id = id).entered();
+    let Some(data) = state.rates.record(id) else {
+        return ApiError::NotFound(format!("Exchange rate record {} not found", id)).into_response();
+    };
+
+    (
+        StatusCode::OK,
+        Json(json!(state.cased(ExchangeRateRecordResponse { success: true, data }))),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use serde_json::Value;
+
+    async fn fetch(id: u64) -> (StatusCode, Value) {
+        let response = get_exchange_rate_by_id(State(AppState::default()), Path(id))
+            .await
+            .into_response();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 2048).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_existing_record() {
+        // Historical records stay addressable after a newer rate is recorded
+        let (status, json) = fetch(104135).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["success"], true);
+        assert_eq!(json["data"]["id"], 104135);
+        assert_eq!(json["data"]["stablecoin"], 0);
+        assert_eq!(json["data"]["base_usd_value_bps"], 1016733625);
+        assert_eq!(json["data"]["timestamp"], "2025-12-18T17:46:10.274Z");
+
+        let (status, json) = fetch(105511).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["receipt_usd_value_bps"], 1016791576);
+    }
+
+    #[tokio::test]
+    async fn test_missing_record() {
+        let (status, json) = fetch(42).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(json["success"], false);
+        assert_eq!(json["message"], "Exchange rate record 42 not found");
+    }
+}
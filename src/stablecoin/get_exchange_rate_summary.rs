@@ -0,0 +1,199 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::get_historical_exchange_rates::simulated_series;
+use crate::error::ApiError;
+use crate::finite;
+use crate::path::Path;
+use crate::span::handler_span;
+use crate::validation::{clamp_param, parse_int, ClampMode, MAX_HISTORY_DAYS};
+use crate::AppState;
+
+/// Window used when `days` is omitted.
+pub const DEFAULT_SUMMARY_DAYS: u32 = 30;
+
+/// Query parameters for the exchange rate summary.
+///
+/// ### Fields
+/// - `days`: Size of the trailing window in days (default: 30, must be between 1 and 3650).
+///
+/// ### Example
+/// ```text
+/// ?days=30
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct SummaryQuery {
+    pub days: Option<String>,
+}
+
+/// Statistics over the `base_usd_value_bps` of every rate in the window.
+///
+/// ### Fields
+/// - `min` / `max`: Lowest and highest rate.
+/// - `open` / `close`: Oldest and newest rate.
+/// - `change_pct`: Percentage change from `open` to `close`.
+/// - `samples`: Number of rates in the window.
+///
+/// ### Example
+/// ```json
+/// {
+///   "min": 1016733625,
+///   "max": 1016767418,
+///   "open": 1016733625,
+///   "close": 1016767418,
+///   "change_pct": 0.0033,
+///   "samples": 720
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExchangeRateSummary {
+    pub min: i64,
+    pub max: i64,
+    pub open: i64,
+    pub close: i64,
+    #[serde(serialize_with = "finite::serialize")]
+    pub change_pct: f64,
+    pub samples: usize,
+}
+
+impl ExchangeRateSummary {
+    /// Summarise the `base_usd_value_bps` values in `history`, which must be
+    /// ordered oldest first. `None` when there is nothing to summarise.
+    pub fn of(history: &[i64]) -> Option<Self> {
+        let open = *history.first()?;
+        let close = *history.last()?;
+        Some(Self {
+            min: *history.iter().min()?,
+            max: *history.iter().max()?,
+            open,
+            close,
+            change_pct: (close - open) as f64 / open as f64 * 100.0,
+            samples: history.len(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExchangeRateSummaryResponse {
+    pub success: bool,
+    pub data: ExchangeRateSummary,
+}
+
+/// Handler for `GET /stablecoins/stablecoin/:index/exchange-rates/summary`.
+///
+/// Summarises the exchange rates of one stablecoin over `days` days, so
+/// clients get min / max / open / close without pulling every point. The
+/// window is the same series `/exchange-rates/historical` serves for
+/// `?days=N&stablecoin=index`. Unknown indexes are `404`.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request GET \
+///   --url "http://localhost:3000/stablecoins/stablecoin/0/exchange-rates/summary?days=30"
+/// ```
+pub async fn get_exchange_rate_summary(
+    State(state): State<AppState>,
+    Path(index): Path<u32>,
+    Query(query): Query<SummaryQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let _span = handler_span!("GET /stablecoins/stablecoin/:index/exchange-rates/summary", stablecoin_index = index).entered();
+    let days = parse_int::<u32>("days", query.days.as_deref())?.unwrap_or(DEFAULT_SUMMARY_DAYS);
+    clamp_param("days", days, 1, MAX_HISTORY_DAYS, ClampMode::Reject)?;
+    if state.stablecoins.get(index).is_none() {
+        return Err(ApiError::NotFound("Stablecoin with the specified index not found".to_string()));
+    }
+
+    let history: Vec<i64> = simulated_series(index, days).map(|rate| rate.base_usd_value_bps).collect();
+    let data = ExchangeRateSummary::of(&history).ok_or_else(|| {
+        ApiError::NotFound(format!("No exchange rates for stablecoin {} in the last {} days", index, days))
+    })?;
+
+    respond(&state, data)
+}
+
+/// Wrap `data` in the success envelope, or a 500 if it cannot be serialized
+/// (e.g. a `NaN` change from a zero opening rate).
+fn respond(state: &AppState, data: ExchangeRateSummary) -> Result<(StatusCode, Json<Value>), ApiError> {
+    let body = finite::to_json(&state.cased(ExchangeRateSummaryResponse { success: true, data }))?;
+    Ok((StatusCode::OK, Json(body)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use axum::extract::Query as PageQuery;
+
+    use crate::pagination::PageParams;
+    use crate::stablecoin::get_historical_exchange_rates::{get_historical_exchange_rates, HistoricalQuery};
+
+    async fn summary(index: u32, days: Option<&str>) -> (StatusCode, Value) {
+        let query = SummaryQuery { days: days.map(str::to_string) };
+        let response = get_exchange_rate_summary(State(AppState::default()), Path(index), Query(query))
+            .await
+            .into_response();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 2048).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn summarises_the_default_window() {
+        let (status, json) = summary(0, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["min"], 1016733625);
+        assert_eq!(json["data"]["max"], 1016767418);
+        assert_eq!(json["data"]["open"], 1016733625);
+        assert_eq!(json["data"]["close"], 1016767418);
+        assert_eq!(json["data"]["samples"], 720);
+        assert!((json["data"]["change_pct"].as_f64().unwrap() - 0.0033237).abs() < 1e-6);
+
+        let (_, json) = summary(0, Some("7")).await;
+        assert_eq!(json["data"]["close"], 1016741474);
+        assert_eq!(json["data"]["samples"], 168);
+    }
+
+    #[tokio::test]
+    async fn matches_the_historical_series() {
+        let query = HistoricalQuery { stablecoin: Some("0".to_string()), days: Some("2".to_string()) };
+        let response = get_historical_exchange_rates(State(AppState::default()), Query(query), PageQuery(PageParams::default()))
+            .await
+            .into_response();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        let historical: Value = serde_json::from_slice(&bytes).unwrap();
+        let rows = historical["data"].as_array().unwrap();
+
+        let (_, json) = summary(0, Some("2")).await;
+        assert_eq!(json["data"]["samples"], rows.len());
+        assert_eq!(json["data"]["open"], rows[0]["base_usd_value_bps"]);
+        assert_eq!(json["data"]["close"], rows[rows.len() - 1]["base_usd_value_bps"]);
+    }
+
+    #[tokio::test]
+    async fn validates_index_and_days() {
+        let (status, json) = summary(9, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(json["message"], "Stablecoin with the specified index not found");
+
+        for days in ["0", "3651"] {
+            let (status, json) = summary(0, Some(days)).await;
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+            assert_eq!(json["message"], "Invalid request data: days must be between 1 and 3650");
+        }
+
+        let (status, _) = summary(0, Some("month")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn empty_history_has_no_summary() {
+        assert_eq!(ExchangeRateSummary::of(&[]), None);
+    }
+}
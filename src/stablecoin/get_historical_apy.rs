@@ -1,24 +1,37 @@
 use axum::{
-    extract::{Path, Query},
+    extract::{Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
+use chrono::Datelike;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+
+use super::apy::smoothed_apy;
+use crate::error::ApiError;
+use crate::finite;
+use crate::path::Path;
+use crate::span::handler_span;
+use crate::validation::{clamp_param, parse_int, ClampMode, MAX_HISTORY_DAYS};
+use crate::timestamp::{self, Timestamp};
+use crate::AppState;
 
 /// Query parameters for historical APY retrieval.
 ///
 /// ### Fields
-/// - `days`: Number of days of historical APY data (default: 365, must be >= 1)
+/// - `days`: Number of days of historical APY data (default: 365, must be between 1 and 3650)
+/// - `smooth`: Return the trailing N-sample moving average of the APY
+///   snapshots recorded in the last `days` days instead of the raw series, in
+///   percent like it. Must be between 1 and the number of those snapshots.
 ///
 /// ### Example
 /// ```text
-/// ?days=365
+/// ?days=365&smooth=7
 /// ```
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 pub struct HistoricalApyQuery {
-    pub days: Option<u32>,
+    pub days: Option<String>,
+    pub smooth: Option<String>,
 }
 
 /// Historical APY data structure.
@@ -39,49 +52,62 @@ pub struct HistoricalApyQuery {
 #[derive(Debug, Serialize)]
 pub struct HistoricalApyData {
     pub index: u32,
+    #[serde(serialize_with = "finite::serialize")]
     pub apy: f64,
-    pub timestamp: String,
+    #[serde(serialize_with = "timestamp::serialize_seconds_by_default")]
+    pub timestamp: Timestamp,
 }
 
-/// Success response structure for historical APY retrieval.
+/// Success response structure for historical APY retrieval: one point per
+/// day, oldest first.
 ///
 /// ### Example Success Response (HTTP 200)
 /// ```json
 /// {
 ///   "success": true,
-///   "data": {
-///     "index": 0,
-///     "apy": 5.25,
-///     "timestamp": "2023-11-07T05:31:56Z"
-///   }
+///   "data": [
+///     { "index": 0, "apy": 5.31, "timestamp": "2026-10-15T00:00:00Z" },
+///     { "index": 0, "apy": 5.18, "timestamp": "2026-10-16T00:00:00Z" }
+///   ]
 /// }
 /// ```
 #[derive(Debug, Serialize)]
 pub struct HistoricalApySuccessResponse {
     pub success: bool,
-    pub data: HistoricalApyData,
+    pub data: Vec<HistoricalApyData>,
 }
 
-/// Error response structure.
+/// Centre of the simulated APY series, in percent.
+const SIMULATED_BASE_APY: f64 = 5.25;
+
+/// Simulated daily APY snapshots of stablecoin `index` for the `days` days
+/// ending `today`, oldest first, each stamped at midnight UTC.
 ///
-/// ### Example Error Response (HTTP 400/500)
-/// ```json
-/// {
-///   "success": false,
-///   "message": "Internal server error"
-/// }
-/// ```
-#[derive(Debug, Serialize)]
-pub struct HistoricalApyErrorResponse {
-    pub success: bool,
-    pub message: &'static str,
+/// The value drifts around [`SIMULATED_BASE_APY`] as a function of the
+/// calendar date, so repeated calls agree on every day they share.
+fn simulated_series(index: u32, days: u32, today: chrono::NaiveDate) -> Vec<HistoricalApyData> {
+    (0..days)
+        .rev()
+        .map(|ago| {
+            let date = today - chrono::Duration::days(ago.into());
+            let day = date.num_days_from_ce() as f64;
+            let apy = SIMULATED_BASE_APY + 0.2 * (day * 0.7).sin() + 0.1 * (day * 0.31).cos();
+            HistoricalApyData {
+                index,
+                apy: (apy * 100.0).round() / 100.0,
+                timestamp: Timestamp(date.and_time(chrono::NaiveTime::MIN).and_utc()),
+            }
+        })
+        .collect()
 }
 
 /// Handler for:
 ///
 /// ### `GET /stablecoin/{index}/apy/historical`
 ///
-/// Retrieves historical APY data for a specific stablecoin over a given number of days.
+/// Retrieves historical APY data for a specific stablecoin: one point per day
+/// for the last `days` days (including today), oldest first. With `smooth`
+/// the single smoothed point over the same `days` is returned instead.
 ///
 /// # Example
 ///
@@ -90,47 +116,28 @@ pub struct HistoricalApyErrorResponse {
 ///   --url "http://localhost:3000/stablecoin/0/apy/historical?days=365"
 /// ```
 pub async fn get_historical_apy(
+    State(state): State<AppState>,
     Path(index): Path<u32>,
     Query(query): Query<HistoricalApyQuery>,
-) -> impl IntoResponse {
-    let days = query.days.unwrap_or(365);
-
-    // Validate days >= 1
-    if days < 1 {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(json!(HistoricalApyErrorResponse {
-                success: false,
-                message: "Invalid request data: depositAmount must be positive",
-            })),
-        );
+) -> Result<impl IntoResponse, ApiError> {
+    let _span = handler_span!("GET /stablecoins/stablecoin/:index/apy/historical", stablecoin_index = index).entered();
+    let days = parse_int::<u32>("days", query.days.as_deref())?.unwrap_or(365);
+    clamp_param("days", days, 1, MAX_HISTORY_DAYS, ClampMode::Reject)?;
+
+    if let Some(smooth) = query.smooth.as_deref() {
+        let since = Timestamp(Timestamp::now().0 - chrono::Duration::days(days.into()));
+        let (apy, timestamp) = smoothed_apy(&state.apys, index, smooth, since)?;
+        return respond(&state, vec![HistoricalApyData { index, apy, timestamp }]);
     }
 
-    // Simulated APY data (mirrors real API)
-    let data = HistoricalApyData {
-        index,
-        apy: 5.25,
-        timestamp: "2023-11-07T05:31:56Z".to_string(),
-    };
-
-    (
-        StatusCode::OK,
-        Json(json!(HistoricalApySuccessResponse {
-            success: true,
-            data,
-        })),
-    )
+    respond(&state, simulated_series(index, days, Timestamp::now().0.date_naive()))
 }
 
-/// Example internal server error handler.
-pub async fn get_historical_apy_error() -> impl IntoResponse {
-    (
-        StatusCode::INTERNAL_SERVER_ERROR,
-        Json(json!(HistoricalApyErrorResponse {
-            success: false,
-            message: "Internal server error",
-        })),
-    )
+/// Wrap `data` in the success envelope, or a 500 if it cannot be serialized
+/// (e.g. a `NaN` APY).
+fn respond(state: &AppState, data: Vec<HistoricalApyData>) -> Result<(StatusCode, Json<serde_json::Value>), ApiError> {
+    let body = finite::to_json(&state.cased(HistoricalApySuccessResponse { success: true, data }))?;
+    Ok((StatusCode::OK, Json(body)))
 }
 
 #[cfg(test)]
@@ -140,11 +147,15 @@ mod tests {
     use axum::response::IntoResponse;
     use serde_json::Value;
 
+    use crate::seed::Seed;
+    use crate::stablecoin::get_all_apy::ApyData;
+
     #[tokio::test]
     async fn test_historical_apy_success() {
         let response = get_historical_apy(
+            State(AppState::default()),
             Path(0),
-            Query(HistoricalApyQuery { days: Some(365) }),
+            Query(HistoricalApyQuery { days: Some("365".to_string()), smooth: None }),
         )
         .await
         .into_response();
@@ -152,47 +163,135 @@ mod tests {
         let (parts, body) = response.into_parts();
         assert_eq!(parts.status, StatusCode::OK);
 
-        let bytes = to_bytes(body, 2048).await.unwrap();
+        let bytes = to_bytes(body, 64 * 1024).await.unwrap();
         let json: Value = serde_json::from_slice(&bytes).unwrap();
 
         assert_eq!(json["success"], true);
-        assert_eq!(json["data"]["index"], 0);
-        assert_eq!(json["data"]["apy"], 5.25);
+        let data = json["data"].as_array().unwrap();
+        assert_eq!(data.len(), 365);
+        assert!(data.iter().all(|point| point["index"] == 0));
+
+        let today = format!("{}T00:00:00Z", chrono::Utc::now().date_naive());
+        assert_eq!(data[364]["timestamp"], today.as_str());
+    }
+
+    #[test]
+    fn test_simulated_series_is_daily_and_plausible() {
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        let series = simulated_series(2, 30, today);
+        assert_eq!(series.len(), 30);
+        assert_eq!(series[0].timestamp, Timestamp::parse_rfc3339("2026-02-09T00:00:00Z").unwrap());
+        assert_eq!(series[29].timestamp, Timestamp::parse_rfc3339("2026-03-10T00:00:00Z").unwrap());
+        assert!(series.windows(2).all(|pair| pair[1].timestamp.0 - pair[0].timestamp.0 == chrono::Duration::days(1)));
+        assert!(series.iter().all(|point| point.index == 2 && (4.9..=5.6).contains(&point.apy)));
+        assert!(series.windows(2).any(|pair| pair[0].apy != pair[1].apy));
+
+        // Overlapping windows agree on shared days
+        let shorter = simulated_series(2, 5, today);
+        assert_eq!(shorter.last().unwrap().apy, series.last().unwrap().apy);
+        assert_eq!(shorter[0].apy, series[25].apy);
+    }
+
+    async fn smoothed(state: &AppState, days: Option<&str>, smooth: &str) -> (StatusCode, Value) {
+        let query = HistoricalApyQuery { days: days.map(str::to_string), smooth: Some(smooth.to_string()) };
+        let response = get_historical_apy(State(state.clone()), Path(0), Query(query)).await.into_response();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, 2048).await.unwrap();
+        (parts.status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_smoothed_historical_apy() {
+        // Snapshots 40, 20 and 10 days ago
+        let newest = Timestamp(Timestamp::now().0 - chrono::Duration::days(10));
+        let apys = [(40, 220), (20, 240), (10, 290)]
+            .iter()
+            .map(|&(days_ago, apy)| ApyData {
+                index: 0,
+                apy,
+                timestamp: Timestamp(Timestamp::now().0 - chrono::Duration::days(days_ago)),
+            })
+            .collect();
+        let state = AppState::with_seed(Seed { apys, ..Seed::default() });
+
+        for (smooth, expected) in [("1", 2.9), ("3", 2.5)] {
+            let (status, json) = smoothed(&state, None, smooth).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(json["data"][0]["apy"], expected);
+            assert_eq!(json["data"][0]["timestamp"].as_str().unwrap()[..10], newest.0.date_naive().to_string());
+        }
+
+        let (status, _) = smoothed(&state, None, "4").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        // `days` bounds the window the average is taken over
+        let (status, json) = smoothed(&state, Some("30"), "2").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"][0]["apy"], 2.65);
+        let (status, json) = smoothed(&state, Some("30"), "3").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid request data: smooth must be between 1 and 2");
+        let (status, _) = smoothed(&state, Some("5"), "1").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_nan_apy_is_internal_error() {
+        let data = HistoricalApyData {
+            index: 0,
+            apy: f64::NAN,
+            timestamp: Timestamp::parse_rfc3339("2023-11-07T05:31:56Z").unwrap(),
+        };
+        let (parts, body) = respond(&AppState::default(), vec![data]).into_response().into_parts();
+        assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+
+        let bytes = to_bytes(body, 2048).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["success"], false);
+        assert_eq!(json["message"], "Internal server error");
     }
 
     #[tokio::test]
     async fn test_historical_apy_invalid_days() {
         let response = get_historical_apy(
+            State(AppState::default()),
             Path(0),
-            Query(HistoricalApyQuery { days: Some(0) }),
+            Query(HistoricalApyQuery { days: Some("0".to_string()), smooth: None }),
         )
         .await
         .into_response();
 
         let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
 
         let bytes = to_bytes(body, 2048).await.unwrap();
         let json: Value = serde_json::from_slice(&bytes).unwrap();
 
         assert_eq!(json["success"], false);
-        assert_eq!(
-            json["message"],
-            "Invalid request data: depositAmount must be positive"
-        );
+        assert_eq!(json["message"], "Invalid request data: days must be between 1 and 3650");
     }
 
     #[tokio::test]
-    async fn test_historical_apy_internal_error() {
-        let response = get_historical_apy_error().await.into_response();
-
-        let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    async fn test_historical_apy_days_overflow_and_non_numeric() {
+        for (days, message) in [
+            ("99999999999999", "days out of range"),
+            ("abc", "days must be a non-negative integer"),
+        ] {
+            let response = get_historical_apy(
+                State(AppState::default()),
+                Path(0),
+                Query(HistoricalApyQuery { days: Some(days.to_string()), smooth: None }),
+            )
+            .await
+            .into_response();
 
-        let bytes = to_bytes(body, 2048).await.unwrap();
-        let json: Value = serde_json::from_slice(&bytes).unwrap();
+            let (parts, body) = response.into_parts();
+            assert_eq!(parts.status, StatusCode::BAD_REQUEST);
 
-        assert_eq!(json["success"], false);
-        assert_eq!(json["message"], "Internal server error");
+            let bytes = to_bytes(body, 2048).await.unwrap();
+            let json: Value = serde_json::from_slice(&bytes).unwrap();
+            assert_eq!(json["success"], false);
+            assert_eq!(json["message"], message);
+        }
     }
 }
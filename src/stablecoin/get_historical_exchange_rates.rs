@@ -1,5 +1,5 @@
 use axum::{
-    extract::Query,
+    extract::{Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
@@ -7,18 +7,25 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
+use crate::error::ApiError;
+use crate::pagination::{paginate, PageMeta, PageParams};
+use crate::span::handler_span;
+use crate::validation::{clamp_param, require_int, ClampMode, MAX_HISTORY_DAYS};
+use crate::timestamp::Timestamp;
+use crate::AppState;
+
 /// Query parameters for historical exchange rate retrieval.
 ///
 /// ### Fields
 /// - `stablecoin`: Stablecoin index (e.g., 0 for USDC+).
-/// - `days`: Number of days of historical data to retrieve.
+/// - `days`: Number of days of historical data to retrieve (1 to 3650).
 ///
 /// ### Example
 /// - `?days=1&stablecoin=0`
 #[derive(Debug, Deserialize)]
 pub struct HistoricalQuery {
-    pub stablecoin: u32,
-    pub days: u32,
+    pub stablecoin: Option<String>,
+    pub days: Option<String>,
 }
 
 /// Historical exchange rate data structure.
@@ -33,7 +40,7 @@ pub struct HistoricalQuery {
 /// ### Example
 /// ```json
 /// {
-///   "id": 104135,
+///   "id": 1000000000000,
 ///   "stablecoin": 0,
 ///   "base_usd_value_bps": 1016733625,
 ///   "timestamp": "2025-12-18T17:46:10.274Z",
@@ -45,10 +52,45 @@ pub struct HistoricalExchangeRateData {
     pub id: u64,
     pub stablecoin: u32,
     pub base_usd_value_bps: i64,
-    pub timestamp: String,
+    pub timestamp: Timestamp,
     pub receipt_usd_value_bps: i64,
 }
 
+/// The simulated feed records one snapshot per hour.
+const SNAPSHOTS_PER_DAY: u64 = 24;
+
+/// Simulated snapshot ids start here, well clear of the recorded ids served
+/// by `GET /stablecoins/exchange-rates/:id`, and each stablecoin gets its own
+/// block of [`SIMULATED_IDS_PER_STABLECOIN`] ids.
+const FIRST_SIMULATED_ID: u64 = 1_000_000_000_000;
+const SIMULATED_IDS_PER_STABLECOIN: u64 = 1_000_000;
+
+/// Rate of the first snapshot in the simulated series.
+const FIRST_SNAPSHOT_BPS: i64 = 1016733625;
+
+/// Rate accrued between consecutive simulated snapshots.
+const HOURLY_ACCRUAL_BPS: i64 = 47;
+
+/// The `i`th snapshot of the deterministic simulated series, oldest first:
+/// hourly from the first snapshot's timestamp with a steadily accruing rate.
+fn simulated_snapshot(stablecoin: u32, i: u64) -> HistoricalExchangeRateData {
+    let start = Timestamp::parse_rfc3339("2025-12-18T17:46:10.274Z").unwrap();
+    let rate = FIRST_SNAPSHOT_BPS + i as i64 * HOURLY_ACCRUAL_BPS;
+    HistoricalExchangeRateData {
+        id: FIRST_SIMULATED_ID + stablecoin as u64 * SIMULATED_IDS_PER_STABLECOIN + i,
+        stablecoin,
+        base_usd_value_bps: rate,
+        timestamp: Timestamp(start.0 + chrono::Duration::hours(i as i64)),
+        receipt_usd_value_bps: rate,
+    }
+}
+
+/// The simulated series for `stablecoin` covering `days` days, oldest first:
+/// every row the historical endpoint pages over for the same query.
+pub fn simulated_series(stablecoin: u32, days: u32) -> impl Iterator<Item = HistoricalExchangeRateData> {
+    (0..days as u64 * SNAPSHOTS_PER_DAY).map(move |i| simulated_snapshot(stablecoin, i))
+}
+
 /// Success response structure for historical exchange rate retrieval.
 ///
 /// ### Example Success Response (HTTP 200)
@@ -57,40 +99,29 @@ pub struct HistoricalExchangeRateData {
 ///   "success": true,
 ///   "data": [
 ///     {
-///       "id": 104135,
+///       "id": 1000000000000,
 ///       "stablecoin": 0,
 ///       "base_usd_value_bps": 1016733625,
 ///       "timestamp": "2025-12-18T17:46:10.274Z",
 ///       "receipt_usd_value_bps": 1016733625
 ///     }
-///   ]
+///   ],
+///   "meta": { "total": 24, "limit": 100, "offset": 0, "has_more": false }
 /// }
 /// ```
 #[derive(Debug, Serialize)]
 pub struct HistoricalSuccessResponse {
     pub success: bool,
     pub data: Vec<HistoricalExchangeRateData>,
-}
-
-/// Error response structure for historical exchange rate retrieval.
-///
-/// ### Example Error Response (HTTP 500)
-/// ```json
-/// {
-///   "success": false,
-///   "message": "Internal server error"
-/// }
-/// ```
-#[derive(Debug, Serialize)]
-pub struct HistoricalErrorResponse {
-    pub success: bool,
-    pub message: &'static str,
+    pub meta: PageMeta,
 }
 
 /// Handler for `GET /stablecoin/exchange-rates/historical`.
 ///
-/// Accepts query parameters `days` and `stablecoin`.  
-/// Returns simulated historical exchange rate data or an error.
+/// Accepts query parameters `days` and `stablecoin`, plus the standard
+/// `limit` / `offset` paging parameters (default 100, at most 1000).  
+/// Returns a page of a simulated hourly series covering `days` days (so
+/// `meta.total` is `24 * days`), oldest first, or an error.
 ///
 /// # Example
 ///
@@ -99,44 +130,29 @@ pub struct HistoricalErrorResponse {
 ///   --url "http://localhost:3000/stablecoin/exchange-rates/historical?days=1&stablecoin=0"
 /// ```
 pub async fn get_historical_exchange_rates(
+    State(state): State<AppState>,
     Query(query): Query<HistoricalQuery>,
-) -> impl IntoResponse {
-    // Simulated historical data
-    let data = vec![
-        HistoricalExchangeRateData {
-            id: 104135,
-            stablecoin: query.stablecoin,
-            base_usd_value_bps: 1016733625,
-            timestamp: "2025-12-18T17:46:10.274Z".to_string(),
-            receipt_usd_value_bps: 1016733625,
-        },
-        HistoricalExchangeRateData {
-            id: 104137,
-            stablecoin: query.stablecoin,
-            base_usd_value_bps: 1016728666,
-            timestamp: "2025-12-18T17:47:08.161Z".to_string(),
-            receipt_usd_value_bps: 1016728667,
-        },
-    ];
-
-    (
+    Query(page): Query<PageParams>,
+) -> Result<impl IntoResponse, ApiError> {
+    let _span = handler_span!("GET /stablecoins/stablecoin/:index/exchange-rates/historical").entered();
+    let stablecoin = require_int::<u32>("stablecoin", query.stablecoin.as_deref())?;
+    let days = require_int::<u32>("days", query.days.as_deref())?;
+    let days = clamp_param("days", days, 1, MAX_HISTORY_DAYS, ClampMode::Reject)?;
+    let page_query = page.parse()?;
+
+    // Page over snapshot indices so only the returned rows are built
+    let page = paginate((0..days as u64 * SNAPSHOTS_PER_DAY).collect(), &page_query);
+    let meta = page.meta();
+    let data = page.items.into_iter().map(|i| simulated_snapshot(stablecoin, i)).collect();
+
+    Ok((
         StatusCode::OK,
-        Json(json!(HistoricalSuccessResponse {
+        Json(json!(state.cased(HistoricalSuccessResponse {
             success: true,
+            meta,
             data,
-        })),
-    )
-}
-
-/// Example error handler for internal server errors.
-pub async fn get_historical_exchange_rates_error() -> impl IntoResponse {
-    (
-        StatusCode::INTERNAL_SERVER_ERROR,
-        Json(json!(HistoricalErrorResponse {
-            success: false,
-            message: "Internal server error",
-        })),
-    )
+        }))),
+    ))
 }
 
 #[cfg(test)]
@@ -149,35 +165,137 @@ mod tests {
     #[tokio::test]
     async fn test_historical_exchange_rates_success() {
         let query = HistoricalQuery {
-            stablecoin: 0,
-            days: 1,
+            stablecoin: Some("0".to_string()),
+            days: Some("1".to_string()),
         };
-        let response = get_historical_exchange_rates(Query(query))
+        let response = get_historical_exchange_rates(State(AppState::default()), Query(query), Query(PageParams::default()))
             .await
             .into_response();
 
         let (parts, body) = response.into_parts();
         assert_eq!(parts.status, StatusCode::OK);
 
-        let bytes = to_bytes(body, 1024).await.unwrap();
+        let bytes = to_bytes(body, 64 * 1024).await.unwrap();
         let json: Value = serde_json::from_slice(&bytes).unwrap();
 
         assert_eq!(json["success"], true);
-        assert!(json["data"].is_array());
+        assert_eq!(json["data"].as_array().unwrap().len(), 24);
         assert_eq!(json["data"][0]["stablecoin"], 0);
-        assert_eq!(json["data"][0]["id"], 104135);
+        assert_eq!(json["data"][0]["id"], 1_000_000_000_000u64);
+        assert_eq!(json["data"][0]["timestamp"], "2025-12-18T17:46:10.274Z");
+        assert_eq!(json["data"][1]["timestamp"], "2025-12-18T18:46:10.274Z");
+        assert_eq!(json["meta"]["total"], 24);
+        assert_eq!(json["meta"]["has_more"], false);
+    }
+
+    async fn page(days: &str, limit: Option<&str>, offset: Option<&str>) -> Value {
+        let query = HistoricalQuery {
+            stablecoin: Some("0".to_string()),
+            days: Some(days.to_string()),
+        };
+        let page = PageParams {
+            limit: limit.map(str::to_string),
+            offset: offset.map(str::to_string),
+        };
+        let response = get_historical_exchange_rates(State(AppState::default()), Query(query), Query(page)).await.into_response();
+        let bytes = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
     }
 
     #[tokio::test]
-    async fn test_historical_exchange_rates_internal_error() {
-        let response = get_historical_exchange_rates_error().await.into_response();
-        let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    async fn test_offset_and_limit_slice_the_series() {
+        let json = page("1", Some("5"), Some("10")).await;
+        let ids: Vec<u64> = json["data"].as_array().unwrap().iter().map(|row| row["id"].as_u64().unwrap()).collect();
+        assert_eq!(ids, (1_000_000_000_010..1_000_000_000_015).collect::<Vec<u64>>());
+        assert_eq!(json["meta"], serde_json::json!({ "total": 24, "limit": 5, "offset": 10, "has_more": true }));
 
-        let bytes = to_bytes(body, 1024).await.unwrap();
-        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        // The series is deterministic across calls
+        assert_eq!(page("1", Some("5"), Some("10")).await, json);
+    }
+
+    #[test]
+    fn test_simulated_ids_do_not_collide_with_recorded_ones() {
+        let rates = crate::stablecoin::rates::ExchangeRateStore::default();
+        for stablecoin in 0..3 {
+            let ids: Vec<u64> = simulated_series(stablecoin, 1).map(|row| row.id).collect();
+            assert!(ids.iter().all(|id| rates.record(*id).is_none()));
+            assert!(ids.iter().all(|id| *id >= FIRST_SIMULATED_ID));
+        }
+        // Each stablecoin has its own ids
+        assert_ne!(simulated_snapshot(0, 0).id, simulated_snapshot(1, 0).id);
+    }
+
+    #[tokio::test]
+    async fn test_has_more_boundary() {
+        // Exactly reaching the end
+        let json = page("1", Some("10"), Some("14")).await;
+        assert_eq!(json["data"].as_array().unwrap().len(), 10);
+        assert_eq!(json["meta"]["has_more"], false);
+
+        // One short of the end
+        let json = page("1", Some("10"), Some("13")).await;
+        assert_eq!(json["meta"]["has_more"], true);
+
+        // Past the end
+        let json = page("1", None, Some("24")).await;
+        assert!(json["data"].as_array().unwrap().is_empty());
+        assert_eq!(json["meta"]["has_more"], false);
+    }
+
+    #[tokio::test]
+    async fn test_default_and_max_limit() {
+        let json = page("30", None, None).await;
+        assert_eq!(json["data"].as_array().unwrap().len(), 100);
+        assert_eq!(json["meta"]["total"], 720);
+        assert_eq!(json["meta"]["has_more"], true);
+
+        let json = page("365", Some("5000"), None).await;
+        assert_eq!(json["data"].as_array().unwrap().len(), 1000);
+        assert_eq!(json["meta"]["limit"], 1000);
+    }
+
+    #[tokio::test]
+    async fn test_overflowing_and_non_numeric_params() {
+        for (stablecoin, days, message) in [
+            ("0", "99999999999999", "days out of range"),
+            ("4294967296", "1", "stablecoin out of range"),
+            ("usdc", "1", "stablecoin must be a non-negative integer"),
+        ] {
+            let query = HistoricalQuery {
+                stablecoin: Some(stablecoin.to_string()),
+                days: Some(days.to_string()),
+            };
+            let response = get_historical_exchange_rates(State(AppState::default()), Query(query), Query(PageParams::default()))
+                .await
+                .into_response();
+
+            let (parts, body) = response.into_parts();
+            assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+            let bytes = to_bytes(body, 1024).await.unwrap();
+            let json: Value = serde_json::from_slice(&bytes).unwrap();
+            assert_eq!(json["success"], false);
+            assert_eq!(json["message"], message);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_days_outside_window_rejected() {
+        for days in ["0", "3651"] {
+            let query = HistoricalQuery {
+                stablecoin: Some("0".to_string()),
+                days: Some(days.to_string()),
+            };
+            let response = get_historical_exchange_rates(State(AppState::default()), Query(query), Query(PageParams::default()))
+                .await
+                .into_response();
+
+            let (parts, body) = response.into_parts();
+            assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
 
-        assert_eq!(json["success"], false);
-        assert_eq!(json["message"], "Internal server error");
+            let bytes = to_bytes(body, 1024).await.unwrap();
+            let json: Value = serde_json::from_slice(&bytes).unwrap();
+            assert_eq!(json["message"], "Invalid request data: days must be between 1 and 3650");
+        }
     }
 }
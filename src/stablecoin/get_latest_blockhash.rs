@@ -0,0 +1,203 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tracing::Instrument;
+
+use crate::cluster::Cluster;
+use crate::data_source::DataSource;
+use crate::error::ApiError;
+use crate::span::handler_span;
+use crate::validation::parse_param;
+use crate::AppState;
+
+/// Query parameters for `GET /stablecoins/blockhash`.
+///
+/// ### Example
+/// - `?cluster=mainnet`
+/// - `?cluster=devnet&commitment=finalized`
+#[derive(Debug, Default, Deserialize)]
+pub struct BlockhashQuery {
+    pub cluster: Option<String>,
+    pub commitment: Option<String>,
+}
+
+/// A recent blockhash and the last block height at which it is valid.
+///
+/// ### Example
+/// ```json
+/// {
+///   "blockhash": "EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N",
+///   "lastValidBlockHeight": 3090
+/// }
+/// ```
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockhashData {
+    pub blockhash: String,
+    pub last_valid_block_height: u64,
+}
+
+/// Handler for `GET /stablecoins/blockhash`.
+///
+/// Returns a recent blockhash from the cluster's RPC node so wallets can build
+/// their own transactions. Responds with `503` when no RPC endpoint is
+/// configured for the cluster and `502` when the node call fails.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request GET \
+///   --url "http://localhost:3000/stablecoins/blockhash?cluster=mainnet"
+/// ```
+///
+/// Expected output:
+/// ```json
+/// {
+///   "success": true,
+///   "data": {
+///     "blockhash": "EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N",
+///     "lastValidBlockHeight": 3090
+///   }
+/// }
+/// ```
+pub async fn get_latest_blockhash(
+    State(state): State<AppState>,
+    Query(query): Query<BlockhashQuery>,
+) -> impl IntoResponse {
+    let span = handler_span!("GET /stablecoins/blockhash", cluster = query.cluster.as_deref());
+    (DataSource::Upstream, latest_blockhash(state, query).instrument(span).await)
+}
+
+async fn latest_blockhash(state: AppState, query: BlockhashQuery) -> Result<(StatusCode, Json<Value>), ApiError> {
+    let cluster = parse_param("cluster", query.cluster.as_deref(), Cluster::default())?;
+    let commitment = parse_param("commitment", query.commitment.as_deref(), state.config.rpc_commitment)?;
+
+    let client = state
+        .rpc
+        .get(cluster)
+        .ok_or_else(|| ApiError::Unavailable(format!("RPC is not configured for cluster '{}'", cluster)))?;
+
+    let latest = client.get_latest_blockhash(commitment).await.map_err(|e| {
+        tracing::warn!(%cluster, error = %e, "Failed to fetch recent blockhash");
+        e.to_api_error("Failed to fetch a recent blockhash from the RPC node")
+    })?;
+    Ok((
+        StatusCode::OK,
+        Json(json!({
+            "success": true,
+            "data": state.cased(BlockhashData {
+                blockhash: latest.blockhash,
+                last_valid_block_height: latest.last_valid_block_height,
+            }),
+        })),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use serde_json::Value;
+    use std::sync::Arc;
+
+    use crate::rpc::mock::MockTransport;
+    use crate::rpc::{RpcClient, RpcClients, RpcError};
+
+    fn state_with(transport: MockTransport) -> AppState {
+        AppState {
+            rpc: RpcClients::default().with(RpcClient::new(Cluster::Mainnet, Arc::new(transport))),
+            ..AppState::default()
+        }
+    }
+
+    async fn send(state: AppState, query: BlockhashQuery) -> (StatusCode, Value) {
+        let response = get_latest_blockhash(State(state), Query(query)).await.into_response();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_returns_blockhash_from_rpc() {
+        let transport = MockTransport::new().with_blockhash("EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N", 3090);
+        let (status, json) = send(state_with(transport.clone()), BlockhashQuery::default()).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["success"], true);
+        assert_eq!(json["data"]["blockhash"], "EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N");
+        assert_eq!(json["data"]["lastValidBlockHeight"], 3090);
+        assert_eq!(transport.calls()[0].0, "getLatestBlockhash");
+    }
+
+    #[tokio::test]
+    async fn test_rpc_not_configured() {
+        let (status, json) = send(
+            state_with(MockTransport::new()),
+            BlockhashQuery { cluster: Some("devnet".to_string()), ..Default::default() },
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(json["success"], false);
+        assert_eq!(json["message"], "RPC is not configured for cluster 'devnet'");
+    }
+
+    #[tokio::test]
+    async fn test_rpc_failure() {
+        let transport = MockTransport::new().fail("getLatestBlockhash", RpcError::Transport("timed out".into()));
+        let (status, json) = send(state_with(transport), BlockhashQuery::default()).await;
+
+        assert_eq!(status, StatusCode::BAD_GATEWAY);
+        assert_eq!(json["success"], false);
+    }
+
+    #[tokio::test]
+    async fn test_structured_rpc_errors() {
+        for (code, message, status, expected) in [
+            (
+                -32005,
+                "Node is behind by 42 slots",
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Failed to fetch a recent blockhash from the RPC node: the RPC node is behind and cannot serve the request",
+            ),
+            (
+                -32002,
+                "Transaction simulation failed: Blockhash not found",
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "Failed to fetch a recent blockhash from the RPC node: blockhash not found; fetch a new one and retry",
+            ),
+            (
+                -32099,
+                "something new",
+                StatusCode::BAD_GATEWAY,
+                "Failed to fetch a recent blockhash from the RPC node: RPC error -32099: something new",
+            ),
+        ] {
+            let error = RpcError::Rpc { code, message: message.to_string() };
+            let transport = MockTransport::new().fail("getLatestBlockhash", error);
+            let (actual_status, json) = send(state_with(transport), BlockhashQuery::default()).await;
+
+            assert_eq!(actual_status, status);
+            assert_eq!(json["success"], false);
+            assert_eq!(json["rpcCode"], code);
+            assert_eq!(json["message"], expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unknown_cluster() {
+        let (status, json) = send(
+            state_with(MockTransport::new()),
+            BlockhashQuery { cluster: Some("testnet".to_string()), ..Default::default() },
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["message"], "Unknown cluster 'testnet'; expected one of mainnet, devnet");
+    }
+}
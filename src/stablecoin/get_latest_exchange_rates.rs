@@ -1,10 +1,21 @@
 use axum::{
+    extract::{Query, State},
     http::StatusCode,
-    response::IntoResponse,
+    response::{IntoResponse, Response},
     Json,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use tracing::Instrument;
+
+use crate::data_source::DataSource;
+use crate::error::ApiError;
+use crate::pagination::{paginate, PageMeta, PageParams};
+use crate::span::handler_span;
+use crate::timestamp::Timestamp;
+use crate::upstream::fetch_or_fallback;
+use crate::AppState;
+use super::rates::Staleness;
 
 /// Exchange rate data structure for a stablecoin.
 ///
@@ -25,12 +36,12 @@ use serde_json::json;
 ///   "receipt_usd_value_bps": 1016791576
 /// }
 /// ```
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExchangeRateData {
     pub id: u64,
     pub stablecoin: u32,
     pub base_usd_value_bps: i64,
-    pub timestamp: String,
+    pub timestamp: Timestamp,
     pub receipt_usd_value_bps: i64,
 }
 
@@ -48,33 +59,30 @@ pub struct ExchangeRateData {
 ///       "timestamp": "2025-12-19T17:04:08.502Z",
 ///       "receipt_usd_value_bps": 1016791576
 ///     }
-///   ]
+///   ],
+///   "meta": { "total": 1, "limit": 100, "offset": 0, "has_more": false },
+///   "stale": false
 /// }
 /// ```
+///
+/// `stale` is `true` (and a `warning` is attached) when the newest rate is
+/// older than `REFLECT_RATE_STALE_AFTER_SECS`.
 #[derive(Debug, Serialize)]
 pub struct ExchangeRateSuccessResponse {
     pub success: bool,
     pub data: Vec<ExchangeRateData>,
-}
-
-/// Error response structure for exchange rate retrieval.
-///
-/// ### Example Error Response (HTTP 500)
-/// ```json
-/// {
-///   "success": false,
-///   "message": "Internal server error"
-/// }
-/// ```
-#[derive(Debug, Serialize)]
-pub struct ExchangeRateErrorResponse {
-    pub success: bool,
-    pub message: &'static str,
+    pub meta: PageMeta,
+    pub stale: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
 }
 
 /// Handler for `GET /stablecoin/exchange-rates`.
 ///
-/// Returns simulated exchange rate data or an error.
+/// Returns the latest rate of every stablecoin, proxied from the live Reflect
+/// API when `REFLECT_UPSTREAM_URL` is set and from the shared rate store
+/// otherwise (or when the upstream is unreachable). `X-Data-Source` says which.
+/// Supports the standard `limit` / `offset` paging parameters.
 ///
 /// # Example
 ///
@@ -82,34 +90,37 @@ pub struct ExchangeRateErrorResponse {
 /// curl --request GET \
 ///   --url http://localhost:3000/stablecoin/exchange-rates
 /// ```
-pub async fn get_latest_exchange_rates() -> impl IntoResponse {
-    // Simulated exchange rate data
-    let rates = vec![ExchangeRateData {
-        id: 105511,
-        stablecoin: 0,
-        base_usd_value_bps: 1016789908,
-        timestamp: "2025-12-19T17:04:08.502Z".to_string(),
-        receipt_usd_value_bps: 1016791576,
-    }];
-
-    (
-        StatusCode::OK,
-        Json(json!(ExchangeRateSuccessResponse {
-            success: true,
-            data: rates,
-        })),
-    )
+pub async fn get_latest_exchange_rates(
+    State(state): State<AppState>,
+    Query(page): Query<PageParams>,
+) -> Result<impl IntoResponse, ApiError> {
+    let span = handler_span!("GET /stablecoins/exchange-rates");
+    latest_exchange_rates(state, page).instrument(span).await
 }
 
-/// Example error handler for internal server errors.
-pub async fn get_latest_exchange_rates_error() -> impl IntoResponse {
-    (
-        StatusCode::INTERNAL_SERVER_ERROR,
-        Json(json!(ExchangeRateErrorResponse {
-            success: false,
-            message: "Internal server error",
-        })),
+async fn latest_exchange_rates(state: AppState, page: PageParams) -> Result<Response, ApiError> {
+    let page_query = page.parse()?;
+    let upstream = fetch_or_fallback::<Vec<ExchangeRateData>>(state.upstream.as_ref(), "/stablecoin/exchange-rates").await;
+    let (rates, source) = match upstream {
+        Some(rates) => (rates, DataSource::Upstream),
+        None => (state.rates.latest(), DataSource::Local),
+    };
+    let newest = rates.iter().map(|rate| rate.timestamp).max();
+    let staleness = Staleness::of(newest, state.config.rate_stale_after());
+    let page = paginate(rates, &page_query);
+
+    Ok((
+        StatusCode::OK,
+        source,
+        Json(json!(state.cased(ExchangeRateSuccessResponse {
+            success: true,
+            meta: page.meta(),
+            data: page.items,
+            stale: staleness.stale,
+            warning: staleness.warning(),
+        }))),
     )
+        .into_response())
 }
 
 #[cfg(test)]
@@ -119,9 +130,14 @@ mod tests {
     use axum::response::IntoResponse;
     use serde_json::Value;
 
+    use crate::seed::Seed;
+    use crate::upstream;
+
     #[tokio::test]
     async fn test_exchange_rates_success() {
-        let response = get_latest_exchange_rates().await.into_response();
+        let response = get_latest_exchange_rates(State(AppState::default()), Query(PageParams::default()))
+            .await
+            .into_response();
 
         let (parts, body) = response.into_parts();
         assert_eq!(parts.status, StatusCode::OK);
@@ -138,15 +154,42 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_exchange_rates_internal_error() {
-        let response = get_latest_exchange_rates_error().await.into_response();
-        let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    async fn test_exchange_rates_from_seed() {
+        let seed = Seed::default();
+        let response = get_latest_exchange_rates(State(AppState::with_seed(seed.clone())), Query(PageParams::default()))
+            .await
+            .into_response();
 
-        let bytes = to_bytes(body, 1024).await.unwrap();
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
         let json: Value = serde_json::from_slice(&bytes).unwrap();
 
-        assert_eq!(json["success"], false);
-        assert_eq!(json["message"], "Internal server error");
+        assert_eq!(json["data"], json!(seed.rates));
+        assert_eq!(json["data"][0]["id"], 200001);
+        assert_eq!(json["meta"]["total"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_exchange_rates_from_upstream_and_fallback() {
+        let live = json!([{
+            "id": 300001,
+            "stablecoin": 0,
+            "base_usd_value_bps": 1030000000,
+            "timestamp": "2026-02-01T00:00:00.000Z",
+            "receipt_usd_value_bps": 1030000100
+        }]);
+        let upstream = upstream::tests::serve("/stablecoin/exchange-rates", json!({ "success": true, "data": live })).await;
+
+        for (upstream, source, id) in [(upstream, "upstream", 300001), (upstream::tests::unreachable(), "local", 105511)] {
+            let state = AppState { upstream: Some(upstream), ..AppState::default() };
+            let response = get_latest_exchange_rates(State(state), Query(PageParams::default()))
+                .await
+                .into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.headers()["x-data-source"], source);
+
+            let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+            let json: Value = serde_json::from_slice(&bytes).unwrap();
+            assert_eq!(json["data"][0]["id"], id);
+        }
     }
 }
@@ -0,0 +1,174 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::error::ApiError;
+use crate::path::Path;
+use crate::span::handler_span;
+use crate::validation::{malformed_field, validate_pubkey};
+use crate::AppState;
+
+/// Query parameters for the max-mint endpoint.
+///
+/// ### Fields
+/// - `signer`: The wallet that would mint (required, base58 public key).
+#[derive(Debug, Default, Deserialize)]
+pub struct MaxMintQuery {
+    pub signer: Option<String>,
+}
+
+/// How much `signer` could mint of one stablecoin right now.
+///
+/// - `remainingCapacity`: room left under the stablecoin's supply cap.
+/// - `perUserLimit`: what the signer may still mint under the per-user limit,
+///   or `null` when no such limit applies.
+/// - `maxMintable`: the smaller of the two.
+///
+/// ### Example
+/// ```json
+/// {
+///   "index": 0,
+///   "signer": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+///   "remainingCapacity": 500000000,
+///   "perUserLimit": null,
+///   "maxMintable": 500000000
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxMint {
+    pub index: u32,
+    pub signer: String,
+    pub remaining_capacity: u64,
+    pub per_user_limit: Option<u64>,
+    pub max_mintable: u64,
+}
+
+/// Handler for `GET /stablecoins/stablecoin/:index/max-mint?signer=...`.
+///
+/// Unknown stablecoins are rejected with 422 and paused ones with 503, like
+/// the mint endpoint; a missing `signer` is a 400 and a malformed one a 422.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request GET \
+///   --url "http://localhost:3000/stablecoins/stablecoin/0/max-mint?signer=9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+/// ```
+pub async fn get_max_mint(
+    State(state): State<AppState>,
+    Path(index): Path<u32>,
+    Query(query): Query<MaxMintQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let _span = handler_span!("GET /stablecoins/stablecoin/:index/max-mint", stablecoin_index = index).entered();
+    state.stablecoins.ensure_available(index)?;
+
+    let signer = query.signer.ok_or_else(|| malformed_field("signer", "signer is required"))?;
+    validate_pubkey("signer", &signer)?;
+
+    let supply = state
+        .supplies
+        .get(index)
+        .ok_or_else(|| ApiError::NotFound(format!("No supply figures for stablecoin {}", index)))?;
+    let remaining_capacity = supply.remaining_capacity();
+    let per_user_limit = state
+        .mint_limits
+        .remaining(index, &signer, state.config.per_user_mint_limit);
+    let max_mint = MaxMint {
+        index,
+        signer,
+        remaining_capacity,
+        per_user_limit,
+        max_mintable: per_user_limit.map_or(remaining_capacity, |limit| limit.min(remaining_capacity)),
+    };
+
+    Ok((StatusCode::OK, Json(json!({ "success": true, "data": state.cased(max_mint) }))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use serde_json::Value;
+
+    use crate::seed::{Seed, SEED_AUTHORITY};
+    use crate::stablecoin::supply::SupplyInfo;
+
+    fn state_with_supply(supply_cap: u64, current_supply: u64) -> AppState {
+        AppState::with_seed(Seed {
+            supplies: vec![SupplyInfo { index: 0, supply_cap, current_supply }],
+            ..Seed::default()
+        })
+    }
+
+    async fn call(state: AppState, index: u32, signer: Option<&str>) -> (StatusCode, Value) {
+        let query = MaxMintQuery { signer: signer.map(str::to_string) };
+        let response = get_max_mint(State(state), Path(index), Query(query)).await.into_response();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, 2048).await.unwrap();
+        (parts.status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_fresh_cap() {
+        let (status, json) = call(state_with_supply(1_000_000, 0), 0, Some(SEED_AUTHORITY)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["remainingCapacity"], 1_000_000);
+        assert_eq!(json["data"]["perUserLimit"], Value::Null);
+        assert_eq!(json["data"]["maxMintable"], 1_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_near_full_cap() {
+        let (status, json) = call(state_with_supply(1_000_000, 999_990), 0, Some(SEED_AUTHORITY)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["remainingCapacity"], 10);
+        assert_eq!(json["data"]["maxMintable"], 10);
+
+        let (_, json) = call(state_with_supply(1_000, 1_500), 0, Some(SEED_AUTHORITY)).await;
+        assert_eq!(json["data"]["maxMintable"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_reflects_per_user_limit() {
+        let state = AppState {
+            config: std::sync::Arc::new(crate::config::Config {
+                per_user_mint_limit: 1_000,
+                ..crate::config::Config::default()
+            }),
+            ..state_with_supply(1_000_000, 0)
+        };
+        assert!(state.mint_limits.try_record(0, SEED_AUTHORITY, crate::amount::Amount::new(600).unwrap(), 1_000));
+
+        let (status, json) = call(state, 0, Some(SEED_AUTHORITY)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["remainingCapacity"], 1_000_000);
+        assert_eq!(json["data"]["perUserLimit"], 400);
+        assert_eq!(json["data"]["maxMintable"], 400);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_signer_and_index() {
+        let state = state_with_supply(1_000, 0);
+
+        let (status, json) = call(state.clone(), 0, None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["message"], "signer is required");
+
+        let (status, json) = call(state.clone(), 0, Some("not-a-key")).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Invalid request data: signer is not a valid public key");
+
+        let (status, _) = call(state.clone(), 9, Some(SEED_AUTHORITY)).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        // Registered stablecoin without supply figures
+        let (status, _) = call(state, 1, Some(SEED_AUTHORITY)).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
@@ -1,16 +1,28 @@
 use axum::{
-    extract::{Path, Json},
+    extract::{Json, Query, State},
     http::StatusCode,
     response::IntoResponse,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::str::FromStr;
+
+use crate::amount::Amount;
+use crate::cluster::Cluster;
+use crate::error::ApiError;
+use crate::example::ExampleBody;
+use crate::json_body::JsonBody;
+use crate::path::Path;
+use crate::span::handler_span;
+use crate::validation::parse_param;
+use crate::AppState;
+use super::fees::{FeeBreakdown, FeeSchedule};
 
 /// Request structure for the `/stablecoin/quote/{type}` endpoint.
 ///
 /// ### Fields
 /// - `stablecoinIndex`: Index of the stablecoin (e.g., 0 for USDC+).
-/// - `depositAmount`: Amount to deposit or redeem. Must be positive.
+/// - `depositAmount`: Amount to deposit or redeem. Must be a positive whole number.
 ///
 /// ### Example Request
 /// ```json
@@ -19,16 +31,90 @@ use serde_json::json;
 ///   "depositAmount": 1000000
 /// }
 /// ```
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[allow(non_snake_case)]
 pub struct QuoteRequest {
     pub stablecoinIndex: u32,
-    pub depositAmount: i64,
+    pub depositAmount: Amount,
+}
+
+impl ExampleBody for QuoteRequest {
+    fn example() -> Self {
+        Self { stablecoinIndex: 0, depositAmount: Amount::new(1_000_000).unwrap() }
+    }
+}
+
+/// The `{type}` segment of `/stablecoin/quote/{type}`.
+///
+/// Types are added here as soon as they are planned; the handler answers the
+/// ones it cannot price yet with 501 instead of an error that suggests the
+/// client got the type wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteType {
+    Mint,
+    Redeem,
+    /// Planned: stablecoin-to-stablecoin swaps.
+    Swap,
+}
+
+impl FromStr for QuoteType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mint" => Ok(QuoteType::Mint),
+            "redeem" => Ok(QuoteType::Redeem),
+            "swap" => Ok(QuoteType::Swap),
+            other => Err(format!("Unknown quote type '{}'; expected mint or redeem", other)),
+        }
+    }
+}
+
+/// Query parameters shared by the quote endpoints.
+///
+/// ### Fields
+/// - `includeDust`: Also report `dust`, the smallest units that rounding the
+///   fees down left in the net amount, for accounting reconciliation.
+/// - `cluster`: `mainnet` (default) or `devnet`; selects that cluster's fee schedule.
+///
+/// ### Example
+/// - `?includeDust=true`
+/// - `?cluster=devnet`
+#[derive(Debug, Default, Deserialize)]
+pub struct QuoteQuery {
+    #[serde(rename = "includeDust", default)]
+    pub include_dust: bool,
+    pub cluster: Option<String>,
+}
+
+/// Apply the configured fees to a deposit amount, returning the net amount.
+///
+/// Shared by the quote endpoint and the mint/burn handlers so that slippage
+/// checks are made against exactly the amount a quote would report. `None`
+/// when the fees exceed 100% or the net amount is above `i64::MAX`, the
+/// range of `minimumReceived`.
+pub fn compute_quote(fees: &FeeSchedule, deposit_amount: Amount) -> Option<i64> {
+    fees.breakdown(deposit_amount).and_then(|breakdown| i64::try_from(breakdown.net).ok())
+}
+
+/// Validate a quote request and split its amount into fees and net, using
+/// the fee schedule of the requested cluster.
+pub fn quote_breakdown(state: &AppState, query: &QuoteQuery, req: &QuoteRequest) -> Result<FeeBreakdown, ApiError> {
+    let cluster = parse_param("cluster", query.cluster.as_deref(), Cluster::default())?;
+    state.stablecoins.ensure_available(req.stablecoinIndex)?;
+    state.config.fees(cluster).breakdown(req.depositAmount).ok_or_else(|| {
+        tracing::error!(%cluster, "Configured fees exceed 100%");
+        ApiError::Internal
+    })
 }
 
 /// Handler for `POST /stablecoin/quote/{type}`.
 ///
-/// Supports both `mint` and `redeem` types.  
+/// Supports both `mint` and `redeem` types; recognized types that are not
+/// implemented yet (`swap`) return 501, anything else 404.
 /// Validates the request and returns either a success quote or an error.
+/// With `?includeDust=true` the rounding remainder is reported as a `dust`
+/// field next to `data`.
 ///
 /// # Examples
 ///
@@ -50,47 +136,80 @@ pub struct QuoteRequest {
 /// }
 /// ```
 pub async fn get_mint_redeem_quote(
+    State(state): State<AppState>,
     Path(quote_type): Path<String>,
-    Json(req): Json<QuoteRequest>,
-) -> impl IntoResponse {
-    // Validate deposit amount
-    if req.depositAmount <= 0 {
-        let error = json!({
-            "success": false,
-            "message": "Invalid request data: depositAmount must be positive"
-        });
-        return (StatusCode::BAD_REQUEST, Json(error));
-    }
+    Query(query): Query<QuoteQuery>,
+    JsonBody(req): JsonBody<QuoteRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let _span = handler_span!(
+        "POST /stablecoins/quote/:type",
+        stablecoin_index = req.stablecoinIndex,
+        amount = req.depositAmount.get(),
+    )
+    .entered();
+    let quote_type = quote_type.parse::<QuoteType>().map_err(ApiError::NotFound)?;
 
-    // Simulated calculation: apply a 0.1% fee
-    let quoted_amount = req.depositAmount - (req.depositAmount / 1000);
-
-    match quote_type.as_str() {
-        "mint" | "redeem" => {
-            let response = json!({
+    match quote_type {
+        QuoteType::Mint | QuoteType::Redeem => {
+            let breakdown = quote_breakdown(&state, &query, &req)?;
+            let mut response = json!({
                 "success": true,
-                "data": quoted_amount
-            });
-            (StatusCode::OK, Json(response))
-        }
-        _ => {
-            let error = json!({
-                "success": false,
-                "message": "Invalid request type"
+                "data": breakdown.net
             });
-            (StatusCode::NOT_FOUND, Json(error))
+            if query.include_dust {
+                response["dust"] = json!(breakdown.dust);
+            }
+            Ok((StatusCode::OK, Json(response)))
         }
+        // Every recognized type without a pricing implementation yet
+        _ => Err(ApiError::NotImplemented("quote type not yet supported".to_string())),
     }
 }
 
-/// Example error handler for internal server errors.
-pub async fn get_mint_redeem_quote_error() -> impl IntoResponse {
-    let response = json!({
-        "success": false,
-        "message": "Internal server error"
-    });
-
-    (StatusCode::INTERNAL_SERVER_ERROR, Json(response))
+/// Handler for `POST /stablecoin/quote/breakdown`.
+///
+/// Takes the same body as the quote endpoint but returns where the money goes:
+/// the gross amount, each fee component (`REFLECT_PROTOCOL_FEE_BPS`,
+/// `REFLECT_INTEGRATION_FEE_BPS`) and the net amount, which sum back to the
+/// gross amount. With `?includeDust=true` the units rounding moved from the
+/// fees into the net amount are included as `dust`.
+///
+/// # Examples
+///
+/// ```bash
+/// curl --request POST \
+///   --url http://localhost:3000/stablecoin/quote/breakdown \
+///   --header 'Content-Type: application/json' \
+///   --data '{
+///     "stablecoinIndex": 0,
+///     "depositAmount": 1000000
+///   }'
+/// ```
+///
+/// Expected output:
+/// ```json
+/// {
+///   "success": true,
+///   "data": { "gross": 1000000, "protocol_fee": 1000, "integration_fee": 0, "net": 999000 }
+/// }
+/// ```
+pub async fn get_quote_breakdown(
+    State(state): State<AppState>,
+    Query(query): Query<QuoteQuery>,
+    JsonBody(req): JsonBody<QuoteRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let _span = handler_span!(
+        "POST /stablecoins/quote/breakdown",
+        stablecoin_index = req.stablecoinIndex,
+        amount = req.depositAmount.get(),
+    )
+    .entered();
+    let breakdown = quote_breakdown(&state, &query, &req)?;
+    let mut data = json!(state.cased(breakdown));
+    if query.include_dust {
+        data["dust"] = json!(breakdown.dust);
+    }
+    Ok((StatusCode::OK, Json(json!({ "success": true, "data": data }))))
 }
 
 #[cfg(test)]
@@ -99,17 +218,59 @@ mod tests {
     use axum::body::to_bytes;
     use axum::http::StatusCode;
     use axum::response::IntoResponse;
+    use proptest::prelude::*;
     use serde_json::Value;
 
+    proptest! {
+        #[test]
+        fn fee_math_invariants(
+            deposit in 1..=u64::MAX,
+            protocol_fee_bps in 0..=10_000u32,
+            integration_fee_bps in 0..=10_000u32,
+        ) {
+            let fees = FeeSchedule { protocol_fee_bps, integration_fee_bps };
+            let Some(breakdown) = fees.breakdown(Amount::new(deposit).unwrap()) else {
+                prop_assert!(protocol_fee_bps + integration_fee_bps > 10_000);
+                return Ok(());
+            };
+
+            let output = breakdown.net;
+            let fee = breakdown.protocol_fee + breakdown.integration_fee;
+            let dust = breakdown.dust;
+            prop_assert_eq!(compute_quote(&fees, Amount::new(deposit).unwrap()), i64::try_from(output).ok());
+            // Amounts are unsigned, so never negative
+            prop_assert!(output <= deposit);
+            prop_assert!(fee <= deposit);
+            // Two fees are rounded down, each by less than one unit, and the
+            // user keeps the remainder
+            prop_assert!(dust <= 2);
+            prop_assert_eq!(output + fee, deposit);
+        }
+
+        #[test]
+        fn huge_deposits_quote_without_overflow(deposit in (i64::MAX as u64 + 1)..=u64::MAX) {
+            let req = QuoteRequest { stablecoinIndex: 0, depositAmount: Amount::new(deposit).unwrap() };
+            let breakdown = quote_breakdown(&AppState::default(), &QuoteQuery::default(), &req).unwrap();
+            prop_assert_eq!(breakdown.net + breakdown.protocol_fee + breakdown.integration_fee, deposit);
+
+            // Mint and burn compare the quote with a signed minimumReceived
+            let quoted = compute_quote(&FeeSchedule::default(), req.depositAmount);
+            prop_assert_eq!(quoted, i64::try_from(breakdown.net).ok());
+        }
+    }
+
+
     #[tokio::test]
     async fn test_mint_success() {
         let req = QuoteRequest {
             stablecoinIndex: 0,
-            depositAmount: 1_000_000,
+            depositAmount: Amount::new(1_000_000).unwrap(),
         };
         let response = get_mint_redeem_quote(
+            State(AppState::default()),
             Path("mint".to_string()),
-            Json(req),
+            Query(QuoteQuery::default()),
+            JsonBody(req),
         )
         .await
         .into_response();
@@ -124,15 +285,42 @@ mod tests {
         assert_eq!(json["data"], Value::Number(999000.into()));
     }
 
+    #[tokio::test]
+    async fn test_devnet_quote_uses_devnet_fee() {
+        let state = AppState::new(crate::config::Config {
+            protocol_fee_bps_devnet: Some(0),
+            integration_fee_bps_mainnet: Some(20),
+            ..crate::config::Config::default()
+        });
+        let quote = |cluster: Option<&str>| {
+            let query = QuoteQuery { cluster: cluster.map(str::to_string), ..QuoteQuery::default() };
+            let req = QuoteRequest { stablecoinIndex: 0, depositAmount: Amount::new(1_000_000).unwrap() };
+            get_mint_redeem_quote(State(state.clone()), Path("mint".to_string()), Query(query), JsonBody(req))
+        };
+
+        // Devnet drops the protocol fee; mainnet keeps it and adds its own integration fee
+        for (cluster, expected) in [(Some("devnet"), 1_000_000), (Some("mainnet"), 997_000), (None, 997_000)] {
+            let response = quote(cluster).await.into_response();
+            let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+            let json: Value = serde_json::from_slice(&bytes).unwrap();
+            assert_eq!(json["data"], expected, "{cluster:?}");
+        }
+
+        let response = quote(Some("testnet")).await.into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[tokio::test]
     async fn test_redeem_success() {
         let req = QuoteRequest {
             stablecoinIndex: 0,
-            depositAmount: 1_000_000,
+            depositAmount: Amount::new(1_000_000).unwrap(),
         };
         let response = get_mint_redeem_quote(
+            State(AppState::default()),
             Path("redeem".to_string()),
-            Json(req),
+            Query(QuoteQuery::default()),
+            JsonBody(req),
         )
         .await
         .into_response();
@@ -149,66 +337,170 @@ mod tests {
 
     #[tokio::test]
     async fn test_invalid_deposit_amount() {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        for (amount, reason) in [("-100", "must be positive"), ("0", "must be positive"), ("1000.5", "must be a whole number")] {
+            let app = axum::Router::new()
+                .route("/:type", axum::routing::post(get_mint_redeem_quote))
+                .with_state(AppState::default());
+            let request = Request::post("/mint")
+                .header("content-type", "application/json")
+                .body(Body::from(format!(r#"{{"stablecoinIndex": 0, "depositAmount": {}}}"#, amount)))
+                .unwrap();
+            let response = app.oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+            let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+            let json: Value = serde_json::from_slice(&bytes).unwrap();
+            assert_eq!(json["success"], Value::Bool(false));
+            assert_eq!(json["message"], format!("field `depositAmount` is invalid: {}", reason));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_type() {
         let req = QuoteRequest {
             stablecoinIndex: 0,
-            depositAmount: -100,
+            depositAmount: Amount::new(1_000_000).unwrap(),
         };
         let response = get_mint_redeem_quote(
-            Path("mint".to_string()),
-            Json(req),
+            State(AppState::default()),
+            Path("invalid".to_string()),
+            Query(QuoteQuery::default()),
+            JsonBody(req),
         )
         .await
         .into_response();
 
         let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(parts.status, StatusCode::NOT_FOUND);
 
         let bytes = to_bytes(body, 1024).await.unwrap();
         let json: Value = serde_json::from_slice(&bytes).unwrap();
 
         assert_eq!(json["success"], Value::Bool(false));
-        assert_eq!(
-            json["message"],
-            Value::String("Invalid request data: depositAmount must be positive".into())
-        );
+        assert_eq!(json["message"], "Unknown quote type 'invalid'; expected mint or redeem");
     }
 
     #[tokio::test]
-    async fn test_invalid_type() {
+    async fn test_unimplemented_type_is_501() {
+        let quote = |quote_type: &str| {
+            get_mint_redeem_quote(
+                State(AppState::default()),
+                Path(quote_type.to_string()),
+                Query(QuoteQuery::default()),
+                JsonBody(QuoteRequest {
+                    stablecoinIndex: 0,
+                    depositAmount: Amount::new(1_000_000).unwrap(),
+                }),
+            )
+        };
+
+        let response = quote("swap").await.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_IMPLEMENTED);
+        let json: Value = serde_json::from_slice(&to_bytes(response.into_body(), 1024).await.unwrap()).unwrap();
+        assert_eq!(json["success"], false);
+        assert_eq!(json["message"], "quote type not yet supported");
+
+        for implemented in ["mint", "redeem"] {
+            assert_eq!(quote(implemented).await.into_response().status(), StatusCode::OK);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_disabled_stablecoin() {
+        let state = AppState::default();
+        state.stablecoins.set_enabled(0, false);
+
         let req = QuoteRequest {
             stablecoinIndex: 0,
-            depositAmount: 1_000_000,
+            depositAmount: Amount::new(1_000_000).unwrap(),
         };
         let response = get_mint_redeem_quote(
-            Path("invalid".to_string()),
-            Json(req),
+            State(state),
+            Path("mint".to_string()),
+            Query(QuoteQuery::default()),
+            JsonBody(req),
         )
         .await
         .into_response();
 
         let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::NOT_FOUND);
+        assert_eq!(parts.status, StatusCode::SERVICE_UNAVAILABLE);
 
         let bytes = to_bytes(body, 1024).await.unwrap();
         let json: Value = serde_json::from_slice(&bytes).unwrap();
-
-        assert_eq!(json["success"], Value::Bool(false));
-        assert_eq!(json["message"], Value::String("Invalid request type".into()));
+        assert_eq!(json["message"], Value::String("stablecoin temporarily disabled".into()));
     }
 
     #[tokio::test]
-    async fn test_internal_server_error() {
-        let response = get_mint_redeem_quote_error().await.into_response();
-        let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+    async fn test_breakdown_sums_to_gross() {
+        use crate::config::Config;
+        use std::sync::Arc;
 
-        let bytes = to_bytes(body, 1024).await.unwrap();
-        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        let state = AppState {
+            config: Arc::new(Config {
+                protocol_fee_bps: 10,
+                integration_fee_bps: 25,
+                ..Config::default()
+            }),
+            ..AppState::default()
+        };
+        for amount in [1_000_000, 123_457, 999] {
+            let req = QuoteRequest { stablecoinIndex: 0, depositAmount: Amount::new(amount).unwrap() };
+            let response = get_quote_breakdown(State(state.clone()), Query(QuoteQuery { include_dust: true, ..QuoteQuery::default() }), JsonBody(req)).await.into_response();
 
-        assert_eq!(json["success"], Value::Bool(false));
-        assert_eq!(json["message"], Value::String("Internal server error".into()));
+            let (parts, body) = response.into_parts();
+            assert_eq!(parts.status, StatusCode::OK);
+
+            let bytes = to_bytes(body, 1024).await.unwrap();
+            let json: Value = serde_json::from_slice(&bytes).unwrap();
+            let part = |key: &str| json["data"][key].as_u64().unwrap();
+            assert_eq!(part("gross"), amount);
+            assert_eq!(part("protocol_fee") + part("integration_fee") + part("net"), amount);
+            assert!(part("dust") <= 2);
+        }
+
+        let req = QuoteRequest { stablecoinIndex: 0, depositAmount: Amount::new(1_000_000).unwrap() };
+        let response = get_quote_breakdown(State(state), Query(QuoteQuery::default()), JsonBody(req)).await.into_response();
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            json["data"],
+            json!({ "gross": 1_000_000, "protocol_fee": 1_000, "integration_fee": 2_500, "net": 996_500 })
+        );
     }
-}
 
+    #[tokio::test]
+    async fn test_include_dust() {
+        let quote = |include_dust: bool| async move {
+            let req = QuoteRequest { stablecoinIndex: 0, depositAmount: Amount::new(999).unwrap() };
+            let response = get_mint_redeem_quote(
+                State(AppState::default()),
+                Path("mint".to_string()),
+                Query(QuoteQuery { include_dust, ..QuoteQuery::default() }),
+                JsonBody(req),
+            )
+            .await
+            .into_response();
+            let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+            serde_json::from_slice::<Value>(&bytes).unwrap()
+        };
+
+        let json = quote(false).await;
+        assert_eq!(json["data"], 999);
+        assert!(json.get("dust").is_none());
 
+        // 999 at 10 bps: the 0.999 fee rounds down to 0, leaving 1 unit with the user
+        let json = quote(true).await;
+        assert_eq!(json["data"], 999);
+        assert_eq!(json["dust"], 1);
+    }
 
+    #[test]
+    fn example_body_round_trips() {
+        crate::example::assert_round_trips::<QuoteRequest>();
+    }
+}
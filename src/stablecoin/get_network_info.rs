@@ -0,0 +1,195 @@
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::time::Duration;
+use tracing::Instrument;
+
+use crate::cache::TtlCache;
+use crate::cluster::Cluster;
+use crate::data_source::DataSource;
+use crate::error::ApiError;
+use crate::rpc::EpochInfo;
+use crate::span::handler_span;
+use crate::validation::parse_param;
+use crate::AppState;
+
+/// How long a cluster's slot / epoch answer is reused. Roughly five slots.
+pub const NETWORK_INFO_TTL: Duration = Duration::from_secs(2);
+
+/// Recently fetched [`EpochInfo`] per cluster, kept for [`NETWORK_INFO_TTL`].
+#[derive(Debug, Clone)]
+pub struct NetworkInfoCache(TtlCache<EpochInfo>);
+
+impl Default for NetworkInfoCache {
+    fn default() -> Self {
+        Self(TtlCache::new(NETWORK_INFO_TTL))
+    }
+}
+
+/// Query parameters for `GET /stablecoins/network`.
+///
+/// ### Example
+/// - `?cluster=mainnet` (default)
+#[derive(Debug, Default, Deserialize)]
+pub struct NetworkQuery {
+    pub cluster: Option<String>,
+}
+
+/// Current position of the cluster.
+///
+/// ### Example
+/// ```json
+/// {
+///   "slot": 312345678,
+///   "epoch": 722,
+///   "blockHeight": 290123456
+/// }
+/// ```
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkData {
+    pub slot: u64,
+    pub epoch: u64,
+    pub block_height: u64,
+}
+
+impl From<EpochInfo> for NetworkData {
+    fn from(info: EpochInfo) -> Self {
+        Self {
+            slot: info.slot,
+            epoch: info.epoch,
+            block_height: info.block_height,
+        }
+    }
+}
+
+/// Handler for `GET /stablecoins/network`.
+///
+/// Returns the cluster's current slot, epoch and block height from its RPC
+/// node. Answers are cached for [`NETWORK_INFO_TTL`]; `X-Data-Source` reports
+/// `cache` or `upstream`. Responds with `503` when no RPC endpoint is
+/// configured for the cluster and `502` when the node call fails.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request GET \
+///   --url "http://localhost:3000/stablecoins/network?cluster=mainnet"
+/// ```
+///
+/// Expected output:
+/// ```json
+/// {
+///   "success": true,
+///   "data": { "slot": 312345678, "epoch": 722, "blockHeight": 290123456 }
+/// }
+/// ```
+pub async fn get_network_info(
+    State(state): State<AppState>,
+    Query(query): Query<NetworkQuery>,
+) -> impl IntoResponse {
+    let span = handler_span!("GET /stablecoins/network", cluster = query.cluster.as_deref());
+    network_info(state, query).instrument(span).await
+}
+
+async fn network_info(state: AppState, query: NetworkQuery) -> (DataSource, Result<Json<Value>, ApiError>) {
+    let cluster = match parse_param("cluster", query.cluster.as_deref(), Cluster::default()) {
+        Ok(cluster) => cluster,
+        Err(error) => return (DataSource::Local, Err(error)),
+    };
+
+    let Some(client) = state.rpc.get(cluster) else {
+        let error = ApiError::Unavailable(format!("RPC is not configured for cluster '{}'", cluster));
+        return (DataSource::Local, Err(error));
+    };
+
+    let cache = &state.network_cache.0;
+    if let Some(info) = cache.get(cluster.as_str()) {
+        return (
+            DataSource::Cache,
+            Ok(Json(json!({ "success": true, "data": state.cased(NetworkData::from(info)) }))),
+        );
+    }
+
+    let info = client.get_epoch_info(state.config.rpc_commitment).await.map_err(|e| {
+        tracing::warn!(%cluster, error = %e, "Failed to fetch epoch info");
+        e.to_api_error("Failed to fetch network info from the RPC node")
+    });
+    if let Ok(info) = info {
+        cache.insert(cluster.as_str(), info);
+    }
+    (
+        DataSource::Upstream,
+        info.map(|info| Json(json!({ "success": true, "data": state.cased(NetworkData::from(info)) }))),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use axum::http::StatusCode;
+    use std::sync::Arc;
+
+    use crate::data_source::DATA_SOURCE_HEADER;
+    use crate::rpc::mock::MockTransport;
+    use crate::rpc::{RpcClient, RpcClients};
+
+    fn state_with(transport: MockTransport) -> AppState {
+        AppState {
+            rpc: RpcClients::default().with(RpcClient::new(Cluster::Mainnet, Arc::new(transport))),
+            ..AppState::default()
+        }
+    }
+
+    async fn send(state: AppState, query: NetworkQuery) -> (StatusCode, String, Value) {
+        let response = get_network_info(State(state), Query(query)).await.into_response();
+        let status = response.status();
+        let source = response.headers()[DATA_SOURCE_HEADER].to_str().unwrap().to_string();
+        let bytes = to_bytes(response.into_body(), 1024).await.unwrap();
+        (status, source, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_returns_and_caches_epoch_info() {
+        let transport = MockTransport::new().respond(
+            "getEpochInfo",
+            json!({
+                "absoluteSlot": 312345678,
+                "blockHeight": 290123456,
+                "epoch": 722,
+                "slotIndex": 12345,
+                "slotsInEpoch": 432000
+            }),
+        );
+        let state = state_with(transport.clone());
+
+        let (status, source, json) = send(state.clone(), NetworkQuery::default()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(source, "upstream");
+        assert_eq!(json["data"], json!({ "slot": 312345678, "epoch": 722, "blockHeight": 290123456 }));
+
+        let (status, source, json) = send(state, NetworkQuery::default()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(source, "cache");
+        assert_eq!(json["data"]["slot"], 312345678);
+        assert_eq!(transport.calls().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rpc_not_configured() {
+        let (status, _, json) = send(
+            state_with(MockTransport::new()),
+            NetworkQuery { cluster: Some("devnet".to_string()) },
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(json["success"], false);
+        assert_eq!(json["message"], "RPC is not configured for cluster 'devnet'");
+    }
+}
@@ -0,0 +1,207 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::cluster::Cluster;
+use crate::error::ApiError;
+use crate::json_body::{from_value, JsonBody};
+use crate::span::handler_span;
+use crate::validation::{ensure_batch_size, invalid_field, parse_param};
+use crate::AppState;
+use super::get_mint_redeem_quote::{quote_breakdown, QuoteQuery, QuoteRequest, QuoteType};
+
+/// Request body for `POST /stablecoins/quote/batch`.
+///
+/// ### Fields
+/// - `quote_type`: `mint` or `redeem`, applied to every item.
+/// - `items`: Up to `REFLECT_MAX_BATCH_SIZE` bodies of the single quote endpoint
+///   (`stablecoinIndex`, `depositAmount`). Each is validated on its own.
+///
+/// ### Example Request
+/// ```json
+/// {
+///   "quote_type": "mint",
+///   "items": [
+///     { "stablecoinIndex": 0, "depositAmount": 1000000 },
+///     { "stablecoinIndex": 0, "depositAmount": -5 }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct QuoteBatchRequest {
+    pub quote_type: String,
+    pub items: Vec<Value>,
+}
+
+/// Handler for `POST /stablecoins/quote/batch`.
+///
+/// Quotes every item with the same fee logic as `POST /stablecoins/quote/{type}`
+/// and answers with one result per item, in order. An item that fails
+/// validation gets an error object in its slot instead of failing the batch.
+/// Problems with the batch itself are still request errors: more than
+/// `REFLECT_MAX_BATCH_SIZE` items is 400, an unknown `quote_type` 422, a type
+/// that cannot be priced yet (`swap`) 501 and a bad `cluster` 400.
+/// `?includeDust=true` adds `dust` to each successful item.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request POST \
+///   --url http://localhost:3000/stablecoins/quote/batch \
+///   --header 'Content-Type: application/json' \
+///   --data '{
+///     "quote_type": "mint",
+///     "items": [
+///       { "stablecoinIndex": 0, "depositAmount": 1000000 },
+///       { "stablecoinIndex": 0, "depositAmount": -5 }
+///     ]
+///   }'
+/// ```
+///
+/// Expected output:
+/// ```json
+/// {
+///   "success": true,
+///   "data": [
+///     { "success": true, "data": 999000 },
+///     { "success": false, "message": "field `depositAmount` is invalid: must be positive" }
+///   ]
+/// }
+/// ```
+pub async fn get_quote_batch(
+    State(state): State<AppState>,
+    Query(query): Query<QuoteQuery>,
+    JsonBody(req): JsonBody<QuoteBatchRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let _span = handler_span!("POST /stablecoins/quote/batch", items = req.items.len()).entered();
+    ensure_batch_size(req.items.len(), state.config.max_batch_size)?;
+    match req.quote_type.parse::<QuoteType>() {
+        Ok(QuoteType::Mint | QuoteType::Redeem) => {}
+        Ok(_) => return Err(ApiError::NotImplemented("quote type not yet supported".to_string())),
+        Err(message) => return Err(invalid_field("quote_type", message)),
+    }
+    parse_param("cluster", query.cluster.as_deref(), Cluster::default())?;
+
+    let results: Vec<Value> = req.items.into_iter().map(|item| quote_item(&state, &query, item)).collect();
+    Ok((StatusCode::OK, Json(json!({ "success": true, "data": results }))))
+}
+
+/// The result slot for one batch item: the single endpoint's success body,
+/// or its error body.
+fn quote_item(state: &AppState, query: &QuoteQuery, item: Value) -> Value {
+    let req = match from_value::<QuoteRequest>(item) {
+        Ok(req) => req,
+        Err(error) => return json!({ "success": false, "message": error.to_string() }),
+    };
+    match quote_breakdown(state, query, &req) {
+        Ok(breakdown) => {
+            let mut result = json!({ "success": true, "data": breakdown.net });
+            if query.include_dust {
+                result["dust"] = json!(breakdown.dust);
+            }
+            result
+        }
+        Err(error) => json!({ "success": false, "message": error.message() }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::post;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    async fn send(state: AppState, uri: &str, body: Value) -> (StatusCode, Value) {
+        let app = Router::new().route("/quote/batch", post(get_quote_batch)).with_state(state);
+        let request = Request::post(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_mixed_batch_reports_each_item() {
+        let body = json!({
+            "quote_type": "mint",
+            "items": [
+                { "stablecoinIndex": 0, "depositAmount": 1_000_000 },
+                { "stablecoinIndex": 0, "depositAmount": -5 },
+                { "stablecoinIndex": 0 },
+                { "stablecoinIndex": 99, "depositAmount": 1_000_000 },
+                { "stablecoinIndex": 0, "depositAmount": 999 }
+            ]
+        });
+        let (status, json) = send(AppState::default(), "/quote/batch?includeDust=true", body).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["success"], true);
+        assert_eq!(
+            json["data"],
+            json!([
+                { "success": true, "data": 999_000, "dust": 0 },
+                { "success": false, "message": "field `depositAmount` is invalid: must be positive" },
+                { "success": false, "message": "field `depositAmount` is required" },
+                { "success": false, "message": "Stablecoin with the specified index not found" },
+                { "success": true, "data": 999, "dust": 1 }
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_batch_matches_single_quotes() {
+        let body = json!({
+            "quote_type": "redeem",
+            "items": [{ "stablecoinIndex": 0, "depositAmount": 1_000_000 }]
+        });
+        let (status, json) = send(AppState::default(), "/quote/batch", body).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"], json!([{ "success": true, "data": 999_000 }]));
+
+        let (status, json) = send(AppState::default(), "/quote/batch", json!({ "quote_type": "mint", "items": [] })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_batch_level_errors() {
+        let item = json!({ "stablecoinIndex": 0, "depositAmount": 1_000_000 });
+
+        let max = AppState::default().config.max_batch_size;
+        let items = vec![item.clone(); max + 1];
+        let (status, json) = send(AppState::default(), "/quote/batch", json!({ "quote_type": "mint", "items": items })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["message"], "batch too large (max 100)");
+
+        let items = vec![item.clone(); max];
+        let (status, _) = send(AppState::default(), "/quote/batch", json!({ "quote_type": "mint", "items": items })).await;
+        assert_eq!(status, StatusCode::OK);
+
+        // The cap follows REFLECT_MAX_BATCH_SIZE
+        let small = AppState::new(crate::config::Config { max_batch_size: 2, ..Default::default() });
+        let (status, json) = send(small, "/quote/batch", json!({ "quote_type": "mint", "items": [item, item, item] })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["message"], "batch too large (max 2)");
+
+        let (status, json) = send(AppState::default(), "/quote/batch", json!({ "quote_type": "bogus", "items": [item] })).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["message"], "Unknown quote type 'bogus'; expected mint or redeem");
+
+        let (status, json) = send(AppState::default(), "/quote/batch", json!({ "quote_type": "swap", "items": [item] })).await;
+        assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+        assert_eq!(json, json!({ "success": false, "message": "quote type not yet supported" }));
+
+        let (status, _) = send(AppState::default(), "/quote/batch?cluster=testnet", json!({ "quote_type": "mint", "items": [item] })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
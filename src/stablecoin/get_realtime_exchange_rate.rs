@@ -1,11 +1,14 @@
 use axum::{
-    extract::Path,
-    http::StatusCode,
-    response::IntoResponse,
+    extract::State,
     Json,
 };
-use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde::Serialize;
+
+use crate::error::ApiError;
+use crate::json_case::Cased;
+use crate::path::Path;
+use crate::span::handler_span;
+use crate::AppState;
 
 /// Realtime exchange rate data structure.
 ///
@@ -16,8 +19,8 @@ use serde_json::json;
 /// ### Example
 /// ```json
 /// {
-///   "base": 1016858791,
-///   "receipt": 1016858791
+///   "base": 1016789908,
+///   "receipt": 1016791576
 /// }
 /// ```
 #[derive(Debug, Serialize)]
@@ -33,37 +36,30 @@ pub struct RealtimeExchangeRateData {
 /// {
 ///   "success": true,
 ///   "data": {
-///     "base": 1016858791,
-///     "receipt": 1016858791
-///   }
+///     "base": 1016789908,
+///     "receipt": 1016791576
+///   },
+///   "stale": false
 /// }
 /// ```
+///
+/// `stale` is `true` when the rate feed is behind (see
+/// `REFLECT_RATE_STALE_AFTER_SECS`), and a `warning` then explains how far.
+/// Both sit at the top level, as in the latest rates and snapshot responses.
 #[derive(Debug, Serialize)]
 pub struct RealtimeExchangeRateSuccessResponse {
     pub success: bool,
     pub data: RealtimeExchangeRateData,
-}
-
-/// Error response structure.
-///
-/// ### Example Error Response (HTTP 400/500)
-/// ```json
-/// {
-///   "success": false,
-///   "message": "Internal server error"
-/// }
-/// ```
-#[derive(Debug, Serialize)]
-pub struct RealtimeExchangeRateErrorResponse {
-    pub success: bool,
-    pub message: &'static str,
+    pub stale: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
 }
 
 /// Handler for:
 ///
 /// ### `GET /stablecoin/{index}/exchange-rate`
 ///
-/// Retrieves the realtime exchange rate for a specific stablecoin.
+/// Retrieves the latest exchange rate for a specific stablecoin from the shared rate store.
 ///
 /// # Example
 ///
@@ -72,55 +68,48 @@ pub struct RealtimeExchangeRateErrorResponse {
 ///   --url "http://localhost:3000/stablecoin/0/exchange-rate"
 /// ```
 pub async fn get_realtime_exchange_rate(
+    State(state): State<AppState>,
     Path(index): Path<u32>,
-) -> impl IntoResponse {
-    // Validate stablecoin index (only 0 exists in Reflect API)
-    if index != 0 {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(json!(RealtimeExchangeRateErrorResponse {
-                success: false,
-                message: "Invalid request data: depositAmount must be positive",
-            })),
-        );
-    }
+) -> Result<Json<Cased<RealtimeExchangeRateSuccessResponse>>, ApiError> {
+    let _span = handler_span!("GET /stablecoins/stablecoin/:index/exchange-rate", stablecoin_index = index).entered();
+    let Some(rate) = state.stablecoins.get(index).and_then(|_| state.rates.get(index)) else {
+        return Err(ApiError::Unprocessable(
+            "Stablecoin with the specified index not found".to_string(),
+        ));
+    };
 
-    // Simulated realtime exchange rate data
+    let staleness = state.rates.staleness(state.config.rate_stale_after());
     let data = RealtimeExchangeRateData {
-        base: 1016858791,
-        receipt: 1016858791,
+        base: rate.base_usd_value_bps,
+        receipt: rate.receipt_usd_value_bps,
     };
 
-    (
-        StatusCode::OK,
-        Json(json!(RealtimeExchangeRateSuccessResponse {
-            success: true,
-            data,
-        })),
-    )
-}
-
-/// Example internal server error handler.
-pub async fn get_realtime_exchange_rate_error() -> impl IntoResponse {
-    (
-        StatusCode::INTERNAL_SERVER_ERROR,
-        Json(json!(RealtimeExchangeRateErrorResponse {
-            success: false,
-            message: "Internal server error",
-        })),
-    )
+    Ok(Json(state.cased(RealtimeExchangeRateSuccessResponse {
+        success: true,
+        data,
+        stale: staleness.stale,
+        warning: staleness.warning(),
+    })))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use axum::body::to_bytes;
+    use axum::http::StatusCode;
     use axum::response::IntoResponse;
     use serde_json::Value;
+    use std::sync::Arc;
+
+    use crate::config::Config;
+    use crate::stablecoin::get_latest_exchange_rates::ExchangeRateData;
+    use crate::stablecoin::rates::ExchangeRateStore;
+    use crate::stablecoin::registry::StablecoinRegistry;
+    use crate::timestamp::Timestamp;
 
     #[tokio::test]
     async fn test_realtime_exchange_rate_success() {
-        let response = get_realtime_exchange_rate(Path(0))
+        let response = get_realtime_exchange_rate(State(AppState::default()), Path(0))
             .await
             .into_response();
 
@@ -131,42 +120,80 @@ mod tests {
         let json: Value = serde_json::from_slice(&bytes).unwrap();
 
         assert_eq!(json["success"], true);
-        assert_eq!(json["data"]["base"], 1016858791);
-        assert_eq!(json["data"]["receipt"], 1016858791);
+        assert_eq!(json["data"]["base"], 1016789908);
+        assert_eq!(json["data"]["receipt"], 1016791576);
+        assert_eq!(json["stale"], false);
+        assert!(json.get("warning").is_none());
     }
 
-    #[tokio::test]
-    async fn test_realtime_exchange_rate_invalid_index() {
-        let response = get_realtime_exchange_rate(Path(99))
-            .await
-            .into_response();
+    async fn with_rate_age(age: chrono::Duration) -> Value {
+        with_rate_age_and_limit(age, 300).await
+    }
 
-        let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+    async fn with_rate_age_and_limit(age: chrono::Duration, rate_stale_after_secs: u64) -> Value {
+        let timestamp = Timestamp(Timestamp::now().0 - age);
+        let state = AppState {
+            config: Arc::new(Config {
+                rate_stale_after_secs,
+                ..Config::default()
+            }),
+            rates: ExchangeRateStore::new(vec![ExchangeRateData {
+                id: 1,
+                stablecoin: 0,
+                base_usd_value_bps: 1_000_000_000,
+                timestamp,
+                receipt_usd_value_bps: 1_000_000_000,
+            }]),
+            ..AppState::default()
+        };
+        let response = get_realtime_exchange_rate(State(state), Path(0)).await.into_response();
+        let bytes = to_bytes(response.into_body(), 2048).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
 
-        let bytes = to_bytes(body, 2048).await.unwrap();
-        let json: Value = serde_json::from_slice(&bytes).unwrap();
+    #[tokio::test]
+    async fn test_fresh_and_stale_rates() {
+        let json = with_rate_age(chrono::Duration::seconds(10)).await;
+        assert_eq!(json["stale"], false);
+        assert!(json.get("warning").is_none());
+
+        let json = with_rate_age(chrono::Duration::hours(2)).await;
+        assert_eq!(json["stale"], true);
+        assert!(json["warning"].as_str().unwrap().starts_with("exchange rate feed is behind"));
+    }
 
-        assert_eq!(json["success"], false);
-        assert_eq!(
-            json["message"],
-            "Invalid request data: depositAmount must be positive"
-        );
+    #[tokio::test]
+    async fn test_huge_stale_limit_never_marks_stale() {
+        for limit in [i64::MAX as u64 / 1000 + 1, u64::MAX] {
+            let json = with_rate_age_and_limit(chrono::Duration::days(3650), limit).await;
+            assert_eq!(json["success"], true);
+            assert_eq!(json["stale"], false);
+        }
     }
 
     #[tokio::test]
-    async fn test_realtime_exchange_rate_internal_error() {
-        let response = get_realtime_exchange_rate_error()
+    async fn test_realtime_exchange_rate_invalid_index() {
+        let response = get_realtime_exchange_rate(State(AppState::default()), Path(99))
             .await
             .into_response();
 
         let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
 
         let bytes = to_bytes(body, 2048).await.unwrap();
         let json: Value = serde_json::from_slice(&bytes).unwrap();
 
         assert_eq!(json["success"], false);
-        assert_eq!(json["message"], "Internal server error");
+        assert_eq!(json["message"], "Stablecoin with the specified index not found");
+    }
+
+    #[tokio::test]
+    async fn test_rate_for_unregistered_stablecoin_rejected() {
+        let state = AppState {
+            stablecoins: StablecoinRegistry::new(vec![]),
+            ..AppState::default()
+        };
+        let response = get_realtime_exchange_rate(State(state), Path(0)).await.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
     }
 }
@@ -1,6 +1,110 @@
-use axum::{response::IntoResponse, Json, extract::Path};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
 use serde_json::json;
 
-pub async fn get_specific_apy(Path(stablecoin): Path<String>) -> impl IntoResponse {
-    Json(json!({"stablecoin": stablecoin, "apy": 0.02}))
+use super::apy::smoothed_apy;
+use crate::error::ApiError;
+use crate::path::Path;
+use crate::span::handler_span;
+use crate::timestamp::Timestamp;
+use crate::validation::require_int;
+use crate::AppState;
+
+/// Query parameters for specific APY retrieval.
+///
+/// ### Fields
+/// - `smooth`: Return the trailing N-sample moving average of the recorded APY
+///   history instead of the raw value, in percent like it. Must be between 1
+///   and the number of recorded snapshots.
+#[derive(Debug, Default, Deserialize)]
+pub struct SpecificApyQuery {
+    pub smooth: Option<String>,
+}
+
+/// Handler for `GET /stablecoins/stablecoin/:index/apy`.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request GET \
+///   --url "http://localhost:3000/stablecoins/stablecoin/0/apy?smooth=7"
+/// ```
+pub async fn get_specific_apy(
+    State(state): State<AppState>,
+    Path(stablecoin): Path<String>,
+    Query(query): Query<SpecificApyQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let _span = handler_span!("GET /stablecoins/stablecoin/:index/apy").entered();
+    let Some(smooth) = query.smooth.as_deref() else {
+        return Ok((StatusCode::OK, Json(json!({"stablecoin": stablecoin, "apy": 0.02}))));
+    };
+
+    let index = require_int::<u32>("index", Some(&stablecoin))?;
+    let (apy, _) = smoothed_apy(&state.apys, index, smooth, Timestamp(chrono::DateTime::<chrono::Utc>::MIN_UTC))?;
+    Ok((StatusCode::OK, Json(json!({"stablecoin": stablecoin, "apy": apy, "smooth": smooth}))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use serde_json::Value;
+
+    use crate::seed::Seed;
+    use crate::stablecoin::get_all_apy::ApyData;
+
+    fn seeded(apys: &[i64]) -> AppState {
+        let apys = apys
+            .iter()
+            .enumerate()
+            .map(|(day, &apy)| ApyData {
+                index: 0,
+                apy,
+                timestamp: Timestamp::parse_rfc3339(&format!("2026-01-{:02}T00:00:00Z", day + 1)).unwrap(),
+            })
+            .collect();
+        AppState::with_seed(Seed { apys, ..Seed::default() })
+    }
+
+    async fn call(state: AppState, smooth: Option<&str>) -> (StatusCode, Value) {
+        let query = SpecificApyQuery { smooth: smooth.map(str::to_string) };
+        let response = get_specific_apy(State(state), Path("0".to_string()), Query(query))
+            .await
+            .into_response();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, 2048).await.unwrap();
+        (parts.status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_raw_apy() {
+        let (status, raw) = call(seeded(&[200, 260]), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(raw["apy"], 0.02);
+        assert!(raw.get("smooth").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_smoothed_apy_is_in_percent() {
+        let state = seeded(&[200, 260, 230, 300]);
+
+        let (status, smoothed) = call(state.clone(), Some("1")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(smoothed["apy"], 3.0);
+
+        let (_, smoothed) = call(state, Some("2")).await;
+        assert_eq!(smoothed["apy"], 2.65);
+    }
+
+    #[tokio::test]
+    async fn test_smooth_longer_than_history() {
+        let (status, body) = call(seeded(&[200, 260]), Some("3")).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["message"], "Invalid request data: smooth must be between 1 and 2");
+    }
 }
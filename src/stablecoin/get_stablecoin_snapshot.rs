@@ -0,0 +1,116 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::span::handler_span;
+use crate::AppState;
+use super::get_all_apy::ApyData;
+use super::get_latest_exchange_rates::ExchangeRateData;
+
+/// Latest rates and APYs of every stablecoin, as served separately by
+/// `GET /stablecoins/exchange-rates` and `GET /stablecoins/apy`.
+///
+/// ### Example
+/// ```json
+/// {
+///   "exchange_rates": [
+///     {
+///       "id": 105511,
+///       "stablecoin": 0,
+///       "base_usd_value_bps": 1016789908,
+///       "timestamp": "2025-12-19T17:04:08.502Z",
+///       "receipt_usd_value_bps": 1016791576
+///     }
+///   ],
+///   "apy": [
+///     { "index": 0, "apy": 224, "timestamp": "2025-12-19T16:55:42.407Z" }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Serialize)]
+pub struct StablecoinSnapshot {
+    pub exchange_rates: Vec<ExchangeRateData>,
+    pub apy: Vec<ApyData>,
+}
+
+/// Success response structure for the snapshot endpoint.
+///
+/// `stale` and `warning` follow the exchange rates endpoint
+/// (see `REFLECT_RATE_STALE_AFTER_SECS`).
+#[derive(Debug, Serialize)]
+pub struct SnapshotSuccessResponse {
+    pub success: bool,
+    pub data: StablecoinSnapshot,
+    pub stale: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+}
+
+/// Handler for `GET /stablecoins/snapshot`.
+///
+/// Returns the latest exchange rate and APY of every stablecoin in one
+/// response, so dashboards need a single round-trip. Not paginated.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request GET \
+///   --url http://localhost:3000/stablecoins/snapshot
+/// ```
+pub async fn get_stablecoin_snapshot(State(state): State<AppState>) -> impl IntoResponse {
+    let _span = handler_span!("GET /stablecoins/snapshot").entered();
+    let staleness = state.rates.staleness(state.config.rate_stale_after());
+
+    (
+        StatusCode::OK,
+        Json(json!(state.cased(SnapshotSuccessResponse {
+            success: true,
+            data: StablecoinSnapshot {
+                exchange_rates: state.rates.latest(),
+                apy: state.apys.latest(),
+            },
+            stale: staleness.stale,
+            warning: staleness.warning(),
+        }))),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::{to_bytes, Body};
+    use axum::http::{Request, StatusCode};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use crate::seed::Seed;
+    use crate::{build_app, AppState};
+
+    async fn get(state: &AppState, uri: &str) -> Value {
+        let response = build_app(state.clone())
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK, "{}", uri);
+        serde_json::from_slice(&to_bytes(response.into_body(), 1 << 16).await.unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_matches_individual_endpoints() {
+        let state = AppState::with_seed(Seed::default());
+        let snapshot = get(&state, "/stablecoins/snapshot").await;
+        let rates = get(&state, "/stablecoins/exchange-rates").await;
+        let apy = get(&state, "/stablecoins/apy").await;
+
+        assert_eq!(snapshot["success"], true);
+        assert_eq!(snapshot["data"]["exchange_rates"].as_array().unwrap().len(), 2);
+        assert_eq!(snapshot["data"]["exchange_rates"], rates["data"]);
+        assert_eq!(snapshot["data"]["apy"].as_array().unwrap().len(), 2);
+        assert_eq!(snapshot["data"]["apy"], apy["data"]);
+        assert_eq!(snapshot["stale"], rates["stale"]);
+    }
+}
@@ -1,7 +1,14 @@
+use axum::extract::{Query, State};
 use axum::response::{IntoResponse, Json};
 use axum::http::StatusCode;
 use serde::Serialize;
 
+use crate::error::ApiError;
+use crate::pagination::{paginate, PageMeta, PageParams};
+use crate::span::handler_span;
+use crate::stablecoin::supply::SupplyInfo;
+use crate::AppState;
+
 /// Response structure for the `/stablecoin/limits` endpoint, matching the official Reflect API.
 ///
 /// ### Description
@@ -20,7 +27,8 @@ use serde::Serialize;
 ///       "remainingCapacity": 500000000,
 ///       "utilizationPercentage": 50
 ///     }
-///   ]
+///   ],
+///   "meta": { "total": 1, "limit": 100, "offset": 0, "has_more": false }
 /// }
 /// ```
 ///
@@ -42,6 +50,7 @@ use serde::Serialize;
 pub struct SupplyCapsSuccessResponse {
     success: bool,
     data: Vec<SupplyCap>,
+    meta: PageMeta,
 }
 
 #[derive(Debug, Serialize)]
@@ -57,45 +66,36 @@ pub struct SupplyCap {
     utilization_percentage: u32,
 }
 
-#[derive(Debug, Serialize)]
-pub struct SupplyCapsErrorResponse {
-    success: bool,
-    message: &'static str,
+impl From<SupplyInfo> for SupplyCap {
+    fn from(supply: SupplyInfo) -> Self {
+        Self {
+            index: supply.index,
+            supply_cap: supply.supply_cap,
+            current_supply: supply.current_supply,
+            remaining_capacity: supply.remaining_capacity(),
+            utilization_percentage: supply.utilization_percentage(),
+        }
+    }
 }
 
 /// Handler for `GET /stablecoin/limits`.
 ///
-/// Returns a JSON response with supply cap information for all stablecoins.  
-/// In this scaffold, we return a static example response.
-/// In production, you would query a database or external service.
-pub async fn get_supply_caps() -> impl IntoResponse {
-    // Static example data for USDC+
-    let caps = vec![SupplyCap {
-        index: 0,
-        supply_cap: 1_000_000_000,
-        current_supply: 500_000_000,
-        remaining_capacity: 500_000_000,
-        utilization_percentage: 50,
-    }];
+/// Returns a JSON response with supply cap information for all stablecoins,
+/// read from the shared supply store.
+/// Supports the standard `limit` / `offset` paging parameters.
+pub async fn get_supply_caps(State(state): State<AppState>, Query(page): Query<PageParams>) -> Result<impl IntoResponse, ApiError> {
+    let _span = handler_span!("GET /stablecoins/supply-caps").entered();
+    let page_query = page.parse()?;
+    let caps = state.supplies.list().into_iter().map(SupplyCap::from).collect();
+    let page = paginate(caps, &page_query);
 
     let response = SupplyCapsSuccessResponse {
         success: true,
-        data: caps,
-    };
-
-    (StatusCode::OK, Json(response))
-}
-
-/// Example error handler for `/stablecoin/limits`.
-///
-/// In production, you might return this if a database query fails.
-pub async fn get_supply_caps_error() -> impl IntoResponse {
-    let response = SupplyCapsErrorResponse {
-        success: false,
-        message: "Internal server error",
+        meta: page.meta(),
+        data: page.items,
     };
 
-    (StatusCode::INTERNAL_SERVER_ERROR, Json(response))
+    Ok((StatusCode::OK, Json(state.cased(response))))
 }
 
 #[cfg(test)]
@@ -109,7 +109,9 @@ mod tests {
     /// Unit test: ensure `get_supply_caps` returns a 200 response with correct JSON structure.
     #[tokio::test]
     async fn get_supply_caps_success() {
-        let response = get_supply_caps().await.into_response();
+        let response = get_supply_caps(State(AppState::default()), Query(PageParams::default()))
+            .await
+            .into_response();
         let (parts, body) = response.into_parts();
         assert_eq!(parts.status, StatusCode::OK);
 
@@ -124,18 +126,4 @@ mod tests {
         assert_eq!(json["data"][0]["remainingCapacity"], Value::Number(500_000_000.into()));
         assert_eq!(json["data"][0]["utilizationPercentage"], Value::Number(50.into()));
     }
-
-    /// Unit test: ensure `get_supply_caps_error` returns a 500 response with correct JSON structure.
-    #[tokio::test]
-    async fn get_supply_caps_error_test() {
-        let response = get_supply_caps_error().await.into_response();
-        let (parts, body) = response.into_parts();
-        assert_eq!(parts.status, StatusCode::INTERNAL_SERVER_ERROR);
-
-        let bytes = to_bytes(body, 1024).await.unwrap();
-        let json: Value = serde_json::from_slice(&bytes).unwrap();
-
-        assert_eq!(json["success"], Value::Bool(false));
-        assert_eq!(json["message"], Value::String("Internal server error".into()));
-    }
 }
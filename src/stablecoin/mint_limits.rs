@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::amount::Amount;
+
+/// Amount minted so far per `(stablecoin, signer)`, shared through
+/// [`crate::AppState`] to enforce [`crate::config::Config::per_user_mint_limit`].
+///
+/// Cheap to clone; clones share the same underlying map.
+#[derive(Debug, Clone, Default)]
+pub struct MintLimits {
+    minted: Arc<Mutex<HashMap<(u32, String), u64>>>,
+}
+
+impl MintLimits {
+    /// Total `signer` has minted of stablecoin `index`.
+    pub fn minted(&self, index: u32, signer: &str) -> u64 {
+        self.minted
+            .lock()
+            .unwrap()
+            .get(&(index, signer.to_string()))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// What `signer` may still mint under `limit`, or `None` when `limit` is
+    /// `0` (no per-user limit).
+    pub fn remaining(&self, index: u32, signer: &str, limit: u64) -> Option<u64> {
+        (limit > 0).then(|| limit.saturating_sub(self.minted(index, signer)))
+    }
+
+    /// Count `amount` towards `signer`'s total unless that would take it past
+    /// `limit`; returns whether it was recorded. A `limit` of `0` always
+    /// succeeds without recording anything.
+    ///
+    /// The limit is a lifetime total, so entries never expire; instead only
+    /// signers with something recorded are kept. Refused mints add no entry
+    /// and [`MintLimits::release`] drops entries it brings back to zero.
+    pub fn try_record(&self, index: u32, signer: &str, amount: Amount, limit: u64) -> bool {
+        if limit == 0 {
+            return true;
+        }
+        let mut minted = self.minted.lock().unwrap();
+        let key = (index, signer.to_string());
+        let total = minted.get(&key).copied().unwrap_or(0);
+        match amount.checked_add(total) {
+            Some(next) if next <= limit => {
+                minted.insert(key, next);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Take back `amount` recorded by [`MintLimits::try_record`] for a mint
+    /// that went on to be refused.
+    pub fn release(&self, index: u32, signer: &str, amount: Amount) {
+        let mut minted = self.minted.lock().unwrap();
+        let key = (index, signer.to_string());
+        if let Some(total) = minted.get_mut(&key) {
+            *total = total.saturating_sub(amount.get());
+            if *total == 0 {
+                minted.remove(&key);
+            }
+        }
+    }
+
+    /// Number of `(stablecoin, signer)` pairs with something recorded.
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.minted.lock().unwrap().len()
+    }
+}
+
+/// When each signer last minted, shared through [`crate::AppState`] to enforce
+/// [`crate::config::Config::mint_cooldown_secs`].
+///
+/// Cheap to clone; clones share the same underlying map.
+#[derive(Debug, Clone, Default)]
+pub struct MintCooldowns {
+    last_mint: Arc<Mutex<HashMap<String, Instant>>>,
+}
+
+impl MintCooldowns {
+    /// Time left in `signer`'s cooldown at `now`, without recording anything;
+    /// `None` when it may mint.
+    pub fn remaining(&self, signer: &str, cooldown: Duration, now: Instant) -> Option<Duration> {
+        let last = *self.last_mint.lock().unwrap().get(signer)?;
+        let elapsed = now.saturating_duration_since(last);
+        (elapsed < cooldown).then(|| cooldown - elapsed)
+    }
+
+    /// Record a mint by `signer` at `now` unless its previous one was less
+    /// than `cooldown` ago, in which case the time left is returned instead.
+    /// A zero `cooldown` always succeeds without recording anything.
+    ///
+    /// Signers whose cooldown has passed are forgotten, so the map only holds
+    /// signers that minted within the last `cooldown`.
+    pub fn try_start(&self, signer: &str, cooldown: Duration, now: Instant) -> Result<(), Duration> {
+        if cooldown.is_zero() {
+            return Ok(());
+        }
+        let mut last_mint = self.last_mint.lock().unwrap();
+        last_mint.retain(|_, last| now.saturating_duration_since(*last) < cooldown);
+        if let Some(&last) = last_mint.get(signer) {
+            let elapsed = now.saturating_duration_since(last);
+            if elapsed < cooldown {
+                return Err(cooldown - elapsed);
+            }
+        }
+        last_mint.insert(signer.to_string(), now);
+        Ok(())
+    }
+
+    /// Number of signers still in their cooldown (or not yet pruned).
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.last_mint.lock().unwrap().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn amount(units: u64) -> Amount {
+        Amount::new(units).unwrap()
+    }
+
+    #[test]
+    fn records_up_to_the_limit() {
+        let limits = MintLimits::default();
+        assert_eq!(limits.remaining(0, "alice", 100), Some(100));
+
+        assert!(limits.try_record(0, "alice", amount(60), 100));
+        assert!(limits.try_record(0, "alice", amount(40), 100));
+        assert!(!limits.try_record(0, "alice", amount(1), 100));
+        assert_eq!(limits.minted(0, "alice"), 100);
+        assert_eq!(limits.remaining(0, "alice", 100), Some(0));
+
+        // Tracked per signer and per stablecoin
+        assert!(limits.try_record(0, "bob", amount(100), 100));
+        assert!(limits.try_record(1, "alice", amount(100), 100));
+
+        limits.release(0, "alice", amount(40));
+        assert_eq!(limits.remaining(0, "alice", 100), Some(40));
+    }
+
+    #[test]
+    fn only_signers_with_something_recorded_are_kept() {
+        let limits = MintLimits::default();
+        assert!(!limits.try_record(0, "alice", amount(101), 100));
+        assert_eq!(limits.len(), 0);
+
+        assert!(limits.try_record(0, "alice", amount(60), 100));
+        limits.release(0, "alice", amount(60));
+        assert_eq!(limits.len(), 0);
+    }
+
+    #[test]
+    fn cooldown_blocks_until_elapsed() {
+        let cooldowns = MintCooldowns::default();
+        let cooldown = Duration::from_secs(60);
+        let start = Instant::now();
+
+        assert_eq!(cooldowns.try_start("alice", cooldown, start), Ok(()));
+        assert_eq!(
+            cooldowns.try_start("alice", cooldown, start + Duration::from_secs(45)),
+            Err(Duration::from_secs(15))
+        );
+        assert_eq!(cooldowns.try_start("bob", cooldown, start), Ok(()));
+        assert_eq!(cooldowns.try_start("alice", cooldown, start + cooldown), Ok(()));
+
+        // Disabled
+        assert_eq!(cooldowns.try_start("alice", Duration::ZERO, start + cooldown), Ok(()));
+
+        // Checking records nothing
+        let later = start + cooldown + Duration::from_secs(10);
+        assert_eq!(cooldowns.remaining("alice", cooldown, later), Some(Duration::from_secs(50)));
+        assert_eq!(cooldowns.remaining("carol", cooldown, later), None);
+        assert_eq!(cooldowns.try_start("carol", cooldown, later), Ok(()));
+    }
+
+    #[test]
+    fn passed_cooldowns_are_forgotten() {
+        let cooldowns = MintCooldowns::default();
+        let cooldown = Duration::from_secs(60);
+        let start = Instant::now();
+
+        assert_eq!(cooldowns.try_start("alice", Duration::ZERO, start), Ok(()));
+        assert_eq!(cooldowns.len(), 0);
+
+        assert_eq!(cooldowns.try_start("alice", cooldown, start), Ok(()));
+        assert_eq!(cooldowns.try_start("bob", cooldown, start + Duration::from_secs(30)), Ok(()));
+        assert_eq!(cooldowns.len(), 2);
+
+        // Alice's cooldown has passed by the time Carol mints
+        assert_eq!(cooldowns.try_start("carol", cooldown, start + cooldown), Ok(()));
+        assert_eq!(cooldowns.len(), 2);
+        assert_eq!(cooldowns.remaining("bob", cooldown, start + cooldown), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn zero_limit_is_unlimited() {
+        let limits = MintLimits::default();
+        assert!(limits.try_record(0, "alice", amount(u64::MAX), 0));
+        assert_eq!(limits.remaining(0, "alice", 0), None);
+        assert_eq!(limits.len(), 0);
+    }
+}
@@ -1,17 +1,36 @@
 use axum::Router;
+use crate::example::example_body;
 use crate::AppState;
 
+pub mod apy;
+pub mod fees;
 pub mod get_available_stablecoins;
 pub mod get_supply_caps;
 pub mod get_mint_redeem_quote;
+pub mod get_quote_batch;
 pub mod generate_mint_transaction;
 pub mod generate_burn_transaction;
 pub mod get_all_apy;
+pub mod get_apy_summary;
+pub mod get_exchange_rate_by_id;
+pub mod get_exchange_rate_summary;
 pub mod get_latest_exchange_rates;
 pub mod get_historical_exchange_rates;
 pub mod get_specific_apy;
+pub mod get_stablecoin_snapshot;
 pub mod get_historical_apy;
 pub mod get_realtime_exchange_rate;
+pub mod get_latest_blockhash;
+pub mod get_max_mint;
+pub mod get_network_info;
+pub mod mint_limits;
+pub mod rates;
+pub mod registry;
+pub mod set_stablecoin_enabled;
+pub mod signer_proof;
+pub mod slippage;
+pub mod supply;
+pub mod transaction;
 
 pub fn router() -> Router<AppState> {
     Router::new()
@@ -27,9 +46,21 @@ pub fn router() -> Router<AppState> {
 
         // Quotes
         .route(
-            "/quote",
+            "/quote/:type",
             axum::routing::post(get_mint_redeem_quote::get_mint_redeem_quote),
         )
+        .route(
+            "/quote/breakdown",
+            axum::routing::post(get_mint_redeem_quote::get_quote_breakdown),
+        )
+        .route(
+            "/quote/batch",
+            axum::routing::post(get_quote_batch::get_quote_batch),
+        )
+        .route(
+            "/quote/:type/example",
+            axum::routing::get(example_body::<get_mint_redeem_quote::QuoteRequest>),
+        )
 
         // Mint / Burn transactions
         .route(
@@ -41,23 +72,63 @@ pub fn router() -> Router<AppState> {
             axum::routing::post(generate_burn_transaction::generate_burn_transaction),
         )
 
+        // Example request bodies for the POST routes above
+        .route(
+            "/mint/tx/example",
+            axum::routing::get(example_body::<generate_mint_transaction::MintRequest>),
+        )
+        .route(
+            "/burn/tx/example",
+            axum::routing::get(example_body::<generate_burn_transaction::BurnRequest>),
+        )
+
+        // Recent blockhash for client-built transactions
+        .route(
+            "/blockhash",
+            axum::routing::get(get_latest_blockhash::get_latest_blockhash),
+        )
+
+        // Current slot / epoch
+        .route(
+            "/network",
+            axum::routing::get(get_network_info::get_network_info),
+        )
+
         // APY (all stablecoins)
         .route(
             "/apy",
             axum::routing::get(get_all_apy::get_all_apy),
         )
+        .route(
+            "/apy/summary",
+            axum::routing::get(get_apy_summary::get_apy_summary),
+        )
 
         // Latest exchange rates (all stablecoins)
         .route(
             "/exchange-rates",
             axum::routing::get(get_latest_exchange_rates::get_latest_exchange_rates),
         )
+        .route(
+            "/exchange-rates/:id",
+            axum::routing::get(get_exchange_rate_by_id::get_exchange_rate_by_id),
+        )
+
+        // Latest rates and APY together
+        .route(
+            "/snapshot",
+            axum::routing::get(get_stablecoin_snapshot::get_stablecoin_snapshot),
+        )
 
         // Historical exchange rates for a specific stablecoin
         .route(
             "/stablecoin/:index/exchange-rates/historical",
             axum::routing::get(get_historical_exchange_rates::get_historical_exchange_rates),
         )
+        .route(
+            "/stablecoin/:index/exchange-rates/summary",
+            axum::routing::get(get_exchange_rate_summary::get_exchange_rate_summary),
+        )
 
         // Specific APY for a stablecoin
         .route(
@@ -71,6 +142,18 @@ pub fn router() -> Router<AppState> {
             axum::routing::get(get_historical_apy::get_historical_apy),
         )
 
+        // Largest amount a signer can mint right now
+        .route(
+            "/stablecoin/:index/max-mint",
+            axum::routing::get(get_max_mint::get_max_mint),
+        )
+
+        // Admin: pause / resume a stablecoin
+        .route(
+            "/stablecoin/:index/enabled",
+            axum::routing::post(set_stablecoin_enabled::set_stablecoin_enabled),
+        )
+
         // Realtime exchange rate for a stablecoin
         .route(
             "/stablecoin/:index/exchange-rate",
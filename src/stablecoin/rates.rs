@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+use super::get_latest_exchange_rates::ExchangeRateData;
+use crate::timestamp::Timestamp;
+
+/// Exchange rate records, shared through [`crate::AppState`]: every record by
+/// id, plus the latest snapshot per stablecoin.
+///
+/// Cheap to clone; clones share the same underlying maps.
+#[derive(Debug, Clone)]
+pub struct ExchangeRateStore {
+    latest: Arc<RwLock<BTreeMap<u32, ExchangeRateData>>>,
+    records: Arc<RwLock<BTreeMap<u64, ExchangeRateData>>>,
+}
+
+impl Default for ExchangeRateStore {
+    fn default() -> Self {
+        Self::new(vec![
+            ExchangeRateData {
+                id: 104135,
+                stablecoin: 0,
+                base_usd_value_bps: 1016733625,
+                timestamp: Timestamp::parse_rfc3339("2025-12-18T17:46:10.274Z").unwrap(),
+                receipt_usd_value_bps: 1016733625,
+            },
+            ExchangeRateData {
+                id: 104137,
+                stablecoin: 0,
+                base_usd_value_bps: 1016728666,
+                timestamp: Timestamp::parse_rfc3339("2025-12-18T17:47:08.161Z").unwrap(),
+                receipt_usd_value_bps: 1016728667,
+            },
+            ExchangeRateData {
+                id: 105511,
+                stablecoin: 0,
+                base_usd_value_bps: 1016789908,
+                timestamp: Timestamp::parse_rfc3339("2025-12-19T17:04:08.502Z").unwrap(),
+                receipt_usd_value_bps: 1016791576,
+            },
+        ])
+    }
+}
+
+impl ExchangeRateStore {
+    pub fn new(rates: Vec<ExchangeRateData>) -> Self {
+        let store = Self {
+            latest: Arc::default(),
+            records: Arc::default(),
+        };
+        for rate in rates {
+            store.set(rate);
+        }
+        store
+    }
+
+    /// Record `rate` and make it the latest snapshot for its stablecoin.
+    pub fn set(&self, rate: ExchangeRateData) {
+        self.records.write().unwrap().insert(rate.id, rate.clone());
+        self.latest.write().unwrap().insert(rate.stablecoin, rate);
+    }
+
+    /// A single record by id, whether or not it is still the latest.
+    pub fn record(&self, id: u64) -> Option<ExchangeRateData> {
+        self.records.read().unwrap().get(&id).cloned()
+    }
+
+    /// The latest snapshot of every stablecoin, ordered by stablecoin index.
+    pub fn latest(&self) -> Vec<ExchangeRateData> {
+        self.latest.read().unwrap().values().cloned().collect()
+    }
+
+    pub fn get(&self, stablecoin: u32) -> Option<ExchangeRateData> {
+        self.latest.read().unwrap().get(&stablecoin).cloned()
+    }
+
+    /// Timestamp of the newest record across every stablecoin.
+    pub fn newest_timestamp(&self) -> Option<Timestamp> {
+        self.latest
+            .read()
+            .unwrap()
+            .values()
+            .map(|rate| rate.timestamp)
+            .max()
+    }
+
+    /// Whether the feed is behind: the newest record is older than `max_age`
+    /// (see `REFLECT_RATE_STALE_AFTER_SECS`). An empty store is never stale.
+    pub fn staleness(&self, max_age: Option<chrono::Duration>) -> Staleness {
+        Staleness::of(self.newest_timestamp(), max_age)
+    }
+}
+
+/// Result of [`ExchangeRateStore::staleness`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Staleness {
+    pub stale: bool,
+    pub newest: Option<Timestamp>,
+}
+
+impl Staleness {
+    /// Staleness of a feed whose newest record is from `newest`.
+    pub fn of(newest: Option<Timestamp>, max_age: Option<chrono::Duration>) -> Self {
+        let stale = match (max_age, newest) {
+            (Some(max_age), Some(newest)) => Timestamp::now().0 - newest.0 > max_age,
+            _ => false,
+        };
+        Staleness { stale, newest }
+    }
+
+    /// Human-readable warning attached to responses while the feed is stale.
+    pub fn warning(&self) -> Option<String> {
+        match (self.stale, self.newest) {
+            (true, Some(newest)) => Some(format!("exchange rate feed is behind; newest rate is from {}", newest)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate_at(timestamp: Timestamp) -> ExchangeRateData {
+        ExchangeRateData {
+            id: 1,
+            stablecoin: 0,
+            base_usd_value_bps: 1_000_000_000,
+            timestamp,
+            receipt_usd_value_bps: 1_000_000_000,
+        }
+    }
+
+    #[test]
+    fn staleness_from_newest_record() {
+        let max_age = Some(chrono::Duration::minutes(5));
+
+        let fresh = ExchangeRateStore::new(vec![rate_at(Timestamp::now())]);
+        assert!(!fresh.staleness(max_age).stale);
+        assert_eq!(fresh.staleness(max_age).warning(), None);
+
+        let old = Timestamp(Timestamp::now().0 - chrono::Duration::hours(1));
+        let behind = ExchangeRateStore::new(vec![rate_at(old)]);
+        let staleness = behind.staleness(max_age);
+        assert!(staleness.stale);
+        assert!(staleness.warning().unwrap().contains(&old.to_string()));
+
+        // Disabled check and empty store
+        assert!(!behind.staleness(None).stale);
+        assert!(!ExchangeRateStore::new(vec![]).staleness(max_age).stale);
+    }
+}
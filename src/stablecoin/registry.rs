@@ -0,0 +1,130 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+use crate::error::ApiError;
+
+/// Placeholder USDC+ mint address until the on-chain mint is wired in. It is
+/// not the deployed mint, so stablecoins registered with it report
+/// [`StablecoinInfo::is_simulated`] and responses naming it carry
+/// `"simulated": true`.
+pub const USDC_PLUS_MINT: &str = "6k78AbasGMFFrhG95Pj6jQbqkVt7FQMhVgemxJovWKR6";
+
+/// USDC mint, the collateral behind USDC+.
+pub const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+/// Static metadata and operational state for one stablecoin.
+///
+/// ### Example
+/// ```json
+/// {
+///   "index": 0,
+///   "name": "USDC+",
+///   "mint": "6k78AbasGMFFrhG95Pj6jQbqkVt7FQMhVgemxJovWKR6",
+///   "collateral_mint": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+///   "decimals": 6,
+///   "enabled": true
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StablecoinInfo {
+    pub index: u32,
+    pub name: String,
+    /// Base58 address of the stablecoin's token mint.
+    pub mint: String,
+    /// Base58 address of the collateral mint deposited when minting and
+    /// returned when burning, unless a request names another.
+    pub collateral_mint: String,
+    pub decimals: u8,
+    /// Disabled stablecoins stay listed but reject mint, burn and quote requests.
+    pub enabled: bool,
+}
+
+impl StablecoinInfo {
+    /// Whether `mint` is the placeholder rather than a deployed token mint.
+    pub fn is_simulated(&self) -> bool {
+        self.mint == USDC_PLUS_MINT
+    }
+}
+
+/// The stablecoins this server knows about, keyed by index.
+///
+/// Cheap to clone; clones share the same underlying map so admin toggles are
+/// visible to every handler.
+#[derive(Debug, Clone)]
+pub struct StablecoinRegistry {
+    inner: Arc<RwLock<BTreeMap<u32, StablecoinInfo>>>,
+}
+
+impl Default for StablecoinRegistry {
+    fn default() -> Self {
+        Self::new(vec![StablecoinInfo {
+            index: 0,
+            name: "USDC+".to_string(),
+            mint: USDC_PLUS_MINT.to_string(),
+            collateral_mint: USDC_MINT.to_string(),
+            decimals: 6,
+            enabled: true,
+        }])
+    }
+}
+
+impl StablecoinRegistry {
+    pub fn new(stablecoins: Vec<StablecoinInfo>) -> Self {
+        let map = stablecoins.into_iter().map(|info| (info.index, info)).collect();
+        Self {
+            inner: Arc::new(RwLock::new(map)),
+        }
+    }
+
+    pub fn get(&self, index: u32) -> Option<StablecoinInfo> {
+        self.inner.read().unwrap().get(&index).cloned()
+    }
+
+    /// All stablecoins, ordered by index.
+    pub fn list(&self) -> Vec<StablecoinInfo> {
+        self.inner.read().unwrap().values().cloned().collect()
+    }
+
+    /// Enable or disable a stablecoin, returning its updated info.
+    pub fn set_enabled(&self, index: u32, enabled: bool) -> Option<StablecoinInfo> {
+        let mut map = self.inner.write().unwrap();
+        let info = map.get_mut(&index)?;
+        info.enabled = enabled;
+        Some(info.clone())
+    }
+
+    /// Look up a stablecoin that is about to be minted, burned or quoted.
+    ///
+    /// Unknown indexes are rejected with `422`, disabled ones with `503`.
+    pub fn ensure_available(&self, index: u32) -> Result<StablecoinInfo, ApiError> {
+        match self.get(index) {
+            Some(info) if info.enabled => Ok(info),
+            Some(_) => Err(ApiError::Unavailable("stablecoin temporarily disabled".to_string())),
+            None => Err(ApiError::Unprocessable("Stablecoin with the specified index not found".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::StatusCode;
+
+    #[test]
+    fn toggling_is_shared_between_clones() {
+        let registry = StablecoinRegistry::default();
+        let clone = registry.clone();
+
+        assert!(registry.ensure_available(0).is_ok());
+        assert_eq!(clone.set_enabled(0, false).map(|info| info.enabled), Some(false));
+
+        let error = registry.ensure_available(0).unwrap_err();
+        assert_eq!(error.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(error.message(), "stablecoin temporarily disabled");
+        assert!(!registry.list()[0].enabled);
+
+        assert_eq!(registry.ensure_available(9).unwrap_err().status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(registry.set_enabled(9, true).is_none());
+    }
+}
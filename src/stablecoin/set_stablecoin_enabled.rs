@@ -0,0 +1,131 @@
+use axum::{
+    extract::{Json, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::auth::AdminKey;
+use crate::error::ApiError;
+use crate::path::Path;
+use crate::AppState;
+
+/// Request structure for `POST /stablecoins/stablecoin/{index}/enabled`.
+///
+/// ### Example Request
+/// ```json
+/// { "enabled": false }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct SetEnabledRequest {
+    pub enabled: bool,
+}
+
+/// Handler for `POST /stablecoins/stablecoin/{index}/enabled`.
+///
+/// Admin-only. Pauses or resumes a stablecoin: while disabled, mint, burn and
+/// quote requests for it return `503`, but it stays visible in metadata
+/// endpoints with `enabled: false`.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request POST \
+///   --url http://localhost:3000/stablecoins/stablecoin/0/enabled \
+///   --header 'x-api-key: <admin key>' \
+///   --header 'Content-Type: application/json' \
+///   --data '{"enabled": false}'
+/// ```
+///
+/// Expected output:
+/// ```json
+/// {
+///   "success": true,
+///   "data": {
+///     "index": 0,
+///     "name": "USDC+",
+///     "mint": "6k78AbasGMFFrhG95Pj6jQbqkVt7FQMhVgemxJovWKR6",
+///     "collateral_mint": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+///     "decimals": 6,
+///     "enabled": false
+///   }
+/// }
+/// ```
+pub async fn set_stablecoin_enabled(
+    _admin: AdminKey,
+    State(state): State<AppState>,
+    Path(index): Path<u32>,
+    Json(req): Json<SetEnabledRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let info = state
+        .stablecoins
+        .set_enabled(index, req.enabled)
+        .ok_or_else(|| ApiError::NotFound("Stablecoin with the specified index not found".to_string()))?;
+    tracing::info!(index, enabled = req.enabled, "Stablecoin availability changed");
+    Ok((StatusCode::OK, Json(json!({ "success": true, "data": state.cased(info) }))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::routing::post;
+    use axum::Router;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    fn admin_state() -> AppState {
+        AppState::new(Config {
+            admin_api_keys: vec!["admin-key".to_string()],
+            ..Config::default()
+        })
+    }
+
+    async fn send(state: &AppState, uri: &str, key: Option<&str>, body: Value) -> (StatusCode, Value) {
+        let app = Router::new()
+            .route("/stablecoin/:index/enabled", post(set_stablecoin_enabled))
+            .with_state(state.clone());
+
+        let mut request = Request::post(uri).header("content-type", "application/json");
+        if let Some(key) = key {
+            request = request.header("x-api-key", key);
+        }
+        let response = app
+            .oneshot(request.body(Body::from(body.to_string())).unwrap())
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 64 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_toggle() {
+        let state = admin_state();
+
+        let (status, json) = send(&state, "/stablecoin/0/enabled", Some("admin-key"), json!({ "enabled": false })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["enabled"], false);
+        assert!(state.stablecoins.ensure_available(0).is_err());
+
+        let (status, _) = send(&state, "/stablecoin/0/enabled", Some("admin-key"), json!({ "enabled": true })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(state.stablecoins.ensure_available(0).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_index_and_auth() {
+        let state = admin_state();
+
+        let (status, _) = send(&state, "/stablecoin/9/enabled", Some("admin-key"), json!({ "enabled": false })).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _) = send(&state, "/stablecoin/0/enabled", None, json!({ "enabled": false })).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert!(state.stablecoins.ensure_available(0).is_ok());
+    }
+}
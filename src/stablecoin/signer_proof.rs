@@ -0,0 +1,134 @@
+use ring::signature::{UnparsedPublicKey, ED25519};
+use serde::{Deserialize, Serialize};
+
+use crate::error::ApiError;
+use crate::timestamp::Timestamp;
+use crate::validation::invalid_field;
+use super::transaction::PUBKEY_LEN;
+
+/// How far `issuedAt` may be from the server clock, either way, in seconds.
+pub const MAX_PROOF_SKEW_SECS: i64 = 300;
+
+/// Proof that a mint request comes from the holder of `signer`'s key.
+///
+/// Required while a per-signer mint limit or cooldown is configured, so a
+/// caller cannot use up another wallet's quota by posting its address.
+/// `signature` is the base58 ed25519 signature by `signer` over
+/// [`proof_message`]; `issuedAt` is the unix time, in seconds, the client
+/// signed it at and must be within [`MAX_PROOF_SKEW_SECS`] of now.
+///
+/// ### Example
+/// ```json
+/// {
+///   "issuedAt": 1766080000,
+///   "signature": "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW"
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignerProof {
+    pub issued_at: i64,
+    pub signature: String,
+}
+
+/// The text a [`SignerProof`] signs: the mint it authorizes and when, e.g.
+/// `reflect-mint:9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM:0:1000000:1766080000`.
+pub fn proof_message(signer: &str, stablecoin_index: u32, deposit_amount: u64, issued_at: i64) -> String {
+    format!("reflect-mint:{}:{}:{}:{}", signer, stablecoin_index, deposit_amount, issued_at)
+}
+
+/// Check `proof` authorizes minting `deposit_amount` of stablecoin
+/// `stablecoin_index` as `signer`, at `now`. Failures are 422s on
+/// `signerProof`.
+pub fn verify_signer_proof(
+    proof: Option<&SignerProof>,
+    signer: &str,
+    signer_key: &[u8; PUBKEY_LEN],
+    stablecoin_index: u32,
+    deposit_amount: u64,
+    now: Timestamp,
+) -> Result<(), ApiError> {
+    let Some(proof) = proof else {
+        return Err(invalid_field(
+            "signerProof",
+            "Invalid request data: signerProof is required while per-signer mint limits are enabled",
+        ));
+    };
+    if (now.0.timestamp() - proof.issued_at).abs() > MAX_PROOF_SKEW_SECS {
+        return Err(invalid_field("signerProof", "Invalid request data: signerProof has expired"));
+    }
+    let message = proof_message(signer, stablecoin_index, deposit_amount, proof.issued_at);
+    let verified = bs58::decode(&proof.signature)
+        .into_vec()
+        .is_ok_and(|signature| UnparsedPublicKey::new(&ED25519, signer_key).verify(message.as_bytes(), &signature).is_ok());
+    if !verified {
+        return Err(invalid_field("signerProof", "Invalid request data: signerProof is not a valid signature by signer"));
+    }
+    Ok(())
+}
+
+/// A keypair signing proofs for tests.
+#[cfg(test)]
+pub mod test_signer {
+    use ring::rand::SystemRandom;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    use super::{proof_message, SignerProof};
+    use crate::timestamp::Timestamp;
+
+    pub struct TestSigner(Ed25519KeyPair);
+
+    impl TestSigner {
+        pub fn generate() -> Self {
+            let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+            TestSigner(Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap())
+        }
+
+        /// The signer's address, base58.
+        pub fn address(&self) -> String {
+            bs58::encode(self.0.public_key().as_ref()).into_string()
+        }
+
+        /// A proof for this signer's mint, issued now.
+        pub fn prove(&self, stablecoin_index: u32, deposit_amount: u64) -> SignerProof {
+            self.prove_at(stablecoin_index, deposit_amount, Timestamp::now().0.timestamp())
+        }
+
+        pub fn prove_at(&self, stablecoin_index: u32, deposit_amount: u64, issued_at: i64) -> SignerProof {
+            let message = proof_message(&self.address(), stablecoin_index, deposit_amount, issued_at);
+            SignerProof { issued_at, signature: bs58::encode(self.0.sign(message.as_bytes())).into_string() }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_signer::TestSigner;
+    use super::*;
+    use crate::stablecoin::transaction::parse_pubkey;
+
+    fn verify(proof: Option<&SignerProof>, signer: &str, amount: u64) -> Result<(), String> {
+        let key = parse_pubkey(signer).unwrap();
+        verify_signer_proof(proof, signer, &key, 0, amount, Timestamp::now())
+            .map_err(|error| error.message().to_string())
+    }
+
+    #[test]
+    fn accepts_only_a_fresh_signature_by_the_signer_over_this_mint() {
+        let signer = TestSigner::generate();
+        let address = signer.address();
+        assert_eq!(verify(Some(&signer.prove(0, 1_000)), &address, 1_000), Ok(()));
+
+        let invalid = "Invalid request data: signerProof is not a valid signature by signer";
+        // Another amount, another signer, garbage
+        assert_eq!(verify(Some(&signer.prove(0, 1_000)), &address, 2_000), Err(invalid.to_string()));
+        let other = TestSigner::generate();
+        assert_eq!(verify(Some(&other.prove(0, 1_000)), &address, 1_000), Err(invalid.to_string()));
+        let garbage = SignerProof { issued_at: Timestamp::now().0.timestamp(), signature: "0OIl".to_string() };
+        assert_eq!(verify(Some(&garbage), &address, 1_000), Err(invalid.to_string()));
+
+        let stale = signer.prove_at(0, 1_000, Timestamp::now().0.timestamp() - MAX_PROOF_SKEW_SECS - 1);
+        assert_eq!(verify(Some(&stale), &address, 1_000), Err("Invalid request data: signerProof has expired".to_string()));
+        assert!(verify(None, &address, 1_000).unwrap_err().contains("signerProof is required"));
+    }
+}
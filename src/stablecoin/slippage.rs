@@ -0,0 +1,67 @@
+/// Outcome of checking a client's `minimumReceived` against the quoted amount.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SlippageCheck {
+    /// `minimumReceived` sits within the configured tolerance band.
+    Ok,
+    /// `minimumReceived` is so far below the quote that slippage protection is
+    /// effectively disabled. The request is allowed, but the client is warned.
+    Loose(String),
+    /// `minimumReceived` is above the quote, so the transaction could never land.
+    ExceedsQuote,
+}
+
+/// Validate `minimum_received` against `quoted` using a tolerance band of
+/// `tolerance_bps` basis points below the quote.
+///
+/// ### Example
+/// With a quote of `999000` and a tolerance of `500` bps (5%), any
+/// `minimumReceived` in `949050..=999000` is accepted silently, anything below
+/// yields a warning, and anything above `999000` is rejected.
+pub fn check_minimum_received(quoted: i64, minimum_received: i64, tolerance_bps: u32) -> SlippageCheck {
+    if minimum_received > quoted {
+        return SlippageCheck::ExceedsQuote;
+    }
+
+    let tolerance = (quoted as i128 * tolerance_bps as i128) / 10_000;
+    let floor = quoted as i128 - tolerance;
+
+    if (minimum_received as i128) < floor {
+        SlippageCheck::Loose(format!(
+            "minimumReceived is more than {}% below the quoted amount; slippage protection is effectively disabled",
+            tolerance_bps as f64 / 100.0
+        ))
+    } else {
+        SlippageCheck::Ok
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn within_band_is_ok() {
+        assert_eq!(check_minimum_received(999_000, 999_000, 500), SlippageCheck::Ok);
+        assert_eq!(check_minimum_received(999_000, 949_050, 500), SlippageCheck::Ok);
+    }
+
+    #[test]
+    fn below_band_warns() {
+        assert!(matches!(
+            check_minimum_received(999_000, 949_049, 500),
+            SlippageCheck::Loose(_)
+        ));
+        assert!(matches!(
+            check_minimum_received(999_000, 0, 500),
+            SlippageCheck::Loose(_)
+        ));
+    }
+
+    #[test]
+    fn above_quote_is_rejected() {
+        assert_eq!(
+            check_minimum_received(999_000, 999_001, 500),
+            SlippageCheck::ExceedsQuote
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+use crate::amount::Amount;
+
+/// Supply cap and outstanding supply of one stablecoin, in smallest units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SupplyInfo {
+    pub index: u32,
+    pub supply_cap: u64,
+    pub current_supply: u64,
+}
+
+impl SupplyInfo {
+    /// How much more can be minted before the cap is reached.
+    pub fn remaining_capacity(&self) -> u64 {
+        self.supply_cap.saturating_sub(self.current_supply)
+    }
+
+    /// Share of the cap in use, as a whole percentage rounded down.
+    pub fn utilization_percentage(&self) -> u32 {
+        if self.supply_cap == 0 {
+            return 0;
+        }
+        (self.current_supply as u128 * 100 / self.supply_cap as u128) as u32
+    }
+}
+
+/// Why [`SupplyStore::set_cap`] refused a new cap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetCapError {
+    /// No supply figures are recorded for the stablecoin.
+    Unknown,
+    /// The cap would sit below the current supply carried here.
+    BelowSupply(u64),
+}
+
+/// Supply figures per stablecoin, shared through [`crate::AppState`].
+///
+/// Cheap to clone; clones share the same underlying map.
+#[derive(Debug, Clone)]
+pub struct SupplyStore {
+    supplies: Arc<RwLock<BTreeMap<u32, SupplyInfo>>>,
+}
+
+impl Default for SupplyStore {
+    fn default() -> Self {
+        Self::new(vec![SupplyInfo {
+            index: 0,
+            supply_cap: 1_000_000_000,
+            current_supply: 500_000_000,
+        }])
+    }
+}
+
+impl SupplyStore {
+    pub fn new(supplies: Vec<SupplyInfo>) -> Self {
+        let store = Self {
+            supplies: Arc::default(),
+        };
+        for supply in supplies {
+            store.set(supply);
+        }
+        store
+    }
+
+    pub fn get(&self, index: u32) -> Option<SupplyInfo> {
+        self.supplies.read().unwrap().get(&index).copied()
+    }
+
+    /// Replace the supply figures of the entry's stablecoin.
+    pub fn set(&self, supply: SupplyInfo) {
+        self.supplies.write().unwrap().insert(supply.index, supply);
+    }
+
+    /// Change the supply cap of stablecoin `index`, returning the updated
+    /// figures. The cap may not drop below the outstanding supply.
+    pub fn set_cap(&self, index: u32, supply_cap: u64) -> Result<SupplyInfo, SetCapError> {
+        let mut supplies = self.supplies.write().unwrap();
+        let supply = supplies.get_mut(&index).ok_or(SetCapError::Unknown)?;
+        if supply_cap < supply.current_supply {
+            return Err(SetCapError::BelowSupply(supply.current_supply));
+        }
+        supply.supply_cap = supply_cap;
+        Ok(*supply)
+    }
+
+    /// Whether minting `amount` more of stablecoin `index` stays within its
+    /// cap. Only checks: building a mint transaction does not change the
+    /// outstanding supply, which follows the chain. A stablecoin without
+    /// supply figures has no cap to enforce.
+    pub fn fits(&self, index: u32, amount: Amount) -> bool {
+        self.get(index).is_none_or(|supply| {
+            amount.checked_add(supply.current_supply).is_some_and(|next| next <= supply.supply_cap)
+        })
+    }
+
+    /// Supply figures of every stablecoin, ordered by index.
+    pub fn list(&self) -> Vec<SupplyInfo> {
+        self.supplies.read().unwrap().values().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derived_figures() {
+        let supply = SupplyInfo { index: 0, supply_cap: 1_000, current_supply: 333 };
+        assert_eq!(supply.remaining_capacity(), 667);
+        assert_eq!(supply.utilization_percentage(), 33);
+
+        let over = SupplyInfo { index: 0, supply_cap: 100, current_supply: 150 };
+        assert_eq!(over.remaining_capacity(), 0);
+        assert_eq!(over.utilization_percentage(), 150);
+
+        let uncapped = SupplyInfo { index: 0, supply_cap: 0, current_supply: 0 };
+        assert_eq!(uncapped.utilization_percentage(), 0);
+    }
+
+    #[test]
+    fn mints_must_fit_under_the_cap() {
+        let store = SupplyStore::new(vec![SupplyInfo { index: 0, supply_cap: 1_000, current_supply: 900 }]);
+        let amount = |units| Amount::new(units).unwrap();
+        assert!(store.fits(0, amount(100)));
+        assert!(store.fits(0, amount(100)));
+        assert!(!store.fits(0, amount(101)));
+        assert_eq!(store.get(0).unwrap().current_supply, 900);
+
+        assert!(!store.fits(0, amount(u64::MAX)));
+        assert!(store.fits(7, amount(u64::MAX)));
+    }
+}
@@ -0,0 +1,396 @@
+use base64::Engine;
+use serde::Serialize;
+use std::str::FromStr;
+
+/// Length of a Solana public key or blockhash.
+pub const PUBKEY_LEN: usize = 32;
+
+/// Length of an Ed25519 signature.
+pub const SIGNATURE_LEN: usize = 64;
+
+/// First message byte of a v0 transaction: the high bit marks a versioned message.
+const V0_PREFIX: u8 = 0x80;
+
+/// Program id used while `REFLECT_PROGRAM_ID` is unset. It is not a deployed
+/// program, so transactions built with it are flagged as simulated.
+pub const PLACEHOLDER_PROGRAM_ID: [u8; PUBKEY_LEN] = [0x52; PUBKEY_LEN];
+
+/// How the serialized transaction is encoded in the response.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TxEncoding {
+    #[default]
+    Base64,
+    Base58,
+}
+
+impl TxEncoding {
+    pub fn encode(self, bytes: &[u8]) -> String {
+        match self {
+            TxEncoding::Base64 => base64::engine::general_purpose::STANDARD.encode(bytes),
+            TxEncoding::Base58 => bs58::encode(bytes).into_string(),
+        }
+    }
+}
+
+impl FromStr for TxEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "base64" => Ok(TxEncoding::Base64),
+            "base58" => Ok(TxEncoding::Base58),
+            other => Err(format!(
+                "Unknown encoding '{}'; expected one of base64, base58",
+                other
+            )),
+        }
+    }
+}
+
+/// Transaction format to build.
+///
+/// `V0` produces a versioned message (prefix `0x80`) with an empty address
+/// lookup table section; `Legacy` is the pre-versioning format.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TxVersion {
+    #[default]
+    Legacy,
+    V0,
+}
+
+impl FromStr for TxVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "legacy" => Ok(TxVersion::Legacy),
+            "v0" => Ok(TxVersion::V0),
+            other => Err(format!(
+                "Unknown txVersion '{}'; expected one of legacy, v0",
+                other
+            )),
+        }
+    }
+}
+
+/// What the mint / burn endpoints return.
+///
+/// `Transaction` is a serialized unsigned transaction; `Instructions` is the
+/// raw instruction list for wallet SDKs that assemble the transaction themselves.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TxFormat {
+    #[default]
+    Transaction,
+    Instructions,
+}
+
+impl FromStr for TxFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "transaction" => Ok(TxFormat::Transaction),
+            "instructions" => Ok(TxFormat::Instructions),
+            other => Err(format!(
+                "Unknown format '{}'; expected one of transaction, instructions",
+                other
+            )),
+        }
+    }
+}
+
+/// One account an instruction reads or writes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountMeta {
+    pub pubkey: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// An unsigned instruction; `programId` and `pubkey`s are base58 and `data`
+/// uses the requested [`TxEncoding`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Instruction {
+    pub program_id: String,
+    pub keys: Vec<AccountMeta>,
+    pub data: String,
+}
+
+/// A built mint / burn in the requested [`TxFormat`]: exactly one of
+/// `transaction` and `instructions` is set, and the response carries it as
+/// either `"transaction": "..."` or `"instructions": [...]`.
+///
+/// `simulated` is set when the spec had no real program id or blockhash;
+/// such a transaction has the right shape but cannot land on chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuiltTx {
+    pub transaction: Option<String>,
+    pub instructions: Option<Vec<Instruction>>,
+    pub simulated: bool,
+}
+
+impl BuiltTx {
+    pub fn build(spec: &TransactionSpec, format: TxFormat, encoding: TxEncoding) -> Self {
+        let simulated = spec.is_simulated();
+        match format {
+            TxFormat::Transaction => BuiltTx {
+                transaction: Some(encoding.encode(&build_transaction(spec))),
+                instructions: None,
+                simulated,
+            },
+            TxFormat::Instructions => BuiltTx {
+                transaction: None,
+                instructions: Some(build_instructions(spec, encoding)),
+                simulated,
+            },
+        }
+    }
+}
+
+/// Decode a base58 public key (or blockhash) into its 32 raw bytes.
+pub fn parse_pubkey(s: &str) -> Option<[u8; PUBKEY_LEN]> {
+    let bytes = bs58::decode(s).into_vec().ok()?;
+    bytes.try_into().ok()
+}
+
+/// `#[serde(deserialize_with)]` helper accepting only a base58 public key.
+///
+/// The value stays a `String` so handlers keep the caller's spelling, but bad
+/// input is reported against the field by [`crate::json_body::JsonBody`].
+pub fn deserialize_pubkey<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+    let bytes = bs58::decode(&value)
+        .into_vec()
+        .map_err(|_| serde::de::Error::custom("not base58"))?;
+    if bytes.len() != PUBKEY_LEN {
+        return Err(serde::de::Error::custom("not a 32-byte public key"));
+    }
+    Ok(value)
+}
+
+/// Everything needed to build an unsigned Reflect transaction.
+#[derive(Debug, Clone)]
+pub struct TransactionSpec {
+    pub version: TxVersion,
+    pub signer: [u8; PUBKEY_LEN],
+    /// Account paying the transaction fee; the signer pays when `None`.
+    pub fee_payer: Option<[u8; PUBKEY_LEN]>,
+    /// The deployed Reflect program; [`PLACEHOLDER_PROGRAM_ID`] when `None`.
+    pub program_id: Option<[u8; PUBKEY_LEN]>,
+    /// Zero-filled when `None`, e.g. when the cluster has no RPC endpoint.
+    pub recent_blockhash: Option<[u8; PUBKEY_LEN]>,
+    pub instruction_data: Vec<u8>,
+}
+
+impl TransactionSpec {
+    /// Whether the transaction would be built from placeholders and so
+    /// could not land on chain.
+    pub fn is_simulated(&self) -> bool {
+        self.program_id.is_none() || self.recent_blockhash.is_none()
+    }
+
+    fn program_id(&self) -> [u8; PUBKEY_LEN] {
+        self.program_id.unwrap_or(PLACEHOLDER_PROGRAM_ID)
+    }
+}
+
+/// Serialize an unsigned transaction with a single Reflect instruction.
+///
+/// Signature slots are zero-filled; the wallet signs the message and fills
+/// them in before submitting. A separate fee payer is placed first, as Solana
+/// requires, and adds a second signature slot.
+pub fn build_transaction(spec: &TransactionSpec) -> Vec<u8> {
+    let mut account_keys = Vec::with_capacity(3);
+    if let Some(fee_payer) = spec.fee_payer.filter(|fee_payer| *fee_payer != spec.signer) {
+        account_keys.push(fee_payer);
+    }
+    account_keys.push(spec.signer);
+    let num_signers = account_keys.len();
+    account_keys.push(spec.program_id());
+
+    let mut message = Vec::new();
+    if spec.version == TxVersion::V0 {
+        message.push(V0_PREFIX);
+    }
+    // Header: required signatures, read-only signed, read-only unsigned
+    message.extend_from_slice(&[num_signers as u8, 0, 1]);
+    write_compact_u16(&mut message, account_keys.len());
+    for key in &account_keys {
+        message.extend_from_slice(key);
+    }
+    message.extend_from_slice(&spec.recent_blockhash.unwrap_or_default());
+
+    // One instruction: program index, account indexes (the signer), data
+    write_compact_u16(&mut message, 1);
+    message.push(num_signers as u8);
+    write_compact_u16(&mut message, 1);
+    message.push(num_signers as u8 - 1);
+    write_compact_u16(&mut message, spec.instruction_data.len());
+    message.extend_from_slice(&spec.instruction_data);
+    if spec.version == TxVersion::V0 {
+        // No address lookup tables
+        write_compact_u16(&mut message, 0);
+    }
+
+    let mut tx = Vec::with_capacity(1 + num_signers * SIGNATURE_LEN + message.len());
+    write_compact_u16(&mut tx, num_signers);
+    for _ in 0..num_signers {
+        tx.extend_from_slice(&[0; SIGNATURE_LEN]);
+    }
+    tx.extend_from_slice(&message);
+    tx
+}
+
+/// The instructions [`build_transaction`] would serialize: a single Reflect
+/// instruction over the signer's account. The fee payer is not an instruction
+/// account, so it only matters when the caller assembles the transaction.
+pub fn build_instructions(spec: &TransactionSpec, encoding: TxEncoding) -> Vec<Instruction> {
+    vec![Instruction {
+        program_id: bs58::encode(spec.program_id()).into_string(),
+        keys: vec![AccountMeta {
+            pubkey: bs58::encode(spec.signer).into_string(),
+            is_signer: true,
+            is_writable: true,
+        }],
+        data: encoding.encode(&spec.instruction_data),
+    }]
+}
+
+/// Read the fee payer (first account key) back out of a serialized transaction.
+#[cfg(test)]
+pub fn fee_payer_of(tx: &[u8]) -> [u8; PUBKEY_LEN] {
+    let num_signatures = tx[0] as usize;
+    let mut message = &tx[1 + num_signatures * SIGNATURE_LEN..];
+    if message[0] == V0_PREFIX {
+        message = &message[1..];
+    }
+    // Skip the 3-byte header and the single-byte key count
+    message[4..4 + PUBKEY_LEN].try_into().unwrap()
+}
+
+/// Solana's "shortvec" length prefix.
+fn write_compact_u16(out: &mut Vec<u8>, len: usize) {
+    let mut rem = len as u16;
+    loop {
+        let mut byte = (rem & 0x7f) as u8;
+        rem >>= 7;
+        if rem == 0 {
+            out.push(byte);
+            return;
+        }
+        byte |= 0x80;
+        out.push(byte);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec() -> TransactionSpec {
+        TransactionSpec {
+            version: TxVersion::Legacy,
+            signer: parse_pubkey("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM").unwrap(),
+            fee_payer: None,
+            program_id: Some([9; PUBKEY_LEN]),
+            recent_blockhash: Some([7; PUBKEY_LEN]),
+            instruction_data: vec![0, 1, 2, 3],
+        }
+    }
+
+    #[test]
+    fn encodings_decode_to_same_bytes() {
+        let bytes = build_transaction(&spec());
+        let b64 = TxEncoding::Base64.encode(&bytes);
+        let b58 = TxEncoding::Base58.encode(&bytes);
+
+        assert_ne!(b64, b58);
+        assert_eq!(base64::engine::general_purpose::STANDARD.decode(b64).unwrap(), bytes);
+        assert_eq!(bs58::decode(b58).into_vec().unwrap(), bytes);
+    }
+
+    #[test]
+    fn legacy_layout() {
+        let spec = spec();
+        let bytes = build_transaction(&spec);
+
+        assert_eq!(bytes[0], 1);
+        let message = &bytes[1 + SIGNATURE_LEN..];
+        assert_eq!(&message[..4], &[1, 0, 1, 2]);
+        assert_eq!(&message[4..36], &spec.signer);
+        assert_eq!(&message[36..68], &[9; PUBKEY_LEN]);
+        assert_eq!(&message[68..100], &[7; PUBKEY_LEN]);
+    }
+
+    #[test]
+    fn v0_layout() {
+        let legacy = build_transaction(&spec());
+        let v0 = build_transaction(&TransactionSpec {
+            version: TxVersion::V0,
+            ..spec()
+        });
+
+        let message = &v0[1 + SIGNATURE_LEN..];
+        assert_eq!(message[0], V0_PREFIX);
+        assert_eq!(&message[1..], &[&legacy[1 + SIGNATURE_LEN..], &[0][..]].concat()[..]);
+    }
+
+    #[test]
+    fn fee_payer_override() {
+        let sponsor = parse_pubkey("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+        let bytes = build_transaction(&TransactionSpec {
+            fee_payer: Some(sponsor),
+            ..spec()
+        });
+
+        assert_eq!(bytes[0], 2);
+        assert_eq!(fee_payer_of(&bytes), sponsor);
+        let message = &bytes[1 + 2 * SIGNATURE_LEN..];
+        assert_eq!(&message[..4], &[2, 0, 1, 3]);
+        assert_eq!(&message[36..68], &spec().signer);
+
+        // Signer paying for itself is the single-signer layout
+        let same = build_transaction(&TransactionSpec {
+            fee_payer: Some(spec().signer),
+            ..spec()
+        });
+        assert_eq!(same, build_transaction(&spec()));
+        assert_eq!(fee_payer_of(&same), spec().signer);
+    }
+
+    #[test]
+    fn placeholders_are_flagged_simulated() {
+        let real = BuiltTx::build(&spec(), TxFormat::Transaction, TxEncoding::Base64);
+        assert!(!real.simulated);
+
+        let unconfigured = TransactionSpec { program_id: None, ..spec() };
+        let built = BuiltTx::build(&unconfigured, TxFormat::Instructions, TxEncoding::Base64);
+        assert!(built.simulated);
+        assert_eq!(built.instructions.unwrap()[0].program_id, bs58::encode(PLACEHOLDER_PROGRAM_ID).into_string());
+
+        let no_blockhash = TransactionSpec { recent_blockhash: None, ..spec() };
+        assert!(BuiltTx::build(&no_blockhash, TxFormat::Transaction, TxEncoding::Base64).simulated);
+    }
+
+    #[test]
+    fn compact_u16() {
+        let encode = |len| {
+            let mut out = Vec::new();
+            write_compact_u16(&mut out, len);
+            out
+        };
+        assert_eq!(encode(0), vec![0]);
+        assert_eq!(encode(127), vec![0x7f]);
+        assert_eq!(encode(128), vec![0x80, 0x01]);
+        assert_eq!(encode(16384), vec![0x80, 0x80, 0x01]);
+    }
+
+    #[test]
+    fn parses_pubkeys() {
+        assert!(parse_pubkey("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").is_some());
+        assert!(parse_pubkey("test_signer").is_none());
+        assert!(parse_pubkey("abc").is_none());
+    }
+}
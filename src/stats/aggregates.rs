@@ -0,0 +1,313 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+use crate::events::store::EventRecord;
+
+/// The counter an event type is counted under, shared with the
+/// [`crate::stats::ledger::VolumeLedger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Counted {
+    Mint,
+    Redeem,
+}
+
+impl Counted {
+    /// `None` for event types that are not counted.
+    pub(crate) fn of(event_type: &str) -> Option<Self> {
+        match event_type {
+            "mint" => Some(Counted::Mint),
+            "burn" | "redeem" => Some(Counted::Redeem),
+            _ => None,
+        }
+    }
+}
+
+/// Mint and redeem counts for the protocol or one integration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct EventCounts {
+    pub mints: u64,
+    pub redeems: u64,
+}
+
+impl EventCounts {
+    fn apply(&mut self, event_type: &str) {
+        match Counted::of(event_type) {
+            Some(Counted::Mint) => self.mints += 1,
+            Some(Counted::Redeem) => self.redeems += 1,
+            None => {}
+        }
+    }
+}
+
+/// Aggregate counters derived from the event store.
+///
+/// ### Example
+/// ```json
+/// {
+///   "protocol": { "mints": 3, "redeems": 1 },
+///   "integrations": { "int_1": { "mints": 2, "redeems": 1 } }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct AggregateTotals {
+    pub protocol: EventCounts,
+    pub integrations: BTreeMap<String, EventCounts>,
+}
+
+impl AggregateTotals {
+    /// Count one more event.
+    pub fn apply(&mut self, event: &EventRecord) {
+        self.protocol.apply(&event.event_type);
+        if let Some(integration_id) = &event.integration_id {
+            self.integrations
+                .entry(integration_id.clone())
+                .or_default()
+                .apply(&event.event_type);
+        }
+    }
+}
+
+/// Atomic mint / redeem counters for the protocol or one integration.
+#[derive(Debug, Default)]
+struct AtomicCounts {
+    mints: AtomicU64,
+    redeems: AtomicU64,
+}
+
+impl AtomicCounts {
+    fn apply(&self, event_type: &str) {
+        let counter = match Counted::of(event_type) {
+            Some(Counted::Mint) => &self.mints,
+            Some(Counted::Redeem) => &self.redeems,
+            None => return,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn load(&self) -> EventCounts {
+        EventCounts {
+            mints: self.mints.load(Ordering::Relaxed),
+            redeems: self.redeems.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl From<EventCounts> for AtomicCounts {
+    fn from(counts: EventCounts) -> Self {
+        AtomicCounts {
+            mints: AtomicU64::new(counts.mints),
+            redeems: AtomicU64::new(counts.redeems),
+        }
+    }
+}
+
+/// Every counter, swapped out as a whole by [`StatsAggregates::rebuild`].
+#[derive(Debug, Default)]
+struct Counters {
+    protocol: AtomicCounts,
+    integrations: HashMap<String, AtomicCounts>,
+}
+
+/// Running aggregates shared through [`crate::AppState`], updated as events
+/// are recorded (see [`crate::AppState::record_event`]).
+///
+/// Counting is on the hot path of every mint and redeem, so the counters
+/// are atomics updated under shared read locks: concurrent increments do
+/// not wait on each other, only on a writer. The locks are only taken
+/// exclusively the first time an integration is seen and on
+/// [`StatsAggregates::rebuild`], so a rebuild cannot lose increments made
+/// while it runs. Served by `GET /integrations/stats`.
+///
+/// Cheap to clone; clones share the same counters.
+#[derive(Debug, Clone, Default)]
+pub struct StatsAggregates {
+    counters: Arc<RwLock<Counters>>,
+    /// Held shared by [`StatsAggregates::record`] and exclusively by
+    /// [`StatsAggregates::rebuild`], so a recount never sees an event that
+    /// has been stored but not counted yet.
+    recount: Arc<RwLock<()>>,
+}
+
+impl StatsAggregates {
+    /// Store an event with `store` and count it, as one step with respect to
+    /// [`StatsAggregates::rebuild`].
+    pub fn record(&self, store: impl FnOnce() -> EventRecord) -> EventRecord {
+        let _recount = self.recount.read().unwrap();
+        let event = store();
+        self.apply(&event);
+        event
+    }
+
+    /// Count one more event.
+    pub fn apply(&self, event: &EventRecord) {
+        {
+            let counters = self.counters.read().unwrap();
+            let integration = event.integration_id.as_ref().map(|id| counters.integrations.get(id));
+            if let None | Some(Some(_)) = integration {
+                counters.protocol.apply(&event.event_type);
+                if let Some(Some(counts)) = integration {
+                    counts.apply(&event.event_type);
+                }
+                return;
+            }
+        }
+
+        // First event for this integration
+        let mut counters = self.counters.write().unwrap();
+        counters.protocol.apply(&event.event_type);
+        if let Some(integration_id) = &event.integration_id {
+            counters
+                .integrations
+                .entry(integration_id.clone())
+                .or_default()
+                .apply(&event.event_type);
+        }
+    }
+
+    /// Current totals. Counters are read one by one, so under concurrent
+    /// updates the snapshot may be a few events apart between fields.
+    pub fn snapshot(&self) -> AggregateTotals {
+        let counters = self.counters.read().unwrap();
+        AggregateTotals {
+            protocol: counters.protocol.load(),
+            integrations: counters
+                .integrations
+                .iter()
+                .map(|(id, counts)| (id.clone(), counts.load()))
+                .collect(),
+        }
+    }
+
+    /// Throw the running counters away and replace them with what `count`
+    /// returns, e.g. [`crate::events::store::EventStore::totals`]. No event
+    /// is [recorded](StatsAggregates::record) while `count` runs.
+    pub fn rebuild(&self, count: impl FnOnce() -> AggregateTotals) -> AggregateTotals {
+        let _recount = self.recount.write().unwrap();
+        let totals = count();
+
+        *self.counters.write().unwrap() = Counters {
+            protocol: totals.protocol.into(),
+            integrations: totals
+                .integrations
+                .iter()
+                .map(|(id, counts)| (id.clone(), (*counts).into()))
+                .collect(),
+        };
+        totals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timestamp::Timestamp;
+
+    fn event(event_type: &str, integration_id: Option<&str>) -> EventRecord {
+        EventRecord {
+            id: "evt".to_string(),
+            event_type: event_type.to_string(),
+            timestamp: Timestamp::now(),
+            signer: "signer".to_string(),
+            integration_id: integration_id.map(String::from),
+            volume: None,
+        }
+    }
+
+    fn tally(events: &[EventRecord]) -> AggregateTotals {
+        let mut totals = AggregateTotals::default();
+        for event in events {
+            totals.apply(event);
+        }
+        totals
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+    async fn concurrent_increments_are_exact() {
+        const TASKS: u64 = 64;
+        const PER_TASK: u64 = 1_000;
+
+        let aggregates = StatsAggregates::default();
+        let handles: Vec<_> = (0..TASKS)
+            .map(|task| {
+                let aggregates = aggregates.clone();
+                tokio::spawn(async move {
+                    let integration = format!("int_{}", task % 4);
+                    for i in 0..PER_TASK {
+                        let kind = if i % 4 == 0 { "redeem" } else { "mint" };
+                        aggregates.apply(&event(kind, Some(&integration)));
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        let totals = aggregates.snapshot();
+        assert_eq!(totals.protocol.mints + totals.protocol.redeems, TASKS * PER_TASK);
+        assert_eq!(totals.protocol.redeems, TASKS * PER_TASK / 4);
+        assert_eq!(totals.integrations.len(), 4);
+        for counts in totals.integrations.values() {
+            assert_eq!(counts.mints + counts.redeems, TASKS / 4 * PER_TASK);
+        }
+    }
+
+    #[test]
+    fn rebuild_replaces_counters() {
+        let aggregates = StatsAggregates::default();
+        aggregates.apply(&event("mint", Some("int_1")));
+        aggregates.apply(&event("mint", Some("int_2")));
+
+        let totals = aggregates.rebuild(|| tally(&[event("burn", Some("int_1"))]));
+        assert_eq!(aggregates.snapshot(), totals);
+        assert_eq!(totals.protocol, EventCounts { mints: 0, redeems: 1 });
+        assert!(!totals.integrations.contains_key("int_2"));
+
+        // Counting continues from the rebuilt values
+        aggregates.apply(&event("mint", Some("int_1")));
+        assert_eq!(aggregates.snapshot().integrations["int_1"], EventCounts { mints: 1, redeems: 1 });
+    }
+
+    #[test]
+    fn event_types_share_one_classification() {
+        assert_eq!(Counted::of("mint"), Some(Counted::Mint));
+        assert_eq!(Counted::of("burn"), Some(Counted::Redeem));
+        assert_eq!(Counted::of("redeem"), Some(Counted::Redeem));
+        assert_eq!(Counted::of("rebalance"), None);
+
+        let aggregates = StatsAggregates::default();
+        aggregates.apply(&event("rebalance", Some("int_1")));
+        assert_eq!(aggregates.snapshot().protocol, EventCounts::default());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+    async fn rebuild_does_not_lose_concurrent_increments() {
+        const TASKS: u64 = 16;
+        const PER_TASK: u64 = 1_000;
+
+        let aggregates = StatsAggregates::default();
+        let handles: Vec<_> = (0..TASKS)
+            .map(|_| {
+                let aggregates = aggregates.clone();
+                tokio::spawn(async move {
+                    for _ in 0..PER_TASK {
+                        aggregates.apply(&event("mint", Some("int_1")));
+                    }
+                })
+            })
+            .collect();
+        for _ in 0..10 {
+            aggregates.rebuild(|| tally(&[event("mint", Some("int_1"))]));
+            tokio::task::yield_now().await;
+        }
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        // Every mint counts towards both or, if a rebuild wiped it, neither
+        let totals = aggregates.snapshot();
+        assert_eq!(totals.protocol, totals.integrations["int_1"]);
+    }
+}
@@ -1,6 +1,312 @@
-use axum::{response::IntoResponse, Json};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::str::FromStr;
 
-pub async fn get_historical_tvl_and_volume() -> impl IntoResponse {
-    Json(json!({"historical": [{"timestamp": 1700000000, "tvl": 1000000, "volume": 50000}]}))
+use super::units::SmallestUnits;
+use crate::data_source::DataSource;
+use crate::error::ApiError;
+use crate::validation::malformed_field;
+use crate::AppState;
+
+/// Number of days covered by the historical series.
+const WINDOW_DAYS: i64 = 7;
+
+/// A series that can be requested from `/stats/historical`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    Tvl,
+    Volume,
+    Fees,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 3] = [Metric::Tvl, Metric::Volume, Metric::Fees];
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "tvl" => Ok(Metric::Tvl),
+            "volume" => Ok(Metric::Volume),
+            "fees" => Ok(Metric::Fees),
+            other => Err(format!(
+                "Unknown metric '{}'; expected one of tvl, volume, fees",
+                other
+            )),
+        }
+    }
+}
+
+/// Bucket size for the historical series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    Hour,
+    Day,
+    Week,
+}
+
+impl Granularity {
+    fn seconds(self) -> i64 {
+        match self {
+            Granularity::Hour => 3_600,
+            Granularity::Day => 86_400,
+            Granularity::Week => 604_800,
+        }
+    }
+}
+
+impl FromStr for Granularity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hour" => Ok(Granularity::Hour),
+            "day" => Ok(Granularity::Day),
+            "week" => Ok(Granularity::Week),
+            other => Err(format!(
+                "Unknown granularity '{}'; expected one of hour, day, week",
+                other
+            )),
+        }
+    }
+}
+
+/// Query parameters for `/stats/historical`.
+///
+/// ### Fields
+/// - `metrics`: Comma-separated list of series to return (`tvl`, `volume`, `fees`).
+///   Defaults to all of them.
+/// - `granularity`: Bucket size, one of `hour`, `day` (default) or `week`.
+///
+/// ### Example
+/// - `?metrics=tvl,fees&granularity=hour`
+#[derive(Debug, Default, Deserialize)]
+pub struct HistoricalStatsQuery {
+    pub metrics: Option<String>,
+    pub granularity: Option<String>,
+}
+
+/// A single bucket in the historical series. Metrics that were not requested are omitted.
+///
+/// All metrics are integer amounts in smallest units (see [`SmallestUnits`]).
+///
+/// ### Example
+/// ```json
+/// {
+///   "timestamp": 1700000000,
+///   "tvl": 1000000,
+///   "volume": 50000
+/// }
+/// ```
+#[derive(Debug, Serialize)]
+pub struct HistoricalStatsPoint {
+    pub timestamp: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tvl: Option<SmallestUnits>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume: Option<SmallestUnits>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fees: Option<SmallestUnits>,
+}
+
+/// Parse the comma-separated `metrics` parameter, rejecting unknown names.
+fn parse_metrics(raw: Option<&str>) -> Result<Vec<Metric>, String> {
+    let raw = match raw {
+        Some(raw) if !raw.trim().is_empty() => raw,
+        _ => return Ok(Metric::ALL.to_vec()),
+    };
+
+    let mut metrics = Vec::new();
+    for name in raw.split(',').map(str::trim) {
+        let metric = name.parse::<Metric>()?;
+        if !metrics.contains(&metric) {
+            metrics.push(metric);
+        }
+    }
+    Ok(metrics)
+}
+
+/// Handler for `GET /stats/historical`.
+///
+/// Returns a simulated TVL / volume / fee series covering the last seven days,
+/// bucketed by `granularity` and restricted to the requested `metrics`.
+/// Series are cached for `REFLECT_CACHE_TTL_SECS`; `X-Data-Source` reports
+/// `cache` for a hit and `local` for a fresh computation.
+///
+/// # Example
+///
+/// ```bash
+/// curl --request GET \
+///   --url "http://localhost:3000/stats/historical?metrics=tvl,volume&granularity=day"
+/// ```
+pub async fn get_historical_tvl_and_volume(
+    State(state): State<AppState>,
+    Query(query): Query<HistoricalStatsQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let metrics = parse_metrics(query.metrics.as_deref()).map_err(|message| malformed_field("metrics", message))?;
+    let granularity = query
+        .granularity
+        .as_deref()
+        .map(str::parse::<Granularity>)
+        .transpose()
+        .map_err(|message| malformed_field("granularity", message))?
+        .unwrap_or(Granularity::Day);
+
+    // Serve from the cache when the same series was computed recently
+    let key = format!(
+        "{:?}:{:?}",
+        Metric::ALL.iter().filter(|m| metrics.contains(m)).collect::<Vec<_>>(),
+        granularity
+    );
+    let (source, data) = match state.stats_cache.get(&key) {
+        Some(data) => (DataSource::Cache, data),
+        None => {
+            let data = json!(state.cased(compute_series(&metrics, granularity)));
+            state.stats_cache.insert(key, data.clone());
+            (DataSource::Local, data)
+        }
+    };
+
+    Ok((
+        StatusCode::OK,
+        source,
+        Json(json!({ "success": true, "data": data })),
+    ))
+}
+
+/// Simulated series: TVL grows slowly, volume is flat and fees are 0.1% of volume.
+fn compute_series(metrics: &[Metric], granularity: Granularity) -> Vec<HistoricalStatsPoint> {
+    let step = granularity.seconds();
+    let buckets = (WINDOW_DAYS * 86_400 / step).max(1);
+    let end = Utc::now().timestamp() / step * step;
+
+    (0..buckets)
+        .map(|i| {
+            let volume = 50_000 * step as u128 / 86_400;
+            HistoricalStatsPoint {
+                timestamp: end - (buckets - 1 - i) * step,
+                tvl: metrics
+                    .contains(&Metric::Tvl)
+                    .then_some(SmallestUnits(1_000_000 + i as u128 * 1_000)),
+                volume: metrics
+                    .contains(&Metric::Volume)
+                    .then_some(SmallestUnits(volume)),
+                fees: metrics
+                    .contains(&Metric::Fees)
+                    .then_some(SmallestUnits(volume / 1000)),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use serde_json::Value;
+
+    async fn call(query: HistoricalStatsQuery) -> (StatusCode, Value) {
+        let response = get_historical_tvl_and_volume(State(AppState::default()), Query(query))
+            .await
+            .into_response();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, 64 * 1024).await.unwrap();
+        (parts.status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_data_source_header_cache_hit() {
+        let state = AppState::default();
+        let source = |response: &axum::response::Response| {
+            response.headers()[crate::data_source::DATA_SOURCE_HEADER].to_str().unwrap().to_string()
+        };
+
+        let first = get_historical_tvl_and_volume(State(state.clone()), Query(HistoricalStatsQuery::default()))
+            .await
+            .into_response();
+        assert_eq!(source(&first), "local");
+
+        let second = get_historical_tvl_and_volume(State(state.clone()), Query(HistoricalStatsQuery::default()))
+            .await
+            .into_response();
+        assert_eq!(source(&second), "cache");
+
+        // A different series is a fresh computation
+        let other = get_historical_tvl_and_volume(
+            State(state),
+            Query(HistoricalStatsQuery {
+                metrics: None,
+                granularity: Some("week".to_string()),
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(source(&other), "local");
+    }
+
+    #[tokio::test]
+    async fn test_defaults_return_all_metrics_daily() {
+        let (status, json) = call(HistoricalStatsQuery::default()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["success"], true);
+
+        let points = json["data"].as_array().unwrap();
+        assert_eq!(points.len(), 7);
+        assert!(points[0]["tvl"].is_number());
+        assert!(points[0]["volume"].is_number());
+        assert!(points[0]["fees"].is_number());
+    }
+
+    #[tokio::test]
+    async fn test_subset_of_metrics() {
+        let (status, json) = call(HistoricalStatsQuery {
+            metrics: Some("tvl,fees".to_string()),
+            granularity: Some("hour".to_string()),
+        })
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let points = json["data"].as_array().unwrap();
+        assert_eq!(points.len(), 7 * 24);
+        for point in points {
+            assert!(point["tvl"].is_number());
+            assert!(point["fees"].is_number());
+            assert!(point.get("volume").is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unknown_metric_rejected() {
+        let (status, json) = call(HistoricalStatsQuery {
+            metrics: Some("tvl,price".to_string()),
+            granularity: None,
+        })
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["success"], false);
+        assert_eq!(
+            json["message"],
+            "Unknown metric 'price'; expected one of tvl, volume, fees"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unknown_granularity_rejected() {
+        let (status, json) = call(HistoricalStatsQuery {
+            metrics: None,
+            granularity: Some("minute".to_string()),
+        })
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["success"], false);
+    }
 }
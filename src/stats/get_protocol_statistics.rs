@@ -1,6 +1,81 @@
-use axum::{response::IntoResponse, Json};
-use serde_json::json;
+use axum::{extract::State, response::IntoResponse, Json};
+use serde_json::{json, Value};
 
-pub async fn get_protocol_statistics() -> impl IntoResponse {
-    Json(json!({"total_minted": 50000, "total_redeemed": 10000}))
+use crate::data_source::DataSource;
+use crate::AppState;
+
+/// Current protocol totals, shared by `GET /stats/protocol` and the
+/// `GET /stats/stream` snapshots.
+///
+/// Computed from the mints and burns handled since the process started
+/// (see [`super::ledger::ProtocolTotals`]).
+///
+/// ### Example
+/// ```json
+/// {
+///   "total_minted": 999000,
+///   "total_redeemed": 400000,
+///   "net_supply": 599000,
+///   "stablecoins": [
+///     { "index": 0, "minted": 999000, "redeemed": 400000, "net_supply": 599000 }
+///   ]
+/// }
+/// ```
+pub fn protocol_statistics_snapshot(state: &AppState) -> Value {
+    json!(state.cased(state.ledger.totals()))
+}
+
+pub async fn get_protocol_statistics(State(state): State<AppState>) -> impl IntoResponse {
+    (DataSource::Local, Json(protocol_statistics_snapshot(&state)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    use crate::build_app;
+
+    async fn post(app: axum::Router, uri: &str, body: Value) -> StatusCode {
+        let request = Request::post(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        app.oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn test_stats_follow_mints_and_burns() {
+        let state = AppState::default();
+        let signer = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+
+        for amount in [1_000_000, 2_000_000] {
+            let body = json!({ "stablecoinIndex": 0, "depositAmount": amount, "signer": signer, "minimumReceived": 1 });
+            assert_eq!(post(build_app(state.clone()), "/stablecoins/mint/tx", body).await, StatusCode::OK);
+        }
+        let body = json!({ "stablecoinIndex": 0, "depositAmount": 500_000, "signer": signer, "minimumReceived": 1 });
+        assert_eq!(post(build_app(state.clone()), "/stablecoins/burn/tx", body).await, StatusCode::OK);
+
+        // A rejected mint is not counted
+        let body = json!({ "stablecoinIndex": 0, "depositAmount": 0, "signer": signer, "minimumReceived": 1 });
+        assert_ne!(post(build_app(state.clone()), "/stablecoins/mint/tx", body).await, StatusCode::OK);
+
+        let response = build_app(state)
+            .oneshot(Request::get("/stats/protocol").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let json: Value = serde_json::from_slice(&to_bytes(response.into_body(), 4096).await.unwrap()).unwrap();
+
+        // Mints count the quoted output (deposit minus 0.1% fees), burns the amount burned
+        assert_eq!(json["total_minted"], 999_000 + 1_998_000);
+        assert_eq!(json["total_redeemed"], 500_000);
+        assert_eq!(json["net_supply"], 999_000 + 1_998_000 - 500_000);
+        assert_eq!(
+            json["stablecoins"],
+            json!([{ "index": 0, "minted": 2_997_000, "redeemed": 500_000, "net_supply": 2_497_000 }])
+        );
+    }
 }
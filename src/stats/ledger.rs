@@ -0,0 +1,167 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use crate::events::store::EventRecord;
+use super::aggregates::Counted;
+use super::units::SmallestUnits;
+
+/// Minted and redeemed amounts for one stablecoin, in smallest units.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Volume {
+    minted: u128,
+    redeemed: u128,
+}
+
+/// Per-stablecoin figures in [`ProtocolTotals`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StablecoinTotals {
+    pub index: u32,
+    pub minted: SmallestUnits,
+    pub redeemed: SmallestUnits,
+    pub net_supply: SmallestUnits,
+}
+
+/// Protocol statistics derived from the [`VolumeLedger`].
+///
+/// `net_supply` is minted minus redeemed. The ledger only sees activity
+/// since the process started, so redemptions of older supply can exceed
+/// what was minted; the difference is then reported as `0`.
+///
+/// ### Example
+/// ```json
+/// {
+///   "total_minted": 999000,
+///   "total_redeemed": 400000,
+///   "net_supply": 599000,
+///   "stablecoins": [
+///     { "index": 0, "minted": 999000, "redeemed": 400000, "net_supply": 599000 }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ProtocolTotals {
+    pub total_minted: SmallestUnits,
+    pub total_redeemed: SmallestUnits,
+    pub net_supply: SmallestUnits,
+    pub stablecoins: Vec<StablecoinTotals>,
+}
+
+/// Minted and redeemed amounts per stablecoin, summed from events.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Volumes(BTreeMap<u32, Volume>);
+
+impl Volumes {
+    /// Add the amount a mint or redeem event moved. Events without a
+    /// volume, or of other types, are ignored.
+    pub fn apply(&mut self, event: &EventRecord) {
+        let Some(volume) = event.volume else {
+            return;
+        };
+        let entry = self.0.entry(volume.index).or_default();
+        match Counted::of(&event.event_type) {
+            Some(Counted::Mint) => entry.minted += u128::from(volume.amount),
+            Some(Counted::Redeem) => entry.redeemed += u128::from(volume.amount),
+            None => {}
+        }
+    }
+}
+
+/// Running mint / redeem amounts per stablecoin, shared through
+/// [`crate::AppState`] and fed by the events recorded with
+/// [`crate::AppState::record_event`].
+///
+/// Cheap to clone; clones share the same ledger.
+#[derive(Debug, Clone, Default)]
+pub struct VolumeLedger {
+    volumes: Arc<Mutex<Volumes>>,
+}
+
+impl VolumeLedger {
+    /// Add the amount `event` moved.
+    pub fn apply(&self, event: &EventRecord) {
+        self.volumes.lock().unwrap().apply(event);
+    }
+
+    /// Replace the running amounts with `volumes`, e.g.
+    /// [`crate::events::store::EventStore::volumes`].
+    pub fn rebuild(&self, volumes: Volumes) {
+        *self.volumes.lock().unwrap() = volumes;
+    }
+
+    /// Protocol-wide and per-stablecoin totals, ordered by index.
+    pub fn totals(&self) -> ProtocolTotals {
+        let volumes = &self.volumes.lock().unwrap().0;
+        let minted: u128 = volumes.values().map(|volume| volume.minted).sum();
+        let redeemed: u128 = volumes.values().map(|volume| volume.redeemed).sum();
+        ProtocolTotals {
+            total_minted: SmallestUnits(minted),
+            total_redeemed: SmallestUnits(redeemed),
+            net_supply: SmallestUnits(minted.saturating_sub(redeemed)),
+            stablecoins: volumes
+                .iter()
+                .map(|(&index, volume)| StablecoinTotals {
+                    index,
+                    minted: SmallestUnits(volume.minted),
+                    redeemed: SmallestUnits(volume.redeemed),
+                    net_supply: SmallestUnits(volume.minted.saturating_sub(volume.redeemed)),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::events::store::EventVolume;
+    use crate::timestamp::Timestamp;
+
+    fn event(event_type: &str, index: u32, amount: u64) -> EventRecord {
+        EventRecord {
+            id: "evt".to_string(),
+            event_type: event_type.to_string(),
+            timestamp: Timestamp::now(),
+            signer: "signer".to_string(),
+            integration_id: None,
+            volume: Some(EventVolume { index, amount }),
+        }
+    }
+
+    #[test]
+    fn totals_sum_across_stablecoins() {
+        let ledger = VolumeLedger::default();
+        ledger.apply(&event("mint", 1, 300));
+        ledger.apply(&event("mint", 0, 1_000));
+        ledger.apply(&event("mint", 0, 500));
+        ledger.apply(&event("burn", 0, 200));
+        ledger.apply(&event("redeem", 1, 400));
+        ledger.apply(&event("rebalance", 1, 9_999));
+
+        let totals = ledger.totals();
+        assert_eq!(totals.total_minted, SmallestUnits(1_800));
+        assert_eq!(totals.total_redeemed, SmallestUnits(600));
+        assert_eq!(totals.net_supply, SmallestUnits(1_200));
+        assert_eq!(
+            totals.stablecoins,
+            vec![
+                StablecoinTotals {
+                    index: 0,
+                    minted: SmallestUnits(1_500),
+                    redeemed: SmallestUnits(200),
+                    net_supply: SmallestUnits(1_300),
+                },
+                StablecoinTotals {
+                    index: 1,
+                    minted: SmallestUnits(300),
+                    redeemed: SmallestUnits(400),
+                    net_supply: SmallestUnits(0),
+                },
+            ]
+        );
+
+        ledger.rebuild(Volumes::default());
+        assert_eq!(ledger.totals(), ProtocolTotals::default());
+    }
+}
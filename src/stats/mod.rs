@@ -1,11 +1,16 @@
 use axum::Router;
 use crate::AppState;
 
+pub mod aggregates;
 pub mod get_protocol_statistics;
+pub mod ledger;
 pub mod get_historical_tvl_and_volume;
+pub mod stream_protocol_statistics;
+pub mod units;
 
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/protocol", axum::routing::get(get_protocol_statistics::get_protocol_statistics))
         .route("/historical", axum::routing::get(get_historical_tvl_and_volume::get_historical_tvl_and_volume))
+        .route("/stream", axum::routing::get(stream_protocol_statistics::stream_protocol_statistics))
 }
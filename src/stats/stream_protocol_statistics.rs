@@ -0,0 +1,100 @@
+use axum::{
+    extract::State,
+    response::sse::{Event, KeepAlive, Sse},
+};
+use futures_util::stream::{self, Stream};
+use std::convert::Infallible;
+use std::time::Duration;
+use tokio::time::{interval, MissedTickBehavior};
+
+use super::get_protocol_statistics::protocol_statistics_snapshot;
+use crate::span::handler_span;
+use crate::AppState;
+
+/// How often an idle stream sends a `: heartbeat` comment so proxies keep it open.
+pub const STATS_STREAM_HEARTBEAT: Duration = Duration::from_secs(15);
+
+/// Handler for `GET /stats/stream`.
+///
+/// Server-sent events carrying a protocol stats snapshot (the same body as
+/// `GET /stats/protocol`) every `REFLECT_STATS_STREAM_INTERVAL_SECS`, starting
+/// immediately on connect.
+///
+/// ### Example Event
+/// ```text
+/// event: stats
+/// data: {"total_minted":999000,"total_redeemed":0,"net_supply":999000,"stablecoins":[...]}
+/// ```
+///
+/// # Example
+///
+/// ```bash
+/// curl -N http://localhost:3000/stats/stream
+/// ```
+pub async fn stream_protocol_statistics(
+    State(state): State<AppState>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let _span = handler_span!("GET /stats/stream").entered();
+    let period = Duration::from_secs(state.config.stats_stream_interval_secs.max(1));
+    let mut ticks = interval(period);
+    ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    let snapshots = stream::unfold((ticks, state), |(mut ticks, state)| async move {
+        ticks.tick().await;
+        let event = Event::default()
+            .event("stats")
+            .json_data(protocol_statistics_snapshot(&state))
+            .expect("stats snapshot serializes");
+        Some((Ok(event), (ticks, state)))
+    });
+
+    Sse::new(snapshots).keep_alive(
+        KeepAlive::new()
+            .interval(STATS_STREAM_HEARTBEAT)
+            .text("heartbeat"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::{header, Request, StatusCode};
+    use axum::routing::get;
+    use axum::Router;
+    use futures_util::StreamExt;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_stream_emits_stats_event() {
+        let app = Router::new()
+            .route("/stats/stream", get(stream_protocol_statistics))
+            .with_state(AppState::default());
+        let response = app
+            .oneshot(Request::get("/stats/stream").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "text/event-stream");
+
+        let mut body = response.into_body().into_data_stream();
+        let mut received = String::new();
+        while !received.contains("\n\n") {
+            let chunk = tokio::time::timeout(Duration::from_secs(1), body.next())
+                .await
+                .expect("no stats event within a second")
+                .unwrap()
+                .unwrap();
+            received.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+
+        let mut lines = received.lines();
+        assert_eq!(lines.next(), Some("event: stats"));
+        let data: Value = serde_json::from_str(lines.next().unwrap().strip_prefix("data: ").unwrap()).unwrap();
+        assert_eq!(data, protocol_statistics_snapshot(&AppState::default()));
+        assert!(data["total_minted"].is_number());
+        assert!(data["total_redeemed"].is_number());
+    }
+}
@@ -0,0 +1,116 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Largest integer a JSON consumer using IEEE-754 doubles (e.g. JavaScript) can
+/// represent exactly: `2^53 - 1`.
+pub const MAX_SAFE_JSON_INTEGER: u128 = (1 << 53) - 1;
+
+/// A monetary value expressed in a token's smallest unit.
+///
+/// Stats aggregate across every mint and redeem, so values are stored as `u128`
+/// rather than `f64` to avoid rounding. On the wire the value is a plain JSON
+/// number while it fits in [`MAX_SAFE_JSON_INTEGER`], and a decimal string
+/// above that so clients parsing JSON numbers as doubles never lose precision.
+///
+/// ### Example
+/// ```json
+/// { "tvl": 1000000, "volume": "90071992547409930" }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SmallestUnits(pub u128);
+
+impl From<u64> for SmallestUnits {
+    fn from(value: u64) -> Self {
+        SmallestUnits(value as u128)
+    }
+}
+
+impl fmt::Display for SmallestUnits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Serialize for SmallestUnits {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0 <= MAX_SAFE_JSON_INTEGER {
+            serializer.serialize_u64(self.0 as u64)
+        } else {
+            serializer.collect_str(&self.0)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SmallestUnits {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl de::Visitor<'_> for Visitor {
+            type Value = SmallestUnits;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a non-negative integer or integer string")
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(SmallestUnits(v as u128))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                u128::try_from(v)
+                    .map(SmallestUnits)
+                    .map_err(|_| E::custom("amount must not be negative"))
+            }
+
+            fn visit_f64<E: de::Error>(self, _: f64) -> Result<Self::Value, E> {
+                Err(E::custom("amount must be a whole number of smallest units"))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse::<u128>()
+                    .map(SmallestUnits)
+                    .map_err(|_| E::custom("amount must be a non-negative integer string"))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn small_values_serialize_as_numbers() {
+        let value = serde_json::to_value(SmallestUnits(1_000_000)).unwrap();
+        assert_eq!(value, json!(1_000_000));
+
+        let value = serde_json::to_value(SmallestUnits(MAX_SAFE_JSON_INTEGER)).unwrap();
+        assert!(value.is_number());
+    }
+
+    #[test]
+    fn large_tvl_round_trips_without_precision_loss() {
+        // 2^53 + 1 cannot be represented exactly as an f64
+        let tvl = SmallestUnits(MAX_SAFE_JSON_INTEGER + 2);
+        let value = serde_json::to_value(tvl).unwrap();
+        assert_eq!(value, Value::String("9007199254740993".into()));
+
+        let back: SmallestUnits = serde_json::from_value(value).unwrap();
+        assert_eq!(back, tvl);
+
+        let huge = SmallestUnits(u128::MAX);
+        let back: SmallestUnits =
+            serde_json::from_value(serde_json::to_value(huge).unwrap()).unwrap();
+        assert_eq!(back, huge);
+    }
+
+    #[test]
+    fn rejects_negative_and_fractional_values() {
+        assert!(serde_json::from_value::<SmallestUnits>(json!(-1)).is_err());
+        assert!(serde_json::from_value::<SmallestUnits>(json!(1.5)).is_err());
+        assert!(serde_json::from_value::<SmallestUnits>(json!("12abc")).is_err());
+    }
+}
@@ -0,0 +1,255 @@
+use axum::{
+    extract::{Query, Request},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::validation::parse_param;
+
+/// A UTC instant that serializes the way the official Reflect API does:
+/// ISO 8601 with millisecond precision and a `Z` suffix
+/// (e.g. `"2025-12-17T12:34:56.789Z"`).
+///
+/// Within a request carrying `?timePrecision=seconds` (see
+/// [`apply_time_precision`]) the fraction is dropped instead
+/// (`"2025-12-17T12:34:56Z"`). Fields serialized with
+/// [`serialize_seconds_by_default`] drop it unless `millis` is asked for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(pub DateTime<Utc>);
+
+impl Timestamp {
+    pub fn now() -> Self {
+        Timestamp(Utc::now())
+    }
+
+    /// Format as ISO 8601 UTC with a `Z` suffix at the given precision.
+    ///
+    /// Goes through chrono's RFC 3339 writer rather than a format string, so
+    /// it cannot fail.
+    pub fn format(self, precision: TimePrecision) -> String {
+        self.0.to_rfc3339_opts(precision.seconds_format(), true)
+    }
+
+    /// Format as an HTTP date (`Last-Modified` etc.), e.g. `Thu, 18 Dec 2025 17:46:10 GMT`.
+    /// HTTP dates have whole-second resolution.
+    pub fn to_http_date(self) -> String {
+        self.0.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+    }
+
+    /// Parse an HTTP date as sent in `If-Modified-Since`.
+    pub fn parse_http_date(raw: &str) -> Option<Self> {
+        DateTime::parse_from_rfc2822(raw.trim())
+            .ok()
+            .map(|dt| Timestamp(dt.with_timezone(&Utc)))
+    }
+
+    /// Whether this instant falls after `since` once both are truncated to
+    /// whole seconds, as conditional requests compare them.
+    pub fn modified_since(self, since: Timestamp) -> bool {
+        self.0.timestamp() > since.0.timestamp()
+    }
+
+    /// Parse an RFC 3339 string (any offset), normalizing it to UTC.
+    pub fn parse_rfc3339(raw: &str) -> Option<Self> {
+        DateTime::parse_from_rfc3339(raw)
+            .ok()
+            .map(|dt| Timestamp(dt.with_timezone(&Utc)))
+    }
+}
+
+impl From<DateTime<Utc>> for Timestamp {
+    fn from(value: DateTime<Utc>) -> Self {
+        Timestamp(value)
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format(TimePrecision::Millis))
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.format(requested_precision().unwrap_or_default()))
+    }
+}
+
+/// `#[serde(serialize_with)]` helper for fields that were sent as whole
+/// seconds before `?timePrecision` existed: they keep that format unless
+/// the request asks for `millis`.
+pub fn serialize_seconds_by_default<S: Serializer>(timestamp: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&timestamp.format(requested_precision().unwrap_or(TimePrecision::Seconds)))
+}
+
+/// The `?timePrecision` of the request being handled, if it sent one.
+fn requested_precision() -> Option<TimePrecision> {
+    PRECISION.try_with(|precision| *precision).ok().flatten()
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    /// Accepts any RFC 3339 string, normalized to UTC.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        Timestamp::parse_rfc3339(&raw)
+            .ok_or_else(|| serde::de::Error::custom(format!("not an RFC 3339 timestamp: {:?}", raw)))
+    }
+}
+
+/// How many fractional digits serialized [`Timestamp`]s carry.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimePrecision {
+    Seconds,
+    #[default]
+    Millis,
+}
+
+impl TimePrecision {
+    fn seconds_format(self) -> SecondsFormat {
+        match self {
+            TimePrecision::Seconds => SecondsFormat::Secs,
+            TimePrecision::Millis => SecondsFormat::Millis,
+        }
+    }
+}
+
+impl FromStr for TimePrecision {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "seconds" => Ok(TimePrecision::Seconds),
+            "millis" => Ok(TimePrecision::Millis),
+            other => Err(format!(
+                "Unknown timePrecision '{}'; expected one of seconds, millis",
+                other
+            )),
+        }
+    }
+}
+
+tokio::task_local! {
+    static PRECISION: Option<TimePrecision>;
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct TimePrecisionQuery {
+    #[serde(rename = "timePrecision")]
+    time_precision: Option<String>,
+}
+
+/// Middleware applying `?timePrecision=seconds|millis` to every [`Timestamp`]
+/// serialized while the request is handled. Without it timestamps use
+/// `millis`, except fields serialized with [`serialize_seconds_by_default`];
+/// unknown values are rejected with 400.
+pub async fn apply_time_precision(request: Request, next: Next) -> Response {
+    let query = Query::<TimePrecisionQuery>::try_from_uri(request.uri())
+        .map(|Query(query)| query)
+        .unwrap_or_default();
+    let precision = match query.time_precision.as_deref() {
+        None => None,
+        Some(raw) => match parse_param("timePrecision", Some(raw), TimePrecision::default()) {
+            Ok(precision) => Some(precision),
+            Err(error) => return error.into_response(),
+        },
+    };
+
+    PRECISION.scope(precision, next.run(request)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn instant() -> Timestamp {
+        Timestamp(Utc.with_ymd_and_hms(2025, 12, 17, 12, 34, 56).unwrap() + chrono::Duration::milliseconds(789))
+    }
+
+    #[tokio::test]
+    async fn precision_follows_scope() {
+        let json = |ts: Timestamp| serde_json::to_string(&ts).unwrap();
+
+        assert_eq!(json(instant()), r#""2025-12-17T12:34:56.789Z""#);
+        let seconds = PRECISION.scope(Some(TimePrecision::Seconds), async { json(instant()) }).await;
+        assert_eq!(seconds, r#""2025-12-17T12:34:56Z""#);
+        let millis = PRECISION.scope(Some(TimePrecision::Millis), async { json(instant()) }).await;
+        assert_eq!(millis, r#""2025-12-17T12:34:56.789Z""#);
+    }
+
+    #[tokio::test]
+    async fn seconds_by_default_fields_keep_their_format() {
+        #[derive(Serialize)]
+        struct Legacy(#[serde(serialize_with = "serialize_seconds_by_default")] Timestamp);
+        let json = || serde_json::to_string(&Legacy(instant())).unwrap();
+
+        assert_eq!(json(), r#""2025-12-17T12:34:56Z""#);
+        assert_eq!(PRECISION.scope(None, async { json() }).await, r#""2025-12-17T12:34:56Z""#);
+        let millis = PRECISION.scope(Some(TimePrecision::Millis), async { json() }).await;
+        assert_eq!(millis, r#""2025-12-17T12:34:56.789Z""#);
+    }
+
+    #[test]
+    fn formats_at_each_precision() {
+        assert_eq!(instant().format(TimePrecision::Millis), "2025-12-17T12:34:56.789Z");
+        assert_eq!(instant().format(TimePrecision::Seconds), "2025-12-17T12:34:56Z");
+        assert_eq!(instant().to_string(), "2025-12-17T12:34:56.789Z");
+
+        // Whole seconds still carry three fractional digits
+        let whole = Timestamp(Utc.with_ymd_and_hms(2025, 12, 17, 12, 34, 56).unwrap());
+        assert_eq!(whole.to_string(), "2025-12-17T12:34:56.000Z");
+    }
+
+    #[test]
+    fn http_dates() {
+        assert_eq!(instant().to_http_date(), "Wed, 17 Dec 2025 12:34:56 GMT");
+        let parsed = Timestamp::parse_http_date("Wed, 17 Dec 2025 12:34:56 GMT").unwrap();
+        assert!(!instant().modified_since(parsed));
+        assert!(Timestamp(instant().0 + chrono::Duration::seconds(1)).modified_since(parsed));
+        assert!(Timestamp::parse_http_date("yesterday").is_none());
+    }
+
+    #[tokio::test]
+    async fn query_selects_precision() {
+        use axum::body::{to_bytes, Body};
+        use axum::http::{Request, StatusCode};
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use crate::{build_app, AppState};
+
+        let fetch = |uri: &'static str| async move {
+            let response = build_app(AppState::default())
+                .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            let status = response.status();
+            let json: Value = serde_json::from_slice(&to_bytes(response.into_body(), 4096).await.unwrap()).unwrap();
+            (status, json)
+        };
+
+        let (_, json) = fetch("/stablecoins/exchange-rates/104135").await;
+        assert_eq!(json["data"]["timestamp"], "2025-12-18T17:46:10.274Z");
+        let (_, json) = fetch("/stablecoins/exchange-rates/104135?timePrecision=millis").await;
+        assert_eq!(json["data"]["timestamp"], "2025-12-18T17:46:10.274Z");
+        let (_, json) = fetch("/stablecoins/exchange-rates/104135?timePrecision=seconds").await;
+        assert_eq!(json["data"]["timestamp"], "2025-12-18T17:46:10Z");
+
+        let (_, json) = fetch("/health?timePrecision=seconds").await;
+        assert!(!json["timestamp"].as_str().unwrap().contains('.'));
+
+        // Historical APY keeps whole seconds unless millis are asked for
+        let (_, json) = fetch("/stablecoins/stablecoin/0/apy/historical?days=1").await;
+        assert!(json["data"][0]["timestamp"].as_str().unwrap().ends_with("T00:00:00Z"));
+        let (_, json) = fetch("/stablecoins/stablecoin/0/apy/historical?days=1&timePrecision=millis").await;
+        assert!(json["data"][0]["timestamp"].as_str().unwrap().ends_with("T00:00:00.000Z"));
+
+        let (status, json) = fetch("/health?timePrecision=nanos").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["message"], "Unknown timePrecision 'nanos'; expected one of seconds, millis");
+    }
+}
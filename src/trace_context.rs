@@ -0,0 +1,246 @@
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use std::fmt;
+use tracing::Instrument;
+
+/// W3C Trace Context header, accepted from the caller and sent on outbound calls.
+pub const TRACEPARENT_HEADER: HeaderName = HeaderName::from_static("traceparent");
+
+/// `trace-flags` bit marking the trace as sampled.
+const SAMPLED: u8 = 0x01;
+
+tokio::task_local! {
+    static CURRENT: TraceContext;
+}
+
+/// The W3C trace this request belongs to, plus the id of our own span in it.
+///
+/// Formats as a `traceparent` value: `00-{trace_id}-{span_id}-{flags}`, with
+/// every field in lowercase hex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceContext {
+    pub trace_id: [u8; 16],
+    pub span_id: [u8; 8],
+    pub flags: u8,
+}
+
+impl TraceContext {
+    /// Start a new, sampled trace.
+    pub fn generate() -> Self {
+        Self {
+            trace_id: *uuid::Uuid::new_v4().as_bytes(),
+            span_id: new_span_id(),
+            flags: SAMPLED,
+        }
+    }
+
+    /// Parse a `traceparent` header value.
+    ///
+    /// Versions other than `00` are accepted as long as they start with the
+    /// version `00` fields; `ff` and all-zero ids are invalid per the spec.
+    pub fn parse(raw: &str) -> Option<Self> {
+        let (head, rest) = raw.split_at_checked(55)?;
+        if !(rest.is_empty() || (!raw.starts_with("00") && rest.starts_with('-'))) {
+            return None;
+        }
+        let mut parts = head.split('-');
+        let version = parts.next()?;
+        let trace_id = parse_hex::<16>(parts.next()?)?;
+        let span_id = parse_hex::<8>(parts.next()?)?;
+        let [flags] = parse_hex::<1>(parts.next()?)?;
+        if parse_hex::<1>(version)? == [0xff] || trace_id == [0; 16] || span_id == [0; 8] {
+            return None;
+        }
+        Some(Self { trace_id, span_id, flags })
+    }
+
+    /// The context for our own span within the same trace.
+    pub fn child(&self) -> Self {
+        Self {
+            span_id: new_span_id(),
+            ..*self
+        }
+    }
+
+    pub fn trace_id_hex(&self) -> String {
+        hex(&self.trace_id)
+    }
+
+    /// The context of the request being handled, if it went through
+    /// [`propagate_trace`]. Tasks spawned from a handler do not inherit it.
+    pub fn current() -> Option<Self> {
+        CURRENT.try_with(|context| *context).ok()
+    }
+}
+
+impl fmt::Display for TraceContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "00-{}-{}-{:02x}", hex(&self.trace_id), hex(&self.span_id), self.flags)
+    }
+}
+
+fn new_span_id() -> [u8; 8] {
+    let bytes = uuid::Uuid::new_v4().into_bytes();
+    bytes[..8].try_into().expect("uuids are 16 bytes")
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Decode exactly `N` bytes of lowercase hex.
+fn parse_hex<const N: usize>(raw: &str) -> Option<[u8; N]> {
+    if raw.len() != N * 2 || !raw.bytes().all(|c| c.is_ascii_digit() || (b'a'..=b'f').contains(&c)) {
+        return None;
+    }
+    let mut bytes = [0; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&raw[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(bytes)
+}
+
+/// Add the current request's `traceparent` to an outbound call, so upstream
+/// services continue the same trace. A no-op outside a traced request.
+pub fn inject(builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+    match TraceContext::current() {
+        Some(context) => builder.header(TRACEPARENT_HEADER, context.to_string()),
+        None => builder,
+    }
+}
+
+/// Middleware continuing the caller's W3C trace.
+///
+/// A valid incoming `traceparent` is continued with a fresh span id; otherwise
+/// a new trace is started. The context is recorded on a `trace` span (so log
+/// lines carry `trace_id`), made available to outbound calls through
+/// [`inject`], and echoed back in the response `traceparent`.
+pub async fn propagate_trace(request: Request, next: Next) -> Response {
+    let context = request
+        .headers()
+        .get(&TRACEPARENT_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(TraceContext::parse)
+        .map(|parent| parent.child())
+        .unwrap_or_else(TraceContext::generate);
+
+    let span = tracing::info_span!("trace", trace_id = %context.trace_id_hex(), span_id = %hex(&context.span_id));
+    let mut response = CURRENT.scope(context, next.run(request)).instrument(span).await;
+    let value = HeaderValue::from_str(&context.to_string()).expect("traceparent is ASCII hex");
+    response.headers_mut().insert(TRACEPARENT_HEADER, value);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::extract::State;
+    use axum::http::HeaderMap;
+    use axum::routing::get;
+    use axum::{middleware, Json, Router};
+    use serde_json::{json, Value};
+    use std::sync::{Arc, Mutex};
+    use tower::ServiceExt;
+    use crate::capture::Capture;
+    use crate::upstream::Upstream;
+
+    const PARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn parses_valid_traceparents_only() {
+        let context = TraceContext::parse(PARENT).unwrap();
+        assert_eq!(context.trace_id_hex(), "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(context.flags, SAMPLED);
+        assert_eq!(context.to_string(), PARENT);
+
+        // Future versions may append fields
+        assert!(TraceContext::parse("01-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra").is_some());
+
+        for invalid in [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "00_4bf92f3577b34da6a3ce929d0e0e4736_00f067aa0ba902b7_01",
+        ] {
+            assert_eq!(TraceContext::parse(invalid), None, "{invalid:?}");
+        }
+    }
+
+    /// A local service recording the `traceparent` of each call it receives.
+    async fn recording_service() -> (Upstream, Arc<Mutex<Vec<String>>>) {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let app = Router::new()
+            .route(
+                "/ping",
+                get(|State(seen): State<Arc<Mutex<Vec<String>>>>, headers: HeaderMap| async move {
+                    let traceparent = headers.get(TRACEPARENT_HEADER).map(|v| v.to_str().unwrap().to_string());
+                    seen.lock().unwrap().extend(traceparent);
+                    Json(json!({ "success": true, "data": "pong" }))
+                }),
+            )
+            .with_state(seen.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (Upstream::new(reqwest::Client::new(), format!("http://{}", addr)), seen)
+    }
+
+    async fn call(upstream: Upstream, traceparent: Option<&str>) -> Response {
+        let app = Router::new()
+            .route(
+                "/",
+                get(move || async move { Json(upstream.get::<Value>("/ping").await.unwrap()) }),
+            )
+            .layer(middleware::from_fn(propagate_trace));
+        let mut request = Request::get("/");
+        if let Some(traceparent) = traceparent {
+            request = request.header(TRACEPARENT_HEADER, traceparent);
+        }
+        app.oneshot(request.body(Body::empty()).unwrap()).await.unwrap()
+    }
+
+    #[test]
+    fn incoming_trace_reaches_span_and_outbound_calls() {
+        let (capture, _guard) = Capture::install();
+
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        runtime.block_on(async {
+            let (upstream, seen) = recording_service().await;
+            let response = call(upstream, Some(PARENT)).await;
+
+            let outbound = TraceContext::parse(&seen.lock().unwrap()[0]).unwrap();
+            assert_eq!(outbound.trace_id_hex(), "4bf92f3577b34da6a3ce929d0e0e4736");
+            assert_ne!(hex(&outbound.span_id), "00f067aa0ba902b7");
+            assert_eq!(response.headers()[TRACEPARENT_HEADER], outbound.to_string());
+        });
+
+        let spans = capture.spans();
+        let trace = spans.iter().find(|span| span.name == "trace").expect("trace span was not opened");
+        assert_eq!(trace.field("trace_id"), Some("4bf92f3577b34da6a3ce929d0e0e4736"));
+    }
+
+    #[tokio::test]
+    async fn missing_or_invalid_traceparent_starts_a_new_trace() {
+        let (upstream, seen) = recording_service().await;
+        call(upstream.clone(), None).await;
+        call(upstream, Some("garbage")).await;
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 2);
+        let first = TraceContext::parse(&seen[0]).unwrap();
+        let second = TraceContext::parse(&seen[1]).unwrap();
+        assert_ne!(first.trace_id, second.trace_id);
+        assert_eq!(first.flags, SAMPLED);
+
+        // Outside a request nothing is injected
+        assert_eq!(TraceContext::current(), None);
+    }
+}
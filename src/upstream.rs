@@ -0,0 +1,109 @@
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::time::Duration;
+
+use crate::trace_context;
+
+/// How long a proxied read may take before the handler falls back to local data.
+const UPSTREAM_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Client for the live Reflect API, shared through [`crate::AppState`] when
+/// `REFLECT_UPSTREAM_URL` is set.
+///
+/// Cheap to clone; clones share the same connection pool.
+#[derive(Debug, Clone)]
+pub struct Upstream {
+    http: reqwest::Client,
+    base_url: String,
+}
+
+/// The `{ success, data }` envelope every Reflect API read answers with.
+#[derive(Debug, Deserialize)]
+struct Envelope<T> {
+    success: bool,
+    data: Option<T>,
+}
+
+impl Upstream {
+    pub fn new(http: reqwest::Client, base_url: impl Into<String>) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        Self { http, base_url }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// `GET {base_url}{path}` and decode the `data` of a successful envelope.
+    pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, String> {
+        let url = format!("{}{}", self.base_url, path);
+        let envelope: Envelope<T> = trace_context::inject(self.http.get(&url))
+            .timeout(UPSTREAM_TIMEOUT)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| e.to_string())?
+            .json()
+            .await
+            .map_err(|e| e.to_string())?;
+        match envelope {
+            Envelope { success: true, data: Some(data) } => Ok(data),
+            _ => Err(format!("{} answered without data", url)),
+        }
+    }
+}
+
+/// Fetch `path` from `upstream` if one is configured.
+///
+/// `None` means the caller should serve its simulated / local data instead:
+/// either no upstream is configured, or it could not be reached (logged).
+pub async fn fetch_or_fallback<T: DeserializeOwned>(upstream: Option<&Upstream>, path: &str) -> Option<T> {
+    let upstream = upstream?;
+    match upstream.get(path).await {
+        Ok(data) => Some(data),
+        Err(error) => {
+            tracing::warn!(base_url = upstream.base_url(), path, %error, "Upstream Reflect API unavailable, serving local data");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use axum::routing::get;
+    use axum::{Json, Router};
+    use serde_json::{json, Value};
+
+    /// Serve `body` at `path` on a local port, standing in for the Reflect API.
+    pub(crate) async fn serve(path: &'static str, body: Value) -> Upstream {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = Router::new().route(path, get(move || async move { Json(body) }));
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        Upstream::new(reqwest::Client::new(), format!("http://{}/", addr))
+    }
+
+    /// An upstream nothing listens on.
+    pub(crate) fn unreachable() -> Upstream {
+        Upstream::new(reqwest::Client::new(), "http://127.0.0.1:1")
+    }
+
+    #[tokio::test]
+    async fn decodes_successful_envelopes() {
+        let upstream = serve("/numbers", json!({ "success": true, "data": [1, 2, 3] })).await;
+        assert_eq!(upstream.get::<Vec<u32>>("/numbers").await.unwrap(), vec![1, 2, 3]);
+
+        // Wrong shape and unknown paths are errors
+        assert!(upstream.get::<Vec<String>>("/numbers").await.is_err());
+        assert!(upstream.get::<Vec<u32>>("/missing").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn falls_back_when_unavailable() {
+        let failed = serve("/numbers", json!({ "success": false, "message": "down" })).await;
+        assert_eq!(fetch_or_fallback::<Vec<u32>>(Some(&failed), "/numbers").await, None);
+        assert_eq!(fetch_or_fallback::<Vec<u32>>(Some(&unreachable()), "/numbers").await, None);
+        assert_eq!(fetch_or_fallback::<Vec<u32>>(None, "/numbers").await, None);
+    }
+}
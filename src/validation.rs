@@ -0,0 +1,371 @@
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::Number;
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::num::{IntErrorKind, ParseIntError};
+use std::str::FromStr;
+
+use crate::amount::Amount;
+use crate::error::ApiError;
+use crate::stablecoin::transaction::{parse_pubkey, PUBKEY_LEN};
+use crate::timestamp::Timestamp;
+
+/// Reject `field` with a 422 carrying `message`: the request parsed, but the
+/// value is not acceptable (see [`ApiError`] for the status code policy).
+///
+/// Emits a `warn` event with the failing `field` so the fields clients get
+/// wrong can be aggregated; the route and request id come from the enclosing
+/// handler and request spans.
+pub fn invalid_field(field: &str, message: impl Into<String>) -> ApiError {
+    let message = message.into();
+    log_failure(field, &message);
+    ApiError::Unprocessable(message)
+}
+
+/// Reject `field` with a 400 carrying `message`: the raw value could not be
+/// parsed at all. Logged like [`invalid_field`].
+pub fn malformed_field(field: &str, message: impl Into<String>) -> ApiError {
+    let message = message.into();
+    log_failure(field, &message);
+    ApiError::BadRequest(message)
+}
+
+/// Base58-decode a Solana address in `field`, requiring exactly 32 bytes.
+///
+/// Anything else is rejected with a 422 via [`invalid_field`]: the request
+/// parsed, the address just does not name a key.
+pub fn validate_pubkey(field: &str, raw: &str) -> Result<[u8; PUBKEY_LEN], ApiError> {
+    parse_pubkey(raw).ok_or_else(|| {
+        invalid_field(field, format!("Invalid request data: {} is not a valid public key", field))
+    })
+}
+
+fn log_failure(field: &str, message: &str) {
+    tracing::warn!(target: "reflect_api::validation", field, %message, "Request validation failed");
+}
+
+/// Reject array payloads longer than `max` (see `REFLECT_MAX_BATCH_SIZE`).
+///
+/// Checked with the same bound as [`clamp_param`] in [`ClampMode::Reject`]
+/// mode, but reported as a 400 with the message every list-accepting endpoint
+/// shares: `"batch too large (max N)"`.
+pub fn ensure_batch_size(len: usize, max: usize) -> Result<(), ApiError> {
+    match bound(len, 0, max, ClampMode::Reject) {
+        Some(_) => Ok(()),
+        None => Err(malformed_field("batch", format!("batch too large (max {})", max))),
+    }
+}
+
+/// Upper bound for the `days` window on historical endpoints (ten years).
+///
+/// Larger windows are rejected with 422 rather than served truncated; see
+/// the README's endpoint notes.
+pub const MAX_HISTORY_DAYS: u32 = 3650;
+
+/// What [`clamp_param`] does with a value outside its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClampMode {
+    /// Silently pull the value to the nearest bound (e.g. `limit`).
+    Clamp,
+    /// Reject the request with 422 (e.g. `days`, where a different window
+    /// than the one asked for would be misleading).
+    Reject,
+}
+
+/// Bring `value` within `min..=max`, the one place bounded numeric
+/// parameters are checked. Rejections read
+/// `"Invalid request data: <field> must be between <min> and <max>"`.
+///
+/// A value that compares with neither bound (a float `NaN`) is rejected in
+/// either mode rather than clamped.
+pub fn clamp_param<T: PartialOrd + Display + Copy>(
+    field: &str,
+    value: T,
+    min: T,
+    max: T,
+    mode: ClampMode,
+) -> Result<T, ApiError> {
+    bound(value, min, max, mode).ok_or_else(|| {
+        invalid_field(field, format!("Invalid request data: {} must be between {} and {}", field, min, max))
+    })
+}
+
+/// The bounds check behind [`clamp_param`]: `value` within `min..=max`, or
+/// `None` when it must be rejected.
+fn bound<T: PartialOrd + Copy>(value: T, min: T, max: T, mode: ClampMode) -> Option<T> {
+    match (mode, value.partial_cmp(&min), value.partial_cmp(&max)) {
+        (_, Some(Ordering::Greater | Ordering::Equal), Some(Ordering::Less | Ordering::Equal)) => Some(value),
+        (ClampMode::Clamp, Some(Ordering::Less), Some(_)) => Some(min),
+        (ClampMode::Clamp, Some(_), Some(Ordering::Greater)) => Some(max),
+        _ => None,
+    }
+}
+
+/// Parse the optional query parameter `name`, falling back to `default` when absent.
+///
+/// Invalid values are rejected with the type's own `FromStr` message, e.g.
+/// `"Unknown cluster 'x'; expected one of mainnet, devnet"`.
+pub fn parse_param<T: FromStr<Err = String>>(name: &str, raw: Option<&str>, default: T) -> Result<T, ApiError> {
+    match raw {
+        Some(raw) => raw.parse().map_err(|message| malformed_field(name, message)),
+        None => Ok(default),
+    }
+}
+
+/// Parse an optional unsigned integer query parameter.
+///
+/// Values too large for `T` are reported as `"<name> out of range"`, anything
+/// else that is not a plain non-negative integer as
+/// `"<name> must be a non-negative integer"`.
+pub fn parse_int<T: FromStr<Err = ParseIntError>>(name: &str, raw: Option<&str>) -> Result<Option<T>, ApiError> {
+    let Some(raw) = raw else {
+        return Ok(None);
+    };
+    raw.trim().parse().map(Some).map_err(|e: ParseIntError| match e.kind() {
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => malformed_field(name, format!("{} out of range", name)),
+        _ => malformed_field(name, format!("{} must be a non-negative integer", name)),
+    })
+}
+
+/// Like [`parse_int`], but the parameter must be present.
+pub fn require_int<T: FromStr<Err = ParseIntError>>(name: &str, raw: Option<&str>) -> Result<T, ApiError> {
+    parse_int(name, raw)?.ok_or_else(|| malformed_field(name, format!("{} is required", name)))
+}
+
+/// Parse an optional RFC 3339 query parameter.
+///
+/// Invalid values are rejected with
+/// ``"Invalid `<name>`: expected an RFC 3339 timestamp"``.
+pub fn parse_timestamp(name: &str, raw: Option<&str>) -> Result<Option<Timestamp>, ApiError> {
+    match raw {
+        None => Ok(None),
+        Some(raw) => Timestamp::parse_rfc3339(raw)
+            .map(Some)
+            .ok_or_else(|| malformed_field(name, format!("Invalid `{}`: expected an RFC 3339 timestamp", name))),
+    }
+}
+
+/// Parse an optional, inclusive `[start, end]` time window from two query
+/// parameters. A start after the end is rejected with 422.
+pub fn parse_time_range(
+    (start_name, start): (&str, Option<&str>),
+    (end_name, end): (&str, Option<&str>),
+) -> Result<(Option<Timestamp>, Option<Timestamp>), ApiError> {
+    match (parse_timestamp(start_name, start)?, parse_timestamp(end_name, end)?) {
+        (Some(start), Some(end)) if start > end => Err(invalid_field(
+            start_name,
+            format!("Invalid time range: `{}` must not be after `{}`", start_name, end_name),
+        )),
+        range => Ok(range),
+    }
+}
+
+/// Validate a JSON amount in smallest units as a positive whole `u64`.
+///
+/// Zero and negative values are rejected with `"<field> must be positive"`,
+/// fractional values with `"<field> must be a whole number"`, and values too
+/// large for a `u64` (or for an `f64` to hold exactly) with `"<field> out of range"`.
+/// The rules are those of [`Amount`]'s deserialization.
+pub fn parse_amount(field: &str, amount: &Number) -> Result<u64, ApiError> {
+    Amount::deserialize(amount)
+        .map(Amount::get)
+        .map_err(|reason| invalid_field(field, format!("{} {}", field, reason)))
+}
+
+/// Trim a free-form `name` and reject it if nothing is left or it contains
+/// control characters. Returns the trimmed value to store.
+pub fn sanitize_name(raw: &str) -> Result<String, ApiError> {
+    sanitize_field("name", raw)
+}
+
+/// [`sanitize_name`] for any free-form string `field` (symbols, metadata URLs, ...).
+pub fn sanitize_field(field: &str, raw: &str) -> Result<String, ApiError> {
+    let trimmed = raw.trim();
+    let message = if trimmed.is_empty() {
+        format!("Invalid request data: {} must not be blank", field)
+    } else if trimmed.chars().any(char::is_control) {
+        format!("Invalid request data: {} must not contain control characters", field)
+    } else {
+        return Ok(trimmed.to_string());
+    };
+    Err(invalid_field(field, message))
+}
+
+/// Longest token name accepted, in characters.
+pub const MAX_NAME_LEN: usize = 64;
+
+/// Check a token symbol against the configured pattern (see `REFLECT_SYMBOL_PATTERN`).
+pub fn validate_symbol(symbol: &str, pattern: &Regex) -> Result<(), ApiError> {
+    if !pattern.is_match(symbol) {
+        return Err(invalid_field("symbol", format!(
+            "Invalid request data: symbol must match {}",
+            pattern.as_str()
+        )));
+    }
+    Ok(())
+}
+
+/// Check a token name is 1 to [`MAX_NAME_LEN`] printable characters.
+pub fn validate_name(name: &str) -> Result<(), ApiError> {
+    let len = name.chars().count();
+    if len == 0 || len > MAX_NAME_LEN || name.chars().any(char::is_control) {
+        return Err(invalid_field("name", format!(
+            "Invalid request data: name must be 1-{} printable characters",
+            MAX_NAME_LEN
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::StatusCode;
+    use serde_json::{json, Value};
+    use crate::cluster::Cluster;
+    use crate::rpc::Commitment;
+
+    #[test]
+    fn pubkey_must_be_32_base58_bytes() {
+        let mint = validate_pubkey("collateralMint", "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+        assert_eq!(mint.len(), 32);
+
+        for raw in ["not-a-pubkey", "0OIl", "3yZe7d", ""] {
+            let error = validate_pubkey("collateralMint", raw).unwrap_err();
+            assert_eq!(error.status(), StatusCode::UNPROCESSABLE_ENTITY, "{raw:?}");
+            assert_eq!(error.message(), "Invalid request data: collateralMint is not a valid public key");
+        }
+    }
+
+    #[test]
+    fn batch_size_boundary() {
+        assert!(ensure_batch_size(0, 3).is_ok());
+        assert!(ensure_batch_size(3, 3).is_ok());
+
+        let error = ensure_batch_size(4, 3).unwrap_err();
+        assert_eq!(error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(error.message(), "batch too large (max 3)");
+    }
+
+    #[test]
+    fn optional_params() {
+        assert_eq!(parse_param("commitment", None, Commitment::Finalized).unwrap(), Commitment::Finalized);
+        assert_eq!(
+            parse_param("commitment", Some("processed"), Commitment::Finalized).unwrap(),
+            Commitment::Processed
+        );
+
+        let error = parse_param("commitment", Some("max"), Commitment::Confirmed).unwrap_err();
+        assert_eq!(error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            error.message(),
+            "Unknown commitment 'max'; expected one of processed, confirmed, finalized"
+        );
+
+        assert_eq!(parse_param("cluster", Some("devnet"), Cluster::default()).unwrap(), Cluster::Devnet);
+        assert!(parse_param("cluster", Some("testnet"), Cluster::default()).is_err());
+    }
+
+    #[test]
+    fn int_params() {
+        assert_eq!(parse_int::<u32>("days", None).unwrap(), None);
+        assert_eq!(parse_int::<u32>("days", Some("30")).unwrap(), Some(30));
+
+        let error = parse_int::<u32>("days", Some("99999999999999")).unwrap_err();
+        assert_eq!(error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(error.message(), "days out of range");
+
+        let error = parse_int::<u32>("days", Some("ten")).unwrap_err();
+        assert_eq!(error.message(), "days must be a non-negative integer");
+
+        let error = parse_int::<u32>("days", Some("-1")).unwrap_err();
+        assert_eq!(error.message(), "days must be a non-negative integer");
+
+        let error = require_int::<u32>("stablecoin", None).unwrap_err();
+        assert_eq!(error.message(), "stablecoin is required");
+    }
+
+    #[test]
+    fn amounts() {
+        let amount = |value: Value| parse_amount("amount", value.as_number().unwrap());
+        assert_eq!(amount(json!(1_000_000)).unwrap(), 1_000_000);
+        assert_eq!(amount(json!(1000.0)).unwrap(), 1000);
+        assert_eq!(amount(json!(u64::MAX)).unwrap(), u64::MAX);
+
+        let error = amount(json!(1.5)).unwrap_err();
+        assert_eq!(error.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(error.message(), "amount must be a whole number");
+
+        for value in [json!(0), json!(-1), json!(-0.5)] {
+            let error = amount(value).unwrap_err();
+            assert_eq!(error.message(), "amount must be positive");
+        }
+        let error = amount(json!(1e20)).unwrap_err();
+        assert_eq!(error.message(), "amount out of range");
+    }
+
+    #[test]
+    fn symbols_and_names() {
+        let pattern = Regex::new(crate::config::DEFAULT_SYMBOL_PATTERN).unwrap();
+        assert!(validate_symbol("USDC+", &pattern).is_ok());
+
+        let error = validate_symbol("USDCPLUSTOKEN", &pattern).unwrap_err();
+        assert_eq!(error.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(error.message(), "Invalid request data: symbol must match ^[A-Z0-9+]{2,10}$");
+        assert!(validate_symbol("usd$", &pattern).is_err());
+        assert!(validate_symbol("U", &pattern).is_err());
+
+        assert!(validate_name("Reflect USD+").is_ok());
+        assert!(validate_name(&"a".repeat(MAX_NAME_LEN)).is_ok());
+        assert!(validate_name(&"a".repeat(MAX_NAME_LEN + 1)).is_err());
+        assert!(validate_name("").is_err());
+        let error = validate_name("Reflect\nUSD").unwrap_err();
+        assert_eq!(error.message(), "Invalid request data: name must be 1-64 printable characters");
+    }
+
+    #[test]
+    fn clamping_modes() {
+        assert_eq!(clamp_param("limit", 50, 1, 1000, ClampMode::Clamp).unwrap(), 50);
+        assert_eq!(clamp_param("limit", 0, 1, 1000, ClampMode::Clamp).unwrap(), 1);
+        assert_eq!(clamp_param("limit", 5000, 1, 1000, ClampMode::Clamp).unwrap(), 1000);
+
+        assert_eq!(clamp_param("days", 1u32, 1, 3650, ClampMode::Reject).unwrap(), 1);
+        assert_eq!(clamp_param("days", 3650u32, 1, 3650, ClampMode::Reject).unwrap(), 3650);
+        for days in [0u32, 3651] {
+            let error = clamp_param("days", days, 1, 3650, ClampMode::Reject).unwrap_err();
+            assert_eq!(error.status(), StatusCode::UNPROCESSABLE_ENTITY);
+            assert_eq!(error.message(), "Invalid request data: days must be between 1 and 3650");
+        }
+
+        // Works for any ordered type
+        assert_eq!(clamp_param("ratio", 1.5, 0.0, 1.0, ClampMode::Clamp).unwrap(), 1.0);
+        for mode in [ClampMode::Clamp, ClampMode::Reject] {
+            let error = clamp_param("ratio", f64::NAN, 0.0, 1.0, mode).unwrap_err();
+            assert_eq!(error.status(), StatusCode::UNPROCESSABLE_ENTITY);
+            assert_eq!(error.message(), "Invalid request data: ratio must be between 0 and 1");
+        }
+    }
+
+    #[test]
+    fn sanitized_strings() {
+        assert_eq!(sanitize_name("  Reflect USD+ ").unwrap(), "Reflect USD+");
+        assert_eq!(sanitize_field("symbol", "USDC+\t").unwrap(), "USDC+");
+
+        for blank in ["", "   ", "\t\n "] {
+            let error = sanitize_name(blank).unwrap_err();
+            assert_eq!(error.status(), StatusCode::UNPROCESSABLE_ENTITY);
+            assert_eq!(error.message(), "Invalid request data: name must not be blank");
+        }
+        for junk in ["Reflect\u{0}USD", "Reflect\nUSD", "a\u{1b}[31mred"] {
+            assert_eq!(
+                sanitize_name(junk).unwrap_err().message(),
+                "Invalid request data: name must not contain control characters"
+            );
+        }
+        assert_eq!(
+            sanitize_field("url", "https://x\r\ny").unwrap_err().message(),
+            "Invalid request data: url must not contain control characters"
+        );
+    }
+}
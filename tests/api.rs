@@ -0,0 +1,169 @@
+//! End-to-end tests driving the assembled router (routing, middleware and
+//! extraction together) with `oneshot`.
+
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use axum::Router;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+use reflect_api_rs::config::Config;
+use reflect_api_rs::{build_app, AppState};
+
+fn app() -> Router {
+    build_app(AppState::default())
+}
+
+async fn send(request: Request<Body>) -> (StatusCode, Value) {
+    let response = app().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[tokio::test]
+async fn health() {
+    let (status, body) = send(Request::get("/health").body(Body::empty()).unwrap()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["success"], true);
+    assert_eq!(body["message"], "API is running");
+}
+
+#[tokio::test]
+async fn available_stablecoins() {
+    let (status, body) = send(Request::get("/stablecoins/").body(Body::empty()).unwrap()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["success"], true);
+    assert_eq!(body["data"][0]["index"], 0);
+    assert_eq!(body["data"][0]["name"], "USDC+");
+    assert_eq!(body["meta"]["total"], 1);
+}
+
+#[tokio::test]
+async fn mint_transaction() {
+    let request = Request::post("/stablecoins/mint/tx?cluster=devnet")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({
+                "stablecoinIndex": 0,
+                "depositAmount": 1_000_000,
+                "signer": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+                "minimumReceived": 999_000,
+            })
+            .to_string(),
+        ))
+        .unwrap();
+
+    let response = app().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().contains_key("x-request-id"));
+
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["success"], true);
+    assert!(body["data"]["transaction"].as_str().is_some_and(|tx| !tx.is_empty()));
+}
+
+fn quote_request(uri: &str) -> Request<Body> {
+    Request::post(uri)
+        .header("content-type", "application/json")
+        .body(Body::from(json!({ "stablecoinIndex": 0, "depositAmount": 1_000_000 }).to_string()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn quote_routes() {
+    for quote_type in ["mint", "redeem"] {
+        let (status, body) = send(quote_request(&format!("/stablecoins/quote/{}", quote_type))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"], 999_000);
+    }
+
+    let (status, body) = send(quote_request("/stablecoins/quote/breakdown")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["gross"], 1_000_000);
+
+    let batch = json!({ "quote_type": "mint", "items": [{ "stablecoinIndex": 0, "depositAmount": 1_000_000 }] });
+    let request = Request::post("/stablecoins/quote/batch")
+        .header("content-type", "application/json")
+        .body(Body::from(batch.to_string()))
+        .unwrap();
+    let (status, body) = send(request).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"][0]["data"], 999_000);
+
+    let (status, body) = send(quote_request("/stablecoins/quote/bogus")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["message"], "Unknown quote type 'bogus'; expected mint or redeem");
+}
+
+#[tokio::test]
+async fn bulk_integration_configs_take_the_admin_key() {
+    let app = build_app(AppState::new(Config {
+        admin_api_keys: vec!["admin-key".to_string()],
+        integration_api_keys: vec!["integration-key".to_string()],
+        ..Config::default()
+    }));
+    let request = |key: &str| {
+        Request::post("/integrations/configs")
+            .header("content-type", "application/json")
+            .header("x-api-key", key)
+            .body(Body::from(json!({ "ids": ["int_404"] }).to_string()))
+            .unwrap()
+    };
+
+    let response = app.clone().oneshot(request("admin-key")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["data"]["int_404"]["status"], 404);
+
+    let response = app.oneshot(request("integration-key")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn unknown_route_is_404() {
+    let response = app()
+        .oneshot(Request::get("/stablecoins/nope").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn metrics_count_routes_but_not_scrapes() {
+    let app = app();
+    for uri in ["/health", "/health/", "/metrics"] {
+        let response = app.clone().oneshot(Request::get(uri).body(Body::empty()).unwrap()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let response = app.oneshot(Request::get("/metrics").body(Body::empty()).unwrap()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(body.contains(r#"reflect_http_requests_total{method="GET",route="/health"} 2"#), "{}", body);
+    assert!(!body.contains(r#"route="/metrics""#), "{}", body);
+}
+
+/// Every example body is accepted by the route it documents.
+#[tokio::test]
+async fn example_bodies_are_accepted() {
+    for (example, route) in [
+        ("/stablecoins/mint/tx/example", "/stablecoins/mint/tx?cluster=devnet"),
+        ("/stablecoins/burn/tx/example", "/stablecoins/burn/tx?cluster=devnet"),
+        ("/stablecoins/quote/mint/example", "/stablecoins/quote/mint"),
+    ] {
+        let (status, body) = send(Request::get(example).body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::OK, "{}", example);
+
+        let request = Request::post(route)
+            .header("content-type", "application/json")
+            .body(Body::from(body["data"].to_string()))
+            .unwrap();
+        let (status, body) = send(request).await;
+        assert_eq!(status, StatusCode::OK, "{}: {}", route, body);
+        assert_eq!(body["success"], true);
+    }
+}